@@ -0,0 +1,79 @@
+use std::fmt;
+
+use reqwest::header::{HeaderValue, InvalidHeaderValue};
+
+/// An API key that never appears in `Debug` output.
+///
+/// With the `secrecy` feature the key is held in a [`secrecy::SecretString`],
+/// which zeroizes its memory on drop.
+#[derive(Clone)]
+pub(crate) struct ApiKey {
+    #[cfg(feature = "secrecy")]
+    inner: secrecy::SecretString,
+    #[cfg(not(feature = "secrecy"))]
+    inner: String,
+}
+
+impl ApiKey {
+    /// Check that the key can be sent in an HTTP header.
+    pub fn validate(&self) -> crate::Result<()> {
+        if self.expose().is_ascii() && self.try_header_value().is_ok() {
+            Ok(())
+        } else {
+            Err(crate::Error::Config(
+                "API key must be valid ASCII".to_owned(),
+            ))
+        }
+    }
+
+    /// Returns the `Authorization` header value for this key.
+    ///
+    /// The value is marked as sensitive, so it is redacted from `Debug` output
+    /// of the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key was not checked with [`ApiKey::validate`] and
+    /// contains non-ASCII characters.
+    pub fn header_value(&self) -> HeaderValue {
+        self.try_header_value()
+            .expect("API key must be valid ASCII")
+    }
+
+    fn try_header_value(&self) -> Result<HeaderValue, InvalidHeaderValue> {
+        let mut value = HeaderValue::from_str(&format!("Bearer {}", self.expose()))?;
+        value.set_sensitive(true);
+        Ok(value)
+    }
+
+    #[cfg(feature = "secrecy")]
+    fn expose(&self) -> &str {
+        secrecy::ExposeSecret::expose_secret(&self.inner)
+    }
+
+    #[cfg(not(feature = "secrecy"))]
+    fn expose(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl From<String> for ApiKey {
+    // Only a no-op without the `secrecy` feature.
+    #[allow(clippy::useless_conversion)]
+    fn from(key: String) -> Self {
+        Self { inner: key.into() }
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl From<secrecy::SecretString> for ApiKey {
+    fn from(inner: secrecy::SecretString) -> Self {
+        Self { inner }
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ApiKey([REDACTED])")
+    }
+}
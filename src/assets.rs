@@ -0,0 +1,336 @@
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::meta::WithMeta;
+
+/// Service for the `/assets` endpoints.
+///
+/// Assets are images hosted by Lettr for use in templates, such as logos
+/// and banners. Upload them here and reference the returned
+/// [`url`](Asset::url) from template HTML.
+#[derive(Clone, Debug)]
+pub struct AssetsSvc(pub(crate) Arc<Config>);
+
+impl AssetsSvc {
+    /// Upload an image and return its hosted URL.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::assets::UploadAssetOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = UploadAssetOptions::from_path("templates/images/logo.png")?;
+    /// let asset = client.assets.upload(options).await?;
+    /// println!("<img src=\"{}\">", asset.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload(&self, options: UploadAssetOptions) -> crate::Result<Asset> {
+        self.upload_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`upload`](Self::upload), but also returns the HTTP status,
+    /// headers, and rate-limit information of the response.
+    pub async fn upload_with_meta(
+        &self,
+        options: UploadAssetOptions,
+    ) -> crate::Result<WithMeta<Asset>> {
+        let request = self
+            .0
+            .build(Method::POST, "/assets")
+            .query(&[("filename", options.filename.as_str())])
+            .bytes(&options.content_type, options.data);
+        let response: WithMeta<AssetResponseWrapper> = self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// List all uploaded assets.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// for asset in client.assets.list().await? {
+    ///     println!("{asset}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<Vec<Asset>> {
+        self.list_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<Asset>>> {
+        let request = self.0.build(Method::GET, "/assets");
+        let response: WithMeta<ListAssetsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.assets))
+    }
+
+    /// Retrieve a single asset.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let asset = client.assets.get("asset-id").await?;
+    /// println!("{}", asset.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, asset_id: &str) -> crate::Result<Asset> {
+        self.get_with_meta(asset_id).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, asset_id: &str) -> crate::Result<WithMeta<Asset>> {
+        let path = format!("/assets/{asset_id}");
+        let request = self.0.build(Method::GET, &path).route("/assets/{id}");
+        let response: WithMeta<AssetResponseWrapper> = self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Delete an asset. Emails already sent keep showing it for a while,
+    /// but new emails referencing its URL show a broken image.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.assets.delete("asset-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, asset_id: &str) -> crate::Result<()> {
+        self.delete_with_meta(asset_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`delete`](Self::delete), but also returns the HTTP status,
+    /// headers, and rate-limit information of the response.
+    pub async fn delete_with_meta(&self, asset_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/assets/{asset_id}");
+        let request = self.0.build(Method::DELETE, &path).route("/assets/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Drops cached `/assets` responses, so the next call fetches fresh
+    /// data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/assets");
+    }
+}
+
+/// The `/assets` operations of [`AssetsSvc`], as a trait.
+///
+/// Application code can depend on `dyn AssetsApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait AssetsApi: Send + Sync {
+    /// See [`AssetsSvc::upload`].
+    async fn upload(&self, options: UploadAssetOptions) -> crate::Result<Asset>;
+
+    /// See [`AssetsSvc::list`].
+    async fn list(&self) -> crate::Result<Vec<Asset>>;
+
+    /// See [`AssetsSvc::get`].
+    async fn get(&self, asset_id: &str) -> crate::Result<Asset>;
+
+    /// See [`AssetsSvc::delete`].
+    async fn delete(&self, asset_id: &str) -> crate::Result<()>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AssetsApi for AssetsSvc {
+    async fn upload(&self, options: UploadAssetOptions) -> crate::Result<Asset> {
+        AssetsSvc::upload(self, options).await
+    }
+
+    async fn list(&self) -> crate::Result<Vec<Asset>> {
+        AssetsSvc::list(self).await
+    }
+
+    async fn get(&self, asset_id: &str) -> crate::Result<Asset> {
+        AssetsSvc::get(self, asset_id).await
+    }
+
+    async fn delete(&self, asset_id: &str) -> crate::Result<()> {
+        AssetsSvc::delete(self, asset_id).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// Options for uploading an asset.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct UploadAssetOptions {
+    /// File name, kept in the hosted URL.
+    pub(crate) filename: String,
+
+    /// MIME type, e.g. `"image/png"`.
+    pub(crate) content_type: String,
+
+    /// File content.
+    pub(crate) data: Bytes,
+}
+
+impl UploadAssetOptions {
+    /// Creates new [`UploadAssetOptions`] for an image with the given file
+    /// name, MIME type, and content.
+    pub fn new(
+        filename: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<Bytes>,
+    ) -> Self {
+        Self {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Reads an image file, taking the file name from the path and the MIME
+    /// type from its extension.
+    ///
+    /// Recognizes `.png`, `.jpg`, `.jpeg`, `.gif`, `.webp`, and `.svg`;
+    /// other files are sent as `application/octet-stream`, which the API
+    /// rejects.
+    ///
+    /// Not available on WASM targets, which have no file system.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`](crate::Error::Io) if the file cannot be read.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+        let content_type = match extension.as_deref() {
+            Some("png") => "image/png",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("gif") => "image/gif",
+            Some("webp") => "image/webp",
+            Some("svg") => "image/svg+xml",
+            _ => "application/octet-stream",
+        };
+        Ok(Self::new(filename, content_type, data))
+    }
+
+    /// Returns the file name.
+    #[must_use]
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Returns the MIME type.
+    #[must_use]
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    /// Returns the file content.
+    #[must_use]
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListAssetsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListAssetsData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListAssetsData {
+    assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AssetResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: Asset,
+}
+
+/// An image hosted by Lettr.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Asset {
+    /// Asset ID.
+    pub id: String,
+    /// File name.
+    pub filename: String,
+    /// MIME type.
+    pub content_type: String,
+    /// File size in bytes.
+    #[serde(default)]
+    pub size: u64,
+    /// Public URL to reference from templates.
+    pub url: String,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Asset {
+    /// Creates an asset with the other fields empty. Set them as needed, e.g.
+    /// for a mock [`AssetsApi`].
+    pub fn new(id: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g. `logo.png (ast_123, 2048 bytes)`.
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}, {} bytes)", self.filename, self.id, self.size)
+    }
+}
@@ -0,0 +1,548 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::contacts::{ListContactsOptions, ListContactsResponse};
+use crate::meta::WithMeta;
+
+/// Service for the `/audiences` endpoints.
+///
+/// An audience is a named group of [contacts](crate::contacts). Static lists
+/// hold the contacts added to them; segments hold every contact whose
+/// attributes match their filter. Send to an audience with
+/// [`CreateEmailOptions::with_audience`](crate::CreateEmailOptions::with_audience).
+#[derive(Clone, Debug)]
+pub struct AudiencesSvc(pub(crate) Arc<Config>);
+
+impl AudiencesSvc {
+    /// List all audiences.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let audiences = client.audiences.list().await?;
+    /// for audience in &audiences {
+    ///     println!("{}: {} contacts", audience.name, audience.contact_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<Vec<Audience>> {
+        self.list_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<Audience>>> {
+        let request = self.0.build(Method::GET, "/audiences");
+        let response: WithMeta<ListAudiencesResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.audiences))
+    }
+
+    /// Create a new list or segment.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::audiences::CreateAudienceOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// // A segment of every contact on the pro plan.
+    /// let options = CreateAudienceOptions::new("Pro customers").with_filter("plan", "pro");
+    ///
+    /// let audience = client.audiences.create(options).await?;
+    /// println!("Audience created: {}", audience.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(&self, options: CreateAudienceOptions) -> crate::Result<Audience> {
+        self.create_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`create`](Self::create), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn create_with_meta(
+        &self,
+        options: CreateAudienceOptions,
+    ) -> crate::Result<WithMeta<Audience>> {
+        let request = self.0.build(Method::POST, "/audiences").json(&options);
+        let response: WithMeta<AudienceResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve a single audience.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let audience = client.audiences.get("audience-id").await?;
+    /// println!("{audience}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, audience_id: &str) -> crate::Result<Audience> {
+        self.get_with_meta(audience_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, audience_id: &str) -> crate::Result<WithMeta<Audience>> {
+        let path = format!("/audiences/{audience_id}");
+        let request = self.0.build(Method::GET, &path).route("/audiences/{id}");
+        let response: WithMeta<AudienceResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Delete an audience. Its contacts are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.audiences.delete("audience-id").await?;
+    /// println!("Audience deleted.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, audience_id: &str) -> crate::Result<()> {
+        self.delete_with_meta(audience_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`delete`](Self::delete), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn delete_with_meta(&self, audience_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/audiences/{audience_id}");
+        let request = self.0.build(Method::DELETE, &path).route("/audiences/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Add contacts to a static list.
+    ///
+    /// Contacts that are already members are ignored. Segment membership
+    /// follows the segment filter and cannot be changed directly.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client
+    ///     .audiences
+    ///     .add_contacts("audience-id", ["contact-1", "contact-2"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_contacts<T, A>(&self, audience_id: &str, contact_ids: T) -> crate::Result<()>
+    where
+        T: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        self.add_contacts_with_meta(audience_id, contact_ids)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`add_contacts`](Self::add_contacts), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn add_contacts_with_meta<T, A>(
+        &self,
+        audience_id: &str,
+        contact_ids: T,
+    ) -> crate::Result<WithMeta<()>>
+    where
+        T: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        let path = format!("/audiences/{audience_id}/contacts");
+        let body = MembersRequest::new(contact_ids);
+        let request = self
+            .0
+            .build(Method::POST, &path)
+            .route("/audiences/{id}/contacts")
+            .json(&body);
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Remove contacts from a static list. The contacts themselves are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client
+    ///     .audiences
+    ///     .remove_contacts("audience-id", ["contact-1"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_contacts<T, A>(
+        &self,
+        audience_id: &str,
+        contact_ids: T,
+    ) -> crate::Result<()>
+    where
+        T: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        self.remove_contacts_with_meta(audience_id, contact_ids)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`remove_contacts`](Self::remove_contacts), but also returns the
+    /// HTTP status, headers, and rate-limit information of the response.
+    pub async fn remove_contacts_with_meta<T, A>(
+        &self,
+        audience_id: &str,
+        contact_ids: T,
+    ) -> crate::Result<WithMeta<()>>
+    where
+        T: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        let path = format!("/audiences/{audience_id}/contacts");
+        let body = MembersRequest::new(contact_ids);
+        let request = self
+            .0
+            .build(Method::DELETE, &path)
+            .route("/audiences/{id}/contacts")
+            .json(&body);
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// List the contacts in an audience.
+    ///
+    /// Filter by email to check whether a single address is a member.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::contacts::ListContactsOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = ListContactsOptions::new().email("jane@example.com");
+    /// let members = client.audiences.contacts("audience-id", options).await?;
+    /// println!("member: {}", !members.contacts.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn contacts(
+        &self,
+        audience_id: &str,
+        options: ListContactsOptions,
+    ) -> crate::Result<ListContactsResponse> {
+        self.contacts_with_meta(audience_id, options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`contacts`](Self::contacts), but also returns the HTTP status,
+    /// headers, and rate-limit information of the response.
+    pub async fn contacts_with_meta(
+        &self,
+        audience_id: &str,
+        options: ListContactsOptions,
+    ) -> crate::Result<WithMeta<ListContactsResponse>> {
+        let path = format!("/audiences/{audience_id}/contacts");
+        let mut request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/audiences/{id}/contacts");
+
+        if let Some(email) = options.email {
+            request = request.query(&[("email", email)]);
+        }
+        if let Some(per_page) = options.per_page {
+            request = request.query(&[("per_page", per_page.to_string())]);
+        }
+        if let Some(page) = options.page {
+            request = request.query(&[("page", page.to_string())]);
+        }
+
+        let response: WithMeta<ListAudienceContactsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Drops cached `/audiences` responses, so the next call fetches fresh
+    /// data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/audiences");
+    }
+}
+
+/// The `/audiences` operations of [`AudiencesSvc`], as a trait.
+///
+/// Application code can depend on `dyn AudiencesApi` instead of the concrete
+/// service, so unit tests can pass in a mock. Contact IDs are taken as a
+/// slice here to keep the trait object safe.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait AudiencesApi: Send + Sync {
+    /// See [`AudiencesSvc::list`].
+    async fn list(&self) -> crate::Result<Vec<Audience>>;
+
+    /// See [`AudiencesSvc::create`].
+    async fn create(&self, options: CreateAudienceOptions) -> crate::Result<Audience>;
+
+    /// See [`AudiencesSvc::get`].
+    async fn get(&self, audience_id: &str) -> crate::Result<Audience>;
+
+    /// See [`AudiencesSvc::delete`].
+    async fn delete(&self, audience_id: &str) -> crate::Result<()>;
+
+    /// See [`AudiencesSvc::add_contacts`].
+    async fn add_contacts(&self, audience_id: &str, contact_ids: &[String]) -> crate::Result<()>;
+
+    /// See [`AudiencesSvc::remove_contacts`].
+    async fn remove_contacts(&self, audience_id: &str, contact_ids: &[String])
+        -> crate::Result<()>;
+
+    /// See [`AudiencesSvc::contacts`].
+    async fn contacts(
+        &self,
+        audience_id: &str,
+        options: ListContactsOptions,
+    ) -> crate::Result<ListContactsResponse>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AudiencesApi for AudiencesSvc {
+    async fn list(&self) -> crate::Result<Vec<Audience>> {
+        AudiencesSvc::list(self).await
+    }
+
+    async fn create(&self, options: CreateAudienceOptions) -> crate::Result<Audience> {
+        AudiencesSvc::create(self, options).await
+    }
+
+    async fn get(&self, audience_id: &str) -> crate::Result<Audience> {
+        AudiencesSvc::get(self, audience_id).await
+    }
+
+    async fn delete(&self, audience_id: &str) -> crate::Result<()> {
+        AudiencesSvc::delete(self, audience_id).await
+    }
+
+    async fn add_contacts(&self, audience_id: &str, contact_ids: &[String]) -> crate::Result<()> {
+        AudiencesSvc::add_contacts(self, audience_id, contact_ids.iter().cloned()).await
+    }
+
+    async fn remove_contacts(
+        &self,
+        audience_id: &str,
+        contact_ids: &[String],
+    ) -> crate::Result<()> {
+        AudiencesSvc::remove_contacts(self, audience_id, contact_ids.iter().cloned()).await
+    }
+
+    async fn contacts(
+        &self,
+        audience_id: &str,
+        options: ListContactsOptions,
+    ) -> crate::Result<ListContactsResponse> {
+        AudiencesSvc::contacts(self, audience_id, options).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// Options for creating a new audience.
+///
+/// Without a filter the audience is a static list that contacts are added to
+/// explicitly. With one or more filters it is a segment of every contact
+/// whose attributes match all of them.
+#[must_use]
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateAudienceOptions {
+    /// Audience name.
+    pub(crate) name: String,
+
+    /// Description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    /// Attribute values a contact must have to be in the segment.
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub(crate) filter: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CreateAudienceOptions {
+    /// Creates new [`CreateAudienceOptions`] for a static list with the given
+    /// name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            filter: serde_json::Map::new(),
+        }
+    }
+
+    /// Sets the description.
+    #[inline]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Makes the audience a segment of contacts whose `attribute` equals
+    /// `value`. Several filters must all match.
+    #[inline]
+    pub fn with_filter(
+        mut self,
+        attribute: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.filter.insert(attribute.into(), value.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct MembersRequest {
+    contact_ids: Vec<String>,
+}
+
+impl MembersRequest {
+    fn new<T, A>(contact_ids: T) -> Self
+    where
+        T: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        Self {
+            contact_ids: contact_ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListAudiencesResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListAudiencesData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListAudiencesData {
+    audiences: Vec<Audience>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AudienceResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: Audience,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListAudienceContactsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListContactsResponse,
+}
+
+/// A named list or segment of contacts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Audience {
+    /// Audience ID.
+    pub id: String,
+    /// Audience name.
+    pub name: String,
+    /// Description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Segment filter; empty for a static list.
+    #[serde(default)]
+    pub filter: serde_json::Map<String, serde_json::Value>,
+    /// Number of contacts in the audience.
+    #[serde(default)]
+    pub contact_count: u64,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Last update timestamp.
+    pub updated_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Audience {
+    /// Creates an audience with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`AudiencesApi`].
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns `true` if membership follows a filter rather than explicit
+    /// additions.
+    #[must_use]
+    pub fn is_segment(&self) -> bool {
+        !self.filter.is_empty()
+    }
+}
+
+/// Formats as a one-line summary, e.g. `Newsletter (a_123, 42 contacts)`.
+impl fmt::Display for Audience {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.is_segment() { "segment, " } else { "" };
+        let noun = if self.contact_count == 1 {
+            "contact"
+        } else {
+            "contacts"
+        };
+        write!(
+            f,
+            "{} ({}, {kind}{} {noun})",
+            self.name, self.id, self.contact_count
+        )
+    }
+}
@@ -0,0 +1,354 @@
+//! Command-line client for the Lettr API.
+//!
+//! Reads its settings from the `LETTR_*` environment variables (see
+//! [`LettrBuilder::from_env_prefixed`](lettr::LettrBuilder::from_env_prefixed))
+//! and prints lists one item per line and other responses in debug format.
+//! Run `lettr help` for usage.
+
+use std::fmt;
+use std::process::ExitCode;
+
+use lettr::blocking::Client;
+use lettr::types::{CreateTemplateOptions, ListEmailsOptions, ListTemplatesOptions};
+use lettr::CreateEmailOptions;
+
+const USAGE: &str = "\
+Usage: lettr <command> [arguments]
+
+Commands:
+  send --from <address> --to <address>... --subject <subject>
+       [--from-name <name>] [--reply-to <address>] [--text <text>]
+       [--html <html>] [--template <slug>] [--var <key=value>]...
+  emails list [--per-page <n>] [--cursor <cursor>] [--recipients <address>]
+              [--from-date <date>] [--to-date <date>]
+  emails get <request-id>
+  domains list
+  domains get <domain>
+  domains create <domain>
+  domains delete <domain>
+  webhooks list
+  webhooks get <webhook-id>
+  webhooks validate <url>
+  templates list [--project-id <id>] [--per-page <n>] [--page <n>]
+  templates push <name> (--html <file> | --json <file>)
+                 [--project-id <id>] [--folder-id <id>]
+  health
+  auth
+  help
+
+The client is configured from the environment: LETTR_API_KEY (required),
+LETTR_REGION, LETTR_BASE_URL, LETTR_API_VERSION, LETTR_TIMEOUT_SECS,
+LETTR_CONNECT_TIMEOUT_SECS, and LETTR_MAX_RETRIES.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Usage(message)) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    let (command, rest) = match args {
+        [] => return Err(CliError::Usage("missing command".to_owned())),
+        [command, rest @ ..] => (command.as_str(), rest),
+    };
+    if matches!(command, "help" | "--help" | "-h") {
+        println!("{USAGE}");
+        return Ok(());
+    }
+
+    let (subcommand, rest) = match command {
+        "emails" | "domains" | "webhooks" | "templates" => match rest {
+            [] => return Err(CliError::Usage(format!("missing `{command}` subcommand"))),
+            [subcommand, rest @ ..] => (subcommand.as_str(), rest),
+        },
+        _ => ("", rest),
+    };
+    let mut args = Args::parse(rest)?;
+
+    let client = || Client::from_env_prefixed("LETTR_");
+    match (command, subcommand) {
+        ("send", _) => {
+            let [] = args.positionals()?;
+            let to = args.all("to");
+            if to.is_empty() {
+                return Err(CliError::Usage("missing `--to`".to_owned()));
+            }
+            let mut email =
+                CreateEmailOptions::new(args.required("from")?, to, args.required("subject")?);
+            if let Some(name) = args.take("from-name") {
+                email = email.with_from_name(name);
+            }
+            if let Some(address) = args.take("reply-to") {
+                email = email.with_reply_to(address);
+            }
+            if let Some(text) = args.take("text") {
+                email = email.with_text(text);
+            }
+            if let Some(html) = args.take("html") {
+                email = email.with_html(html);
+            }
+            if let Some(slug) = args.take("template") {
+                email = email.with_template(slug);
+            }
+            for var in args.all("var") {
+                let (key, value) = var.split_once('=').ok_or_else(|| {
+                    CliError::Usage(format!("`--var {var}` is not of the form key=value"))
+                })?;
+                email = email.with_substitution(key, value);
+            }
+            args.finish()?;
+            let email = email.try_build().map_err(lettr::Error::from)?;
+            println!("{}", client()?.emails.send(email)?);
+        }
+        ("emails", "list") => {
+            let [] = args.positionals()?;
+            let mut options = ListEmailsOptions::new();
+            if let Some(per_page) = args.number("per-page")? {
+                options = options.per_page(per_page);
+            }
+            if let Some(cursor) = args.take("cursor") {
+                options = options.cursor(cursor);
+            }
+            if let Some(recipients) = args.take("recipients") {
+                options = options.recipients(recipients);
+            }
+            if let Some(from) = args.take("from-date") {
+                options = options.from_date(from);
+            }
+            if let Some(to) = args.take("to-date") {
+                options = options.to_date(to);
+            }
+            args.finish()?;
+            let response = client()?.emails.list(options)?;
+            print_lines(&response.results);
+            if let Some(cursor) = response.pagination.next_cursor {
+                println!("(more with --cursor {cursor})");
+            }
+        }
+        ("emails", "get") => {
+            let [request_id] = args.positionals()?;
+            args.finish()?;
+            print(client()?.emails.get(&request_id)?);
+        }
+        ("domains", "list") => {
+            let [] = args.positionals()?;
+            args.finish()?;
+            print_lines(client()?.domains.list()?);
+        }
+        ("domains", "get") => {
+            let [domain] = args.positionals()?;
+            args.finish()?;
+            print(client()?.domains.get(&domain)?);
+        }
+        ("domains", "create") => {
+            let [domain] = args.positionals()?;
+            args.finish()?;
+            print(client()?.domains.create(&domain)?);
+        }
+        ("domains", "delete") => {
+            let [domain] = args.positionals()?;
+            args.finish()?;
+            client()?.domains.delete(&domain)?;
+            println!("Deleted {domain}");
+        }
+        ("webhooks", "list") => {
+            let [] = args.positionals()?;
+            args.finish()?;
+            print_lines(client()?.webhooks.list()?);
+        }
+        ("webhooks", "get") => {
+            let [webhook_id] = args.positionals()?;
+            args.finish()?;
+            print(client()?.webhooks.get(&webhook_id)?);
+        }
+        ("webhooks", "validate") => {
+            let [url] = args.positionals()?;
+            args.finish()?;
+            println!("{}", client()?.webhooks.validate_url(&url)?);
+        }
+        ("templates", "list") => {
+            let [] = args.positionals()?;
+            let mut options = ListTemplatesOptions::new();
+            if let Some(project_id) = args.number("project-id")? {
+                options = options.project_id(project_id);
+            }
+            if let Some(per_page) = args.number("per-page")? {
+                options = options.per_page(per_page);
+            }
+            if let Some(page) = args.number("page")? {
+                options = options.page(page);
+            }
+            args.finish()?;
+            print_lines(client()?.templates.list(options)?.templates);
+        }
+        ("templates", "push") => {
+            let [name] = args.positionals()?;
+            let mut template = CreateTemplateOptions::new(name);
+            template = match (args.take("html"), args.take("json")) {
+                (Some(path), None) => template.with_html(read_file(&path)?),
+                (None, Some(path)) => template.with_json(read_file(&path)?),
+                _ => {
+                    return Err(CliError::Usage(
+                        "pass exactly one of `--html` and `--json`".to_owned(),
+                    ))
+                }
+            };
+            if let Some(project_id) = args.number("project-id")? {
+                template = template.with_project_id(project_id);
+            }
+            if let Some(folder_id) = args.number("folder-id")? {
+                template = template.with_folder_id(folder_id);
+            }
+            args.finish()?;
+            let template = template.try_build().map_err(lettr::Error::from)?;
+            print(client()?.templates.create(template)?);
+        }
+        ("health", _) => {
+            let [] = args.positionals()?;
+            args.finish()?;
+            print(client()?.health()?);
+        }
+        ("auth", _) => {
+            let [] = args.positionals()?;
+            args.finish()?;
+            print(client()?.auth_check()?);
+        }
+        (command, "") => return Err(CliError::Usage(format!("unknown command `{command}`"))),
+        (command, subcommand) => {
+            return Err(CliError::Usage(format!(
+                "unknown command `{command} {subcommand}`"
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn print(value: impl fmt::Debug) {
+    println!("{value:#?}");
+}
+
+fn print_lines<T: fmt::Display>(items: impl IntoIterator<Item = T>) {
+    for item in items {
+        println!("{item}");
+    }
+}
+
+fn read_file(path: &str) -> Result<String, CliError> {
+    std::fs::read_to_string(path).map_err(|e| CliError::Io(format!("cannot read {path}: {e}")))
+}
+
+/// Parsed `--name value` options and positional arguments.
+struct Args {
+    options: Vec<(String, String)>,
+    positionals: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: &[String]) -> Result<Self, CliError> {
+        let mut options = Vec::new();
+        let mut positionals = Vec::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| CliError::Usage(format!("missing value for `--{name}`")))?;
+                    options.push((name.to_owned(), value.clone()));
+                }
+                None => positionals.push(arg.clone()),
+            }
+        }
+        Ok(Self {
+            options,
+            positionals,
+        })
+    }
+
+    /// Takes the positional arguments, which must number exactly `N`.
+    fn positionals<const N: usize>(&mut self) -> Result<[String; N], CliError> {
+        std::mem::take(&mut self.positionals)
+            .try_into()
+            .map_err(|positionals: Vec<String>| {
+                CliError::Usage(format!(
+                    "expected {N} argument(s), got {}",
+                    positionals.len()
+                ))
+            })
+    }
+
+    /// Takes every value of a repeatable option.
+    fn all(&mut self, name: &str) -> Vec<String> {
+        let (matching, rest) = std::mem::take(&mut self.options)
+            .into_iter()
+            .partition(|(option, _)| option == name);
+        self.options = rest;
+        matching.into_iter().map(|(_, value)| value).collect()
+    }
+
+    /// Takes the last value of an option.
+    fn take(&mut self, name: &str) -> Option<String> {
+        self.all(name).pop()
+    }
+
+    fn required(&mut self, name: &str) -> Result<String, CliError> {
+        self.take(name)
+            .ok_or_else(|| CliError::Usage(format!("missing `--{name}`")))
+    }
+
+    fn number<T: std::str::FromStr>(&mut self, name: &str) -> Result<Option<T>, CliError> {
+        self.take(name)
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    CliError::Usage(format!("`--{name}` must be a number, got `{value}`"))
+                })
+            })
+            .transpose()
+    }
+
+    /// Fails if any option was not consumed.
+    fn finish(self) -> Result<(), CliError> {
+        match self.options.first() {
+            Some((name, _)) => Err(CliError::Usage(format!("unknown option `--{name}`"))),
+            None => Ok(()),
+        }
+    }
+}
+
+enum CliError {
+    Usage(String),
+    Io(String),
+    Lettr(lettr::Error),
+}
+
+impl From<lettr::Error> for CliError {
+    fn from(err: lettr::Error) -> Self {
+        Self::Lettr(err)
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usage(message) | Self::Io(message) => f.write_str(message),
+            Self::Lettr(err) => {
+                write!(f, "{err}")?;
+                if let lettr::Error::Validation(validation) = err {
+                    for (field, message) in validation.clone().into_field_messages() {
+                        write!(f, "\n  {field}: {message}")?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
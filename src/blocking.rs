@@ -0,0 +1,1759 @@
+//! A blocking client that can be used next to the async one.
+//!
+//! [`Client`] wraps an async [`Lettr`](crate::Lettr) client together with
+//! its own single-threaded tokio runtime, so a mostly-async application can
+//! still make a Lettr call from synchronous code (a CLI subcommand, a
+//! `Drop` impl, a callback from a C library).
+//!
+//! Like `reqwest::blocking`, the client must not be used or dropped inside
+//! an async runtime: it panics there, since blocking would stall the
+//! runtime's worker thread.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use lettr::CreateEmailOptions;
+//!
+//! fn main() -> lettr::Result<()> {
+//!     let client = lettr::blocking::Client::new("your-api-key");
+//!
+//!     let email = CreateEmailOptions::new("from@example.com", ["to@example.com"], "Hello!")
+//!         .with_text("Hello World!");
+//!
+//!     let response = client.emails.send(email)?;
+//!     println!("Request ID: {}", response.request_id);
+//!     Ok(())
+//! }
+//! ```
+
+pub use self::client::{
+    AssetsSvc, AudiencesSvc, BlocklistsSvc, BroadcastsSvc, Client, ContactsSvc, DomainsSvc,
+    EmailsSvc, EventStream, ExportsSvc, InboundSvc, IpPoolsSvc, SmtpCredentialsSvc, StatsSvc,
+    TeamSvc, TemplatesSvc, UnsubscribeGroupsSvc, WebhooksSvc,
+};
+
+/// Wraps a client built by [`LettrBuilder`](crate::LettrBuilder).
+pub(crate) fn from_async(client: crate::Lettr) -> crate::Result<Client> {
+    Client::from_async(client)
+}
+
+mod client {
+    use std::future::Future;
+    use std::io::Write;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::runtime::Runtime;
+
+    use crate::assets::{Asset, UploadAssetOptions};
+    use crate::audiences::{Audience, CreateAudienceOptions};
+    use crate::blocklists::BlocklistStatus;
+    use crate::broadcasts::{
+        Broadcast, BroadcastStats, CreateBroadcastOptions, ListBroadcastsOptions,
+        ListBroadcastsResponse,
+    };
+    use crate::client::{AuthCheckResponse, HealthResponse, Readiness};
+    use crate::contacts::{
+        Contact, CreateContactOptions, ListContactsOptions, ListContactsResponse,
+        UpdateContactOptions,
+    };
+    use crate::domains::{CreateDomainResponse, Domain, DomainDetail};
+    use crate::emails::{
+        CreateEmailOptions, EmailEvent, EmailEventDetail, GetEmailResponse, LinkClicks,
+        ListBouncesOptions, ListBouncesResponse, ListEmailsOptions, ListEmailsResponse,
+        RecipientErasure, SendEmailResponse,
+    };
+    use crate::event_stream::{EventBatch, StreamEventsOptions};
+    use crate::exports::{CreateExportOptions, ExportJob};
+    use crate::inbound::{
+        CreateInboundRouteOptions, CreateInboundRuleOptions, InboundMessage, InboundRoute,
+        InboundRule, ListInboundMessagesOptions, ListInboundMessagesResponse,
+    };
+    use crate::ip_pools::IpPool;
+    use crate::smtp::{CreateSmtpCredentialOptions, NewSmtpCredential, SmtpCredential};
+    use crate::stats::{StatsOptions, StatsResponse};
+    use crate::team::{InviteTeamMemberOptions, TeamInvitation, TeamMember, TeamRole};
+    use crate::templates::{
+        CreateTemplateOptions, CreateTemplateResponse, ListTemplatesOptions, ListTemplatesResponse,
+        Template,
+    };
+    use crate::unsubscribe_groups::{
+        CreateUnsubscribeGroupOptions, RecipientPreferences, UnsubscribeGroup,
+    };
+    use crate::webhooks::{Webhook, WebhookSecret, WebhookUrlValidation};
+    use crate::{Lettr, LettrBuilder, RequestOptions, WithMeta};
+
+    /// Runtime shared by a blocking client and its services.
+    #[derive(Debug, Clone)]
+    struct Blocker(Arc<Runtime>);
+
+    impl Blocker {
+        fn new() -> crate::Result<Self> {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map(|runtime| Self(Arc::new(runtime)))
+                .map_err(|e| crate::Error::Config(format!("failed to start runtime: {e}")))
+        }
+
+        fn wait<F: Future>(&self, future: F) -> F::Output {
+            self.0.block_on(future)
+        }
+    }
+
+    /// The blocking Lettr API client.
+    ///
+    /// Has the same services and methods as [`Lettr`], without `async`.
+    /// Create one with [`Client::new`] or
+    /// [`LettrBuilder::build_blocking`](crate::LettrBuilder::build_blocking).
+    /// Clones share the connection pool, limiters, and runtime.
+    #[derive(Clone, Debug)]
+    pub struct Client {
+        /// Email sending, listing, and retrieval.
+        pub emails: EmailsSvc,
+        /// Domain management.
+        pub domains: DomainsSvc,
+        /// Webhook listing and retrieval.
+        pub webhooks: WebhooksSvc,
+        /// Template listing and creation.
+        pub templates: TemplatesSvc,
+        /// Contact management.
+        pub contacts: ContactsSvc,
+        /// Audience lists and segments.
+        pub audiences: AudiencesSvc,
+        /// Campaigns sent to an audience.
+        pub broadcasts: BroadcastsSvc,
+        /// Aggregate sending statistics.
+        pub stats: StatsSvc,
+        /// SMTP relay credentials.
+        pub smtp_credentials: SmtpCredentialsSvc,
+        /// Inbound routes and received messages.
+        pub inbound: InboundSvc,
+        /// Dedicated IP pools.
+        pub ip_pools: IpPoolsSvc,
+        /// Team members and invitations.
+        pub team: TeamSvc,
+        /// Asynchronous event exports.
+        pub exports: ExportsSvc,
+        /// Unsubscribe groups and recipient preferences.
+        pub unsubscribe_groups: UnsubscribeGroupsSvc,
+        /// Blocklist status of dedicated IPs and sending domains.
+        pub blocklists: BlocklistsSvc,
+        /// Images hosted for use in templates.
+        pub assets: AssetsSvc,
+
+        inner: Lettr,
+        blocker: Blocker,
+    }
+
+    impl Client {
+        /// Creates a new blocking client with the given API key.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the API key contains non-ASCII characters.
+        #[must_use]
+        pub fn new(api_key: &str) -> Self {
+            Self::builder(api_key).build_blocking()
+        }
+
+        /// Creates a new blocking client with the given API key, returning an
+        /// error instead of panicking on an invalid key.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::Config`](crate::Error::Config) if the API key
+        /// contains non-ASCII characters or the HTTP client or runtime cannot
+        /// be initialized.
+        pub fn try_new(api_key: &str) -> crate::Result<Self> {
+            Self::builder(api_key).try_build_blocking()
+        }
+
+        /// Creates a [`LettrBuilder`]; finish it with
+        /// [`build_blocking`](LettrBuilder::build_blocking).
+        pub fn builder(api_key: &str) -> LettrBuilder {
+            LettrBuilder::new(api_key)
+        }
+
+        /// Creates a new blocking client from the `LETTR_API_KEY` environment
+        /// variable.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the environment variable is not set or the API key is
+        /// invalid.
+        #[must_use]
+        pub fn from_env() -> Self {
+            Self::try_from_env().unwrap_or_else(|e| panic!("{e}"))
+        }
+
+        /// Creates a new blocking client from the `LETTR_API_KEY` environment
+        /// variable, returning an error instead of panicking.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::Config`](crate::Error::Config) if the environment
+        /// variable is not set or the API key is invalid.
+        pub fn try_from_env() -> crate::Result<Self> {
+            let api_key = std::env::var("LETTR_API_KEY").map_err(|_| {
+                crate::Error::Config("LETTR_API_KEY environment variable not set".to_owned())
+            })?;
+            Self::try_new(&api_key)
+        }
+
+        /// Creates a new blocking client from a TOML config file.
+        ///
+        /// See [`LettrBuilder::from_config`] for the file format. Only
+        /// available with the `toml` feature.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::Config`](crate::Error::Config) if the file cannot
+        /// be read, is invalid, or holds no API key.
+        #[cfg(feature = "toml")]
+        pub fn from_config(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+            LettrBuilder::from_config(path)?.try_build_blocking()
+        }
+
+        /// Creates a new blocking client from environment variables starting
+        /// with `prefix`.
+        ///
+        /// See [`LettrBuilder::from_env_prefixed`] for the variable names.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::Config`](crate::Error::Config) if a variable holds
+        /// an invalid value or the API key variable is not set.
+        pub fn from_env_prefixed(prefix: &str) -> crate::Result<Self> {
+            LettrBuilder::from_env_prefixed(prefix)?.try_build_blocking()
+        }
+
+        pub(crate) fn from_async(inner: Lettr) -> crate::Result<Self> {
+            Ok(Self::with_blocker(inner, Blocker::new()?))
+        }
+
+        fn with_blocker(inner: Lettr, blocker: Blocker) -> Self {
+            Self {
+                emails: EmailsSvc {
+                    inner: inner.emails.clone(),
+                    blocker: blocker.clone(),
+                },
+                domains: DomainsSvc {
+                    inner: inner.domains.clone(),
+                    blocker: blocker.clone(),
+                },
+                webhooks: WebhooksSvc {
+                    inner: inner.webhooks.clone(),
+                    blocker: blocker.clone(),
+                },
+                templates: TemplatesSvc {
+                    inner: inner.templates.clone(),
+                    blocker: blocker.clone(),
+                },
+                contacts: ContactsSvc {
+                    inner: inner.contacts.clone(),
+                    blocker: blocker.clone(),
+                },
+                audiences: AudiencesSvc {
+                    inner: inner.audiences.clone(),
+                    blocker: blocker.clone(),
+                },
+                broadcasts: BroadcastsSvc {
+                    inner: inner.broadcasts.clone(),
+                    blocker: blocker.clone(),
+                },
+                stats: StatsSvc {
+                    inner: inner.stats.clone(),
+                    blocker: blocker.clone(),
+                },
+                smtp_credentials: SmtpCredentialsSvc {
+                    inner: inner.smtp_credentials.clone(),
+                    blocker: blocker.clone(),
+                },
+                inbound: InboundSvc {
+                    inner: inner.inbound.clone(),
+                    blocker: blocker.clone(),
+                },
+                ip_pools: IpPoolsSvc {
+                    inner: inner.ip_pools.clone(),
+                    blocker: blocker.clone(),
+                },
+                team: TeamSvc {
+                    inner: inner.team.clone(),
+                    blocker: blocker.clone(),
+                },
+                exports: ExportsSvc {
+                    inner: inner.exports.clone(),
+                    blocker: blocker.clone(),
+                },
+                unsubscribe_groups: UnsubscribeGroupsSvc {
+                    inner: inner.unsubscribe_groups.clone(),
+                    blocker: blocker.clone(),
+                },
+                blocklists: BlocklistsSvc {
+                    inner: inner.blocklists.clone(),
+                    blocker: blocker.clone(),
+                },
+                assets: AssetsSvc {
+                    inner: inner.assets.clone(),
+                    blocker: blocker.clone(),
+                },
+                inner,
+                blocker,
+            }
+        }
+
+        /// Blocking version of [`Lettr::clear_cache`].
+        pub fn clear_cache(&self) {
+            self.inner.clear_cache();
+        }
+
+        /// Blocking version of [`Lettr::with_options`].
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::Config`](crate::Error::Config) if the API key
+        /// override contains non-ASCII characters.
+        pub fn with_options(&self, options: RequestOptions) -> crate::Result<Self> {
+            let inner = self.inner.with_options(options)?;
+            Ok(Self::with_blocker(inner, self.blocker.clone()))
+        }
+
+        /// Blocking version of [`Lettr::shutdown`].
+        pub fn shutdown(&self, timeout: Duration) -> bool {
+            self.blocker.wait(self.inner.shutdown(timeout))
+        }
+
+        /// Blocking version of [`Lettr::health`].
+        pub fn health(&self) -> crate::Result<HealthResponse> {
+            self.blocker.wait(self.inner.health())
+        }
+
+        /// Blocking version of [`Lettr::health_with_meta`].
+        pub fn health_with_meta(&self) -> crate::Result<WithMeta<HealthResponse>> {
+            self.blocker.wait(self.inner.health_with_meta())
+        }
+
+        /// Blocking version of [`Lettr::readiness`].
+        pub fn readiness(&self) -> Readiness {
+            self.blocker.wait(self.inner.readiness())
+        }
+
+        /// Blocking version of [`Lettr::readiness_within`].
+        pub fn readiness_within(&self, timeout: Duration) -> Readiness {
+            self.blocker.wait(self.inner.readiness_within(timeout))
+        }
+
+        /// Blocking version of [`Lettr::warm_up`].
+        pub fn warm_up(&self) -> crate::Result<()> {
+            self.blocker.wait(self.inner.warm_up())
+        }
+
+        /// Blocking version of [`Lettr::auth_check`].
+        pub fn auth_check(&self) -> crate::Result<AuthCheckResponse> {
+            self.blocker.wait(self.inner.auth_check())
+        }
+
+        /// Blocking version of [`Lettr::auth_check_with_meta`].
+        pub fn auth_check_with_meta(&self) -> crate::Result<WithMeta<AuthCheckResponse>> {
+            self.blocker.wait(self.inner.auth_check_with_meta())
+        }
+    }
+
+    /// Blocking service for the `/emails` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct EmailsSvc {
+        inner: crate::emails::EmailsSvc,
+        blocker: Blocker,
+    }
+
+    impl EmailsSvc {
+        /// Blocking version of [`EmailsSvc::send`](crate::emails::EmailsSvc::send).
+        pub fn send(&self, email: CreateEmailOptions) -> crate::Result<SendEmailResponse> {
+            self.blocker.wait(self.inner.send(email))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::send_with_meta`](crate::emails::EmailsSvc::send_with_meta).
+        pub fn send_with_meta(
+            &self,
+            email: CreateEmailOptions,
+        ) -> crate::Result<WithMeta<SendEmailResponse>> {
+            self.blocker.wait(self.inner.send_with_meta(email))
+        }
+
+        /// Blocking version of [`EmailsSvc::list`](crate::emails::EmailsSvc::list).
+        pub fn list(&self, options: ListEmailsOptions) -> crate::Result<ListEmailsResponse> {
+            self.blocker.wait(self.inner.list(options))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::list_with_meta`](crate::emails::EmailsSvc::list_with_meta).
+        pub fn list_with_meta(
+            &self,
+            options: ListEmailsOptions,
+        ) -> crate::Result<WithMeta<ListEmailsResponse>> {
+            self.blocker.wait(self.inner.list_with_meta(options))
+        }
+
+        /// Blocking version of [`EmailsSvc::get`](crate::emails::EmailsSvc::get).
+        pub fn get(&self, request_id: &str) -> crate::Result<GetEmailResponse> {
+            self.blocker.wait(self.inner.get(request_id))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::get_with_meta`](crate::emails::EmailsSvc::get_with_meta).
+        pub fn get_with_meta(&self, request_id: &str) -> crate::Result<WithMeta<GetEmailResponse>> {
+            self.blocker.wait(self.inner.get_with_meta(request_id))
+        }
+
+        /// Blocking version of [`EmailsSvc::clicks`](crate::emails::EmailsSvc::clicks).
+        pub fn clicks(&self, request_id: &str) -> crate::Result<Vec<LinkClicks>> {
+            self.blocker.wait(self.inner.clicks(request_id))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::clicks_with_meta`](crate::emails::EmailsSvc::clicks_with_meta).
+        pub fn clicks_with_meta(
+            &self,
+            request_id: &str,
+        ) -> crate::Result<WithMeta<Vec<LinkClicks>>> {
+            self.blocker.wait(self.inner.clicks_with_meta(request_id))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::list_bounces`](crate::emails::EmailsSvc::list_bounces).
+        pub fn list_bounces(
+            &self,
+            options: ListBouncesOptions,
+        ) -> crate::Result<ListBouncesResponse> {
+            self.blocker.wait(self.inner.list_bounces(options))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::list_bounces_with_meta`](crate::emails::EmailsSvc::list_bounces_with_meta).
+        pub fn list_bounces_with_meta(
+            &self,
+            options: ListBouncesOptions,
+        ) -> crate::Result<WithMeta<ListBouncesResponse>> {
+            self.blocker
+                .wait(self.inner.list_bounces_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::recipient_history`](crate::emails::EmailsSvc::recipient_history).
+        pub fn recipient_history(
+            &self,
+            address: &str,
+            options: ListEmailsOptions,
+        ) -> crate::Result<Vec<EmailEvent>> {
+            self.blocker
+                .wait(self.inner.recipient_history(address, options))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::delete_recipient_data`](crate::emails::EmailsSvc::delete_recipient_data).
+        pub fn delete_recipient_data(&self, address: &str) -> crate::Result<RecipientErasure> {
+            self.blocker.wait(self.inner.delete_recipient_data(address))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::delete_recipient_data_with_meta`](crate::emails::EmailsSvc::delete_recipient_data_with_meta).
+        pub fn delete_recipient_data_with_meta(
+            &self,
+            address: &str,
+        ) -> crate::Result<WithMeta<RecipientErasure>> {
+            self.blocker
+                .wait(self.inner.delete_recipient_data_with_meta(address))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::get_recipient_erasure`](crate::emails::EmailsSvc::get_recipient_erasure).
+        pub fn get_recipient_erasure(&self, erasure_id: &str) -> crate::Result<RecipientErasure> {
+            self.blocker
+                .wait(self.inner.get_recipient_erasure(erasure_id))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::get_recipient_erasure_with_meta`](crate::emails::EmailsSvc::get_recipient_erasure_with_meta).
+        pub fn get_recipient_erasure_with_meta(
+            &self,
+            erasure_id: &str,
+        ) -> crate::Result<WithMeta<RecipientErasure>> {
+            self.blocker
+                .wait(self.inner.get_recipient_erasure_with_meta(erasure_id))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::stream_events`](crate::emails::EmailsSvc::stream_events),
+        /// yielding events as an [`Iterator`].
+        pub fn stream_events(&self, options: StreamEventsOptions) -> EventStream {
+            EventStream {
+                inner: self.inner.stream_events(options),
+                blocker: self.blocker.clone(),
+            }
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::events_since`](crate::emails::EmailsSvc::events_since).
+        pub fn events_since(
+            &self,
+            cursor: Option<&str>,
+            options: StreamEventsOptions,
+        ) -> crate::Result<EventBatch> {
+            self.blocker.wait(self.inner.events_since(cursor, options))
+        }
+
+        /// Blocking version of
+        /// [`EmailsSvc::events_since_with_meta`](crate::emails::EmailsSvc::events_since_with_meta).
+        pub fn events_since_with_meta(
+            &self,
+            cursor: Option<&str>,
+            options: StreamEventsOptions,
+        ) -> crate::Result<WithMeta<EventBatch>> {
+            self.blocker
+                .wait(self.inner.events_since_with_meta(cursor, options))
+        }
+
+        /// See [`EmailsSvc::invalidate_cache`](crate::emails::EmailsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking version of [`EventStream`](crate::event_stream::EventStream),
+    /// returned by [`EmailsSvc::stream_events`].
+    #[derive(Debug)]
+    pub struct EventStream {
+        inner: crate::event_stream::EventStream,
+        blocker: Blocker,
+    }
+
+    impl EventStream {
+        /// See [`EventStream::cursor`](crate::event_stream::EventStream::cursor).
+        #[must_use]
+        pub fn cursor(&self) -> Option<&str> {
+            self.inner.cursor()
+        }
+    }
+
+    impl Iterator for EventStream {
+        type Item = crate::Result<EmailEventDetail>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.blocker.wait(self.inner.next())
+        }
+    }
+
+    /// Blocking service for the `/domains` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct DomainsSvc {
+        inner: crate::domains::DomainsSvc,
+        blocker: Blocker,
+    }
+
+    impl DomainsSvc {
+        /// Blocking version of [`DomainsSvc::list`](crate::domains::DomainsSvc::list).
+        pub fn list(&self) -> crate::Result<Vec<Domain>> {
+            self.blocker.wait(self.inner.list())
+        }
+
+        /// Blocking version of
+        /// [`DomainsSvc::list_with_meta`](crate::domains::DomainsSvc::list_with_meta).
+        pub fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<Domain>>> {
+            self.blocker.wait(self.inner.list_with_meta())
+        }
+
+        /// Blocking version of [`DomainsSvc::create`](crate::domains::DomainsSvc::create).
+        pub fn create(&self, domain: &str) -> crate::Result<CreateDomainResponse> {
+            self.blocker.wait(self.inner.create(domain))
+        }
+
+        /// Blocking version of
+        /// [`DomainsSvc::create_with_meta`](crate::domains::DomainsSvc::create_with_meta).
+        pub fn create_with_meta(
+            &self,
+            domain: &str,
+        ) -> crate::Result<WithMeta<CreateDomainResponse>> {
+            self.blocker.wait(self.inner.create_with_meta(domain))
+        }
+
+        /// Blocking version of [`DomainsSvc::get`](crate::domains::DomainsSvc::get).
+        pub fn get(&self, domain: &str) -> crate::Result<DomainDetail> {
+            self.blocker.wait(self.inner.get(domain))
+        }
+
+        /// Blocking version of
+        /// [`DomainsSvc::get_with_meta`](crate::domains::DomainsSvc::get_with_meta).
+        pub fn get_with_meta(&self, domain: &str) -> crate::Result<WithMeta<DomainDetail>> {
+            self.blocker.wait(self.inner.get_with_meta(domain))
+        }
+
+        /// Blocking version of [`DomainsSvc::delete`](crate::domains::DomainsSvc::delete).
+        pub fn delete(&self, domain: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete(domain))
+        }
+
+        /// Blocking version of
+        /// [`DomainsSvc::delete_with_meta`](crate::domains::DomainsSvc::delete_with_meta).
+        pub fn delete_with_meta(&self, domain: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker.wait(self.inner.delete_with_meta(domain))
+        }
+
+        /// See [`DomainsSvc::invalidate_cache`](crate::domains::DomainsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/webhooks` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct WebhooksSvc {
+        inner: crate::webhooks::WebhooksSvc,
+        blocker: Blocker,
+    }
+
+    impl WebhooksSvc {
+        /// Blocking version of [`WebhooksSvc::list`](crate::webhooks::WebhooksSvc::list).
+        pub fn list(&self) -> crate::Result<Vec<Webhook>> {
+            self.blocker.wait(self.inner.list())
+        }
+
+        /// Blocking version of
+        /// [`WebhooksSvc::list_with_meta`](crate::webhooks::WebhooksSvc::list_with_meta).
+        pub fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<Webhook>>> {
+            self.blocker.wait(self.inner.list_with_meta())
+        }
+
+        /// Blocking version of [`WebhooksSvc::get`](crate::webhooks::WebhooksSvc::get).
+        pub fn get(&self, webhook_id: &str) -> crate::Result<Webhook> {
+            self.blocker.wait(self.inner.get(webhook_id))
+        }
+
+        /// Blocking version of
+        /// [`WebhooksSvc::get_with_meta`](crate::webhooks::WebhooksSvc::get_with_meta).
+        pub fn get_with_meta(&self, webhook_id: &str) -> crate::Result<WithMeta<Webhook>> {
+            self.blocker.wait(self.inner.get_with_meta(webhook_id))
+        }
+
+        /// Blocking version of
+        /// [`WebhooksSvc::signing_secret`](crate::webhooks::WebhooksSvc::signing_secret).
+        pub fn signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret> {
+            self.blocker.wait(self.inner.signing_secret(webhook_id))
+        }
+
+        /// Blocking version of
+        /// [`WebhooksSvc::signing_secret_with_meta`](crate::webhooks::WebhooksSvc::signing_secret_with_meta).
+        pub fn signing_secret_with_meta(
+            &self,
+            webhook_id: &str,
+        ) -> crate::Result<WithMeta<WebhookSecret>> {
+            self.blocker
+                .wait(self.inner.signing_secret_with_meta(webhook_id))
+        }
+
+        /// Blocking version of
+        /// [`WebhooksSvc::rotate_signing_secret`](crate::webhooks::WebhooksSvc::rotate_signing_secret).
+        pub fn rotate_signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret> {
+            self.blocker
+                .wait(self.inner.rotate_signing_secret(webhook_id))
+        }
+
+        /// Blocking version of
+        /// [`WebhooksSvc::rotate_signing_secret_with_meta`](crate::webhooks::WebhooksSvc::rotate_signing_secret_with_meta).
+        pub fn rotate_signing_secret_with_meta(
+            &self,
+            webhook_id: &str,
+        ) -> crate::Result<WithMeta<WebhookSecret>> {
+            self.blocker
+                .wait(self.inner.rotate_signing_secret_with_meta(webhook_id))
+        }
+
+        /// Blocking version of
+        /// [`WebhooksSvc::validate_url`](crate::webhooks::WebhooksSvc::validate_url).
+        pub fn validate_url(&self, url: &str) -> crate::Result<WebhookUrlValidation> {
+            self.blocker.wait(self.inner.validate_url(url))
+        }
+
+        /// Blocking version of
+        /// [`WebhooksSvc::validate_url_with_meta`](crate::webhooks::WebhooksSvc::validate_url_with_meta).
+        pub fn validate_url_with_meta(
+            &self,
+            url: &str,
+        ) -> crate::Result<WithMeta<WebhookUrlValidation>> {
+            self.blocker.wait(self.inner.validate_url_with_meta(url))
+        }
+
+        /// See [`WebhooksSvc::invalidate_cache`](crate::webhooks::WebhooksSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/templates` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct TemplatesSvc {
+        inner: crate::templates::TemplatesSvc,
+        blocker: Blocker,
+    }
+
+    impl TemplatesSvc {
+        /// Blocking version of [`TemplatesSvc::list`](crate::templates::TemplatesSvc::list).
+        pub fn list(&self, options: ListTemplatesOptions) -> crate::Result<ListTemplatesResponse> {
+            self.blocker.wait(self.inner.list(options))
+        }
+
+        /// Blocking version of
+        /// [`TemplatesSvc::list_with_meta`](crate::templates::TemplatesSvc::list_with_meta).
+        pub fn list_with_meta(
+            &self,
+            options: ListTemplatesOptions,
+        ) -> crate::Result<WithMeta<ListTemplatesResponse>> {
+            self.blocker.wait(self.inner.list_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`TemplatesSvc::create`](crate::templates::TemplatesSvc::create).
+        pub fn create(
+            &self,
+            options: CreateTemplateOptions,
+        ) -> crate::Result<CreateTemplateResponse> {
+            self.blocker.wait(self.inner.create(options))
+        }
+
+        /// Blocking version of
+        /// [`TemplatesSvc::create_with_meta`](crate::templates::TemplatesSvc::create_with_meta).
+        pub fn create_with_meta(
+            &self,
+            options: CreateTemplateOptions,
+        ) -> crate::Result<WithMeta<CreateTemplateResponse>> {
+            self.blocker.wait(self.inner.create_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`TemplatesSvc::locales`](crate::templates::TemplatesSvc::locales).
+        pub fn locales(
+            &self,
+            slug: &str,
+            options: ListTemplatesOptions,
+        ) -> crate::Result<Vec<Template>> {
+            self.blocker.wait(self.inner.locales(slug, options))
+        }
+
+        /// Blocking version of [`TemplatesSvc::clicks`](crate::templates::TemplatesSvc::clicks).
+        pub fn clicks(&self, slug: &str) -> crate::Result<Vec<LinkClicks>> {
+            self.blocker.wait(self.inner.clicks(slug))
+        }
+
+        /// Blocking version of
+        /// [`TemplatesSvc::clicks_with_meta`](crate::templates::TemplatesSvc::clicks_with_meta).
+        pub fn clicks_with_meta(&self, slug: &str) -> crate::Result<WithMeta<Vec<LinkClicks>>> {
+            self.blocker.wait(self.inner.clicks_with_meta(slug))
+        }
+
+        /// See
+        /// [`TemplatesSvc::invalidate_cache`](crate::templates::TemplatesSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/contacts` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct ContactsSvc {
+        inner: crate::contacts::ContactsSvc,
+        blocker: Blocker,
+    }
+
+    impl ContactsSvc {
+        /// Blocking version of [`ContactsSvc::list`](crate::contacts::ContactsSvc::list).
+        pub fn list(&self, options: ListContactsOptions) -> crate::Result<ListContactsResponse> {
+            self.blocker.wait(self.inner.list(options))
+        }
+
+        /// Blocking version of
+        /// [`ContactsSvc::list_with_meta`](crate::contacts::ContactsSvc::list_with_meta).
+        pub fn list_with_meta(
+            &self,
+            options: ListContactsOptions,
+        ) -> crate::Result<WithMeta<ListContactsResponse>> {
+            self.blocker.wait(self.inner.list_with_meta(options))
+        }
+
+        /// Blocking version of [`ContactsSvc::create`](crate::contacts::ContactsSvc::create).
+        pub fn create(&self, options: CreateContactOptions) -> crate::Result<Contact> {
+            self.blocker.wait(self.inner.create(options))
+        }
+
+        /// Blocking version of
+        /// [`ContactsSvc::create_with_meta`](crate::contacts::ContactsSvc::create_with_meta).
+        pub fn create_with_meta(
+            &self,
+            options: CreateContactOptions,
+        ) -> crate::Result<WithMeta<Contact>> {
+            self.blocker.wait(self.inner.create_with_meta(options))
+        }
+
+        /// Blocking version of [`ContactsSvc::get`](crate::contacts::ContactsSvc::get).
+        pub fn get(&self, contact_id: &str) -> crate::Result<Contact> {
+            self.blocker.wait(self.inner.get(contact_id))
+        }
+
+        /// Blocking version of
+        /// [`ContactsSvc::get_with_meta`](crate::contacts::ContactsSvc::get_with_meta).
+        pub fn get_with_meta(&self, contact_id: &str) -> crate::Result<WithMeta<Contact>> {
+            self.blocker.wait(self.inner.get_with_meta(contact_id))
+        }
+
+        /// Blocking version of [`ContactsSvc::update`](crate::contacts::ContactsSvc::update).
+        pub fn update(
+            &self,
+            contact_id: &str,
+            options: UpdateContactOptions,
+        ) -> crate::Result<Contact> {
+            self.blocker.wait(self.inner.update(contact_id, options))
+        }
+
+        /// Blocking version of
+        /// [`ContactsSvc::update_with_meta`](crate::contacts::ContactsSvc::update_with_meta).
+        pub fn update_with_meta(
+            &self,
+            contact_id: &str,
+            options: UpdateContactOptions,
+        ) -> crate::Result<WithMeta<Contact>> {
+            self.blocker
+                .wait(self.inner.update_with_meta(contact_id, options))
+        }
+
+        /// Blocking version of [`ContactsSvc::delete`](crate::contacts::ContactsSvc::delete).
+        pub fn delete(&self, contact_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete(contact_id))
+        }
+
+        /// Blocking version of
+        /// [`ContactsSvc::delete_with_meta`](crate::contacts::ContactsSvc::delete_with_meta).
+        pub fn delete_with_meta(&self, contact_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker.wait(self.inner.delete_with_meta(contact_id))
+        }
+
+        /// See [`ContactsSvc::invalidate_cache`](crate::contacts::ContactsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/audiences` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct AudiencesSvc {
+        inner: crate::audiences::AudiencesSvc,
+        blocker: Blocker,
+    }
+
+    impl AudiencesSvc {
+        /// Blocking version of [`AudiencesSvc::list`](crate::audiences::AudiencesSvc::list).
+        pub fn list(&self) -> crate::Result<Vec<Audience>> {
+            self.blocker.wait(self.inner.list())
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::list_with_meta`](crate::audiences::AudiencesSvc::list_with_meta).
+        pub fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<Audience>>> {
+            self.blocker.wait(self.inner.list_with_meta())
+        }
+
+        /// Blocking version of [`AudiencesSvc::create`](crate::audiences::AudiencesSvc::create).
+        pub fn create(&self, options: CreateAudienceOptions) -> crate::Result<Audience> {
+            self.blocker.wait(self.inner.create(options))
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::create_with_meta`](crate::audiences::AudiencesSvc::create_with_meta).
+        pub fn create_with_meta(
+            &self,
+            options: CreateAudienceOptions,
+        ) -> crate::Result<WithMeta<Audience>> {
+            self.blocker.wait(self.inner.create_with_meta(options))
+        }
+
+        /// Blocking version of [`AudiencesSvc::get`](crate::audiences::AudiencesSvc::get).
+        pub fn get(&self, audience_id: &str) -> crate::Result<Audience> {
+            self.blocker.wait(self.inner.get(audience_id))
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::get_with_meta`](crate::audiences::AudiencesSvc::get_with_meta).
+        pub fn get_with_meta(&self, audience_id: &str) -> crate::Result<WithMeta<Audience>> {
+            self.blocker.wait(self.inner.get_with_meta(audience_id))
+        }
+
+        /// Blocking version of [`AudiencesSvc::delete`](crate::audiences::AudiencesSvc::delete).
+        pub fn delete(&self, audience_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete(audience_id))
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::delete_with_meta`](crate::audiences::AudiencesSvc::delete_with_meta).
+        pub fn delete_with_meta(&self, audience_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker.wait(self.inner.delete_with_meta(audience_id))
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::add_contacts`](crate::audiences::AudiencesSvc::add_contacts).
+        pub fn add_contacts<T, A>(&self, audience_id: &str, contact_ids: T) -> crate::Result<()>
+        where
+            T: IntoIterator<Item = A>,
+            A: Into<String>,
+        {
+            self.blocker
+                .wait(self.inner.add_contacts(audience_id, contact_ids))
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::add_contacts_with_meta`](crate::audiences::AudiencesSvc::add_contacts_with_meta).
+        pub fn add_contacts_with_meta<T, A>(
+            &self,
+            audience_id: &str,
+            contact_ids: T,
+        ) -> crate::Result<WithMeta<()>>
+        where
+            T: IntoIterator<Item = A>,
+            A: Into<String>,
+        {
+            self.blocker
+                .wait(self.inner.add_contacts_with_meta(audience_id, contact_ids))
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::remove_contacts`](crate::audiences::AudiencesSvc::remove_contacts).
+        pub fn remove_contacts<T, A>(&self, audience_id: &str, contact_ids: T) -> crate::Result<()>
+        where
+            T: IntoIterator<Item = A>,
+            A: Into<String>,
+        {
+            self.blocker
+                .wait(self.inner.remove_contacts(audience_id, contact_ids))
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::remove_contacts_with_meta`](crate::audiences::AudiencesSvc::remove_contacts_with_meta).
+        pub fn remove_contacts_with_meta<T, A>(
+            &self,
+            audience_id: &str,
+            contact_ids: T,
+        ) -> crate::Result<WithMeta<()>>
+        where
+            T: IntoIterator<Item = A>,
+            A: Into<String>,
+        {
+            self.blocker.wait(
+                self.inner
+                    .remove_contacts_with_meta(audience_id, contact_ids),
+            )
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::contacts`](crate::audiences::AudiencesSvc::contacts).
+        pub fn contacts(
+            &self,
+            audience_id: &str,
+            options: ListContactsOptions,
+        ) -> crate::Result<ListContactsResponse> {
+            self.blocker.wait(self.inner.contacts(audience_id, options))
+        }
+
+        /// Blocking version of
+        /// [`AudiencesSvc::contacts_with_meta`](crate::audiences::AudiencesSvc::contacts_with_meta).
+        pub fn contacts_with_meta(
+            &self,
+            audience_id: &str,
+            options: ListContactsOptions,
+        ) -> crate::Result<WithMeta<ListContactsResponse>> {
+            self.blocker
+                .wait(self.inner.contacts_with_meta(audience_id, options))
+        }
+
+        /// See
+        /// [`AudiencesSvc::invalidate_cache`](crate::audiences::AudiencesSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/broadcasts` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct BroadcastsSvc {
+        inner: crate::broadcasts::BroadcastsSvc,
+        blocker: Blocker,
+    }
+
+    impl BroadcastsSvc {
+        /// Blocking version of [`BroadcastsSvc::list`](crate::broadcasts::BroadcastsSvc::list).
+        pub fn list(
+            &self,
+            options: ListBroadcastsOptions,
+        ) -> crate::Result<ListBroadcastsResponse> {
+            self.blocker.wait(self.inner.list(options))
+        }
+
+        /// Blocking version of
+        /// [`BroadcastsSvc::list_with_meta`](crate::broadcasts::BroadcastsSvc::list_with_meta).
+        pub fn list_with_meta(
+            &self,
+            options: ListBroadcastsOptions,
+        ) -> crate::Result<WithMeta<ListBroadcastsResponse>> {
+            self.blocker.wait(self.inner.list_with_meta(options))
+        }
+
+        /// Blocking version of [`BroadcastsSvc::create`](crate::broadcasts::BroadcastsSvc::create).
+        pub fn create(&self, options: CreateBroadcastOptions) -> crate::Result<Broadcast> {
+            self.blocker.wait(self.inner.create(options))
+        }
+
+        /// Blocking version of
+        /// [`BroadcastsSvc::create_with_meta`](crate::broadcasts::BroadcastsSvc::create_with_meta).
+        pub fn create_with_meta(
+            &self,
+            options: CreateBroadcastOptions,
+        ) -> crate::Result<WithMeta<Broadcast>> {
+            self.blocker.wait(self.inner.create_with_meta(options))
+        }
+
+        /// Blocking version of [`BroadcastsSvc::get`](crate::broadcasts::BroadcastsSvc::get).
+        pub fn get(&self, broadcast_id: &str) -> crate::Result<Broadcast> {
+            self.blocker.wait(self.inner.get(broadcast_id))
+        }
+
+        /// Blocking version of
+        /// [`BroadcastsSvc::get_with_meta`](crate::broadcasts::BroadcastsSvc::get_with_meta).
+        pub fn get_with_meta(&self, broadcast_id: &str) -> crate::Result<WithMeta<Broadcast>> {
+            self.blocker.wait(self.inner.get_with_meta(broadcast_id))
+        }
+
+        /// Blocking version of [`BroadcastsSvc::schedule`](crate::broadcasts::BroadcastsSvc::schedule).
+        pub fn schedule(&self, broadcast_id: &str, scheduled_at: &str) -> crate::Result<Broadcast> {
+            self.blocker
+                .wait(self.inner.schedule(broadcast_id, scheduled_at))
+        }
+
+        /// Blocking version of
+        /// [`BroadcastsSvc::schedule_with_meta`](crate::broadcasts::BroadcastsSvc::schedule_with_meta).
+        pub fn schedule_with_meta(
+            &self,
+            broadcast_id: &str,
+            scheduled_at: &str,
+        ) -> crate::Result<WithMeta<Broadcast>> {
+            self.blocker
+                .wait(self.inner.schedule_with_meta(broadcast_id, scheduled_at))
+        }
+
+        /// Blocking version of [`BroadcastsSvc::send`](crate::broadcasts::BroadcastsSvc::send).
+        pub fn send(&self, broadcast_id: &str) -> crate::Result<Broadcast> {
+            self.blocker.wait(self.inner.send(broadcast_id))
+        }
+
+        /// Blocking version of
+        /// [`BroadcastsSvc::send_with_meta`](crate::broadcasts::BroadcastsSvc::send_with_meta).
+        pub fn send_with_meta(&self, broadcast_id: &str) -> crate::Result<WithMeta<Broadcast>> {
+            self.blocker.wait(self.inner.send_with_meta(broadcast_id))
+        }
+
+        /// Blocking version of [`BroadcastsSvc::cancel`](crate::broadcasts::BroadcastsSvc::cancel).
+        pub fn cancel(&self, broadcast_id: &str) -> crate::Result<Broadcast> {
+            self.blocker.wait(self.inner.cancel(broadcast_id))
+        }
+
+        /// Blocking version of
+        /// [`BroadcastsSvc::cancel_with_meta`](crate::broadcasts::BroadcastsSvc::cancel_with_meta).
+        pub fn cancel_with_meta(&self, broadcast_id: &str) -> crate::Result<WithMeta<Broadcast>> {
+            self.blocker.wait(self.inner.cancel_with_meta(broadcast_id))
+        }
+
+        /// Blocking version of [`BroadcastsSvc::delete`](crate::broadcasts::BroadcastsSvc::delete).
+        pub fn delete(&self, broadcast_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete(broadcast_id))
+        }
+
+        /// Blocking version of
+        /// [`BroadcastsSvc::delete_with_meta`](crate::broadcasts::BroadcastsSvc::delete_with_meta).
+        pub fn delete_with_meta(&self, broadcast_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker.wait(self.inner.delete_with_meta(broadcast_id))
+        }
+
+        /// Blocking version of [`BroadcastsSvc::stats`](crate::broadcasts::BroadcastsSvc::stats).
+        pub fn stats(&self, broadcast_id: &str) -> crate::Result<BroadcastStats> {
+            self.blocker.wait(self.inner.stats(broadcast_id))
+        }
+
+        /// Blocking version of
+        /// [`BroadcastsSvc::stats_with_meta`](crate::broadcasts::BroadcastsSvc::stats_with_meta).
+        pub fn stats_with_meta(
+            &self,
+            broadcast_id: &str,
+        ) -> crate::Result<WithMeta<BroadcastStats>> {
+            self.blocker.wait(self.inner.stats_with_meta(broadcast_id))
+        }
+
+        /// See
+        /// [`BroadcastsSvc::invalidate_cache`](crate::broadcasts::BroadcastsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/stats` endpoint.
+    #[derive(Clone, Debug)]
+    pub struct StatsSvc {
+        inner: crate::stats::StatsSvc,
+        blocker: Blocker,
+    }
+
+    impl StatsSvc {
+        /// Blocking version of [`StatsSvc::get`](crate::stats::StatsSvc::get).
+        pub fn get(&self, options: StatsOptions) -> crate::Result<StatsResponse> {
+            self.blocker.wait(self.inner.get(options))
+        }
+
+        /// Blocking version of
+        /// [`StatsSvc::get_with_meta`](crate::stats::StatsSvc::get_with_meta).
+        pub fn get_with_meta(
+            &self,
+            options: StatsOptions,
+        ) -> crate::Result<WithMeta<StatsResponse>> {
+            self.blocker.wait(self.inner.get_with_meta(options))
+        }
+
+        /// See [`StatsSvc::invalidate_cache`](crate::stats::StatsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/smtp/credentials` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct SmtpCredentialsSvc {
+        inner: crate::smtp::SmtpCredentialsSvc,
+        blocker: Blocker,
+    }
+
+    impl SmtpCredentialsSvc {
+        /// Blocking version of
+        /// [`SmtpCredentialsSvc::list`](crate::smtp::SmtpCredentialsSvc::list).
+        pub fn list(&self) -> crate::Result<Vec<SmtpCredential>> {
+            self.blocker.wait(self.inner.list())
+        }
+
+        /// Blocking version of
+        /// [`SmtpCredentialsSvc::list_with_meta`](crate::smtp::SmtpCredentialsSvc::list_with_meta).
+        pub fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<SmtpCredential>>> {
+            self.blocker.wait(self.inner.list_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`SmtpCredentialsSvc::create`](crate::smtp::SmtpCredentialsSvc::create).
+        pub fn create(
+            &self,
+            options: CreateSmtpCredentialOptions,
+        ) -> crate::Result<NewSmtpCredential> {
+            self.blocker.wait(self.inner.create(options))
+        }
+
+        /// Blocking version of
+        /// [`SmtpCredentialsSvc::create_with_meta`](crate::smtp::SmtpCredentialsSvc::create_with_meta).
+        pub fn create_with_meta(
+            &self,
+            options: CreateSmtpCredentialOptions,
+        ) -> crate::Result<WithMeta<NewSmtpCredential>> {
+            self.blocker.wait(self.inner.create_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`SmtpCredentialsSvc::get`](crate::smtp::SmtpCredentialsSvc::get).
+        pub fn get(&self, credential_id: &str) -> crate::Result<SmtpCredential> {
+            self.blocker.wait(self.inner.get(credential_id))
+        }
+
+        /// Blocking version of
+        /// [`SmtpCredentialsSvc::get_with_meta`](crate::smtp::SmtpCredentialsSvc::get_with_meta).
+        pub fn get_with_meta(
+            &self,
+            credential_id: &str,
+        ) -> crate::Result<WithMeta<SmtpCredential>> {
+            self.blocker.wait(self.inner.get_with_meta(credential_id))
+        }
+
+        /// Blocking version of
+        /// [`SmtpCredentialsSvc::revoke`](crate::smtp::SmtpCredentialsSvc::revoke).
+        pub fn revoke(&self, credential_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.revoke(credential_id))
+        }
+
+        /// Blocking version of
+        /// [`SmtpCredentialsSvc::revoke_with_meta`](crate::smtp::SmtpCredentialsSvc::revoke_with_meta).
+        pub fn revoke_with_meta(&self, credential_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker
+                .wait(self.inner.revoke_with_meta(credential_id))
+        }
+
+        /// See
+        /// [`SmtpCredentialsSvc::invalidate_cache`](crate::smtp::SmtpCredentialsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/inbound` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct InboundSvc {
+        inner: crate::inbound::InboundSvc,
+        blocker: Blocker,
+    }
+
+    impl InboundSvc {
+        /// Blocking version of
+        /// [`InboundSvc::list_routes`](crate::inbound::InboundSvc::list_routes).
+        pub fn list_routes(&self) -> crate::Result<Vec<InboundRoute>> {
+            self.blocker.wait(self.inner.list_routes())
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::list_routes_with_meta`](crate::inbound::InboundSvc::list_routes_with_meta).
+        pub fn list_routes_with_meta(&self) -> crate::Result<WithMeta<Vec<InboundRoute>>> {
+            self.blocker.wait(self.inner.list_routes_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::create_route`](crate::inbound::InboundSvc::create_route).
+        pub fn create_route(
+            &self,
+            options: CreateInboundRouteOptions,
+        ) -> crate::Result<InboundRoute> {
+            self.blocker.wait(self.inner.create_route(options))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::create_route_with_meta`](crate::inbound::InboundSvc::create_route_with_meta).
+        pub fn create_route_with_meta(
+            &self,
+            options: CreateInboundRouteOptions,
+        ) -> crate::Result<WithMeta<InboundRoute>> {
+            self.blocker
+                .wait(self.inner.create_route_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::delete_route`](crate::inbound::InboundSvc::delete_route).
+        pub fn delete_route(&self, route_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete_route(route_id))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::delete_route_with_meta`](crate::inbound::InboundSvc::delete_route_with_meta).
+        pub fn delete_route_with_meta(&self, route_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker
+                .wait(self.inner.delete_route_with_meta(route_id))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::list_rules`](crate::inbound::InboundSvc::list_rules).
+        pub fn list_rules(&self) -> crate::Result<Vec<InboundRule>> {
+            self.blocker.wait(self.inner.list_rules())
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::list_rules_with_meta`](crate::inbound::InboundSvc::list_rules_with_meta).
+        pub fn list_rules_with_meta(&self) -> crate::Result<WithMeta<Vec<InboundRule>>> {
+            self.blocker.wait(self.inner.list_rules_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::create_rule`](crate::inbound::InboundSvc::create_rule).
+        pub fn create_rule(&self, options: CreateInboundRuleOptions) -> crate::Result<InboundRule> {
+            self.blocker.wait(self.inner.create_rule(options))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::create_rule_with_meta`](crate::inbound::InboundSvc::create_rule_with_meta).
+        pub fn create_rule_with_meta(
+            &self,
+            options: CreateInboundRuleOptions,
+        ) -> crate::Result<WithMeta<InboundRule>> {
+            self.blocker.wait(self.inner.create_rule_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::delete_rule`](crate::inbound::InboundSvc::delete_rule).
+        pub fn delete_rule(&self, rule_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete_rule(rule_id))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::delete_rule_with_meta`](crate::inbound::InboundSvc::delete_rule_with_meta).
+        pub fn delete_rule_with_meta(&self, rule_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker.wait(self.inner.delete_rule_with_meta(rule_id))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::list_messages`](crate::inbound::InboundSvc::list_messages).
+        pub fn list_messages(
+            &self,
+            options: ListInboundMessagesOptions,
+        ) -> crate::Result<ListInboundMessagesResponse> {
+            self.blocker.wait(self.inner.list_messages(options))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::list_messages_with_meta`](crate::inbound::InboundSvc::list_messages_with_meta).
+        pub fn list_messages_with_meta(
+            &self,
+            options: ListInboundMessagesOptions,
+        ) -> crate::Result<WithMeta<ListInboundMessagesResponse>> {
+            self.blocker
+                .wait(self.inner.list_messages_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::get_message`](crate::inbound::InboundSvc::get_message).
+        pub fn get_message(&self, message_id: &str) -> crate::Result<InboundMessage> {
+            self.blocker.wait(self.inner.get_message(message_id))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::get_message_with_meta`](crate::inbound::InboundSvc::get_message_with_meta).
+        pub fn get_message_with_meta(
+            &self,
+            message_id: &str,
+        ) -> crate::Result<WithMeta<InboundMessage>> {
+            self.blocker
+                .wait(self.inner.get_message_with_meta(message_id))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::delete_message`](crate::inbound::InboundSvc::delete_message).
+        pub fn delete_message(&self, message_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete_message(message_id))
+        }
+
+        /// Blocking version of
+        /// [`InboundSvc::delete_message_with_meta`](crate::inbound::InboundSvc::delete_message_with_meta).
+        pub fn delete_message_with_meta(&self, message_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker
+                .wait(self.inner.delete_message_with_meta(message_id))
+        }
+
+        /// See
+        /// [`InboundSvc::invalidate_cache`](crate::inbound::InboundSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/ip-pools` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct IpPoolsSvc {
+        inner: crate::ip_pools::IpPoolsSvc,
+        blocker: Blocker,
+    }
+
+    impl IpPoolsSvc {
+        /// Blocking version of
+        /// [`IpPoolsSvc::list`](crate::ip_pools::IpPoolsSvc::list).
+        pub fn list(&self) -> crate::Result<Vec<IpPool>> {
+            self.blocker.wait(self.inner.list())
+        }
+
+        /// Blocking version of
+        /// [`IpPoolsSvc::list_with_meta`](crate::ip_pools::IpPoolsSvc::list_with_meta).
+        pub fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<IpPool>>> {
+            self.blocker.wait(self.inner.list_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`IpPoolsSvc::get`](crate::ip_pools::IpPoolsSvc::get).
+        pub fn get(&self, pool_id: &str) -> crate::Result<IpPool> {
+            self.blocker.wait(self.inner.get(pool_id))
+        }
+
+        /// Blocking version of
+        /// [`IpPoolsSvc::get_with_meta`](crate::ip_pools::IpPoolsSvc::get_with_meta).
+        pub fn get_with_meta(&self, pool_id: &str) -> crate::Result<WithMeta<IpPool>> {
+            self.blocker.wait(self.inner.get_with_meta(pool_id))
+        }
+
+        /// Blocking version of
+        /// [`IpPoolsSvc::assign_domain`](crate::ip_pools::IpPoolsSvc::assign_domain).
+        pub fn assign_domain(&self, pool_id: &str, domain: &str) -> crate::Result<IpPool> {
+            self.blocker.wait(self.inner.assign_domain(pool_id, domain))
+        }
+
+        /// Blocking version of
+        /// [`IpPoolsSvc::assign_domain_with_meta`](crate::ip_pools::IpPoolsSvc::assign_domain_with_meta).
+        pub fn assign_domain_with_meta(
+            &self,
+            pool_id: &str,
+            domain: &str,
+        ) -> crate::Result<WithMeta<IpPool>> {
+            self.blocker
+                .wait(self.inner.assign_domain_with_meta(pool_id, domain))
+        }
+
+        /// See
+        /// [`IpPoolsSvc::invalidate_cache`](crate::ip_pools::IpPoolsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/team` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct TeamSvc {
+        inner: crate::team::TeamSvc,
+        blocker: Blocker,
+    }
+
+    impl TeamSvc {
+        /// Blocking version of
+        /// [`TeamSvc::list_members`](crate::team::TeamSvc::list_members).
+        pub fn list_members(&self) -> crate::Result<Vec<TeamMember>> {
+            self.blocker.wait(self.inner.list_members())
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::list_members_with_meta`](crate::team::TeamSvc::list_members_with_meta).
+        pub fn list_members_with_meta(&self) -> crate::Result<WithMeta<Vec<TeamMember>>> {
+            self.blocker.wait(self.inner.list_members_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::remove_member`](crate::team::TeamSvc::remove_member).
+        pub fn remove_member(&self, member_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.remove_member(member_id))
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::remove_member_with_meta`](crate::team::TeamSvc::remove_member_with_meta).
+        pub fn remove_member_with_meta(&self, member_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker
+                .wait(self.inner.remove_member_with_meta(member_id))
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::list_roles`](crate::team::TeamSvc::list_roles).
+        pub fn list_roles(&self) -> crate::Result<Vec<TeamRole>> {
+            self.blocker.wait(self.inner.list_roles())
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::list_roles_with_meta`](crate::team::TeamSvc::list_roles_with_meta).
+        pub fn list_roles_with_meta(&self) -> crate::Result<WithMeta<Vec<TeamRole>>> {
+            self.blocker.wait(self.inner.list_roles_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::list_invitations`](crate::team::TeamSvc::list_invitations).
+        pub fn list_invitations(&self) -> crate::Result<Vec<TeamInvitation>> {
+            self.blocker.wait(self.inner.list_invitations())
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::list_invitations_with_meta`](crate::team::TeamSvc::list_invitations_with_meta).
+        pub fn list_invitations_with_meta(&self) -> crate::Result<WithMeta<Vec<TeamInvitation>>> {
+            self.blocker.wait(self.inner.list_invitations_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::invite`](crate::team::TeamSvc::invite).
+        pub fn invite(&self, options: InviteTeamMemberOptions) -> crate::Result<TeamInvitation> {
+            self.blocker.wait(self.inner.invite(options))
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::invite_with_meta`](crate::team::TeamSvc::invite_with_meta).
+        pub fn invite_with_meta(
+            &self,
+            options: InviteTeamMemberOptions,
+        ) -> crate::Result<WithMeta<TeamInvitation>> {
+            self.blocker.wait(self.inner.invite_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::revoke_invitation`](crate::team::TeamSvc::revoke_invitation).
+        pub fn revoke_invitation(&self, invitation_id: &str) -> crate::Result<()> {
+            self.blocker
+                .wait(self.inner.revoke_invitation(invitation_id))
+        }
+
+        /// Blocking version of
+        /// [`TeamSvc::revoke_invitation_with_meta`](crate::team::TeamSvc::revoke_invitation_with_meta).
+        pub fn revoke_invitation_with_meta(
+            &self,
+            invitation_id: &str,
+        ) -> crate::Result<WithMeta<()>> {
+            self.blocker
+                .wait(self.inner.revoke_invitation_with_meta(invitation_id))
+        }
+
+        /// See
+        /// [`TeamSvc::invalidate_cache`](crate::team::TeamSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/exports` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct ExportsSvc {
+        inner: crate::exports::ExportsSvc,
+        blocker: Blocker,
+    }
+
+    impl ExportsSvc {
+        /// Blocking version of
+        /// [`ExportsSvc::create`](crate::exports::ExportsSvc::create).
+        pub fn create(&self, options: CreateExportOptions) -> crate::Result<ExportJob> {
+            self.blocker.wait(self.inner.create(options))
+        }
+
+        /// Blocking version of
+        /// [`ExportsSvc::create_with_meta`](crate::exports::ExportsSvc::create_with_meta).
+        pub fn create_with_meta(
+            &self,
+            options: CreateExportOptions,
+        ) -> crate::Result<WithMeta<ExportJob>> {
+            self.blocker.wait(self.inner.create_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`ExportsSvc::get`](crate::exports::ExportsSvc::get).
+        pub fn get(&self, export_id: &str) -> crate::Result<ExportJob> {
+            self.blocker.wait(self.inner.get(export_id))
+        }
+
+        /// Blocking version of
+        /// [`ExportsSvc::get_with_meta`](crate::exports::ExportsSvc::get_with_meta).
+        pub fn get_with_meta(&self, export_id: &str) -> crate::Result<WithMeta<ExportJob>> {
+            self.blocker.wait(self.inner.get_with_meta(export_id))
+        }
+
+        /// Blocking version of
+        /// [`ExportsSvc::wait`](crate::exports::ExportsSvc::wait).
+        pub fn wait(&self, export_id: &str, poll_interval: Duration) -> crate::Result<ExportJob> {
+            self.blocker.wait(self.inner.wait(export_id, poll_interval))
+        }
+
+        /// Blocking version of
+        /// [`ExportsSvc::download`](crate::exports::ExportsSvc::download).
+        pub fn download<W>(&self, export_id: &str, writer: &mut W) -> crate::Result<u64>
+        where
+            W: Write + Send + ?Sized,
+        {
+            self.blocker.wait(self.inner.download(export_id, writer))
+        }
+
+        /// Blocking version of
+        /// [`ExportsSvc::download_with_meta`](crate::exports::ExportsSvc::download_with_meta).
+        pub fn download_with_meta<W>(
+            &self,
+            export_id: &str,
+            writer: &mut W,
+        ) -> crate::Result<WithMeta<u64>>
+        where
+            W: Write + Send + ?Sized,
+        {
+            self.blocker
+                .wait(self.inner.download_with_meta(export_id, writer))
+        }
+
+        /// See
+        /// [`ExportsSvc::invalidate_cache`](crate::exports::ExportsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/unsubscribe-groups` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct UnsubscribeGroupsSvc {
+        inner: crate::unsubscribe_groups::UnsubscribeGroupsSvc,
+        blocker: Blocker,
+    }
+
+    impl UnsubscribeGroupsSvc {
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::list`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::list).
+        pub fn list(&self) -> crate::Result<Vec<UnsubscribeGroup>> {
+            self.blocker.wait(self.inner.list())
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::list_with_meta`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::list_with_meta).
+        pub fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<UnsubscribeGroup>>> {
+            self.blocker.wait(self.inner.list_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::create`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::create).
+        pub fn create(
+            &self,
+            options: CreateUnsubscribeGroupOptions,
+        ) -> crate::Result<UnsubscribeGroup> {
+            self.blocker.wait(self.inner.create(options))
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::create_with_meta`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::create_with_meta).
+        pub fn create_with_meta(
+            &self,
+            options: CreateUnsubscribeGroupOptions,
+        ) -> crate::Result<WithMeta<UnsubscribeGroup>> {
+            self.blocker.wait(self.inner.create_with_meta(options))
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::get`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::get).
+        pub fn get(&self, group_id: &str) -> crate::Result<UnsubscribeGroup> {
+            self.blocker.wait(self.inner.get(group_id))
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::get_with_meta`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::get_with_meta).
+        pub fn get_with_meta(&self, group_id: &str) -> crate::Result<WithMeta<UnsubscribeGroup>> {
+            self.blocker.wait(self.inner.get_with_meta(group_id))
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::delete`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::delete).
+        pub fn delete(&self, group_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete(group_id))
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::delete_with_meta`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::delete_with_meta).
+        pub fn delete_with_meta(&self, group_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker.wait(self.inner.delete_with_meta(group_id))
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::preferences`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::preferences).
+        pub fn preferences(&self, email: &str) -> crate::Result<RecipientPreferences> {
+            self.blocker.wait(self.inner.preferences(email))
+        }
+
+        /// Blocking version of
+        /// [`UnsubscribeGroupsSvc::preferences_with_meta`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::preferences_with_meta).
+        pub fn preferences_with_meta(
+            &self,
+            email: &str,
+        ) -> crate::Result<WithMeta<RecipientPreferences>> {
+            self.blocker.wait(self.inner.preferences_with_meta(email))
+        }
+
+        /// See
+        /// [`UnsubscribeGroupsSvc::invalidate_cache`](crate::unsubscribe_groups::UnsubscribeGroupsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/blocklists` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct BlocklistsSvc {
+        inner: crate::blocklists::BlocklistsSvc,
+        blocker: Blocker,
+    }
+
+    impl BlocklistsSvc {
+        /// Blocking version of
+        /// [`BlocklistsSvc::list`](crate::blocklists::BlocklistsSvc::list).
+        pub fn list(&self) -> crate::Result<Vec<BlocklistStatus>> {
+            self.blocker.wait(self.inner.list())
+        }
+
+        /// Blocking version of
+        /// [`BlocklistsSvc::list_with_meta`](crate::blocklists::BlocklistsSvc::list_with_meta).
+        pub fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<BlocklistStatus>>> {
+            self.blocker.wait(self.inner.list_with_meta())
+        }
+
+        /// Blocking version of
+        /// [`BlocklistsSvc::get`](crate::blocklists::BlocklistsSvc::get).
+        pub fn get(&self, target: &str) -> crate::Result<BlocklistStatus> {
+            self.blocker.wait(self.inner.get(target))
+        }
+
+        /// Blocking version of
+        /// [`BlocklistsSvc::get_with_meta`](crate::blocklists::BlocklistsSvc::get_with_meta).
+        pub fn get_with_meta(&self, target: &str) -> crate::Result<WithMeta<BlocklistStatus>> {
+            self.blocker.wait(self.inner.get_with_meta(target))
+        }
+
+        /// See
+        /// [`BlocklistsSvc::invalidate_cache`](crate::blocklists::BlocklistsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+
+    /// Blocking service for the `/assets` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct AssetsSvc {
+        inner: crate::assets::AssetsSvc,
+        blocker: Blocker,
+    }
+
+    impl AssetsSvc {
+        /// Blocking version of
+        /// [`AssetsSvc::upload`](crate::assets::AssetsSvc::upload).
+        pub fn upload(&self, options: UploadAssetOptions) -> crate::Result<Asset> {
+            self.blocker.wait(self.inner.upload(options))
+        }
+
+        /// Blocking version of
+        /// [`AssetsSvc::upload_with_meta`](crate::assets::AssetsSvc::upload_with_meta).
+        pub fn upload_with_meta(
+            &self,
+            options: UploadAssetOptions,
+        ) -> crate::Result<WithMeta<Asset>> {
+            self.blocker.wait(self.inner.upload_with_meta(options))
+        }
+
+        /// Blocking version of [`AssetsSvc::list`](crate::assets::AssetsSvc::list).
+        pub fn list(&self) -> crate::Result<Vec<Asset>> {
+            self.blocker.wait(self.inner.list())
+        }
+
+        /// Blocking version of
+        /// [`AssetsSvc::list_with_meta`](crate::assets::AssetsSvc::list_with_meta).
+        pub fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<Asset>>> {
+            self.blocker.wait(self.inner.list_with_meta())
+        }
+
+        /// Blocking version of [`AssetsSvc::get`](crate::assets::AssetsSvc::get).
+        pub fn get(&self, asset_id: &str) -> crate::Result<Asset> {
+            self.blocker.wait(self.inner.get(asset_id))
+        }
+
+        /// Blocking version of
+        /// [`AssetsSvc::get_with_meta`](crate::assets::AssetsSvc::get_with_meta).
+        pub fn get_with_meta(&self, asset_id: &str) -> crate::Result<WithMeta<Asset>> {
+            self.blocker.wait(self.inner.get_with_meta(asset_id))
+        }
+
+        /// Blocking version of
+        /// [`AssetsSvc::delete`](crate::assets::AssetsSvc::delete).
+        pub fn delete(&self, asset_id: &str) -> crate::Result<()> {
+            self.blocker.wait(self.inner.delete(asset_id))
+        }
+
+        /// Blocking version of
+        /// [`AssetsSvc::delete_with_meta`](crate::assets::AssetsSvc::delete_with_meta).
+        pub fn delete_with_meta(&self, asset_id: &str) -> crate::Result<WithMeta<()>> {
+            self.blocker.wait(self.inner.delete_with_meta(asset_id))
+        }
+
+        /// See
+        /// [`AssetsSvc::invalidate_cache`](crate::assets::AssetsSvc::invalidate_cache).
+        pub fn invalidate_cache(&self) {
+            self.inner.invalidate_cache();
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::meta::WithMeta;
+
+/// Service for the `/blocklists` endpoints.
+///
+/// Lettr regularly checks the dedicated IPs and sending domains of an
+/// account against public blocklists (RBLs) such as Spamhaus. Mail from a
+/// listed IP or domain is often rejected or sent to spam, so it pays to poll
+/// this service from deliverability monitoring.
+#[derive(Clone, Debug)]
+pub struct BlocklistsSvc(pub(crate) Arc<Config>);
+
+impl BlocklistsSvc {
+    /// List the blocklist status of every dedicated IP and sending domain.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let statuses = client.blocklists.list().await?;
+    /// for status in statuses.iter().filter(|status| status.is_listed()) {
+    ///     println!("{status}");
+    ///     for listing in &status.listings {
+    ///         println!("  {listing}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<Vec<BlocklistStatus>> {
+        self.list_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<BlocklistStatus>>> {
+        let request = self.0.build(Method::GET, "/blocklists");
+        let response: WithMeta<ListBlocklistsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.statuses))
+    }
+
+    /// Retrieve the blocklist status of one dedicated IP or sending domain.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let status = client.blocklists.get("192.0.2.10").await?;
+    /// if status.is_listed() {
+    ///     eprintln!("{status}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, target: &str) -> crate::Result<BlocklistStatus> {
+        self.get_with_meta(target).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, target: &str) -> crate::Result<WithMeta<BlocklistStatus>> {
+        let path = format!("/blocklists/{target}");
+        let request = self.0.build(Method::GET, &path).route("/blocklists/{id}");
+        let response: WithMeta<BlocklistStatusResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Drops cached `/blocklists` responses, so the next call fetches fresh
+    /// data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/blocklists");
+    }
+}
+
+/// The `/blocklists` operations of [`BlocklistsSvc`], as a trait.
+///
+/// Application code can depend on `dyn BlocklistsApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait BlocklistsApi: Send + Sync {
+    /// See [`BlocklistsSvc::list`].
+    async fn list(&self) -> crate::Result<Vec<BlocklistStatus>>;
+
+    /// See [`BlocklistsSvc::get`].
+    async fn get(&self, target: &str) -> crate::Result<BlocklistStatus>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl BlocklistsApi for BlocklistsSvc {
+    async fn list(&self) -> crate::Result<Vec<BlocklistStatus>> {
+        BlocklistsSvc::list(self).await
+    }
+
+    async fn get(&self, target: &str) -> crate::Result<BlocklistStatus> {
+        BlocklistsSvc::get(self, target).await
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListBlocklistsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListBlocklistsData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListBlocklistsData {
+    statuses: Vec<BlocklistStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlocklistStatusResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: BlocklistStatus,
+}
+
+/// The blocklist status of a dedicated IP or sending domain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct BlocklistStatus {
+    /// The checked IP address or domain.
+    pub target: String,
+    /// Kind of target: `"ip"` or `"domain"`.
+    pub target_type: String,
+    /// Blocklists currently listing the target. Empty if it is clean.
+    #[serde(default)]
+    pub listings: Vec<BlocklistListing>,
+    /// Timestamp of the last check.
+    pub checked_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl BlocklistStatus {
+    /// Creates a status without listings. Set the other fields as needed,
+    /// e.g. for a mock [`BlocklistsApi`].
+    pub fn new(target: impl Into<String>, target_type: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            target_type: target_type.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether any blocklist lists the target.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::blocklists::{BlocklistListing, BlocklistStatus};
+    ///
+    /// let mut status = BlocklistStatus::new("192.0.2.10", "ip");
+    /// assert!(!status.is_listed());
+    ///
+    /// status.listings.push(BlocklistListing::new(
+    ///     "Spamhaus ZEN",
+    ///     "2024-01-15T10:30:00+00:00",
+    /// ));
+    /// assert!(status.is_listed());
+    /// ```
+    #[must_use]
+    pub fn is_listed(&self) -> bool {
+        !self.listings.is_empty()
+    }
+}
+
+/// Formats as a one-line summary, e.g. `192.0.2.10 (ip, listed on 2
+/// blocklists)` or `example.com (domain, not listed)`.
+impl fmt::Display for BlocklistStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_listed() {
+            return write!(f, "{} ({}, not listed)", self.target, self.target_type);
+        }
+        let blocklists = if self.listings.len() == 1 {
+            "blocklist"
+        } else {
+            "blocklists"
+        };
+        write!(
+            f,
+            "{} ({}, listed on {} {blocklists})",
+            self.target,
+            self.target_type,
+            self.listings.len()
+        )
+    }
+}
+
+/// A blocklist entry for an IP or domain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct BlocklistListing {
+    /// Name of the blocklist, e.g. `"Spamhaus ZEN"`.
+    pub blocklist: String,
+    /// Timestamp of the first check that found the listing.
+    pub listed_since: String,
+    /// Page for requesting removal from the blocklist, if it has one.
+    #[serde(default)]
+    pub delist_url: Option<String>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl BlocklistListing {
+    /// Creates a listing with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`BlocklistsApi`].
+    pub fn new(blocklist: impl Into<String>, listed_since: impl Into<String>) -> Self {
+        Self {
+            blocklist: blocklist.into(),
+            listed_since: listed_since.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g. `Spamhaus ZEN (since
+/// 2024-01-15T10:30:00+00:00)`.
+impl fmt::Display for BlocklistListing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (since {})", self.blocklist, self.listed_since)
+    }
+}
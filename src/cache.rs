@@ -0,0 +1,193 @@
+//! In-memory caching of `GET` responses.
+//!
+//! A [`CacheConfig`] can be attached to a client via
+//! [`LettrBuilder::cache`](crate::LettrBuilder::cache). Successful `GET`
+//! responses are then kept for the configured TTL and served without a
+//! request, which helps hot paths such as checking
+//! [`Domain::can_send`](crate::domains::Domain::can_send) before every email.
+//!
+//! Successful `POST`, `PUT`, `PATCH`, and `DELETE` requests invalidate the
+//! cached entries of the resource they modified. Use
+//! [`Lettr::clear_cache`](crate::Lettr::clear_cache) or the services'
+//! `invalidate_cache` methods to drop entries explicitly.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::Url;
+
+use crate::time::Instant;
+use crate::transport::Response;
+
+/// Default maximum number of cached responses.
+const DEFAULT_CAPACITY: usize = 256;
+
+/// Response cache configuration.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use lettr::Lettr;
+/// use lettr::cache::CacheConfig;
+///
+/// let client = Lettr::builder("your-api-key")
+///     .cache(CacheConfig::new(Duration::from_secs(60)).capacity(100))
+///     .build();
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    ttl: Duration,
+    capacity: usize,
+}
+
+impl CacheConfig {
+    /// Creates a new [`CacheConfig`] keeping responses for `ttl`.
+    ///
+    /// The capacity defaults to 256 responses.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            capacity: DEFAULT_CAPACITY,
+        }
+    }
+
+    /// Sets the maximum number of cached responses.
+    ///
+    /// When the cache is full, the oldest response is evicted.
+    #[inline]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+}
+
+/// Shared response cache.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    config: CacheConfig,
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    response: Response,
+    inserted: Instant,
+}
+
+impl ResponseCache {
+    /// Creates a new, empty [`ResponseCache`].
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cached response for `url`, if it is still fresh.
+    pub fn get(&self, url: &Url, now: Instant) -> Option<Response> {
+        let mut entries = self.lock();
+        let entry = entries.get(url.as_str())?;
+        if now.duration_since(entry.inserted) < self.config.ttl {
+            return Some(entry.response.clone());
+        }
+        entries.remove(url.as_str());
+        None
+    }
+
+    /// Caches `response` for `url`.
+    pub fn insert(&self, url: &Url, response: Response, now: Instant) {
+        if self.config.capacity == 0 {
+            return;
+        }
+
+        let ttl = self.config.ttl;
+        let mut entries = self.lock();
+        entries.retain(|_, entry| now.duration_since(entry.inserted) < ttl);
+
+        if entries.len() >= self.config.capacity && !entries.contains_key(url.as_str()) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.inserted)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(
+            url.as_str().to_owned(),
+            Entry {
+                response,
+                inserted: now,
+            },
+        );
+    }
+
+    /// Removes the entries for `prefix` and the paths below it, with any
+    /// query, e.g. `/emails?page=2` and `/emails/abc` for `/emails`, but not
+    /// `/emails-archive`.
+    pub fn invalidate(&self, prefix: &str) {
+        // Compare in the form the keys were serialized in.
+        let prefix = Url::parse(prefix).map_or_else(|_| prefix.to_owned(), String::from);
+        self.lock().retain(|url, _| !covers(&prefix, url));
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Returns `true` if `url` is `prefix` or below it, with or without a query.
+fn covers(prefix: &str, url: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    url.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '?']))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::Url;
+
+    use super::{CacheConfig, ResponseCache};
+    use crate::time::Instant;
+    use crate::transport::{Response, StatusCode};
+
+    #[test]
+    fn invalidate_stops_at_path_segments() {
+        let cache = ResponseCache::new(CacheConfig::new(Duration::from_secs(60)));
+        let now = Instant::now();
+        let urls = [
+            "https://api.example.com/v1/emails",
+            "https://api.example.com/v1/emails?page=2",
+            "https://api.example.com/v1/emails/abc",
+            "https://api.example.com/v1/emails-archive",
+            "https://api.example.com/v1/domains",
+        ];
+        for url in urls {
+            let response = Response::new(StatusCode::OK, Default::default(), "{}");
+            cache.insert(&Url::parse(url).unwrap(), response, now);
+        }
+
+        cache.invalidate("https://api.example.com/v1/emails");
+
+        let mut cached: Vec<_> = cache.lock().keys().cloned().collect();
+        cached.sort();
+        assert_eq!(
+            cached,
+            [
+                "https://api.example.com/v1/domains",
+                "https://api.example.com/v1/emails-archive",
+            ]
+        );
+    }
+}
@@ -0,0 +1,306 @@
+//! Recording and replaying HTTP traffic for tests.
+//!
+//! A cassette is a JSON file of request/response pairs. With
+//! `LETTR_RECORD=1` set, a [`CassetteTransport`] sends requests to the real
+//! API and records every exchange; otherwise it answers requests from the
+//! file without touching the network. This lets a test suite cover real
+//! response shapes while running offline in CI.
+//!
+//! Secrets are scrubbed before anything is written: request headers
+//! (including the API key) are never recorded, cookies are dropped from
+//! response headers, attachment contents and substitution values are
+//! redacted from request bodies, and passwords and secrets from response
+//! bodies.
+//!
+//! Enable it with [`LettrBuilder::cassette`](crate::LettrBuilder::cassette):
+//!
+//! ```rust,no_run
+//! # async fn run() -> lettr::Result<()> {
+//! let client = lettr::Lettr::builder("your-api-key")
+//!     .cassette("tests/cassettes/domains.json")
+//!     .build();
+//!
+//! let domains = client.domains.list().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Requests are matched to recorded exchanges by method, path, and query, in
+//! recording order; bodies are not compared.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use reqwest::header::{HeaderName, HeaderValue, COOKIE, SET_COOKIE};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::transport::{HeaderMap, HttpTransport, Request, Response, StatusCode};
+
+/// Environment variable that switches cassettes to recording.
+pub const RECORD_ENV: &str = "LETTR_RECORD";
+
+/// An [`HttpTransport`] that records traffic to, or replays it from, a
+/// cassette file.
+pub struct CassetteTransport {
+    path: PathBuf,
+    mode: Mode,
+    state: Mutex<State>,
+}
+
+enum Mode {
+    Record(Arc<dyn HttpTransport>),
+    Replay,
+}
+
+#[derive(Default)]
+struct State {
+    interactions: Vec<Interaction>,
+    /// Which interactions have been replayed already.
+    used: Vec<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    /// Path and query of the URL; the host is left out so cassettes work
+    /// against any region.
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    body: String,
+}
+
+impl CassetteTransport {
+    /// Creates a transport that sends requests through `inner` and records
+    /// them to `path`, replacing any existing cassette.
+    pub fn record(path: impl Into<PathBuf>, inner: impl HttpTransport + 'static) -> Self {
+        Self::recording(path.into(), Arc::new(inner))
+    }
+
+    /// Creates a transport that answers requests from the cassette at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the cassette cannot
+    /// be read or is invalid.
+    pub fn replay(path: impl Into<PathBuf>) -> crate::Result<Self> {
+        let path = path.into();
+        let interactions = load(&path)?;
+        Ok(Self {
+            state: Mutex::new(State {
+                used: vec![false; interactions.len()],
+                interactions,
+            }),
+            path,
+            mode: Mode::Replay,
+        })
+    }
+
+    /// Records through `inner` if [`RECORD_ENV`] is set to `1` or `true`, and
+    /// replays otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if replaying and the
+    /// cassette cannot be read or is invalid.
+    pub fn from_env(
+        path: impl Into<PathBuf>,
+        inner: impl HttpTransport + 'static,
+    ) -> crate::Result<Self> {
+        Self::from_env_shared(path.into(), Arc::new(inner))
+    }
+
+    pub(crate) fn from_env_shared(
+        path: PathBuf,
+        inner: Arc<dyn HttpTransport>,
+    ) -> crate::Result<Self> {
+        if recording() {
+            Ok(Self::recording(path, inner))
+        } else {
+            Self::replay(path)
+        }
+    }
+
+    fn recording(path: PathBuf, inner: Arc<dyn HttpTransport>) -> Self {
+        Self {
+            path,
+            mode: Mode::Record(inner),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Returns `true` if requests go to the network and are recorded.
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        matches!(self.mode, Mode::Record(_))
+    }
+
+    /// Returns the path of the cassette file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn save(&self, request: RecordedRequest, response: &Response) -> crate::Result<()> {
+        let interaction = Interaction {
+            request,
+            response: RecordedResponse::from_response(response),
+        };
+        let mut state = self.state();
+        state.interactions.push(interaction);
+        let json = serde_json::to_vec_pretty(&state.interactions)
+            .map_err(|e| crate::Error::Transport(Box::new(e)))?;
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(|e| crate::Error::Transport(Box::new(e)))?;
+        }
+        std::fs::write(&self.path, json).map_err(|e| crate::Error::Transport(Box::new(e)))
+    }
+
+    fn find(&self, request: &RecordedRequest) -> crate::Result<Response> {
+        let mut state = self.state();
+        let State { interactions, used } = &mut *state;
+        let index = interactions
+            .iter()
+            .zip(used.iter())
+            .position(|(interaction, used)| {
+                !used
+                    && interaction.request.method == request.method
+                    && interaction.request.path == request.path
+            })
+            .ok_or_else(|| {
+                crate::Error::Transport(
+                    format!(
+                        "no recorded response for {} {} in cassette {}; run with {RECORD_ENV}=1 \
+                         to record it",
+                        request.method,
+                        request.path,
+                        self.path.display()
+                    )
+                    .into(),
+                )
+            })?;
+        used[index] = true;
+        interactions[index].response.to_response(&self.path)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl HttpTransport for CassetteTransport {
+    async fn execute(&self, request: Request) -> crate::Result<Response> {
+        let recorded = RecordedRequest::from_request(&request);
+        match self.mode {
+            Mode::Record(ref inner) => {
+                let response = inner.execute(request).await?;
+                self.save(recorded, &response)?;
+                Ok(response)
+            }
+            Mode::Replay => self.find(&recorded),
+        }
+    }
+}
+
+impl fmt::Debug for CassetteTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CassetteTransport")
+            .field("path", &self.path)
+            .field("recording", &self.is_recording())
+            .finish()
+    }
+}
+
+impl RecordedRequest {
+    fn from_request(request: &Request) -> Self {
+        let mut path = request.url.path().to_owned();
+        if let Some(query) = request.url.query() {
+            path.push('?');
+            path.push_str(query);
+        }
+        let body = request
+            .body
+            .as_ref()
+            .and_then(|body| serde_json::from_slice::<Value>(body).ok())
+            .map(|mut json| {
+                crate::wire_log::redact(&mut json);
+                json
+            });
+        Self {
+            method: request.method.to_string(),
+            path,
+            body,
+        }
+    }
+}
+
+impl RecordedResponse {
+    fn from_response(response: &Response) -> Self {
+        let headers = response
+            .headers
+            .iter()
+            .filter(|(name, _)| **name != SET_COOKIE && **name != COOKIE)
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+            .collect();
+        let mut body = String::from_utf8_lossy(&response.body).into_owned();
+        if let Ok(mut json) = serde_json::from_str::<Value>(&body) {
+            if crate::wire_log::redact_secrets(&mut json) {
+                body = json.to_string();
+            }
+        }
+        Self {
+            status: response.status.as_u16(),
+            headers,
+            body,
+        }
+    }
+
+    fn to_response(&self, path: &Path) -> crate::Result<Response> {
+        let invalid = |what: &str| {
+            crate::Error::Transport(format!("invalid {what} in cassette {}", path.display()).into())
+        };
+        let status = StatusCode::from_u16(self.status).map_err(|_| invalid("status"))?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.insert(
+                HeaderName::try_from(name.as_str()).map_err(|_| invalid("header name"))?,
+                HeaderValue::try_from(value.as_str()).map_err(|_| invalid("header value"))?,
+            );
+        }
+        Ok(Response::new(status, headers, self.body.clone()))
+    }
+}
+
+/// Returns `true` if [`RECORD_ENV`] asks for recording.
+fn recording() -> bool {
+    std::env::var(RECORD_ENV).is_ok_and(|value| value == "1" || value.eq_ignore_ascii_case("true"))
+}
+
+fn load(path: &Path) -> crate::Result<Vec<Interaction>> {
+    let bytes = std::fs::read(path).map_err(|e| {
+        crate::Error::Config(format!(
+            "cannot read cassette {}: {e}; run with {RECORD_ENV}=1 to record it",
+            path.display()
+        ))
+    })?;
+    serde_json::from_slice(&bytes)
+        .map_err(|e| crate::Error::Config(format!("invalid cassette {}: {e}", path.display())))
+}
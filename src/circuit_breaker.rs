@@ -0,0 +1,275 @@
+//! Circuit breaking for API outages.
+//!
+//! With a [`CircuitBreakerConfig`] attached via
+//! [`LettrBuilder::circuit_breaker`](crate::LettrBuilder::circuit_breaker),
+//! the client stops sending requests after a run of consecutive failures and
+//! fails fast with [`Error::CircuitOpen`](crate::Error::CircuitOpen) instead
+//! of waiting on timeouts. After the open period a few probe requests are let
+//! through; if they succeed the circuit closes again.
+//!
+//! Connection failures and `5xx` responses count as failures. Other error
+//! responses show that the API is up, so they count as successes.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::time::Instant;
+
+/// Circuit breaker configuration.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use lettr::Lettr;
+/// use lettr::circuit_breaker::CircuitBreakerConfig;
+///
+/// let client = Lettr::builder("your-api-key")
+///     .circuit_breaker(
+///         CircuitBreakerConfig::new(5, Duration::from_secs(30)).half_open_probes(2),
+///     )
+///     .build();
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    failure_threshold: u32,
+    open_duration: Duration,
+    half_open_probes: u32,
+}
+
+impl CircuitBreakerConfig {
+    /// Creates a new [`CircuitBreakerConfig`] that opens the circuit after
+    /// `failure_threshold` consecutive failures and keeps it open for
+    /// `open_duration`.
+    ///
+    /// One probe request is allowed in the half-open state by default.
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+            half_open_probes: 1,
+        }
+    }
+
+    /// Sets how many probe requests must succeed in the half-open state
+    /// before the circuit closes.
+    ///
+    /// Values below one are treated as one.
+    #[inline]
+    pub fn half_open_probes(mut self, probes: u32) -> Self {
+        self.half_open_probes = probes.max(1);
+        self
+    }
+}
+
+/// Shared circuit breaker state.
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { in_flight: u32, successes: u32 },
+}
+
+impl CircuitBreaker {
+    /// Creates a new, closed [`CircuitBreaker`].
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(State::Closed { failures: 0 }),
+        }
+    }
+
+    /// Ask permission to send a request.
+    ///
+    /// Fails with the time until the next probe when the circuit is open.
+    /// The outcome of a granted request is reported with
+    /// [`Permit::record`].
+    pub fn acquire(&self, now: Instant) -> Result<Permit<'_>, Duration> {
+        let mut state = self.lock();
+
+        if let State::Open { until } = *state {
+            if now < until {
+                return Err(until - now);
+            }
+            *state = State::HalfOpen {
+                in_flight: 0,
+                successes: 0,
+            };
+        }
+
+        match *state {
+            State::HalfOpen {
+                ref mut in_flight,
+                successes,
+            } => {
+                if *in_flight + successes >= self.config.half_open_probes {
+                    return Err(Duration::ZERO);
+                }
+                *in_flight += 1;
+                Ok(Permit {
+                    breaker: self,
+                    probe: true,
+                })
+            }
+            _ => Ok(Permit {
+                breaker: self,
+                probe: false,
+            }),
+        }
+    }
+
+    /// Record the outcome of a request granted by [`acquire`](Self::acquire).
+    fn record(&self, success: bool, probe: bool, now: Instant) {
+        let mut state = self.lock();
+
+        // Only probes decide the half-open state; this request was granted
+        // before the circuit opened.
+        if !probe && matches!(*state, State::HalfOpen { .. }) {
+            return;
+        }
+
+        *state = match *state {
+            State::Closed { .. } if success => State::Closed { failures: 0 },
+            State::Closed { failures } if failures + 1 < self.config.failure_threshold => {
+                State::Closed {
+                    failures: failures + 1,
+                }
+            }
+            State::HalfOpen {
+                in_flight,
+                successes,
+            } if success => {
+                if successes + 1 >= self.config.half_open_probes {
+                    State::Closed { failures: 0 }
+                } else {
+                    State::HalfOpen {
+                        in_flight: in_flight.saturating_sub(1),
+                        successes: successes + 1,
+                    }
+                }
+            }
+            // A request granted before the circuit opened finished meanwhile.
+            State::Open { until } => State::Open { until },
+            _ => State::Open {
+                until: now + self.config.open_duration,
+            },
+        };
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Permission to send one request, granted by [`CircuitBreaker::acquire`].
+///
+/// Dropping it without [`record`](Self::record), e.g. when the request's
+/// future is cancelled, frees its probe slot without counting an outcome.
+#[derive(Debug)]
+pub(crate) struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    /// Whether the request is a half-open probe holding a slot.
+    probe: bool,
+}
+
+impl Permit<'_> {
+    /// Record whether the API was reachable for the request.
+    pub fn record(mut self, success: bool, now: Instant) {
+        let probe = std::mem::take(&mut self.probe);
+        self.breaker.record(success, probe, now);
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if !self.probe {
+            return;
+        }
+        if let State::HalfOpen {
+            ref mut in_flight, ..
+        } = *self.breaker.lock()
+        {
+            *in_flight = in_flight.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::{CircuitBreaker, CircuitBreakerConfig};
+    use crate::time::Instant;
+    use crate::transport::{async_trait, HttpTransport, Request, Response, StatusCode};
+    use crate::{Error, Lettr};
+
+    /// Fails the first request with a server error, never answers the
+    /// second, and answers later ones.
+    #[derive(Default)]
+    struct Flaky {
+        requests: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl HttpTransport for Flaky {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            let body = r#"{"message":"OK","data":{"status":"ok","timestamp":"now"}}"#;
+            match self.requests.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok(Response::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Default::default(),
+                    "{}",
+                )),
+                1 => std::future::pending().await,
+                _ => Ok(Response::new(StatusCode::OK, Default::default(), body)),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn dropped_probe_frees_its_slot() {
+        let client = Lettr::builder("test-key")
+            .transport(Flaky::default())
+            .circuit_breaker(CircuitBreakerConfig::new(1, Duration::ZERO))
+            .build();
+
+        assert!(client.health().await.is_err());
+
+        // The probe is cancelled while the transport is waiting.
+        let probe = tokio::time::timeout(Duration::from_millis(10), client.health()).await;
+        assert!(probe.is_err());
+
+        match client.health().await {
+            Ok(health) => assert_eq!(health.data.status, "ok"),
+            Err(Error::CircuitOpen { .. }) => panic!("the dropped probe leaked its slot"),
+            Err(err) => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[test]
+    fn only_the_probe_closes_a_half_open_circuit() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig::new(1, Duration::from_secs(10)));
+        let start = Instant::now();
+
+        let failed = breaker.acquire(start).unwrap();
+        let slow = breaker.acquire(start).unwrap();
+        failed.record(false, start);
+
+        let later = start + Duration::from_secs(10);
+        let probe = breaker.acquire(later).unwrap();
+        // Granted before the circuit opened, so its success does not count.
+        slow.record(true, later);
+        assert_eq!(breaker.acquire(later).unwrap_err(), Duration::ZERO);
+
+        probe.record(true, later);
+        assert!(breaker.acquire(later).is_ok());
+    }
+}
@@ -1,9 +1,44 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::config::Config;
+use crate::api_key::ApiKey;
+use crate::assets::AssetsSvc;
+use crate::audiences::AudiencesSvc;
+use crate::blocklists::BlocklistsSvc;
+use crate::broadcasts::BroadcastsSvc;
+use crate::cache::CacheConfig;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::cassette::CassetteTransport;
+use crate::circuit_breaker::CircuitBreakerConfig;
+use crate::clock::{Clock, SharedClock};
+use crate::config::{Config, DEFAULT_RAW_BODY_LIMIT};
+use crate::contacts::ContactsSvc;
 use crate::domains::DomainsSvc;
-use crate::emails::EmailsSvc;
+use crate::emails::{CreateEmailOptions, EmailDefaults, EmailsSvc};
+use crate::exports::ExportsSvc;
+use crate::inbound::InboundSvc;
+use crate::ip_pools::IpPoolsSvc;
+use crate::meta::WithMeta;
+use crate::middleware::{ErrorHook, Middleware, RequestContext};
+use crate::profile::Profile;
+use crate::rate_limit::RateLimit;
+use crate::retry::RetryPolicy;
+use crate::send_window::{DeferHook, SendWindow};
+use crate::smtp::SmtpCredentialsSvc;
+use crate::stats::StatsSvc;
+use crate::team::TeamSvc;
 use crate::templates::TemplatesSvc;
+use crate::time::Instant;
+#[cfg(all(
+    any(feature = "rustls", feature = "native-tls"),
+    not(target_arch = "wasm32")
+))]
+use crate::tls::{Certificate, Identity, TlsConfig};
+use crate::transport::{
+    HttpClient, HttpClientBuilder, HttpOptions, HttpTransport, ReqwestTransport, SharedTransport,
+};
+use crate::unsubscribe_groups::UnsubscribeGroupsSvc;
 use crate::webhooks::WebhooksSvc;
 
 /// The Lettr API client.
@@ -37,8 +72,32 @@ pub struct Lettr {
     pub webhooks: WebhooksSvc,
     /// Template listing and creation.
     pub templates: TemplatesSvc,
+    /// Contact management.
+    pub contacts: ContactsSvc,
+    /// Audience lists and segments.
+    pub audiences: AudiencesSvc,
+    /// Campaigns sent to an audience.
+    pub broadcasts: BroadcastsSvc,
+    /// Aggregate sending statistics.
+    pub stats: StatsSvc,
+    /// SMTP relay credentials.
+    pub smtp_credentials: SmtpCredentialsSvc,
+    /// Inbound routes and received messages.
+    pub inbound: InboundSvc,
+    /// Dedicated IP pools.
+    pub ip_pools: IpPoolsSvc,
+    /// Team members and invitations.
+    pub team: TeamSvc,
+    /// Asynchronous event exports.
+    pub exports: ExportsSvc,
+    /// Unsubscribe groups and recipient preferences.
+    pub unsubscribe_groups: UnsubscribeGroupsSvc,
+    /// Blocklist status of dedicated IPs and sending domains.
+    pub blocklists: BlocklistsSvc,
+    /// Images hosted for use in templates.
+    pub assets: AssetsSvc,
 
-    config: Arc<Config>,
+    pub(crate) config: Arc<Config>,
 }
 
 impl Lettr {
@@ -49,13 +108,64 @@ impl Lettr {
     /// Panics if the API key contains non-ASCII characters.
     #[must_use]
     pub fn new(api_key: &str) -> Self {
-        let config = Arc::new(Config::new(api_key));
+        Self::builder(api_key).build()
+    }
+
+    /// Creates a new [`Lettr`] client with the given API key, returning an
+    /// error instead of panicking on an invalid key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the API key contains
+    /// non-ASCII characters or the HTTP client cannot be initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::{Error, Lettr};
+    ///
+    /// assert!(matches!(Lettr::try_new("kéy"), Err(Error::Config(_))));
+    /// ```
+    pub fn try_new(api_key: &str) -> crate::Result<Self> {
+        Self::builder(api_key).try_build()
+    }
+
+    /// Creates a [`LettrBuilder`] for configuring a client with the given API key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::Lettr;
+    /// use lettr::rate_limit::RateLimit;
+    ///
+    /// let client = Lettr::builder("your-api-key")
+    ///     .rate_limit(RateLimit::per_second(10).burst(20))
+    ///     .build();
+    /// ```
+    pub fn builder(api_key: &str) -> LettrBuilder {
+        LettrBuilder::new(api_key)
+    }
+
+    fn from_inner(config: Config) -> Self {
+        let config = Arc::new(config);
 
         Self {
             emails: EmailsSvc(Arc::clone(&config)),
             domains: DomainsSvc(Arc::clone(&config)),
             webhooks: WebhooksSvc(Arc::clone(&config)),
             templates: TemplatesSvc(Arc::clone(&config)),
+            contacts: ContactsSvc(Arc::clone(&config)),
+            audiences: AudiencesSvc(Arc::clone(&config)),
+            broadcasts: BroadcastsSvc(Arc::clone(&config)),
+            stats: StatsSvc(Arc::clone(&config)),
+            smtp_credentials: SmtpCredentialsSvc(Arc::clone(&config)),
+            inbound: InboundSvc(Arc::clone(&config)),
+            ip_pools: IpPoolsSvc(Arc::clone(&config)),
+            team: TeamSvc(Arc::clone(&config)),
+            exports: ExportsSvc(Arc::clone(&config)),
+            unsubscribe_groups: UnsubscribeGroupsSvc(Arc::clone(&config)),
+            blocklists: BlocklistsSvc(Arc::clone(&config)),
+            assets: AssetsSvc(Arc::clone(&config)),
             config,
         }
     }
@@ -67,64 +177,1260 @@ impl Lettr {
     /// Panics if the environment variable is not set.
     #[must_use]
     pub fn from_env() -> Self {
-        let api_key =
-            std::env::var("LETTR_API_KEY").expect("LETTR_API_KEY environment variable not set");
-        Self::new(&api_key)
+        Self::try_from_env().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates a new [`Lettr`] client from the `LETTR_API_KEY` environment
+    /// variable, returning an error instead of panicking on misconfiguration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the variable is not
+    /// set or does not hold a valid API key.
+    pub fn try_from_env() -> crate::Result<Self> {
+        let api_key = std::env::var("LETTR_API_KEY").map_err(|_| {
+            crate::Error::Config("LETTR_API_KEY environment variable not set".to_owned())
+        })?;
+        Self::try_new(&api_key)
+    }
+
+    /// Creates a new [`Lettr`] client from a TOML config file.
+    ///
+    /// See [`LettrBuilder::from_config`] for the file format. Only available
+    /// with the `toml` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the file cannot be
+    /// read, is invalid, or holds no API key.
+    #[cfg(all(feature = "toml", not(target_arch = "wasm32")))]
+    pub fn from_config(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        LettrBuilder::from_config(path)?.try_build()
+    }
+
+    /// Creates a new [`Lettr`] client from environment variables starting with
+    /// `prefix`.
+    ///
+    /// See [`LettrBuilder::from_env_prefixed`] for the variable names.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if a variable holds an
+    /// invalid value or the API key variable is not set.
+    pub fn from_env_prefixed(prefix: &str) -> crate::Result<Self> {
+        LettrBuilder::from_env_prefixed(prefix)?.try_build()
+    }
+
+    /// Drops all responses cached by this client and its clones.
+    ///
+    /// Does nothing unless caching was enabled with [`LettrBuilder::cache`].
+    pub fn clear_cache(&self) {
+        self.config.clear_cache();
+    }
+
+    /// Returns a client applying `options` to every request it sends.
+    ///
+    /// The returned client is cheap to create and shares the transport,
+    /// limiters, circuit breaker, and shutdown state with `self`, so
+    /// multi-tenant platforms can use one key per customer team without
+    /// building a client per tenant. Clients with a different API key do not
+    /// use the response cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the API key
+    /// override contains non-ASCII characters.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use lettr::{Lettr, CreateEmailOptions, RequestOptions};
+    ///
+    /// # async fn run(tenant_key: &str) -> lettr::Result<()> {
+    /// let client = Lettr::new("platform-api-key");
+    ///
+    /// let email = CreateEmailOptions::new("sender@example.com", ["user@example.com"], "Hello!")
+    ///     .with_text("Hello!");
+    ///
+    /// client
+    ///     .with_options(RequestOptions::new().api_key(tenant_key))?
+    ///     .emails
+    ///     .send(email)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> crate::Result<Self> {
+        let config = match options.api_key {
+            Some(api_key) => self.config.with_api_key(api_key)?,
+            None => Config::clone(&self.config),
+        };
+        Ok(Self::from_inner(config))
+    }
+
+    /// Shuts down the client and all its clones, waiting up to `timeout` for
+    /// in-flight requests to finish.
+    ///
+    /// New requests fail with [`Error::Shutdown`](crate::Error::Shutdown) and
+    /// background components such as a [`SendQueue`](crate::queue::SendQueue)
+    /// worker stop after their current email. Returns `false` if requests were
+    /// still in flight when the timeout elapsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # async fn run() {
+    /// let client = lettr::Lettr::new("your-api-key");
+    ///
+    /// // On SIGTERM:
+    /// if !client.shutdown(Duration::from_secs(20)).await {
+    ///     eprintln!("some requests did not finish in time");
+    /// }
+    /// # }
+    /// ```
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.config.shutdown(timeout).await
     }
 
     /// Check the health of the Lettr API.
     ///
     /// This endpoint does not require authentication.
-    #[maybe_async::maybe_async]
     pub async fn health(&self) -> crate::Result<HealthResponse> {
+        self.health_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`health`](Self::health), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn health_with_meta(&self) -> crate::Result<WithMeta<HealthResponse>> {
         let request = self.config.build(reqwest::Method::GET, "/health");
-        let response = self.config.send(request).await?;
-        let body = response.json::<HealthResponse>().await?;
-        Ok(body)
+        self.config.send_json_with_meta(request).await
+    }
+
+    /// Probes the API for readiness checks and status pages.
+    ///
+    /// Calls [`health`](Self::health), bypassing the response cache, and
+    /// reports the outcome and latency instead of an error. The probe gives up
+    /// after two seconds; use [`readiness_within`](Self::readiness_within) for
+    /// another limit.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// let client = lettr::Lettr::new("your-api-key");
+    ///
+    /// let readiness = client.readiness().await;
+    /// if !readiness.healthy {
+    ///     eprintln!("Lettr is not ready: {:?}", readiness.last_error);
+    /// }
+    /// # }
+    /// ```
+    pub async fn readiness(&self) -> Readiness {
+        self.readiness_within(READINESS_TIMEOUT).await
     }
 
-    /// Validate the API key and return associated team information.
-    #[maybe_async::maybe_async]
+    /// Like [`readiness`](Self::readiness), giving up after `timeout`.
+    pub async fn readiness_within(&self, timeout: Duration) -> Readiness {
+        let started = Instant::now();
+        let request = self
+            .config
+            .build(reqwest::Method::GET, "/health")
+            .no_cache();
+
+        let result = crate::time::timeout(timeout, self.config.send(request))
+            .await
+            .unwrap_or(Err(crate::Error::Timeout(timeout)));
+
+        let latency = started.elapsed();
+        let last_error = match result {
+            Ok(_) if latency > timeout => Some(crate::Error::Timeout(timeout)),
+            Ok(_) => None,
+            Err(err) => Some(err),
+        };
+
+        Readiness {
+            healthy: last_error.is_none(),
+            latency,
+            last_error,
+        }
+    }
+
+    /// Opens a connection to the API host ahead of the first real request.
+    ///
+    /// Resolves DNS and completes the TCP and TLS handshakes by calling the
+    /// health endpoint, bypassing the response cache. The connection stays in
+    /// the client's pool, so the first email sent afterwards, e.g. right
+    /// after a serverless cold start, skips the setup latency.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API could not be reached or reported itself
+    /// unhealthy.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = lettr::Lettr::new("your-api-key");
+    /// client.warm_up().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn warm_up(&self) -> crate::Result<()> {
+        let request = self
+            .config
+            .build(reqwest::Method::GET, "/health")
+            .no_cache();
+        self.config.send(request).await.map(drop)
+    }
+
+    /// Validate the API key and return associated team information, plus the
+    /// key's name, scopes, and expiry when the API reports them.
+    ///
+    /// Use [`AuthCheckData::can_send_emails`] to fail fast at startup when
+    /// handed a read-only key.
     pub async fn auth_check(&self) -> crate::Result<AuthCheckResponse> {
+        self.auth_check_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`auth_check`](Self::auth_check), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn auth_check_with_meta(&self) -> crate::Result<WithMeta<AuthCheckResponse>> {
         let request = self.config.build(reqwest::Method::GET, "/auth/check");
-        let response = self.config.send(request).await?;
-        let body = response.json::<AuthCheckResponse>().await?;
-        Ok(body)
+        self.config.send_json_with_meta(request).await
     }
 }
 
+/// The Lettr API region a client talks to.
+///
+/// Select a region with [`LettrBuilder::region`]. Customers with
+/// data-residency requirements can reach another API host, once Lettr gives
+/// them one, with [`Region::Custom`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Region {
+    /// The US region (`https://app.lettr.com/api`).
+    #[default]
+    Us,
+    /// A custom base URL, such as a private gateway or a local mock server.
+    ///
+    /// The URL should include the API path prefix, e.g. `https://gateway.internal/api`.
+    Custom(String),
+}
+
+impl Region {
+    /// Returns the API base URL for this region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::Region;
+    ///
+    /// assert_eq!(Region::Us.base_url(), "https://app.lettr.com/api");
+    /// assert_eq!(Region::Custom("http://localhost:8080".into()).base_url(), "http://localhost:8080");
+    /// ```
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        match self {
+            Self::Us => "https://app.lettr.com/api",
+            Self::Custom(url) => url,
+        }
+    }
+}
+
+/// Per-call overrides for a [`Lettr`] client, applied with
+/// [`Lettr::with_options`].
+#[must_use]
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    api_key: Option<ApiKey>,
+}
+
+impl RequestOptions {
+    /// Creates empty [`RequestOptions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the request with `api_key` instead of the client's key.
+    #[inline]
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(ApiKey::from(api_key.into()));
+        self
+    }
+
+    /// Like [`api_key`](Self::api_key), with the key held in a
+    /// [`SecretString`](secrecy::SecretString).
+    #[cfg(feature = "secrecy")]
+    #[inline]
+    pub fn api_key_secret(mut self, api_key: secrecy::SecretString) -> Self {
+        self.api_key = Some(ApiKey::from(api_key));
+        self
+    }
+}
+
+/// Builder for a configured [`Lettr`] client.
+///
+/// Created with [`Lettr::builder`].
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct LettrBuilder {
+    api_key: ApiKey,
+    rate_limit: Option<RateLimit>,
+    max_in_flight: Option<usize>,
+    cache: Option<CacheConfig>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    max_retries: u32,
+    retry_policy: RetryPolicy,
+    transport: Option<SharedTransport>,
+    clock: Option<SharedClock>,
+    region: Region,
+    api_version: Option<String>,
+    reject_version_mismatch: bool,
+    app_info: Option<String>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    error_hooks: Vec<ErrorHook>,
+    wire_logging: bool,
+    raw_body_limit: usize,
+    email_defaults: EmailDefaults,
+    send_window: Option<SendWindow>,
+    defer_hooks: Vec<DeferHook>,
+    #[cfg(not(target_arch = "wasm32"))]
+    cassette: Option<std::path::PathBuf>,
+    http: HttpOptions,
+    #[cfg(all(
+        any(feature = "rustls", feature = "native-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    tls: TlsConfig,
+}
+
+impl LettrBuilder {
+    /// Creates a new [`LettrBuilder`] with the given API key.
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self::with_api_key(ApiKey::from(api_key.into()))
+    }
+
+    /// Creates a new [`LettrBuilder`] with an API key held in a [`SecretString`].
+    ///
+    /// The key stays wrapped for the lifetime of the client and is zeroized
+    /// when the client is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::LettrBuilder;
+    /// use lettr::secrecy::SecretString;
+    ///
+    /// let key = SecretString::from("your-api-key");
+    /// let client = LettrBuilder::from_secret(key).build();
+    /// ```
+    ///
+    /// [`SecretString`]: secrecy::SecretString
+    #[cfg(feature = "secrecy")]
+    pub fn from_secret(api_key: secrecy::SecretString) -> Self {
+        Self::with_api_key(ApiKey::from(api_key))
+    }
+
+    /// Creates a new [`LettrBuilder`] from a TOML config file.
+    ///
+    /// Only `api_key` is required. `base_url` takes precedence over `region`
+    /// (only `"us"` for now). Settings not in the file can still be set on the
+    /// returned builder. Only available with the `toml` feature.
+    ///
+    /// ```toml
+    /// api_key = "your-api-key"
+    /// region = "us"
+    /// # base_url = "https://gateway.internal/api"
+    /// api_version = "2025-01-01"
+    /// timeout_secs = 10
+    /// connect_timeout_secs = 2.5
+    /// max_retries = 3
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the file cannot be
+    /// read, is invalid, or holds no API key.
+    #[cfg(all(feature = "toml", not(target_arch = "wasm32")))]
+    pub fn from_config(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        Profile::from_file(path)?.into_builder(&path.display().to_string())
+    }
+
+    /// Creates a new [`LettrBuilder`] from environment variables starting with
+    /// `prefix`.
+    ///
+    /// The variables mirror the keys of [`from_config`](Self::from_config):
+    /// `{prefix}API_KEY` (required), `{prefix}REGION`, `{prefix}BASE_URL`,
+    /// `{prefix}API_VERSION`, `{prefix}TIMEOUT_SECS`,
+    /// `{prefix}CONNECT_TIMEOUT_SECS`, and `{prefix}MAX_RETRIES`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if a variable holds an
+    /// invalid value or the API key variable is not set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// // Reads MYAPP_LETTR_API_KEY, MYAPP_LETTR_REGION, ...
+    /// let client = lettr::LettrBuilder::from_env_prefixed("MYAPP_LETTR_")?
+    ///     .max_in_flight(8)
+    ///     .try_build()?;
+    /// # Ok::<(), lettr::Error>(())
+    /// ```
+    pub fn from_env_prefixed(prefix: &str) -> crate::Result<Self> {
+        Profile::from_env(prefix)?.into_builder(&format!("`{prefix}*` environment variables"))
+    }
+
+    fn with_api_key(api_key: ApiKey) -> Self {
+        Self {
+            api_key,
+            rate_limit: None,
+            max_in_flight: None,
+            cache: None,
+            circuit_breaker: None,
+            max_retries: 0,
+            retry_policy: RetryPolicy::default(),
+            transport: None,
+            clock: None,
+            region: Region::default(),
+            api_version: None,
+            reject_version_mismatch: false,
+            app_info: None,
+            middleware: Vec::new(),
+            error_hooks: Vec::new(),
+            wire_logging: false,
+            raw_body_limit: DEFAULT_RAW_BODY_LIMIT,
+            email_defaults: EmailDefaults::default(),
+            send_window: None,
+            defer_hooks: Vec::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            cassette: None,
+            http: HttpOptions::default(),
+            #[cfg(all(
+                any(feature = "rustls", feature = "native-tls"),
+                not(target_arch = "wasm32")
+            ))]
+            tls: TlsConfig::default(),
+        }
+    }
+
+    /// Selects the API region, and with it the base URL requests are sent to.
+    ///
+    /// Defaults to [`Region::Us`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::{Lettr, Region};
+    ///
+    /// let client = Lettr::builder("your-api-key")
+    ///     .region(Region::Custom("https://lettr-gateway.internal/api".into()))
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn region(mut self, region: Region) -> Self {
+        self.region = region;
+        self
+    }
+
+    /// Pins the API version the client talks to.
+    ///
+    /// The version is sent in the [`Lettr-Version`](crate::meta::API_VERSION_HEADER)
+    /// header of every request. Without a pinned version, the API's current
+    /// default is used.
+    ///
+    /// A response reporting a different version in the same header is
+    /// logged as a warning. Responses without the header are accepted. To
+    /// fail such requests instead, enable
+    /// [`reject_version_mismatch`](Self::reject_version_mismatch).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .api_version("2025-01-01")
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn api_version(mut self, version: impl Into<String>) -> Self {
+        self.api_version = Some(version.into());
+        self
+    }
+
+    /// Fails requests with [`Error::VersionMismatch`](crate::Error::VersionMismatch)
+    /// when the response reports a different version than the one pinned
+    /// with [`api_version`](Self::api_version), instead of deserializing a
+    /// response shape the SDK may not expect. Disabled by default.
+    ///
+    /// Has no effect without a pinned version, or when the API does not send
+    /// the [`Lettr-Version`](crate::meta::API_VERSION_HEADER) header.
+    #[inline]
+    pub fn reject_version_mismatch(mut self, enabled: bool) -> Self {
+        self.reject_version_mismatch = enabled;
+        self
+    }
+
+    /// Identifies the application in the `User-Agent` header.
+    ///
+    /// `name/version` is appended to the default `lettr-rust/x.y.z`, so Lettr
+    /// support can tell which integration sent a request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // User-Agent: lettr-rust/0.1.0 myapp/1.2.3
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .app_info("myapp", "1.2.3")
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn app_info(mut self, name: impl AsRef<str>, version: impl AsRef<str>) -> Self {
+        self.app_info = Some(format!("{}/{}", name.as_ref(), version.as_ref()));
+        self
+    }
+
+    /// Limits how fast the client sends requests.
+    ///
+    /// The limit is shared by all services of the built client and its clones,
+    /// so batch jobs can stay within the API's limits instead of receiving
+    /// `429 Too Many Requests` responses.
+    #[inline]
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = Some(limit);
+        self
+    }
+
+    /// Limits how many requests the client sends concurrently.
+    ///
+    /// The limit is shared by all services of the built client and its clones,
+    /// so a burst of concurrent tasks waits for a free slot instead of opening
+    /// hundreds of connections. It composes with [`rate_limit`](Self::rate_limit):
+    /// a request first waits for the rate limiter, then for a free slot.
+    /// Waiting requests get a slot in the order they asked for one. Values
+    /// below one are treated as one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .max_in_flight(8)
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight);
+        self
+    }
+
+    /// Caches successful `GET` responses in memory.
+    ///
+    /// See the [`cache`](crate::cache) module for how entries are invalidated.
+    #[inline]
+    pub fn cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(config);
+        self
+    }
+
+    /// Fails requests fast while the API is unreachable or keeps returning
+    /// server errors.
+    ///
+    /// See the [`circuit_breaker`](crate::circuit_breaker) module for which
+    /// responses count as failures.
+    #[inline]
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Enables automatic retries for requests the API rejected without
+    /// processing them (`429 Too Many Requests` and `503 Service Unavailable`),
+    /// and for requests that could not connect.
+    ///
+    /// Between attempts the client waits as long as the API asked via the
+    /// `Retry-After` or `X-RateLimit-Reset` headers, falling back to
+    /// exponential backoff. If the API asks for more than 30 seconds, the
+    /// request fails right away instead, with the requested delay on the
+    /// error. Retries are disabled by default.
+    ///
+    /// The request body is serialized once, and every attempt sends the same
+    /// buffer, so retrying an email with large attachments neither
+    /// re-serializes nor copies it.
+    ///
+    /// Which failures are retried can be changed with
+    /// [`retry_policy`](Self::retry_policy).
+    #[inline]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets which failed requests are retried once
+    /// [`max_retries`](Self::max_retries) is set, e.g. to also retry
+    /// `502 Bad Gateway` or to exclude an endpoint. See the
+    /// [`retry`](crate::retry) module.
+    #[inline]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Sets the total timeout for each request attempt, from connecting until
+    /// the response body has been read.
+    ///
+    /// Not available on WASM targets, where the host controls timeouts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .timeout(Duration::from_secs(10))
+    ///     .connect_timeout(Duration::from_secs(2))
+    ///     .build();
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    ///
+    /// Not available on WASM targets, where the host controls timeouts.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Enables TCP keepalive probes on idle connections with the given interval.
+    ///
+    /// Not available on WASM targets.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.http.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Uses HTTP/2 without negotiating it first.
+    ///
+    /// Only useful in front of gateways known to speak HTTP/2, e.g. over
+    /// plain-text connections. Requires the `http2` feature; not available on
+    /// WASM targets.
+    #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+    #[inline]
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Lets HTTP/2 flow-control windows grow with the measured bandwidth.
+    ///
+    /// Helps long-lived senders pushing large batches over a single
+    /// connection. Requires the `http2` feature; not available on WASM
+    /// targets.
+    #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+    #[inline]
+    pub fn http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.http.http2_adaptive_window = Some(enabled);
+        self
+    }
+
+    /// Sends HTTP/2 pings at this interval, including while the connection
+    /// is idle, so dead connections are detected before they are reused.
+    ///
+    /// Requires the `http2` feature; not available on WASM targets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .http2_keep_alive_interval(Duration::from_secs(30))
+    ///     .http2_keep_alive_timeout(Duration::from_secs(10))
+    ///     .build();
+    /// ```
+    #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+    #[inline]
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Closes the connection if an HTTP/2 ping is not answered within
+    /// `timeout`. Only has an effect together with
+    /// [`http2_keep_alive_interval`](Self::http2_keep_alive_interval).
+    ///
+    /// Requires the `http2` feature; not available on WASM targets.
+    #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+    #[inline]
+    pub fn http2_keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.http.http2_keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends requests through an existing HTTP client instead of building a new one.
+    ///
+    /// This lets an application share its pre-configured client (proxy, TLS,
+    /// connection pool settings) with the SDK. The authentication and
+    /// `User-Agent` headers are added to each request, so the client needs no
+    /// Lettr-specific setup.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), reqwest::Error> {
+    /// let http = reqwest::Client::builder()
+    ///     .timeout(std::time::Duration::from_secs(10))
+    ///     .build()?;
+    ///
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .http_client(http)
+    ///     .build();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn http_client(self, client: HttpClient) -> Self {
+        self.transport(ReqwestTransport::new(client))
+    }
+
+    /// Sends requests through a custom [`HttpTransport`] instead of `reqwest`.
+    ///
+    /// See the [`transport`](crate::transport) module for an example.
+    #[inline]
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Trusts an additional root certificate, e.g. the CA of a TLS-intercepting
+    /// proxy.
+    ///
+    /// See the [`tls`](crate::tls) module for an example. Only available with
+    /// the `rustls` or `native-tls` feature.
+    #[cfg(all(
+        any(feature = "rustls", feature = "native-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    #[inline]
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.tls.root_certificates.push(certificate);
+        self
+    }
+
+    /// Presents a client certificate to servers that require mutual TLS.
+    ///
+    /// See the [`tls`](crate::tls) module for an example. Only available with
+    /// the `rustls` or `native-tls` feature.
+    #[cfg(all(
+        any(feature = "rustls", feature = "native-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    #[inline]
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.tls.identity = Some(identity);
+        self
+    }
+
+    /// Reads the time and sleeps through `clock` instead of the system clock.
+    ///
+    /// Used in tests to check retry backoff, rate limiting, cache expiry, and
+    /// queue scheduling without real sleeps. See the [`clock`](crate::clock)
+    /// module for an example.
+    #[inline]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Registers a [`Middleware`] to run around every request.
+    ///
+    /// Middleware runs in registration order. See the
+    /// [`middleware`](crate::middleware) module for an example.
+    #[inline]
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Registers a callback that runs once for every failed call, after
+    /// retries are exhausted, e.g. to report errors to an error tracker.
+    ///
+    /// Hooks run in registration order, on the task that made the call, so
+    /// they should return quickly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .on_error(|err, context| {
+    ///         eprintln!(
+    ///             "{} {} failed after {} attempts: {err}",
+    ///             context.method, context.path, context.attempts,
+    ///         );
+    ///     })
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn on_error(
+        mut self,
+        hook: impl Fn(&crate::Error, &RequestContext) + Send + Sync + 'static,
+    ) -> Self {
+        self.error_hooks.push(ErrorHook::new(hook));
+        self
+    }
+
+    /// Logs every request and response at debug level, for diagnosing
+    /// serialization mismatches without an intercepting proxy.
+    ///
+    /// Records go to the [`log`](https://docs.rs/log) facade under the
+    /// `lettr::wire` target and include the method, URL, headers, and bodies
+    /// truncated to 4 KiB. The `Authorization` header, attachment contents,
+    /// substitution values, and returned passwords and secrets are redacted.
+    #[inline]
+    pub fn wire_logging(mut self, enabled: bool) -> Self {
+        self.wire_logging = enabled;
+        self
+    }
+
+    /// Sets how many bytes of an unparseable response body are kept on the
+    /// error, available via [`Error::raw_body`](crate::Error::raw_body).
+    ///
+    /// Defaults to 4 KiB; `0` keeps no body.
+    #[inline]
+    pub fn raw_body_limit(mut self, bytes: usize) -> Self {
+        self.raw_body_limit = bytes;
+        self
+    }
+
+    /// Sets the sender used for emails created with an empty `from` address.
+    ///
+    /// `name` is only used together with the default address; pass `""` for
+    /// no display name. The default is filled in when the email is sent, so
+    /// call [`CreateEmailOptions::try_build`](crate::CreateEmailOptions::try_build)
+    /// only on emails that set their own sender.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .default_from("noreply@example.com", "Example")
+    ///     .default_reply_to("support@example.com")
+    ///     .build();
+    ///
+    /// // Sent from "Example <noreply@example.com>".
+    /// let email = CreateEmailOptions::new("", ["user@example.com"], "Welcome!")
+    ///     .with_text("Hello!");
+    /// ```
+    #[inline]
+    pub fn default_from(mut self, address: impl Into<String>, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.email_defaults.from = Some(address.into());
+        self.email_defaults.from_name = (!name.is_empty()).then_some(name);
+        self
+    }
+
+    /// Adds a reply-to address used for emails that set none of their own.
+    ///
+    /// Call repeatedly to add several addresses. Emails with a
+    /// [`with_reply_to`](crate::CreateEmailOptions::with_reply_to) address
+    /// keep only their own.
+    #[inline]
+    pub fn default_reply_to(mut self, address: impl Into<String>) -> Self {
+        self.email_defaults.reply_to.push(address.into());
+        self
+    }
+
+    /// Sets metadata, e.g. the environment or deploy version, merged into
+    /// every email sent by the client so its events are always attributable.
+    ///
+    /// Keys the email sets itself with
+    /// [`with_metadata_entry`](crate::CreateEmailOptions::with_metadata_entry)
+    /// take precedence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .default_metadata(HashMap::from([
+    ///         ("service".to_owned(), "billing".into()),
+    ///         ("environment".to_owned(), "production".into()),
+    ///     ]))
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn default_metadata(mut self, metadata: HashMap<String, serde_json::Value>) -> Self {
+        self.email_defaults.metadata = metadata;
+        self
+    }
+
+    /// Sets the locales tried, in order, when an email's
+    /// [`with_locale`](crate::CreateEmailOptions::with_locale) has no template
+    /// variant, before falling back to the template itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .locale_fallback(["en"])
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn locale_fallback<T, A>(mut self, locales: T) -> Self
+    where
+        T: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        self.email_defaults.locale_fallback = locales.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Restricts non-transactional emails to the days and hours of `window`.
+    ///
+    /// Sends outside the window wait until it opens, or fail if the window
+    /// [rejects](crate::send_window::SendWindow::reject_outside) them. See the
+    /// [`send_window`](crate::send_window) module.
+    #[inline]
+    pub fn send_window(mut self, window: SendWindow) -> Self {
+        self.send_window = Some(window);
+        self
+    }
+
+    /// Registers a callback that runs when a send waits for the
+    /// [send window](Self::send_window) to open, with the email and the
+    /// time until the window opens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::send_window::SendWindow;
+    ///
+    /// let client = lettr::Lettr::builder("your-api-key")
+    ///     .send_window(SendWindow::hours(9, 20))
+    ///     .on_send_deferred(|email, wait| {
+    ///         eprintln!("deferring {:?} by {wait:?}", email.subject());
+    ///     })
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn on_send_deferred(
+        mut self,
+        hook: impl Fn(&CreateEmailOptions, Duration) + Send + Sync + 'static,
+    ) -> Self {
+        self.defer_hooks.push(DeferHook::new(hook));
+        self
+    }
+
+    /// Records traffic to, or replays it from, the cassette file at `path`.
+    ///
+    /// With `LETTR_RECORD=1` set, requests go to the API through the
+    /// configured transport and every exchange is written to `path` with
+    /// secrets scrubbed; otherwise requests are answered from the file. See
+    /// the [`cassette`](crate::cassette) module.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn cassette(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.cassette = Some(path.into());
+        self
+    }
+
+    /// Builds the [`Lettr`] client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the API key contains non-ASCII characters.
+    #[must_use]
+    pub fn build(self) -> Lettr {
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Builds the [`Lettr`] client, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the API key contains
+    /// non-ASCII characters, the API version or app info is not a valid header
+    /// value, the HTTP client cannot be initialized, or connection settings
+    /// (timeouts, keepalive, HTTP/2, TLS) were combined with a custom HTTP
+    /// client or transport, or a cassette to replay cannot be read.
+    pub fn try_build(self) -> crate::Result<Lettr> {
+        let transport = match self.transport {
+            Some(ref transport) => {
+                if self.has_http_settings() {
+                    return Err(crate::Error::Config(
+                        "HTTP client settings cannot be combined with a custom HTTP client or \
+                         transport"
+                            .to_owned(),
+                    ));
+                }
+                Arc::clone(transport)
+            }
+            None => self.default_transport()?,
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let transport: SharedTransport = match self.cassette {
+            Some(ref path) => {
+                Arc::new(CassetteTransport::from_env_shared(path.clone(), transport)?)
+            }
+            None => transport,
+        };
+
+        let mut config = Config::new(self.api_key, transport)?;
+        if let Some(clock) = self.clock {
+            config.set_clock(clock);
+        }
+        config.set_base_url(self.region.base_url());
+        if let Some(version) = self.api_version {
+            config.set_api_version(version)?;
+        }
+        config.set_reject_version_mismatch(self.reject_version_mismatch);
+        if let Some(ref app_info) = self.app_info {
+            config.set_app_info(app_info)?;
+        }
+        if let Some(limit) = self.rate_limit {
+            config.set_rate_limit(limit);
+        }
+        if let Some(max_in_flight) = self.max_in_flight {
+            config.set_max_in_flight(max_in_flight);
+        }
+        if let Some(cache) = self.cache {
+            config.set_cache(cache);
+        }
+        if let Some(breaker) = self.circuit_breaker {
+            config.set_circuit_breaker(breaker);
+        }
+        config.set_max_retries(self.max_retries);
+        config.set_retry_policy(self.retry_policy);
+        config.set_wire_logging(self.wire_logging);
+        config.set_raw_body_limit(self.raw_body_limit);
+        config.set_email_defaults(self.email_defaults);
+        if let Some(window) = self.send_window {
+            config.set_send_window(window);
+        }
+        for hook in self.defer_hooks {
+            config.add_defer_hook(hook);
+        }
+        for middleware in self.middleware {
+            config.add_middleware(middleware);
+        }
+        for hook in self.error_hooks {
+            config.add_error_hook(hook);
+        }
+        Ok(Lettr::from_inner(config))
+    }
+
+    /// Builds a [`blocking::Client`](crate::blocking::Client), which can be
+    /// used from synchronous code next to async clients. Requires the
+    /// `blocking` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the API key contains non-ASCII characters.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    #[must_use]
+    pub fn build_blocking(self) -> crate::blocking::Client {
+        self.try_build_blocking().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Builds a [`blocking::Client`](crate::blocking::Client), returning an
+    /// error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) in the same cases as
+    /// [`try_build`](Self::try_build), or if the client's runtime cannot be
+    /// started.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub fn try_build_blocking(self) -> crate::Result<crate::blocking::Client> {
+        crate::blocking::from_async(self.try_build()?)
+    }
+
+    /// Returns `true` if settings for the default reqwest client were made.
+    fn has_http_settings(&self) -> bool {
+        #[cfg(all(
+            any(feature = "rustls", feature = "native-tls"),
+            not(target_arch = "wasm32")
+        ))]
+        if !self.tls.is_empty() {
+            return true;
+        }
+        self.http.is_customized()
+    }
+
+    /// Builds the reqwest client used when no transport was given.
+    fn default_transport(&self) -> crate::Result<SharedTransport> {
+        let builder = self.http.apply(HttpClientBuilder::new());
+        // Enabling `native-tls` opts out of the default rustls, even when the
+        // default features stay on.
+        #[cfg(all(feature = "native-tls", not(target_arch = "wasm32")))]
+        let builder = builder.use_native_tls();
+        #[cfg(all(
+            any(feature = "rustls", feature = "native-tls"),
+            not(target_arch = "wasm32")
+        ))]
+        let builder = self.tls.clone().apply(builder);
+
+        let http = builder
+            .build()
+            .map_err(|e| crate::Error::Config(format!("failed to build HTTP client: {e}")))?;
+        Ok(Arc::new(ReqwestTransport::new(http)))
+    }
+}
+
+/// Default time limit of [`Lettr::readiness`].
+const READINESS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Outcome of a [`Lettr::readiness`] probe.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Readiness {
+    /// Whether the API answered the health check successfully and in time.
+    pub healthy: bool,
+    /// Time the probe took.
+    pub latency: Duration,
+    /// Why the probe failed, if it did.
+    pub last_error: Option<crate::Error>,
+}
+
 /// Response from the health check endpoint.
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Deserialize)]
+#[non_exhaustive]
 pub struct HealthResponse {
     /// Status message.
     pub message: String,
     /// Health check data.
     pub data: HealthData,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Health check data.
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Deserialize)]
+#[non_exhaustive]
 pub struct HealthData {
     /// Health status (e.g., "ok").
     pub status: String,
     /// Timestamp of the health check.
     pub timestamp: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Response from the auth check endpoint.
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Deserialize)]
+#[non_exhaustive]
 pub struct AuthCheckResponse {
     /// Status message.
     pub message: String,
     /// Auth check data.
     pub data: AuthCheckData,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Auth check data.
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Deserialize)]
+#[non_exhaustive]
 pub struct AuthCheckData {
     /// The team ID associated with the API key.
     pub team_id: i64,
     /// Timestamp of the auth check.
     pub timestamp: String,
+    /// Name given to the API key, if any.
+    pub name: Option<String>,
+    /// Scopes granted to the API key, e.g. `emails:send` or `domains:read`.
+    /// `None` if the API did not report them, in which case the key has full
+    /// access.
+    #[serde(alias = "permissions")]
+    pub scopes: Option<Vec<String>>,
+    /// When the API key expires, if it does.
+    pub expires_at: Option<String>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AuthCheckData {
+    /// Scope required to send emails.
+    pub const SEND_EMAILS: &'static str = "emails:send";
+
+    /// Returns whether the API key has `scope`, directly, through a
+    /// `resource:*` wildcard, or through `*`. Keys without reported
+    /// [`scopes`](Self::scopes) have every scope.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::types::AuthCheckData;
+    ///
+    /// let mut data = AuthCheckData::default();
+    /// assert!(data.has_scope("domains:write"));
+    ///
+    /// data.scopes = Some(vec!["emails:*".to_owned(), "domains:read".to_owned()]);
+    /// assert!(data.has_scope("emails:send"));
+    /// assert!(data.has_scope("domains:read"));
+    /// assert!(!data.has_scope("domains:write"));
+    /// ```
+    #[must_use]
+    pub fn has_scope(&self, scope: &str) -> bool {
+        let Some(scopes) = &self.scopes else {
+            return true;
+        };
+        let resource = scope
+            .split_once(':')
+            .map_or(scope, |(resource, _)| resource);
+        scopes.iter().any(|granted| {
+            granted == "*"
+                || granted == scope
+                || granted
+                    .strip_suffix(":*")
+                    .is_some_and(|prefix| prefix == resource)
+        })
+    }
+
+    /// Returns whether the API key may send emails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = lettr::Lettr::new("your-api-key");
+    /// let key = client.auth_check().await?.data;
+    /// assert!(key.can_send_emails(), "the configured Lettr API key is read-only");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn can_send_emails(&self) -> bool {
+        self.has_scope(Self::SEND_EMAILS)
+    }
+
+    /// Returns whether the API key only has `:read` scopes.
+    #[must_use]
+    pub fn is_read_only(&self) -> bool {
+        self.scopes
+            .as_ref()
+            .is_some_and(|scopes| scopes.iter().all(|scope| scope.ends_with(":read")))
+    }
 }
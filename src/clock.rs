@@ -0,0 +1,189 @@
+//! Time source of a client.
+//!
+//! Retry backoff, rate limiting, cache TTLs, the circuit breaker, and the
+//! [send queue](crate::queue) read the time and sleep through a [`Clock`].
+//! Tests can replace the default [`SystemClock`] with a [`MockClock`] via
+//! [`LettrBuilder::clock`](crate::LettrBuilder::clock), so backoff and queue
+//! behavior can be checked without real sleeps.
+//!
+//! The [`readiness`](crate::Lettr::readiness) probe always uses real time,
+//! since its timeout guards against a hanging connection.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// `std::time` on native targets; `web-time` on WASM, where `std` has no clock.
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::{Instant, SystemTime};
+#[cfg(target_arch = "wasm32")]
+pub use web_time::{Instant, SystemTime};
+
+/// A source of time for a client.
+///
+/// With the async client, implementations use the
+/// [`async_trait`](crate::transport::async_trait) attribute.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait Clock: Send + Sync {
+    /// Returns the current monotonic time.
+    fn now(&self) -> Instant;
+
+    /// Returns the current wall-clock time.
+    fn system_now(&self) -> SystemTime;
+
+    /// Waits for `duration`.
+    async fn sleep(&self, duration: Duration);
+}
+
+impl fmt::Debug for dyn Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Clock")
+    }
+}
+
+/// The default [`Clock`], backed by the system clock and real sleeps.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        crate::time::sleep(duration).await;
+    }
+}
+
+/// A [`Clock`] that only moves when told to.
+///
+/// [`sleep`](Clock::sleep) returns immediately after advancing the clock by
+/// the requested duration, and records it. Clones share the same time, so
+/// a test can keep a clone to advance the clock and inspect the sleeps of a
+/// client built with it.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use lettr::Lettr;
+/// use lettr::clock::MockClock;
+/// use lettr::transport::{async_trait, Bytes, HeaderMap, HttpTransport, Request, Response, StatusCode};
+///
+/// struct Unavailable;
+///
+/// #[async_trait]
+/// impl HttpTransport for Unavailable {
+///     async fn execute(&self, _: Request) -> lettr::Result<Response> {
+///         Ok(Response::new(StatusCode::SERVICE_UNAVAILABLE, HeaderMap::new(), Bytes::new()))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let clock = MockClock::new();
+/// let client = Lettr::builder("your-api-key")
+///     .transport(Unavailable)
+///     .clock(clock.clone())
+///     .max_retries(3)
+///     .build();
+///
+/// assert!(client.health().await.is_err());
+/// assert_eq!(
+///     clock.sleeps(),
+///     [Duration::from_millis(500), Duration::from_secs(1), Duration::from_secs(2)],
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug)]
+struct MockState {
+    start: Instant,
+    start_system: SystemTime,
+    elapsed: Duration,
+    sleeps: Vec<Duration>,
+}
+
+impl MockClock {
+    /// Creates a [`MockClock`] starting at the current time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::starting_at(SystemTime::now())
+    }
+
+    /// Creates a [`MockClock`] whose wall-clock time starts at `system_now`.
+    #[must_use]
+    pub fn starting_at(system_now: SystemTime) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockState {
+                start: Instant::now(),
+                start_system: system_now,
+                elapsed: Duration::ZERO,
+                sleeps: Vec::new(),
+            })),
+        }
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.lock().elapsed += duration;
+    }
+
+    /// Returns the durations of all sleeps so far, in order.
+    #[must_use]
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.lock().sleeps.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        let state = self.lock();
+        state.start + state.elapsed
+    }
+
+    fn system_now(&self) -> SystemTime {
+        let state = self.lock();
+        state.start_system + state.elapsed
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        let mut state = self.lock();
+        state.elapsed += duration;
+        state.sleeps.push(duration);
+    }
+}
+
+/// Shared handle to a clock.
+pub(crate) type SharedClock = Arc<dyn Clock>;
+
+/// Returns the time since the Unix epoch according to `clock`.
+pub(crate) fn unix_now(clock: &dyn Clock) -> Option<Duration> {
+    clock
+        .system_now()
+        .duration_since(crate::time::UNIX_EPOCH)
+        .ok()
+}
@@ -0,0 +1,84 @@
+//! Client-side limit on concurrent requests.
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// A counting semaphore shared by all clones of a client.
+///
+/// Waiters are served in FIFO order, and a returned permit wakes only the
+/// next one.
+#[derive(Debug)]
+pub(crate) struct ConcurrencyLimiter(Semaphore);
+
+/// A permit to send one request; returned to the limiter when dropped.
+pub(crate) type Permit<'a> = SemaphorePermit<'a>;
+
+impl ConcurrencyLimiter {
+    /// Creates a limiter allowing `max_in_flight` concurrent requests.
+    pub fn new(max_in_flight: usize) -> Self {
+        Self(Semaphore::new(
+            max_in_flight.clamp(1, Semaphore::MAX_PERMITS),
+        ))
+    }
+
+    /// Waits until a request may be sent.
+    pub async fn acquire(&self) -> Permit<'_> {
+        self.0
+            .acquire()
+            .await
+            .expect("the semaphore is never closed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::transport::{async_trait, HeaderMap, HttpTransport, Request, Response, StatusCode};
+    use crate::Lettr;
+
+    /// Answers after a short delay, tracking the most requests in flight.
+    #[derive(Default)]
+    struct Slow {
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+        requests: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl HttpTransport for Arc<Slow> {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.requests.fetch_add(1, Ordering::SeqCst);
+
+            let body = r#"{"message":"OK","data":{"status":"ok","timestamp":"now"}}"#;
+            Ok(Response::new(StatusCode::OK, HeaderMap::new(), body))
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn limits_requests_in_flight() {
+        let transport = Arc::new(Slow::default());
+        let client = Lettr::builder("test-key")
+            .transport(transport.clone())
+            .max_in_flight(3)
+            .build();
+
+        let tasks: Vec<_> = (0..20)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.health().await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+
+        assert_eq!(transport.requests.load(Ordering::SeqCst), 20);
+        assert_eq!(transport.peak.load(Ordering::SeqCst), 3);
+    }
+}
@@ -1,86 +1,778 @@
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
-use reqwest::Method;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 
-const BASE_URL: &str = "https://app.lettr.com/api";
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
 
-// Use the correct reqwest types based on blocking feature.
-#[cfg(feature = "blocking")]
-use reqwest::blocking::Client as HttpClient;
-#[cfg(not(feature = "blocking"))]
-use reqwest::Client as HttpClient;
+use crate::api_key::ApiKey;
+use crate::cache::{CacheConfig, ResponseCache};
+use crate::circuit_breaker::{self, CircuitBreaker, CircuitBreakerConfig};
+use crate::client::Region;
+use crate::clock::{self, SharedClock, SystemClock};
+use crate::concurrency::ConcurrencyLimiter;
+use crate::emails::EmailDefaults;
+use crate::error::{ParseError, RawErrorResponse};
+use crate::meta::{WithMeta, API_VERSION_HEADER};
+use crate::middleware::{ErrorHook, Middleware, RequestContext};
+use crate::rate_limit::{RateLimit, RateLimitInfo, RateLimiter};
+use crate::retry::{Failure, RetryPolicy, IDEMPOTENCY_KEY_HEADER};
+use crate::send_window::{DeferHook, SendWindow};
+use crate::templates::SlugMemo;
+use crate::transport::{self, Response, SharedTransport};
+use crate::wire_log;
 
-#[cfg(not(feature = "blocking"))]
-pub(crate) type RequestBuilder = reqwest::RequestBuilder;
-#[cfg(feature = "blocking")]
-pub(crate) type RequestBuilder = reqwest::blocking::RequestBuilder;
+pub(crate) use crate::transport::RequestBuilder;
 
-#[cfg(not(feature = "blocking"))]
-pub(crate) type Response = reqwest::Response;
-#[cfg(feature = "blocking")]
-pub(crate) type Response = reqwest::blocking::Response;
+/// Default `User-Agent` header value.
+const USER_AGENT_PREFIX: &str = concat!("lettr-rust/", env!("CARGO_PKG_VERSION"));
+/// Delay before the first retry when the API gave no hint; doubled per attempt.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Upper bound for the delay before a retry, suggested or not.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+/// Default number of bytes of an unparseable response body kept on errors.
+pub(crate) const DEFAULT_RAW_BODY_LIMIT: usize = 4096;
+/// How often [`Config::shutdown`] checks for in-flight requests.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(10);
 
 /// Internal configuration for the Lettr HTTP client.
+///
+/// Stateful parts (limiters, cache, shutdown state) are behind `Arc`s, so a
+/// copy made with [`with_api_key`](Config::with_api_key) shares them.
 #[derive(Debug, Clone)]
 pub(crate) struct Config {
-    http: HttpClient,
+    transport: SharedTransport,
+    clock: SharedClock,
+    api_key: ApiKey,
+    headers: HeaderMap,
     base_url: String,
+    api_version: Option<String>,
+    reject_version_mismatch: bool,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    concurrency: Option<Arc<ConcurrencyLimiter>>,
+    cache: Option<Arc<ResponseCache>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    max_retries: u32,
+    retry_policy: RetryPolicy,
+    middleware: Vec<Arc<dyn Middleware>>,
+    error_hooks: Vec<ErrorHook>,
+    wire_logging: bool,
+    raw_body_limit: usize,
+    email_defaults: EmailDefaults,
+    send_window: Option<SendWindow>,
+    defer_hooks: Vec<DeferHook>,
+    template_slugs: Arc<SlugMemo>,
+    shut_down: Arc<AtomicBool>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+/// Outcome of a single request attempt.
+enum Attempt {
+    Done(crate::Result<Response>),
+    Retry(crate::Error, Duration),
 }
 
 impl Config {
     /// Creates a new [`Config`] with the given API key.
-    pub fn new(api_key: &str) -> Self {
+    ///
+    /// Requests are sent through `transport`. The authentication headers are
+    /// attached to every request, so a shared client needs no Lettr-specific
+    /// configuration.
+    ///
+    /// Fails if the API key is not a valid header value.
+    pub fn new(api_key: ApiKey, transport: SharedTransport) -> crate::Result<Self> {
+        api_key.validate()?;
+
         let mut headers = HeaderMap::new();
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {api_key}"))
-                .expect("API key must be valid ASCII"),
-        );
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        headers.insert(
-            USER_AGENT,
-            HeaderValue::from_static(concat!("lettr-rust/", env!("CARGO_PKG_VERSION"))),
-        );
+        headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_PREFIX));
 
-        let http = HttpClient::builder()
-            .default_headers(headers)
-            .build()
-            .expect("Failed to build HTTP client");
+        Ok(Self {
+            transport,
+            clock: Arc::new(SystemClock),
+            api_key,
+            headers,
+            base_url: Region::default().base_url().to_owned(),
+            api_version: None,
+            reject_version_mismatch: false,
+            rate_limiter: None,
+            concurrency: None,
+            cache: None,
+            circuit_breaker: None,
+            max_retries: 0,
+            retry_policy: RetryPolicy::default(),
+            middleware: Vec::new(),
+            error_hooks: Vec::new(),
+            wire_logging: false,
+            raw_body_limit: DEFAULT_RAW_BODY_LIMIT,
+            email_defaults: EmailDefaults::default(),
+            send_window: None,
+            defer_hooks: Vec::new(),
+            template_slugs: Arc::default(),
+            shut_down: Arc::new(AtomicBool::new(false)),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+        })
+    }
 
-        Self {
-            http,
-            base_url: BASE_URL.to_owned(),
+    /// Append `product` (e.g. `myapp/1.2.3`) to the `User-Agent` header.
+    ///
+    /// Fails if `product` is not a valid header value.
+    pub fn set_app_info(&mut self, product: &str) -> crate::Result<()> {
+        let user_agent = format!("{USER_AGENT_PREFIX} {product}");
+        let value = HeaderValue::from_str(&user_agent)
+            .map_err(|_| crate::Error::Config(format!("invalid app info `{product}`")))?;
+        self.headers.insert(USER_AGENT, value);
+        Ok(())
+    }
+
+    /// Pin the API version sent with every request.
+    ///
+    /// Fails if `version` is not a valid header value.
+    pub fn set_api_version(&mut self, version: String) -> crate::Result<()> {
+        let value = HeaderValue::from_str(&version)
+            .map_err(|_| crate::Error::Config(format!("invalid API version `{version}`")))?;
+        self.headers.insert(API_VERSION_HEADER, value);
+        self.api_version = Some(version);
+        Ok(())
+    }
+
+    /// Fail responses reporting another API version than the pinned one,
+    /// instead of logging a warning.
+    pub fn set_reject_version_mismatch(&mut self, enabled: bool) {
+        self.reject_version_mismatch = enabled;
+    }
+
+    /// Returns a copy sending requests with `api_key` instead.
+    ///
+    /// The copy shares the transport, limiters, circuit breaker, and shutdown
+    /// state, but not the response cache or template slugs, since those
+    /// belong to the original key's team.
+    pub fn with_api_key(&self, api_key: ApiKey) -> crate::Result<Self> {
+        api_key.validate()?;
+        Ok(Self {
+            api_key,
+            cache: None,
+            template_slugs: Arc::default(),
+            ..self.clone()
+        })
+    }
+
+    /// Read the time and sleep through `clock`.
+    ///
+    /// Must be called before the rate limit is set.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Returns the clock of the client.
+    pub fn clock(&self) -> &dyn clock::Clock {
+        &*self.clock
+    }
+
+    /// Returns a handle to the clock of the client.
+    pub fn shared_clock(&self) -> SharedClock {
+        Arc::clone(&self.clock)
+    }
+
+    /// Apply a client-side rate limit to all requests.
+    pub fn set_rate_limit(&mut self, limit: RateLimit) {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(limit, self.clock.now())));
+    }
+
+    /// Limit how many requests may be sent concurrently.
+    pub fn set_max_in_flight(&mut self, max_in_flight: usize) {
+        self.concurrency = Some(Arc::new(ConcurrencyLimiter::new(max_in_flight)));
+    }
+
+    /// Cache successful `GET` responses.
+    pub fn set_cache(&mut self, config: CacheConfig) {
+        self.cache = Some(Arc::new(ResponseCache::new(config)));
+    }
+
+    /// Drop cached responses for paths starting with `path`.
+    pub fn invalidate_cache(&self, path: &str) {
+        if let Some(ref cache) = self.cache {
+            cache.invalidate(&format!("{}{path}", self.base_url));
+        }
+    }
+
+    /// Drop all cached responses.
+    pub fn clear_cache(&self) {
+        if let Some(ref cache) = self.cache {
+            cache.clear();
         }
     }
 
-    /// Override the base URL (useful for testing).
-    #[allow(dead_code)]
+    /// Stop sending requests while the API keeps failing.
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) {
+        self.circuit_breaker = Some(Arc::new(CircuitBreaker::new(config)));
+    }
+
+    /// Set how many times a failed request may be retried.
+    pub fn set_max_retries(&mut self, max_retries: u32) {
+        self.max_retries = max_retries;
+    }
+
+    /// Set which failed requests are retried.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Register a middleware to run around every request.
+    pub fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middleware.push(middleware);
+    }
+
+    /// Register a callback to run for every failed call.
+    pub fn add_error_hook(&mut self, hook: ErrorHook) {
+        self.error_hooks.push(hook);
+    }
+
+    /// Enable debug logging of requests and responses.
+    pub fn set_wire_logging(&mut self, enabled: bool) {
+        self.wire_logging = enabled;
+    }
+
+    /// Set how many bytes of an unparseable response body errors keep.
+    pub fn set_raw_body_limit(&mut self, limit: usize) {
+        self.raw_body_limit = limit;
+    }
+
+    /// Template slugs listed to resolve locale variants.
+    pub fn template_slugs(&self) -> &SlugMemo {
+        &self.template_slugs
+    }
+
+    /// Defaults filled into every email before it is sent.
+    pub fn email_defaults(&self) -> &EmailDefaults {
+        &self.email_defaults
+    }
+
+    /// Set the defaults filled into every email before it is sent.
+    pub fn set_email_defaults(&mut self, defaults: EmailDefaults) {
+        self.email_defaults = defaults;
+    }
+
+    /// Days and hours in which non-transactional emails may be sent, if
+    /// restricted.
+    pub fn send_window(&self) -> Option<&SendWindow> {
+        self.send_window.as_ref()
+    }
+
+    /// Restrict non-transactional emails to a send window.
+    pub fn set_send_window(&mut self, window: SendWindow) {
+        self.send_window = Some(window);
+    }
+
+    /// Hooks called when a send waits for the send window to open.
+    pub fn defer_hooks(&self) -> &[DeferHook] {
+        &self.defer_hooks
+    }
+
+    /// Register a hook called when a send waits for the send window to open.
+    pub fn add_defer_hook(&mut self, hook: DeferHook) {
+        self.defer_hooks.push(hook);
+    }
+
+    /// Returns `true` once [`shutdown`](Self::shutdown) was called.
+    pub fn is_shut_down(&self) -> bool {
+        self.shut_down.load(Ordering::SeqCst)
+    }
+
+    /// Reject new requests and wait up to `timeout` for in-flight requests to
+    /// finish. Returns `false` if requests were still in flight afterwards.
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.shut_down.store(true, Ordering::SeqCst);
+
+        let deadline = self.clock.now() + timeout;
+        while self.in_flight.load(Ordering::SeqCst) > 0 {
+            if self.clock.now() >= deadline {
+                return false;
+            }
+            self.clock.sleep(SHUTDOWN_POLL_INTERVAL).await;
+        }
+        true
+    }
+
+    /// Override the base URL.
     pub fn set_base_url(&mut self, base_url: impl Into<String>) {
-        self.base_url = base_url.into();
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
     }
 
     /// Build an HTTP request for the given method and path.
+    ///
+    /// A path with IDs in it needs a [`route`](RequestBuilder::route)
+    /// template, so metrics are labeled by endpoint.
     pub fn build(&self, method: Method, path: &str) -> RequestBuilder {
         let url = format!("{}{path}", self.base_url);
-        self.http.request(method, url)
+        // The key is only turned into a header per request, so the long-lived
+        // copy stays in `ApiKey`.
+        let mut headers = self.headers.clone();
+        headers.insert(AUTHORIZATION, self.api_key.header_value());
+        RequestBuilder::new(method, &url, headers)
     }
 
     /// Send a built request and handle non-success status codes.
     ///
-    /// Returns the raw response on success, or an appropriate error.
-    #[maybe_async::maybe_async]
+    /// Returns the raw response on success, or an appropriate error. Requests
+    /// that failed in a way the retry policy allows are re-sent up to
+    /// `max_retries` times, waiting as long as the API asked via its rate-limit headers, or
+    /// with exponential backoff when it gave no hint. A final error is
+    /// reported to the error hooks.
     pub async fn send(&self, request: RequestBuilder) -> crate::Result<Response> {
-        let response = request.send().await?;
-        let status = response.status();
+        let mut call = Call::start(self, &request);
+        let result = self.send_attempts(request, &mut call.attempts).await;
+        self.finish(call, result)
+    }
+
+    /// Send a built request and deserialize the JSON response body, keeping
+    /// the response metadata.
+    pub async fn send_json_with_meta<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> crate::Result<WithMeta<T>> {
+        let mut call = Call::start(self, &request);
+        let result = match self.send_attempts(request, &mut call.attempts).await {
+            Ok(response) => self.decode(response),
+            Err(err) => Err(err),
+        };
+        self.finish(call, result)
+    }
+
+    /// Runs the retry loop of [`send`](Self::send), counting attempts in
+    /// `attempts`.
+    async fn send_attempts(
+        &self,
+        request: RequestBuilder,
+        attempts: &mut u32,
+    ) -> crate::Result<Response> {
+        if self.is_shut_down() {
+            return Err(crate::Error::Shutdown);
+        }
+        let _in_flight = InFlight::enter(&self.in_flight);
+
+        let cacheable = request.is_cacheable();
+        let route = request.route_template();
+        let request = request.build()?;
+
+        if cacheable && request.method == Method::GET {
+            if let Some(response) = self
+                .cache
+                .as_ref()
+                .and_then(|c| c.get(&request.url, self.clock.now()))
+            {
+                return Ok(response);
+            }
+        }
+
+        let mut attempt = 0;
+
+        loop {
+            *attempts = attempt + 1;
+            match self.attempt(request.clone(), route, attempt).await {
+                Attempt::Done(Ok(response)) => {
+                    self.update_cache(&request, &response, cacheable);
+                    return Ok(response);
+                }
+                Attempt::Done(Err(err)) => return Err(err),
+                Attempt::Retry(_, delay) if attempt < self.max_retries => {
+                    self.clock.sleep(delay).await;
+                    attempt += 1;
+                }
+                Attempt::Retry(err, _) => return Err(err),
+            }
+        }
+    }
+
+    /// Deserialize the JSON body of a successful response.
+    fn decode<T: DeserializeOwned>(&self, response: Response) -> crate::Result<WithMeta<T>> {
+        if self.wire_logging() {
+            wire_log::log_response_body(&response.body);
+        }
+
+        let deserializer = &mut serde_json::Deserializer::from_slice(&response.body);
+        let data = serde_path_to_error::deserialize(deserializer).map_err(|e| {
+            let mut err = ParseError::from_path_error(&e).with_response(&response);
+            err.raw_body = self.raw_body(&response.body);
+            crate::Error::Parse(err)
+        })?;
+        Ok(WithMeta::new(data, response))
+    }
+
+    /// Report a failed call to the error hooks.
+    fn finish<T>(&self, call: Call, result: crate::Result<T>) -> crate::Result<T> {
+        if let Err(ref err) = result {
+            if !self.error_hooks.is_empty() {
+                let context = RequestContext {
+                    method: call.method,
+                    path: call.path,
+                    attempts: call.attempts,
+                    elapsed: self.clock.now().duration_since(call.started),
+                };
+                for hook in &self.error_hooks {
+                    hook.call(err, &context);
+                }
+            }
+        }
+        result
+    }
+
+    /// Cache a successful `GET` response, or invalidate the resource a
+    /// successful write modified.
+    fn update_cache(&self, request: &transport::Request, response: &Response, cacheable: bool) {
+        let Some(ref cache) = self.cache else {
+            return;
+        };
+
+        if request.method == Method::GET {
+            if cacheable {
+                cache.insert(&request.url, response.clone(), self.clock.now());
+            }
+        } else if let Some(path) = request.url.as_str().strip_prefix(&self.base_url) {
+            // `/domains/example.com` invalidates everything under `/domains`.
+            let collection = path.split(['/', '?']).find(|s| !s.is_empty()).unwrap_or("");
+            cache.invalidate(&format!("{}/{collection}", self.base_url));
+        }
+    }
+
+    /// Check that a response reports the pinned API version, warning about
+    /// or rejecting a different one. Responses without a version header are
+    /// accepted.
+    fn check_api_version(&self, response: Response) -> crate::Result<Response> {
+        let (Some(requested), Some(received)) = (
+            self.api_version.as_deref(),
+            response.headers.get(API_VERSION_HEADER),
+        ) else {
+            return Ok(response);
+        };
+
+        let received = String::from_utf8_lossy(received.as_bytes());
+        if received == requested {
+            return Ok(response);
+        }
+        if !self.reject_version_mismatch {
+            log::warn!("requested API version {requested}, but the API reported {received}");
+            return Ok(response);
+        }
+        Err(crate::Error::VersionMismatch {
+            requested: requested.to_owned(),
+            received: received.into_owned(),
+        })
+    }
+
+    /// Report whether the API was reachable to the circuit breaker.
+    fn record_outcome(&self, permit: Option<circuit_breaker::Permit<'_>>, success: bool) {
+        if let Some(permit) = permit {
+            permit.record(success, self.clock.now());
+        }
+    }
+
+    /// Keeps the start of a response body for an error, lossily decoded as
+    /// UTF-8 and truncated to `raw_body_limit` bytes.
+    fn raw_body(&self, body: &[u8]) -> Option<String> {
+        if self.raw_body_limit == 0 {
+            return None;
+        }
+        let mut text = String::from_utf8_lossy(body).into_owned();
+        if text.len() > self.raw_body_limit {
+            let mut end = self.raw_body_limit;
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            text.truncate(end);
+        }
+        Some(text)
+    }
+
+    fn wire_logging(&self) -> bool {
+        self.wire_logging && wire_log::enabled()
+    }
+
+    /// Send a request once, classifying failures that may be retried.
+    async fn attempt(
+        &self,
+        mut request: transport::Request,
+        route: Option<&'static str>,
+        attempt: u32,
+    ) -> Attempt {
+        if let Some(ref limiter) = self.rate_limiter {
+            let wait = limiter.reserve(self.clock.now());
+            if !wait.is_zero() {
+                self.clock.sleep(wait).await;
+            }
+        }
+
+        // Taken after the rate limiter, so waiting for a token does not hold
+        // a connection slot.
+        let mut _permit = None;
+        if let Some(ref limiter) = self.concurrency {
+            _permit = Some(limiter.acquire().await);
+        }
+
+        // Asked for last, so the circuit state is current when the request
+        // is sent. Released when dropped, so a cancelled request frees its
+        // probe.
+        let mut breaker_permit = None;
+        if let Some(ref breaker) = self.circuit_breaker {
+            match breaker.acquire(self.clock.now()) {
+                Ok(permit) => breaker_permit = Some(permit),
+                Err(retry_in) => {
+                    return Attempt::Done(Err(crate::Error::CircuitOpen { retry_in }));
+                }
+            }
+        }
+
+        for middleware in &self.middleware {
+            middleware.on_request(&mut request);
+        }
+
+        if self.wire_logging() {
+            wire_log::log_request(
+                &request.method,
+                &request.url,
+                &request.headers,
+                request.body.as_deref(),
+            );
+        }
+
+        let url = request.url.clone();
+        let method = request.method.clone();
+        let path = url
+            .as_str()
+            .strip_prefix(&self.base_url)
+            .unwrap_or(url.path());
+        let path = path.split('?').next().unwrap_or_default().to_owned();
+
+        #[cfg(feature = "metrics")]
+        let endpoint = crate::metrics::Endpoint::new(&method, route.unwrap_or(&path));
+        #[cfg(feature = "metrics")]
+        {
+            if attempt > 0 {
+                endpoint.record_retry();
+            }
+            if let Some(ref body) = request.body {
+                endpoint.record_request_body(body.len());
+            }
+        }
+        #[cfg(not(feature = "metrics"))]
+        let _ = route;
+        let has_idempotency_key = request.headers.contains_key(IDEMPOTENCY_KEY_HEADER);
+        let may_retry = |failure| {
+            self.retry_policy
+                .allows(&method, &path, has_idempotency_key, failure)
+        };
+        let started = self.clock.now();
+        let result = self.transport.execute(request).await;
+        let elapsed = self.clock.now().duration_since(started);
+
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => {
+                #[cfg(feature = "metrics")]
+                endpoint.record_failure(elapsed);
+
+                self.record_outcome(breaker_permit, false);
+                if transport::is_connect(&err) && may_retry(Failure::Connect) {
+                    return Attempt::Retry(err, backoff(attempt));
+                }
+                return Attempt::Done(Err(err));
+            }
+        };
+        for middleware in &self.middleware {
+            middleware.on_response(&response, elapsed);
+        }
+        if self.wire_logging() {
+            wire_log::log_response(response.status, &url, &response.headers);
+        }
+
+        #[cfg(feature = "metrics")]
+        endpoint.record_response(response.status, elapsed, response.body.len());
+
+        let status = response.status;
+        self.record_outcome(breaker_permit, !status.is_server_error());
 
         if status.is_success() {
-            Ok(response)
+            return Attempt::Done(self.check_api_version(response));
+        }
+
+        let rate_limit = RateLimitInfo::from_headers(&response.headers);
+        let suggested_delay = rate_limit.suggested_delay_at(clock::unix_now(self.clock()));
+        let delay = suggested_delay.unwrap_or_else(|| backoff(attempt));
+        if self.wire_logging() {
+            wire_log::log_response_body(&response.body);
+        }
+
+        let err = if status == StatusCode::TOO_MANY_REQUESTS {
+            crate::Error::RateLimited {
+                retry_after: suggested_delay,
+                limit: rate_limit.limit,
+                remaining: rate_limit.remaining,
+            }
+        } else {
+            let body = String::from_utf8_lossy(&response.body);
+            serde_json::from_str::<RawErrorResponse>(&body)
+                .unwrap_or_else(|_| {
+                    RawErrorResponse::unparseable(status, self.raw_body(&response.body))
+                })
+                .into_error(&response, rate_limit)
+        };
+
+        // A longer wait than the backoff allows would stall the call for
+        // minutes or hours; the caller can decide with `retry_after` instead.
+        if may_retry(Failure::Status(status)) && delay <= RETRY_MAX_DELAY {
+            Attempt::Retry(err, delay)
         } else {
-            let body = response.text().await.unwrap_or_default();
+            Attempt::Done(Err(err))
+        }
+    }
+}
+
+/// A call in progress, for the [`RequestContext`] of error hooks.
+struct Call {
+    method: Method,
+    path: String,
+    attempts: u32,
+    started: clock::Instant,
+}
+
+impl Call {
+    fn start(config: &Config, request: &RequestBuilder) -> Self {
+        let (method, url) = request.target();
+        let path = url.strip_prefix(&config.base_url).unwrap_or(url);
+        Self {
+            method: method.clone(),
+            path: path.split('?').next().unwrap_or_default().to_owned(),
+            attempts: 0,
+            started: config.clock.now(),
+        }
+    }
+}
+
+/// Counts a request as in flight until dropped, including when the request
+/// future is cancelled.
+struct InFlight<'a>(&'a AtomicUsize);
+
+impl<'a> InFlight<'a> {
+    fn enter(counter: &'a AtomicUsize) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(counter)
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
 
-            match serde_json::from_str::<crate::error::RawErrorResponse>(&body) {
-                Ok(raw) => Err(raw.into_error()),
-                Err(_) => Err(crate::Error::Parse(format!("HTTP {status}: {body}"))),
+/// Exponential backoff delay for the given (zero-based) retry attempt.
+fn backoff(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RETRY_MAX_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::clock::MockClock;
+    use crate::transport::{async_trait, HeaderMap, HttpTransport, Request, Response, StatusCode};
+    use crate::{Error, Lettr};
+
+    /// Rejects every request with `429` and a `Retry-After` header.
+    struct RateLimited {
+        retry_after: &'static str,
+        requests: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for RateLimited {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            let mut headers = HeaderMap::new();
+            headers.insert("retry-after", self.retry_after.parse().unwrap());
+            Ok(Response::new(StatusCode::TOO_MANY_REQUESTS, headers, "{}"))
+        }
+    }
+
+    /// Calls the health check with retries against a [`RateLimited`] API,
+    /// returning the error, the number of requests, and the sleeps.
+    async fn health_with_retry_after(retry_after: &'static str) -> (Error, usize, Vec<Duration>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let clock = MockClock::new();
+        let client = Lettr::builder("test-key")
+            .transport(RateLimited {
+                retry_after,
+                requests: requests.clone(),
+            })
+            .clock(clock.clone())
+            .max_retries(2)
+            .build();
+        let err = client.health().await.unwrap_err();
+        (err, requests.load(Ordering::SeqCst), clock.sleeps())
+    }
+
+    #[tokio::test]
+    async fn waits_for_a_short_retry_after() {
+        let (_, requests, sleeps) = health_with_retry_after("2").await;
+        assert_eq!(requests, 3);
+        assert_eq!(sleeps, [Duration::from_secs(2); 2]);
+    }
+
+    #[tokio::test]
+    async fn fails_on_a_retry_after_beyond_the_cap() {
+        let (err, requests, sleeps) = health_with_retry_after("86400").await;
+        assert_eq!(requests, 1);
+        assert!(sleeps.is_empty());
+        assert!(matches!(
+            err,
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            } if retry_after == Duration::from_secs(86_400)
+        ));
+    }
+
+    /// Answers every request healthily, reporting API version `2024-01-01`
+    /// if `versioned`.
+    struct Versioned {
+        versioned: bool,
+    }
+
+    #[async_trait]
+    impl HttpTransport for Versioned {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            let mut headers = HeaderMap::new();
+            if self.versioned {
+                headers.insert("lettr-version", "2024-01-01".parse().unwrap());
             }
+            let body = r#"{"message":"OK","data":{"status":"ok","timestamp":"now"}}"#;
+            Ok(Response::new(StatusCode::OK, headers, body))
         }
     }
+
+    async fn health_pinned(versioned: bool, reject: bool) -> crate::Result<()> {
+        let client = Lettr::builder("test-key")
+            .transport(Versioned { versioned })
+            .api_version("2025-01-01")
+            .reject_version_mismatch(reject)
+            .build();
+        client.health().await.map(drop)
+    }
+
+    #[tokio::test]
+    async fn accepts_a_version_mismatch_by_default() {
+        assert!(health_pinned(true, false).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_a_version_mismatch_when_asked() {
+        let err = health_pinned(true, true).await.unwrap_err();
+        assert!(matches!(err, Error::VersionMismatch { received, .. } if received == "2024-01-01"));
+        assert!(health_pinned(false, true).await.is_ok());
+    }
 }
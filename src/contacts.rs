@@ -0,0 +1,555 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::meta::WithMeta;
+
+/// Service for the `/contacts` endpoints.
+#[derive(Clone, Debug)]
+pub struct ContactsSvc(pub(crate) Arc<Config>);
+
+impl ContactsSvc {
+    /// List contacts with optional filtering and pagination.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::contacts::ListContactsOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = ListContactsOptions::new().per_page(50);
+    /// let response = client.contacts.list(options).await?;
+    ///
+    /// for contact in &response.contacts {
+    ///     println!("{}: {}", contact.id, contact.email);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self, options: ListContactsOptions) -> crate::Result<ListContactsResponse> {
+        self.list_with_meta(options).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(
+        &self,
+        options: ListContactsOptions,
+    ) -> crate::Result<WithMeta<ListContactsResponse>> {
+        let mut request = self.0.build(Method::GET, "/contacts");
+
+        if let Some(email) = options.email {
+            request = request.query(&[("email", email)]);
+        }
+        if let Some(per_page) = options.per_page {
+            request = request.query(&[("per_page", per_page.to_string())]);
+        }
+        if let Some(page) = options.page {
+            request = request.query(&[("page", page.to_string())]);
+        }
+
+        let response: WithMeta<ListContactsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Create a new contact.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::contacts::CreateContactOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let contact = CreateContactOptions::new("user@example.com")
+    ///     .with_first_name("Jane")
+    ///     .with_attribute("plan", "pro");
+    ///
+    /// let contact = client.contacts.create(contact).await?;
+    /// println!("Contact created: {}", contact.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(&self, options: CreateContactOptions) -> crate::Result<Contact> {
+        self.create_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`create`](Self::create), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn create_with_meta(
+        &self,
+        options: CreateContactOptions,
+    ) -> crate::Result<WithMeta<Contact>> {
+        let request = self.0.build(Method::POST, "/contacts").json(&options);
+        let response: WithMeta<ContactResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve a single contact.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let contact = client.contacts.get("contact-id").await?;
+    /// println!("{} (unsubscribed: {})", contact.email, contact.unsubscribed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, contact_id: &str) -> crate::Result<Contact> {
+        self.get_with_meta(contact_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, contact_id: &str) -> crate::Result<WithMeta<Contact>> {
+        let path = format!("/contacts/{contact_id}");
+        let request = self.0.build(Method::GET, &path).route("/contacts/{id}");
+        let response: WithMeta<ContactResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Update a contact. Only the fields set on `options` are changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::contacts::UpdateContactOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let changes = UpdateContactOptions::new().with_attribute("plan", "enterprise");
+    /// let contact = client.contacts.update("contact-id", changes).await?;
+    /// println!("Updated at {}", contact.updated_at);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        contact_id: &str,
+        options: UpdateContactOptions,
+    ) -> crate::Result<Contact> {
+        self.update_with_meta(contact_id, options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`update`](Self::update), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn update_with_meta(
+        &self,
+        contact_id: &str,
+        options: UpdateContactOptions,
+    ) -> crate::Result<WithMeta<Contact>> {
+        let path = format!("/contacts/{contact_id}");
+        let request = self
+            .0
+            .build(Method::PATCH, &path)
+            .route("/contacts/{id}")
+            .json(&options);
+        let response: WithMeta<ContactResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Delete a contact.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.contacts.delete("contact-id").await?;
+    /// println!("Contact deleted.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, contact_id: &str) -> crate::Result<()> {
+        self.delete_with_meta(contact_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`delete`](Self::delete), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn delete_with_meta(&self, contact_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/contacts/{contact_id}");
+        let request = self.0.build(Method::DELETE, &path).route("/contacts/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Drops cached `/contacts` responses, so the next call fetches fresh data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/contacts");
+    }
+}
+
+/// The `/contacts` operations of [`ContactsSvc`], as a trait.
+///
+/// Application code can depend on `dyn ContactsApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait ContactsApi: Send + Sync {
+    /// See [`ContactsSvc::list`].
+    async fn list(&self, options: ListContactsOptions) -> crate::Result<ListContactsResponse>;
+
+    /// See [`ContactsSvc::create`].
+    async fn create(&self, options: CreateContactOptions) -> crate::Result<Contact>;
+
+    /// See [`ContactsSvc::get`].
+    async fn get(&self, contact_id: &str) -> crate::Result<Contact>;
+
+    /// See [`ContactsSvc::update`].
+    async fn update(
+        &self,
+        contact_id: &str,
+        options: UpdateContactOptions,
+    ) -> crate::Result<Contact>;
+
+    /// See [`ContactsSvc::delete`].
+    async fn delete(&self, contact_id: &str) -> crate::Result<()>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl ContactsApi for ContactsSvc {
+    async fn list(&self, options: ListContactsOptions) -> crate::Result<ListContactsResponse> {
+        ContactsSvc::list(self, options).await
+    }
+
+    async fn create(&self, options: CreateContactOptions) -> crate::Result<Contact> {
+        ContactsSvc::create(self, options).await
+    }
+
+    async fn get(&self, contact_id: &str) -> crate::Result<Contact> {
+        ContactsSvc::get(self, contact_id).await
+    }
+
+    async fn update(
+        &self,
+        contact_id: &str,
+        options: UpdateContactOptions,
+    ) -> crate::Result<Contact> {
+        ContactsSvc::update(self, contact_id, options).await
+    }
+
+    async fn delete(&self, contact_id: &str) -> crate::Result<()> {
+        ContactsSvc::delete(self, contact_id).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// Options for listing contacts.
+#[must_use]
+#[derive(Debug, Default, Clone)]
+pub struct ListContactsOptions {
+    pub(crate) email: Option<String>,
+    pub(crate) per_page: Option<u32>,
+    pub(crate) page: Option<u32>,
+}
+
+impl ListContactsOptions {
+    /// Creates new [`ListContactsOptions`] with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only return the contact with this email address.
+    #[inline]
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Sets the number of results per page (1-100).
+    #[inline]
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the page number.
+    #[inline]
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// Options for creating a new contact.
+#[must_use]
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateContactOptions {
+    /// Email address.
+    pub(crate) email: String,
+
+    /// First name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) first_name: Option<String>,
+
+    /// Last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) last_name: Option<String>,
+
+    /// Custom attributes, usable as substitution data in templates.
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub(crate) attributes: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CreateContactOptions {
+    /// Creates new [`CreateContactOptions`] for the given email address.
+    pub fn new(email: impl Into<String>) -> Self {
+        Self {
+            email: email.into(),
+            first_name: None,
+            last_name: None,
+            attributes: serde_json::Map::new(),
+        }
+    }
+
+    /// Sets the first name.
+    #[inline]
+    pub fn with_first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.first_name = Some(first_name.into());
+        self
+    }
+
+    /// Sets the last name.
+    #[inline]
+    pub fn with_last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Sets a custom attribute.
+    #[inline]
+    pub fn with_attribute(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Changes to a contact. Fields that are not set are left unchanged.
+#[must_use]
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct UpdateContactOptions {
+    /// New email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) email: Option<String>,
+
+    /// New first name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) first_name: Option<String>,
+
+    /// New last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) last_name: Option<String>,
+
+    /// Attributes to set; other attributes are kept.
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub(crate) attributes: serde_json::Map<String, serde_json::Value>,
+
+    /// New subscription state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) unsubscribed: Option<bool>,
+}
+
+impl UpdateContactOptions {
+    /// Creates new [`UpdateContactOptions`] that change nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Changes the email address.
+    #[inline]
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Changes the first name.
+    #[inline]
+    pub fn with_first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.first_name = Some(first_name.into());
+        self
+    }
+
+    /// Changes the last name.
+    #[inline]
+    pub fn with_last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Sets a custom attribute, keeping the others.
+    #[inline]
+    pub fn with_attribute(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+
+    /// Unsubscribes or resubscribes the contact.
+    #[inline]
+    pub fn with_unsubscribed(mut self, unsubscribed: bool) -> Self {
+        self.unsubscribed = Some(unsubscribed);
+        self
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListContactsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListContactsResponse,
+}
+
+/// Response from listing contacts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct ListContactsResponse {
+    /// List of contacts.
+    pub contacts: Vec<Contact>,
+    /// Pagination information.
+    pub pagination: ContactPagination,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ListContactsResponse {
+    /// Creates a single page holding `contacts`, e.g. for a mock
+    /// [`ContactsApi`] in tests.
+    pub fn new(contacts: Vec<Contact>) -> Self {
+        let count = u32::try_from(contacts.len()).unwrap_or(u32::MAX);
+        Self {
+            pagination: ContactPagination {
+                total: contacts.len() as u64,
+                per_page: count,
+                current_page: 1,
+                last_page: 1,
+                extra: serde_json::Map::new(),
+            },
+            contacts,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+/// Pagination metadata for contact list responses.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct ContactPagination {
+    /// Total number of contacts.
+    pub total: u64,
+    /// Number of results per page.
+    pub per_page: u32,
+    /// Current page number.
+    pub current_page: u32,
+    /// Last page number.
+    pub last_page: u32,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContactResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: Contact,
+}
+
+/// A recipient contact.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct Contact {
+    /// Contact ID.
+    pub id: String,
+    /// Email address.
+    pub email: String,
+    /// First name.
+    #[serde(default)]
+    pub first_name: Option<String>,
+    /// Last name.
+    #[serde(default)]
+    pub last_name: Option<String>,
+    /// Custom attributes.
+    #[serde(default)]
+    pub attributes: serde_json::Map<String, serde_json::Value>,
+    /// Whether the contact unsubscribed from emails.
+    #[serde(default)]
+    pub unsubscribed: bool,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Last update timestamp.
+    pub updated_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Contact {
+    /// Creates a contact with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`ContactsApi`].
+    pub fn new(id: impl Into<String>, email: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            email: email.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g. `Jane Doe <jane@example.com> (c_123)`.
+impl fmt::Display for Contact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = [self.first_name.as_deref(), self.last_name.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if name.is_empty() {
+            write!(f, "{} ({})", self.email, self.id)
+        } else {
+            write!(f, "{name} <{}> ({})", self.email, self.id)
+        }
+    }
+}
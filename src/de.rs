@@ -0,0 +1,80 @@
+//! Deserialization helpers for response types.
+
+/// Implements `Deserialize` for a response struct whose unknown fields are
+/// collected in an `extra` map.
+///
+/// `#[serde(flatten)]` does the same, but buffers the whole object in an
+/// intermediate tree before deserializing any field, so a list response
+/// holds the body, a copy of every item, and the parsed items at once. This
+/// visits the object's fields one by one instead, deserializing known fields
+/// straight from the body.
+///
+/// Fields before the `;` are required, fields after it default when missing.
+/// A field can name its JSON key with `field = "key"`.
+macro_rules! deserialize_with_extra {
+    (
+        $ty:ident {
+            $($required:ident $(= $required_key:literal)?),* ;
+            $($default:ident $(= $default_key:literal)?),* $(,)?
+        }
+    ) => {
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl<'de> serde::de::Visitor<'de> for Visitor {
+                    type Value = $ty;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str(concat!("struct ", stringify!($ty)))
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<$ty, A::Error>
+                    where
+                        A: serde::de::MapAccess<'de>,
+                    {
+                        $(let mut $required = None;)*
+                        $(let mut $default = None;)*
+                        let mut extra = serde_json::Map::new();
+                        while let Some(key) = map.next_key::<String>()? {
+                            match key.as_str() {
+                                $($crate::de::deserialize_with_extra!(@key $required $($required_key)?) => {
+                                    $required = Some(map.next_value()?);
+                                })*
+                                $($crate::de::deserialize_with_extra!(@key $default $($default_key)?) => {
+                                    $default = Some(map.next_value()?);
+                                })*
+                                _ => {
+                                    let value = map.next_value()?;
+                                    extra.insert(key, value);
+                                }
+                            }
+                        }
+                        Ok($ty {
+                            $($required: $required.ok_or_else(|| {
+                                serde::de::Error::missing_field(
+                                    $crate::de::deserialize_with_extra!(@key $required $($required_key)?),
+                                )
+                            })?,)*
+                            $($default: $default.unwrap_or_default(),)*
+                            extra,
+                        })
+                    }
+                }
+
+                deserializer.deserialize_map(Visitor)
+            }
+        }
+    };
+    (@key $field:ident) => {
+        stringify!($field)
+    };
+    (@key $field:ident $key:literal) => {
+        $key
+    };
+}
+
+pub(crate) use deserialize_with_extra;
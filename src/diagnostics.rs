@@ -0,0 +1,263 @@
+//! [`miette`](https://docs.rs/miette) diagnostics for SDK errors.
+//!
+//! [`Error`], [`ApiError`], and [`ValidationError`] implement
+//! [`miette::Diagnostic`] with an error code and, where the SDK can tell,
+//! help on how to fix the problem. The API reports which fields failed
+//! validation but not where they are, so
+//! [`ValidationError::with_request`] pairs an error with the request it
+//! rejected, labeling each failing field in the request JSON.
+//!
+//! Only available with the `miette` feature.
+
+use std::fmt;
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+use crate::error::{ApiError, ValidationError};
+use crate::transport::StatusCode;
+use crate::Error;
+
+type Text<'a> = Option<Box<dyn fmt::Display + 'a>>;
+
+fn text<'a>(text: impl fmt::Display + 'a) -> Text<'a> {
+    Some(Box::new(text))
+}
+
+/// Appends the request ID hint to `help`, if the error carries one.
+fn with_request_id<'a>(help: Option<String>, request_id: Option<&str>) -> Text<'a> {
+    let hint =
+        request_id.map(|id| format!("include request ID {id} when contacting Lettr support"));
+    match (help, hint) {
+        (Some(help), Some(hint)) => text(format!("{help}\n{hint}")),
+        (help, hint) => help.or(hint).map(|help| Box::new(help) as _),
+    }
+}
+
+impl Diagnostic for Error {
+    fn code<'a>(&'a self) -> Text<'a> {
+        let code = match self {
+            Self::Api(e) => return e.code(),
+            Self::Validation(e) => return e.code(),
+            Self::Http(_) => "lettr::http",
+            Self::Transport(_) => "lettr::transport",
+            Self::Parse(_) => "lettr::parse",
+            Self::Serialize(_) => "lettr::serialize",
+            Self::InvalidOptions(_) => "lettr::invalid_options",
+            Self::Config(_) => "lettr::config",
+            Self::Queue(_) => "lettr::queue",
+            Self::RateLimited { .. } => "lettr::rate_limited",
+            Self::Timeout(_) => "lettr::timeout",
+            Self::VersionMismatch { .. } => "lettr::version_mismatch",
+            Self::Shutdown => "lettr::shutdown",
+            Self::CircuitOpen { .. } => "lettr::circuit_open",
+            Self::OutsideSendWindow { .. } => "lettr::outside_send_window",
+            Self::Io(_) => "lettr::io",
+            Self::Render(_) => "lettr::render",
+        };
+        text(code)
+    }
+
+    fn help<'a>(&'a self) -> Text<'a> {
+        let help = match self {
+            Self::Api(e) => return e.help(),
+            Self::Validation(e) => return e.help(),
+            Self::Http(_) if self.is_retryable() => {
+                "the Lettr API could not be reached; check the network and try again".to_owned()
+            }
+            Self::Parse(e) => {
+                let help = "the response did not match this SDK version; updating it may help";
+                return with_request_id(Some(help.to_owned()), e.request_id.as_deref());
+            }
+            Self::InvalidOptions(_) => "fix the problems above before sending".to_owned(),
+            Self::Config(_) => {
+                "pass an API key to `Lettr::new` or set the LETTR_API_KEY environment variable"
+                    .to_owned()
+            }
+            Self::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            } => format!("wait {retry_after:?} before sending more requests"),
+            Self::RateLimited { .. } => "slow down before sending more requests".to_owned(),
+            Self::Timeout(_) => {
+                "raise the limit with `LettrBuilder::timeout` if the API is slow".to_owned()
+            }
+            Self::VersionMismatch { requested, .. } => {
+                format!("the API no longer serves version {requested}; pin a supported version")
+            }
+            Self::Shutdown => "create a new client to send more requests".to_owned(),
+            Self::CircuitOpen { retry_in } => {
+                format!("the API failed repeatedly; requests resume in {retry_in:?}")
+            }
+            Self::OutsideSendWindow { opens_in } => {
+                format!("send again once the send window opens in {opens_in:?}")
+            }
+            _ => return None,
+        };
+        text(help)
+    }
+}
+
+impl Diagnostic for ApiError {
+    fn code<'a>(&'a self) -> Text<'a> {
+        match &self.error_code {
+            Some(code) => text(format!("lettr::api::{code}")),
+            None => text("lettr::api"),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Text<'a> {
+        let help = match self.status {
+            StatusCode::UNAUTHORIZED => Some("check that the API key is valid and not revoked"),
+            StatusCode::FORBIDDEN => Some("the API key lacks the permission for this request"),
+            StatusCode::NOT_FOUND => Some("check that the resource ID is correct"),
+            status if status.is_server_error() => {
+                Some("the Lettr API failed to process the request; it may succeed later")
+            }
+            _ => None,
+        };
+        with_request_id(help.map(str::to_owned), self.request_id.as_deref())
+    }
+}
+
+impl Diagnostic for ValidationError {
+    fn code<'a>(&'a self) -> Text<'a> {
+        match &self.error_code {
+            Some(code) => text(format!("lettr::validation::{code}")),
+            None => text("lettr::validation"),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Text<'a> {
+        let fields = self.fields();
+        let help = (!fields.is_empty()).then(|| format!("fix the fields {}", fields.join(", ")));
+        with_request_id(help, self.request_id.as_deref())
+    }
+}
+
+impl ValidationError {
+    /// Pairs this error with the request the API rejected, so the diagnostic
+    /// labels each failing field in the request JSON.
+    ///
+    /// Fields missing from the request, such as a required field that was
+    /// not set, are labeled at the start of the request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::{CreateEmailOptions, Error, Lettr};
+    /// # async fn run(client: Lettr, email: CreateEmailOptions) -> miette::Result<()> {
+    /// match client.emails.send(email.clone()).await {
+    ///     Err(Error::Validation(e)) => Err(e.with_request(&email).into()),
+    ///     other => other.map(drop).map_err(Into::into),
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_request(&self, request: &impl serde::Serialize) -> ValidationReport {
+        let source = serde_json::to_string_pretty(request).unwrap_or_default();
+        let labels = self
+            .fields()
+            .into_iter()
+            .map(|field| {
+                let (offset, len) = locate(&source, field).unwrap_or((0, source.len().min(1)));
+                LabeledSpan::new(Some(self.field(field).join("; ")), offset, len)
+            })
+            .collect();
+        ValidationReport {
+            error: self.clone(),
+            source,
+            labels,
+        }
+    }
+}
+
+/// Finds the key of `field`, a dotted path such as `attachments.0.filename`,
+/// in pretty-printed JSON, returning its offset and length.
+fn locate(json: &str, field: &str) -> Option<(usize, usize)> {
+    let mut found = None;
+    let mut from = 0;
+    for segment in field.split('.') {
+        if segment.parse::<usize>().is_ok() {
+            continue;
+        }
+        let key = format!("\"{segment}\":");
+        let offset = from + json[from..].find(&key)?;
+        found = Some((offset, key.len() - 1));
+        from = offset + key.len();
+    }
+    found
+}
+
+/// A [`ValidationError`] together with the request it rejected; see
+/// [`ValidationError::with_request`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use lettr::transport::StatusCode;
+/// use lettr::types::ValidationError;
+/// use lettr::CreateEmailOptions;
+/// use miette::Diagnostic;
+///
+/// let email = CreateEmailOptions::new("hello@example.com", ["not-an-address"], "Hi!")
+///     .with_text("Hi!");
+/// let err = ValidationError {
+///     message: "The given data was invalid.".to_owned(),
+///     error_code: None,
+///     errors: HashMap::from([("to.0".to_owned(), vec!["The to.0 must be a valid email.".to_owned()])]),
+///     status: StatusCode::UNPROCESSABLE_ENTITY,
+///     request_id: None,
+/// };
+///
+/// let report = err.with_request(&email);
+/// let label = report.labels().unwrap().next().unwrap();
+/// assert_eq!(label.label(), Some("The to.0 must be a valid email."));
+/// assert_eq!(&report.request()[label.offset()..][..label.len()], "\"to\"");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValidationReport {
+    error: ValidationError,
+    source: String,
+    labels: Vec<LabeledSpan>,
+}
+
+impl ValidationReport {
+    /// Returns the validation error.
+    #[must_use]
+    pub fn error(&self) -> &ValidationError {
+        &self.error
+    }
+
+    /// Returns the request, as the JSON the labels point into.
+    #[must_use]
+    pub fn request(&self) -> &str {
+        &self.source
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.error.message)
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+impl Diagnostic for ValidationReport {
+    fn code<'a>(&'a self) -> Text<'a> {
+        self.error.code()
+    }
+
+    fn help<'a>(&'a self) -> Text<'a> {
+        self.error.help()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(self.labels.iter().cloned()))
+    }
+}
@@ -1,9 +1,11 @@
+use std::fmt;
 use std::sync::Arc;
 
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::config::Config;
+use crate::meta::WithMeta;
 
 /// Service for the `/domains` endpoints.
 #[derive(Clone, Debug)]
@@ -26,12 +28,17 @@ impl DomainsSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn list(&self) -> crate::Result<Vec<Domain>> {
+        self.list_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<Domain>>> {
         let request = self.0.build(Method::GET, "/domains");
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<ListDomainsResponseWrapper>().await?;
-        Ok(wrapper.data.domains)
+        let response: WithMeta<ListDomainsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.domains))
     }
 
     /// Register a new sending domain.
@@ -50,15 +57,23 @@ impl DomainsSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn create(&self, domain: &str) -> crate::Result<CreateDomainResponse> {
+        self.create_with_meta(domain).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`create`](Self::create), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn create_with_meta(
+        &self,
+        domain: &str,
+    ) -> crate::Result<WithMeta<CreateDomainResponse>> {
         let body = CreateDomainRequest {
             domain: domain.to_owned(),
         };
         let request = self.0.build(Method::POST, "/domains").json(&body);
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<CreateDomainResponseWrapper>().await?;
-        Ok(wrapper.data)
+        let response: WithMeta<CreateDomainResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
     }
 
     /// Retrieve details of a single sending domain.
@@ -77,13 +92,18 @@ impl DomainsSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn get(&self, domain: &str) -> crate::Result<DomainDetail> {
+        self.get_with_meta(domain).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, domain: &str) -> crate::Result<WithMeta<DomainDetail>> {
         let path = format!("/domains/{domain}");
-        let request = self.0.build(Method::GET, &path);
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<ShowDomainResponseWrapper>().await?;
-        Ok(wrapper.data)
+        let request = self.0.build(Method::GET, &path).route("/domains/{id}");
+        let response: WithMeta<ShowDomainResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
     }
 
     /// Delete a sending domain.
@@ -102,12 +122,65 @@ impl DomainsSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn delete(&self, domain: &str) -> crate::Result<()> {
+        self.delete_with_meta(domain).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`delete`](Self::delete), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn delete_with_meta(&self, domain: &str) -> crate::Result<WithMeta<()>> {
         let path = format!("/domains/{domain}");
-        let request = self.0.build(Method::DELETE, &path);
-        self.0.send(request).await?;
-        Ok(())
+        let request = self.0.build(Method::DELETE, &path).route("/domains/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Drops cached `/domains` responses, so the next call fetches fresh data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/domains");
+    }
+}
+
+/// The `/domains` operations of [`DomainsSvc`], as a trait.
+///
+/// Application code can depend on `dyn DomainsApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait DomainsApi: Send + Sync {
+    /// See [`DomainsSvc::list`].
+    async fn list(&self) -> crate::Result<Vec<Domain>>;
+
+    /// See [`DomainsSvc::create`].
+    async fn create(&self, domain: &str) -> crate::Result<CreateDomainResponse>;
+
+    /// See [`DomainsSvc::get`].
+    async fn get(&self, domain: &str) -> crate::Result<DomainDetail>;
+
+    /// See [`DomainsSvc::delete`].
+    async fn delete(&self, domain: &str) -> crate::Result<()>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl DomainsApi for DomainsSvc {
+    async fn list(&self) -> crate::Result<Vec<Domain>> {
+        DomainsSvc::list(self).await
+    }
+
+    async fn create(&self, domain: &str) -> crate::Result<CreateDomainResponse> {
+        DomainsSvc::create(self, domain).await
+    }
+
+    async fn get(&self, domain: &str) -> crate::Result<DomainDetail> {
+        DomainsSvc::get(self, domain).await
+    }
+
+    async fn delete(&self, domain: &str) -> crate::Result<()> {
+        DomainsSvc::delete(self, domain).await
     }
 }
 
@@ -133,7 +206,8 @@ struct ListDomainsData {
 }
 
 /// A sending domain.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct Domain {
     /// Domain name.
     pub domain: String,
@@ -151,6 +225,48 @@ pub struct Domain {
     pub created_at: String,
     /// Last update timestamp.
     pub updated_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Domain {
+    /// Creates a domain entry with the other fields empty. Set them as
+    /// needed, e.g. for a mock [`DomainsApi`](crate::services::DomainsApi).
+    pub fn new(domain: impl Into<String>) -> Self {
+        Self {
+            domain: domain.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g. `example.com (Approved, can send)`.
+///
+/// # Example
+///
+/// ```
+/// use lettr::domains::Domain;
+///
+/// let mut domain = Domain::new("example.com");
+/// domain.status_label = "Pending".to_owned();
+/// assert_eq!(domain.to_string(), "example.com (Pending, cannot send)");
+/// ```
+impl fmt::Display for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.status_label.is_empty() {
+            &self.status
+        } else {
+            &self.status_label
+        };
+        let sending = if self.can_send {
+            "can send"
+        } else {
+            "cannot send"
+        };
+        write!(f, "{} ({status}, {sending})", self.domain)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -161,7 +277,8 @@ struct CreateDomainResponseWrapper {
 }
 
 /// Response from creating a new domain.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct CreateDomainResponse {
     /// Domain name.
     pub domain: String,
@@ -171,10 +288,26 @@ pub struct CreateDomainResponse {
     pub status_label: String,
     /// DKIM configuration.
     pub dkim: Option<DkimInfo>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CreateDomainResponse {
+    /// Creates a response with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`DomainsApi`](crate::services::DomainsApi).
+    pub fn new(domain: impl Into<String>) -> Self {
+        Self {
+            domain: domain.into(),
+            ..Self::default()
+        }
+    }
 }
 
 /// DKIM signing information for a domain.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct DkimInfo {
     /// DKIM public key.
     pub public: String,
@@ -182,6 +315,10 @@ pub struct DkimInfo {
     pub selector: String,
     /// DKIM headers configuration.
     pub headers: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -192,7 +329,8 @@ struct ShowDomainResponseWrapper {
 }
 
 /// Detailed domain information including DNS records.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct DomainDetail {
     /// Domain name.
     pub domain: String,
@@ -214,20 +352,45 @@ pub struct DomainDetail {
     pub created_at: String,
     /// Last update timestamp.
     pub updated_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl DomainDetail {
+    /// Creates domain details with the other fields empty. Set them as
+    /// needed, e.g. for a mock [`DomainsApi`](crate::services::DomainsApi).
+    pub fn new(domain: impl Into<String>) -> Self {
+        Self {
+            domain: domain.into(),
+            ..Self::default()
+        }
+    }
 }
 
 /// DNS records for domain verification.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct DnsRecords {
     /// DKIM DNS record information.
     pub dkim: Option<DkimDnsRecord>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// DKIM DNS record details.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct DkimDnsRecord {
     /// DKIM selector.
     pub selector: String,
     /// DKIM public key.
     pub public: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
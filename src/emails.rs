@@ -1,10 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::sync::Arc;
 
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
 
+use crate::clock::{Clock, SystemClock};
 use crate::config::Config;
+use crate::de::deserialize_with_extra;
+use crate::error::{InvalidOptions, Problem};
+use crate::event_stream::{EventBatch, EventStream, StreamEventsOptions};
+use crate::filter::{DateRange, Timestamp};
+use crate::meta::WithMeta;
+use crate::send_window;
+use crate::templates::{locale_chain, locale_slug, TemplatesSvc};
+use crate::transport::Bytes;
 
 /// Service for the `/emails` endpoints.
 #[derive(Clone, Debug)]
@@ -29,12 +40,31 @@ impl EmailsSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn send(&self, email: CreateEmailOptions) -> crate::Result<SendEmailResponse> {
+        self.send_with_meta(email).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`send`](Self::send), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn send_with_meta(
+        &self,
+        mut email: CreateEmailOptions,
+    ) -> crate::Result<WithMeta<SendEmailResponse>> {
+        self.0.email_defaults().apply(&mut email);
+        if email.locale.is_some() && email.template_slug.is_some() {
+            let templates = TemplatesSvc(Arc::clone(&self.0));
+            let slugs = templates.slugs(email.project_id).await?;
+            email.resolve_locale(&self.0.email_defaults().locale_fallback, |slug| {
+                slugs.contains(slug)
+            });
+        }
+        // The locale only selects the template; the API does not take it.
+        email.locale = None;
+        send_window::enter(&self.0, &email).await?;
         let request = self.0.build(Method::POST, "/emails").json(&email);
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<SendEmailResponseWrapper>().await?;
-        Ok(wrapper.data)
+        let response: WithMeta<SendEmailResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
     }
 
     /// Retrieve a list of sent emails with optional filtering and pagination.
@@ -56,8 +86,16 @@ impl EmailsSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn list(&self, options: ListEmailsOptions) -> crate::Result<ListEmailsResponse> {
+        self.list_with_meta(options).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(
+        &self,
+        options: ListEmailsOptions,
+    ) -> crate::Result<WithMeta<ListEmailsResponse>> {
         let mut request = self.0.build(Method::GET, "/emails");
 
         if let Some(per_page) = options.per_page {
@@ -76,9 +114,9 @@ impl EmailsSvc {
             request = request.query(&[("to", to.as_str())]);
         }
 
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<ListEmailsResponseWrapper>().await?;
-        Ok(wrapper.data)
+        let response: WithMeta<ListEmailsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
     }
 
     /// Retrieve all events for a specific email by its request ID.
@@ -97,25 +135,618 @@ impl EmailsSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn get(&self, request_id: &str) -> crate::Result<GetEmailResponse> {
+        self.get_with_meta(request_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(
+        &self,
+        request_id: &str,
+    ) -> crate::Result<WithMeta<GetEmailResponse>> {
         let path = format!("/emails/{request_id}");
-        let request = self.0.build(Method::GET, &path);
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<GetEmailResponseWrapper>().await?;
-        Ok(wrapper.data)
+        let request = self.0.build(Method::GET, &path).route("/emails/{id}");
+        let response: WithMeta<GetEmailResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve the clicks on each tracked link of an email, most clicked
+    /// first.
+    ///
+    /// Only links in emails sent with click tracking are counted; see
+    /// [`TemplatesSvc::clicks`](crate::templates::TemplatesSvc::clicks) for
+    /// the clicks across every email sent with a template.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// for link in client.emails.clicks("request-id").await? {
+    ///     println!("{}: {} unique, {} total", link.url, link.unique_clicks, link.total_clicks);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clicks(&self, request_id: &str) -> crate::Result<Vec<LinkClicks>> {
+        self.clicks_with_meta(request_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`clicks`](Self::clicks), but also returns the HTTP status,
+    /// headers, and rate-limit information of the response.
+    pub async fn clicks_with_meta(
+        &self,
+        request_id: &str,
+    ) -> crate::Result<WithMeta<Vec<LinkClicks>>> {
+        let path = format!("/emails/{request_id}/clicks");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/emails/{id}/clicks");
+        let response: WithMeta<LinkClicksResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.links))
+    }
+
+    /// Retrieve the bounces of a date range, newest first.
+    ///
+    /// Returns only bounce and out-of-band bounce events, typed with their
+    /// [bounce class](Bounce::bounce_class) and reason, so bounce processing
+    /// does not have to sift through every event.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::emails::{BounceCategory, ListBouncesOptions};
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = ListBouncesOptions::new("2024-01-01", "2024-01-31")
+    ///     .category(BounceCategory::Hard);
+    /// let bounces = client.emails.list_bounces(options).await?;
+    ///
+    /// for bounce in &bounces.results {
+    ///     println!("{}: {}", bounce.rcpt_to, bounce.reason.as_deref().unwrap_or("-"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_bounces(
+        &self,
+        options: ListBouncesOptions,
+    ) -> crate::Result<ListBouncesResponse> {
+        self.list_bounces_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`list_bounces`](Self::list_bounces), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn list_bounces_with_meta(
+        &self,
+        options: ListBouncesOptions,
+    ) -> crate::Result<WithMeta<ListBouncesResponse>> {
+        let mut request = self
+            .0
+            .build(Method::GET, "/emails/bounces")
+            .query(&[("from", options.from), ("to", options.to)]);
+
+        if let Some(per_page) = options.per_page {
+            request = request.query(&[("per_page", per_page.to_string())]);
+        }
+        if let Some(cursor) = options.cursor {
+            request = request.query(&[("cursor", cursor)]);
+        }
+        if let Some(recipient) = options.recipient {
+            request = request.query(&[("recipient", recipient)]);
+        }
+        if let Some(domain) = options.domain {
+            request = request.query(&[("domain", domain)]);
+        }
+        if let Some(category) = options.category {
+            request = request.query(&[("category", category.as_str())]);
+        }
+
+        let response: WithMeta<ListBouncesResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve every email event of one recipient, oldest first.
+    ///
+    /// Follows the pagination cursor until the last page, so this makes one
+    /// request per page; narrow the range with
+    /// [`from_date`](ListEmailsOptions::from_date) and
+    /// [`to_date`](ListEmailsOptions::to_date) for busy addresses. The
+    /// recipient filter of `options` is replaced by `address`. Events seen on
+    /// more than one page, e.g. because new ones arrived while paging, are
+    /// returned once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::emails::ListEmailsOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = ListEmailsOptions::new().from_date("2025-01-01");
+    /// let history = client
+    ///     .emails
+    ///     .recipient_history("user@example.com", options)
+    ///     .await?;
+    /// for event in &history {
+    ///     println!("{}: {}", event.timestamp, event.subject);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recipient_history(
+        &self,
+        address: &str,
+        options: ListEmailsOptions,
+    ) -> crate::Result<Vec<EmailEvent>> {
+        let mut options = options.recipients(address);
+        let mut events: Vec<EmailEvent> = Vec::new();
+        let mut seen = HashSet::new();
+        loop {
+            let page = self.list(options.clone()).await?;
+            events.extend(
+                page.results
+                    .into_iter()
+                    .filter(|event| seen.insert(event.event_id.clone())),
+            );
+            match page.pagination.next_cursor {
+                Some(cursor) if options.cursor.as_ref() != Some(&cursor) => {
+                    options.cursor = Some(cursor);
+                }
+                _ => break,
+            }
+        }
+        // ISO 8601 timestamps in the same format sort chronologically as
+        // text. The sort is stable, so events with equal timestamps keep the
+        // API's order.
+        events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+        Ok(events)
+    }
+
+    /// Erase the stored data of a recipient, e.g. to honor a GDPR erasure
+    /// request.
+    ///
+    /// The API removes the address from sent emails, events, and
+    /// suppression records in the background. The returned
+    /// [`RecipientErasure`] starts out pending; check on it with
+    /// [`get_recipient_erasure`](Self::get_recipient_erasure) and keep its ID
+    /// as proof of the request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let erasure = client.emails.delete_recipient_data("user@example.com").await?;
+    /// println!("Erasure {} is {}", erasure.id, erasure.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_recipient_data(&self, address: &str) -> crate::Result<RecipientErasure> {
+        self.delete_recipient_data_with_meta(address)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`delete_recipient_data`](Self::delete_recipient_data), but also
+    /// returns the HTTP status, headers, and rate-limit information of the
+    /// response.
+    pub async fn delete_recipient_data_with_meta(
+        &self,
+        address: &str,
+    ) -> crate::Result<WithMeta<RecipientErasure>> {
+        // The address goes in the body rather than the path, so it needs no
+        // escaping.
+        let body = RecipientErasureRequest {
+            email: address.to_owned(),
+        };
+        let request = self.0.build(Method::POST, "/emails/erasures").json(&body);
+        let response: WithMeta<RecipientErasureResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve the current status of a recipient data erasure.
+    ///
+    /// Never served from the response cache, since the status changes while
+    /// the erasure runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let erasure = client.emails.get_recipient_erasure("erasure-id").await?;
+    /// if erasure.is_completed() {
+    ///     println!("Erased at {}", erasure.completed_at.unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_recipient_erasure(&self, erasure_id: &str) -> crate::Result<RecipientErasure> {
+        self.get_recipient_erasure_with_meta(erasure_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`get_recipient_erasure`](Self::get_recipient_erasure), but also
+    /// returns the HTTP status, headers, and rate-limit information of the
+    /// response.
+    pub async fn get_recipient_erasure_with_meta(
+        &self,
+        erasure_id: &str,
+    ) -> crate::Result<WithMeta<RecipientErasure>> {
+        let path = format!("/emails/erasures/{erasure_id}");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/emails/erasures/{id}")
+            .no_cache();
+        let response: WithMeta<RecipientErasureResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Stream email events as they happen, by long-polling the API.
+    ///
+    /// A pull-based alternative to webhooks for deployments without a public
+    /// endpoint. The stream reconnects after transient failures and resumes
+    /// where it left off; see [`EventStream`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::event_stream::StreamEventsOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let mut events = client.emails.stream_events(StreamEventsOptions::new());
+    /// while let Some(event) = events.next().await {
+    ///     let event = event?;
+    ///     println!("{}: {}", event.event_type, event.rcpt_to);
+    ///     // Resume here after a restart.
+    ///     if let Some(cursor) = events.cursor() {
+    ///         std::fs::write("events.cursor", cursor)?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_events(&self, options: StreamEventsOptions) -> EventStream {
+        EventStream::new(Arc::clone(&self.0), options)
+    }
+
+    /// Fetch the events after `cursor` in one call, for consumers that run
+    /// periodically rather than [streaming](Self::stream_events).
+    ///
+    /// Pass the [`next_cursor`](EventBatch::next_cursor) of the previous
+    /// batch to continue where it ended, or `None` to start with events that
+    /// happen from now on. Events come in the order they happened, and a
+    /// cursor always yields the same events first, so a consumer that
+    /// crashes before saving the new cursor sees the batch again. The cursor
+    /// of `options` is replaced by `cursor`. Returns right away, with an
+    /// empty batch if nothing happened, unless a
+    /// [`wait`](StreamEventsOptions::wait) is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::event_stream::StreamEventsOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let cursor = std::fs::read_to_string("events.cursor").ok();
+    /// let batch = client
+    ///     .emails
+    ///     .events_since(cursor.as_deref(), StreamEventsOptions::new())
+    ///     .await?;
+    /// for event in &batch.events {
+    ///     println!("{}: {}", event.event_type, event.rcpt_to);
+    /// }
+    /// std::fs::write("events.cursor", &batch.next_cursor)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn events_since(
+        &self,
+        cursor: Option<&str>,
+        options: StreamEventsOptions,
+    ) -> crate::Result<EventBatch> {
+        self.events_since_with_meta(cursor, options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`events_since`](Self::events_since), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn events_since_with_meta(
+        &self,
+        cursor: Option<&str>,
+        mut options: StreamEventsOptions,
+    ) -> crate::Result<WithMeta<EventBatch>> {
+        options.cursor = cursor.map(str::to_owned);
+        let wait = options.wait.unwrap_or_default();
+        crate::event_stream::fetch(&self.0, &options, wait).await
+    }
+
+    /// Drops cached `/emails` responses, so the next call fetches fresh data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/emails");
+    }
+}
+
+/// The `/emails` operations of [`EmailsSvc`], as a trait.
+///
+/// Application code can depend on `dyn EmailsApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+///
+/// # Example
+///
+/// ```
+/// use lettr::event_stream::{EventBatch, StreamEventsOptions};
+/// use lettr::services::EmailsApi;
+/// use lettr::types::{
+///     GetEmailResponse, LinkClicks, ListBouncesOptions, ListBouncesResponse, ListEmailsOptions,
+///     ListEmailsResponse, RecipientErasure, SendEmailResponse,
+/// };
+/// use lettr::transport::async_trait;
+/// use lettr::CreateEmailOptions;
+///
+/// async fn welcome(emails: &dyn EmailsApi, to: &str) -> lettr::Result<String> {
+///     let email = CreateEmailOptions::new("hello@example.com", [to], "Welcome!")
+///         .with_text("Welcome aboard!");
+///     Ok(emails.send(email).await?.request_id)
+/// }
+///
+/// struct MockEmails;
+///
+/// #[async_trait]
+/// impl EmailsApi for MockEmails {
+///     async fn send(&self, _: CreateEmailOptions) -> lettr::Result<SendEmailResponse> {
+///         Ok(SendEmailResponse::new("test-1", 1, 0))
+///     }
+///
+///     async fn list(&self, _: ListEmailsOptions) -> lettr::Result<ListEmailsResponse> {
+///         unimplemented!()
+///     }
+///
+///     async fn get(&self, _: &str) -> lettr::Result<GetEmailResponse> {
+///         unimplemented!()
+///     }
+///
+///     async fn events_since(
+///         &self,
+///         _: Option<&str>,
+///         _: StreamEventsOptions,
+///     ) -> lettr::Result<EventBatch> {
+///         unimplemented!()
+///     }
+///
+///     async fn clicks(&self, _: &str) -> lettr::Result<Vec<LinkClicks>> {
+///         unimplemented!()
+///     }
+///
+///     async fn list_bounces(&self, _: ListBouncesOptions) -> lettr::Result<ListBouncesResponse> {
+///         unimplemented!()
+///     }
+///
+///     async fn delete_recipient_data(&self, _: &str) -> lettr::Result<RecipientErasure> {
+///         unimplemented!()
+///     }
+///
+///     async fn get_recipient_erasure(&self, _: &str) -> lettr::Result<RecipientErasure> {
+///         unimplemented!()
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// assert_eq!(welcome(&MockEmails, "user@example.com").await.unwrap(), "test-1");
+///
+/// // In production, pass the real service.
+/// let client = lettr::Lettr::new("your-api-key");
+/// let _: &dyn EmailsApi = &client.emails;
+/// # }
+/// ```
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait EmailsApi: Send + Sync {
+    /// See [`EmailsSvc::send`].
+    async fn send(&self, email: CreateEmailOptions) -> crate::Result<SendEmailResponse>;
+
+    /// See [`EmailsSvc::list`].
+    async fn list(&self, options: ListEmailsOptions) -> crate::Result<ListEmailsResponse>;
+
+    /// See [`EmailsSvc::get`].
+    async fn get(&self, request_id: &str) -> crate::Result<GetEmailResponse>;
+
+    /// See [`EmailsSvc::events_since`].
+    async fn events_since(
+        &self,
+        cursor: Option<&str>,
+        options: StreamEventsOptions,
+    ) -> crate::Result<EventBatch>;
+
+    /// See [`EmailsSvc::clicks`].
+    async fn clicks(&self, request_id: &str) -> crate::Result<Vec<LinkClicks>>;
+
+    /// See [`EmailsSvc::list_bounces`].
+    async fn list_bounces(&self, options: ListBouncesOptions)
+        -> crate::Result<ListBouncesResponse>;
+
+    /// See [`EmailsSvc::delete_recipient_data`].
+    async fn delete_recipient_data(&self, address: &str) -> crate::Result<RecipientErasure>;
+
+    /// See [`EmailsSvc::get_recipient_erasure`].
+    async fn get_recipient_erasure(&self, erasure_id: &str) -> crate::Result<RecipientErasure>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl EmailsApi for EmailsSvc {
+    async fn send(&self, email: CreateEmailOptions) -> crate::Result<SendEmailResponse> {
+        EmailsSvc::send(self, email).await
+    }
+
+    async fn list(&self, options: ListEmailsOptions) -> crate::Result<ListEmailsResponse> {
+        EmailsSvc::list(self, options).await
+    }
+
+    async fn get(&self, request_id: &str) -> crate::Result<GetEmailResponse> {
+        EmailsSvc::get(self, request_id).await
+    }
+
+    async fn events_since(
+        &self,
+        cursor: Option<&str>,
+        options: StreamEventsOptions,
+    ) -> crate::Result<EventBatch> {
+        EmailsSvc::events_since(self, cursor, options).await
+    }
+
+    async fn clicks(&self, request_id: &str) -> crate::Result<Vec<LinkClicks>> {
+        EmailsSvc::clicks(self, request_id).await
+    }
+
+    async fn list_bounces(
+        &self,
+        options: ListBouncesOptions,
+    ) -> crate::Result<ListBouncesResponse> {
+        EmailsSvc::list_bounces(self, options).await
+    }
+
+    async fn delete_recipient_data(&self, address: &str) -> crate::Result<RecipientErasure> {
+        EmailsSvc::delete_recipient_data(self, address).await
+    }
+
+    async fn get_recipient_erasure(&self, erasure_id: &str) -> crate::Result<RecipientErasure> {
+        EmailsSvc::get_recipient_erasure(self, erasure_id).await
+    }
+}
+
+/// A minimal, provider-agnostic interface for sending emails.
+///
+/// Libraries can accept `&dyn EmailSender` instead of a Lettr service, so
+/// applications can swap the provider, and tests can pass a double that only
+/// implements [`send`](Self::send).
+///
+/// # Example
+///
+/// ```
+/// use lettr::services::EmailSender;
+/// use lettr::types::SendEmailResponse;
+/// use lettr::transport::async_trait;
+/// use lettr::CreateEmailOptions;
+///
+/// async fn notify(sender: &dyn EmailSender, to: &[&str]) -> usize {
+///     let emails = to
+///         .iter()
+///         .map(|to| CreateEmailOptions::new("hello@example.com", [*to], "Hi!").with_text("Hi!"))
+///         .collect();
+///     sender.send_batch(emails).await.iter().filter(|r| r.is_ok()).count()
+/// }
+///
+/// struct NullSender;
+///
+/// #[async_trait]
+/// impl EmailSender for NullSender {
+///     async fn send(&self, _: CreateEmailOptions) -> lettr::Result<SendEmailResponse> {
+///         Ok(SendEmailResponse::new("test-1", 1, 0))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// assert_eq!(notify(&NullSender, &["a@example.com", "b@example.com"]).await, 2);
+///
+/// // In production, pass the real service.
+/// let client = lettr::Lettr::new("your-api-key");
+/// let _: &dyn EmailSender = &client.emails;
+/// # }
+/// ```
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait EmailSender: Send + Sync {
+    /// Sends a single email.
+    async fn send(&self, email: CreateEmailOptions) -> crate::Result<SendEmailResponse>;
+
+    /// Sends several emails, returning one result per email in the same
+    /// order.
+    ///
+    /// The default implementation sends them one after another, so a failed
+    /// email does not stop the rest.
+    async fn send_batch(
+        &self,
+        emails: Vec<CreateEmailOptions>,
+    ) -> Vec<crate::Result<SendEmailResponse>> {
+        let mut results = Vec::with_capacity(emails.len());
+        for email in emails {
+            results.push(self.send(email).await);
+        }
+        results
+    }
+
+    /// Returns the clock that [`SendJob`](crate::send_job::SendJob)s sending
+    /// through this sender read the time from and sleep on.
+    ///
+    /// Defaults to the system clock. A client's
+    /// [`emails`](crate::Lettr::emails) service returns the clock set with
+    /// [`LettrBuilder::clock`](crate::LettrBuilder::clock).
+    fn clock(&self) -> Arc<dyn Clock> {
+        Arc::new(SystemClock)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl EmailSender for EmailsSvc {
+    async fn send(&self, email: CreateEmailOptions) -> crate::Result<SendEmailResponse> {
+        EmailsSvc::send(self, email).await
+    }
+
+    fn clock(&self) -> Arc<dyn Clock> {
+        self.0.shared_clock()
     }
 }
 
 // ── Request Types ──────────────────────────────────────────────────────────
 
+/// Longest subject, in characters, accepted by
+/// [`CreateEmailOptions::try_build`].
+pub const MAX_SUBJECT_LEN: usize = 998;
+
 /// Options for sending an email via the Lettr API.
 ///
 /// Use the builder methods to construct the email step by step.
 ///
 /// At minimum, `from`, `to`, `subject`, and either `html` or `text` must be provided.
 #[must_use]
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateEmailOptions {
     /// Sender email address.
     from: String,
@@ -127,6 +758,10 @@ pub struct CreateEmailOptions {
     /// Recipient email addresses.
     to: Vec<String>,
 
+    /// Audience to send to, in addition to `to`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    audience_id: Option<String>,
+
     /// Email subject.
     subject: String,
 
@@ -142,6 +777,19 @@ pub struct CreateEmailOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     reply_to: Option<Vec<String>>,
 
+    /// `Message-ID` header to send the email with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_id: Option<String>,
+
+    /// `In-Reply-To` header: the `Message-ID` of the email this one replies
+    /// to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_reply_to: Option<String>,
+
+    /// `References` header: the `Message-ID`s of the thread, oldest first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    references: Option<Vec<String>>,
+
     /// Template slug for sending with a pre-defined template.
     #[serde(skip_serializing_if = "Option::is_none")]
     template_slug: Option<String>,
@@ -150,6 +798,10 @@ pub struct CreateEmailOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     template_version: Option<u32>,
 
+    /// Locale of the template variant to send, resolved before sending.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+
     /// Project ID for template lookup.
     #[serde(skip_serializing_if = "Option::is_none")]
     project_id: Option<u64>,
@@ -162,6 +814,14 @@ pub struct CreateEmailOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     metadata: Option<HashMap<String, serde_json::Value>>,
 
+    /// Tags for grouping emails in statistics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+
+    /// Unsubscribe group the email belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unsubscribe_group_id: Option<String>,
+
     /// File attachments.
     #[serde(skip_serializing_if = "Option::is_none")]
     attachments: Option<Vec<Attachment>>,
@@ -200,15 +860,22 @@ impl CreateEmailOptions {
             from: from.into(),
             from_name: None,
             to: to.into_iter().map(Into::into).collect(),
+            audience_id: None,
             subject: subject.into(),
             html: None,
             text: None,
             reply_to: None,
+            message_id: None,
+            in_reply_to: None,
+            references: None,
             template_slug: None,
             template_version: None,
+            locale: None,
             project_id: None,
             substitution_data: None,
             metadata: None,
+            tags: None,
+            unsubscribe_group_id: None,
             attachments: None,
             options: None,
         }
@@ -221,6 +888,29 @@ impl CreateEmailOptions {
         self
     }
 
+    /// Sends the email to every contact in an
+    /// [audience](crate::audiences::AudiencesSvc), in addition to any
+    /// recipients given to [`new`](Self::new).
+    ///
+    /// Template substitution data is filled in from each contact's
+    /// attributes, so one request reaches the whole audience.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let email = CreateEmailOptions::new("news@example.com", Vec::<String>::new(), "June update")
+    ///     .with_audience("audience-id")
+    ///     .with_template("monthly-update");
+    /// assert!(email.try_build().is_ok());
+    /// ```
+    #[inline]
+    pub fn with_audience(mut self, audience_id: impl Into<String>) -> Self {
+        self.audience_id = Some(audience_id.into());
+        self
+    }
+
     /// Sets the HTML body of the email.
     #[inline]
     pub fn with_html(mut self, html: impl Into<String>) -> Self {
@@ -244,24 +934,116 @@ impl CreateEmailOptions {
         self
     }
 
-    /// Sets the template slug for sending with a pre-defined template.
+    /// Sets the `Message-ID` header, e.g. to an ID generated when the email
+    /// was recorded, so later replies can be matched to it. Angle brackets
+    /// are added if missing.
+    ///
+    /// The API may rewrite the ID; the one the email was sent with is in
+    /// [`SendEmailResponse::message_id`].
     #[inline]
-    pub fn with_template(mut self, slug: impl Into<String>) -> Self {
-        self.template_slug = Some(slug.into());
+    pub fn with_message_id(mut self, message_id: impl Into<String>) -> Self {
+        self.message_id = Some(angle_bracketed(message_id.into()));
         self
     }
 
-    /// Sets the template version.
+    /// Marks the email as a reply to the email with `message_id`, so mail
+    /// clients show it in the same thread. Angle brackets are added if
+    /// missing.
+    ///
+    /// Sets the `In-Reply-To` header, and adds the ID to the `References`
+    /// header unless it is there already; set the earlier IDs of a longer
+    /// thread with [`with_references`](Self::with_references).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let reply = CreateEmailOptions::new("support@example.com", ["user@example.com"], "Re: Order #42")
+    ///     .with_text("Your order has shipped.")
+    ///     .with_references(["order-42.1@example.com"])
+    ///     .with_in_reply_to("order-42.2@example.com");
+    ///
+    /// assert_eq!(reply.in_reply_to(), Some("<order-42.2@example.com>"));
+    /// assert_eq!(
+    ///     reply.references(),
+    ///     ["<order-42.1@example.com>", "<order-42.2@example.com>"],
+    /// );
+    /// ```
     #[inline]
-    pub fn with_template_version(mut self, version: u32) -> Self {
-        self.template_version = Some(version);
+    pub fn with_in_reply_to(mut self, message_id: impl Into<String>) -> Self {
+        let message_id = angle_bracketed(message_id.into());
+        let references = self.references.get_or_insert_with(Vec::new);
+        if !references.contains(&message_id) {
+            references.push(message_id.clone());
+        }
+        self.in_reply_to = Some(message_id);
         self
     }
 
-    /// Sets the project ID for template lookup.
+    /// Sets the `References` header to the `Message-ID`s of the thread,
+    /// oldest first. Angle brackets are added if missing.
     #[inline]
-    pub fn with_project_id(mut self, project_id: u64) -> Self {
-        self.project_id = Some(project_id);
+    pub fn with_references<T, A>(mut self, message_ids: T) -> Self
+    where
+        T: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        self.references = Some(
+            message_ids
+                .into_iter()
+                .map(|id| angle_bracketed(id.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Sets the template slug for sending with a pre-defined template.
+    #[inline]
+    pub fn with_template(mut self, slug: impl Into<String>) -> Self {
+        self.template_slug = Some(slug.into());
+        self
+    }
+
+    /// Sets the template version.
+    #[inline]
+    pub fn with_template_version(mut self, version: u32) -> Self {
+        self.template_version = Some(version);
+        self
+    }
+
+    /// Sends the template's variant for `locale`, e.g. `"de-AT"`, if one
+    /// exists.
+    ///
+    /// Before sending, the client looks for a
+    /// [locale variant](crate::templates::CreateTemplateOptions::locale_variant)
+    /// of the template for `de-at`, then `de`, then each locale set with
+    /// [`LettrBuilder::locale_fallback`](crate::LettrBuilder::locale_fallback),
+    /// and sends the template itself if there is none. Looking up variants
+    /// lists the project's templates once; the list is reused for five
+    /// minutes, or until a template is created through the client or
+    /// [`TemplatesSvc::invalidate_cache`](crate::templates::TemplatesSvc::invalidate_cache)
+    /// is called. Has no effect on emails without a template.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let email = CreateEmailOptions::new("sender@example.com", ["user@example.com"], "Willkommen!")
+    ///     .with_template("welcome")
+    ///     .with_locale("de-AT");
+    /// ```
+    #[inline]
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Sets the project ID for template lookup.
+    #[inline]
+    pub fn with_project_id(mut self, project_id: u64) -> Self {
+        self.project_id = Some(project_id);
         self
     }
 
@@ -305,6 +1087,37 @@ impl CreateEmailOptions {
         self
     }
 
+    /// Adds a tag, e.g. `"password-reset"`, to group the email in
+    /// [statistics](crate::stats::StatsOptions::tag).
+    #[inline]
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.get_or_insert_with(Vec::new).push(tag.into());
+        self
+    }
+
+    /// Assigns the email to an
+    /// [unsubscribe group](crate::unsubscribe_groups::UnsubscribeGroupsSvc).
+    ///
+    /// Recipients who unsubscribed from the group are skipped, and the
+    /// unsubscribe link in the email only opts out of this group instead of
+    /// all mail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let email = CreateEmailOptions::new("hello@example.com", ["user@example.com"], "Tips")
+    ///     .with_text("Did you know…")
+    ///     .with_unsubscribe_group("product-tips");
+    /// assert_eq!(email.unsubscribe_group(), Some("product-tips"));
+    /// ```
+    #[inline]
+    pub fn with_unsubscribe_group(mut self, group_id: impl Into<String>) -> Self {
+        self.unsubscribe_group_id = Some(group_id.into());
+        self
+    }
+
     /// Adds a file attachment.
     #[inline]
     pub fn with_attachment(mut self, attachment: Attachment) -> Self {
@@ -340,11 +1153,215 @@ impl CreateEmailOptions {
             .transactional = Some(transactional);
         self
     }
+
+    /// Sends the email through the [IP pool](crate::ip_pools) named `name`
+    /// instead of the one assigned to the sending domain, e.g. to keep
+    /// marketing and critical transactional traffic on separate IPs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let email = CreateEmailOptions::new("hello@example.com", ["user@example.com"], "Reset")
+    ///     .with_text("Your reset link: ...")
+    ///     .with_ip_pool("transactional");
+    /// assert_eq!(email.options().unwrap().ip_pool.as_deref(), Some("transactional"));
+    /// ```
+    #[inline]
+    pub fn with_ip_pool(mut self, name: impl Into<String>) -> Self {
+        self.options
+            .get_or_insert_with(EmailOptions::default)
+            .ip_pool = Some(name.into());
+        self
+    }
+
+    /// Checks the email for problems the API would reject, and returns it if
+    /// there are none.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`Problem`] found: an empty sender or subject, no
+    /// recipients or audience, a subject over [`MAX_SUBJECT_LEN`] characters, no body and
+    /// no template, or a template combined with a body.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::types::Problem;
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let email = CreateEmailOptions::new("sender@example.com", Vec::<String>::new(), "Hello!");
+    /// let err = email.try_build().unwrap_err();
+    /// assert_eq!(err.problems, [Problem::NoRecipients, Problem::NoContent]);
+    /// ```
+    pub fn try_build(self) -> Result<Self, InvalidOptions> {
+        let mut problems = Vec::new();
+        if self.from.trim().is_empty() {
+            problems.push(Problem::NoSender);
+        }
+        if self.to.is_empty() && self.audience_id.is_none() {
+            problems.push(Problem::NoRecipients);
+        }
+        let subject_len = self.subject.chars().count();
+        if self.subject.trim().is_empty() {
+            problems.push(Problem::NoSubject);
+        } else if subject_len > MAX_SUBJECT_LEN {
+            problems.push(Problem::SubjectTooLong {
+                len: subject_len,
+                max: MAX_SUBJECT_LEN,
+            });
+        }
+        let has_body = self.html.is_some() || self.text.is_some();
+        match (has_body, self.template_slug.is_some()) {
+            (false, false) => problems.push(Problem::NoContent),
+            (true, true) => problems.push(Problem::TemplateWithContent),
+            _ => {}
+        }
+
+        if problems.is_empty() {
+            Ok(self)
+        } else {
+            Err(InvalidOptions { problems })
+        }
+    }
+
+    /// Returns the sender email address.
+    #[must_use]
+    pub fn from_address(&self) -> &str {
+        &self.from
+    }
+
+    /// Returns the sender display name, if set.
+    #[must_use]
+    pub fn from_name(&self) -> Option<&str> {
+        self.from_name.as_deref()
+    }
+
+    /// Returns the recipient email addresses.
+    #[must_use]
+    pub fn to(&self) -> &[String] {
+        &self.to
+    }
+
+    /// Returns the audience the email is sent to, if any.
+    #[must_use]
+    pub fn audience(&self) -> Option<&str> {
+        self.audience_id.as_deref()
+    }
+
+    /// Returns the subject line.
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Returns the HTML body, if set.
+    #[must_use]
+    pub fn html(&self) -> Option<&str> {
+        self.html.as_deref()
+    }
+
+    /// Returns the plain text body, if set.
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Returns the reply-to addresses.
+    #[must_use]
+    pub fn reply_to(&self) -> &[String] {
+        self.reply_to.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the `Message-ID` header, if set.
+    #[must_use]
+    pub fn message_id(&self) -> Option<&str> {
+        self.message_id.as_deref()
+    }
+
+    /// Returns the `In-Reply-To` header, if set.
+    #[must_use]
+    pub fn in_reply_to(&self) -> Option<&str> {
+        self.in_reply_to.as_deref()
+    }
+
+    /// Returns the `References` header.
+    #[must_use]
+    pub fn references(&self) -> &[String] {
+        self.references.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the template slug, if set.
+    #[must_use]
+    pub fn template_slug(&self) -> Option<&str> {
+        self.template_slug.as_deref()
+    }
+
+    /// Returns the locale of the template variant to send, if set.
+    #[must_use]
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Replaces the template with its variant for the requested locale, the
+    /// first of the [locale chain](crate::templates::locale_chain) for which
+    /// `exists` returns `true`, and clears the locale.
+    pub(crate) fn resolve_locale(&mut self, fallback: &[String], exists: impl Fn(&str) -> bool) {
+        let Some(locale) = self.locale.take() else {
+            return;
+        };
+        let Some(ref mut slug) = self.template_slug else {
+            return;
+        };
+        if let Some(variant) = locale_chain(&locale, fallback)
+            .iter()
+            .map(|locale| locale_slug(slug, locale))
+            .find(|variant| exists(variant))
+        {
+            *slug = variant;
+        }
+    }
+
+    /// Returns the template substitution data, if any.
+    #[must_use]
+    pub fn substitution_data(&self) -> Option<&HashMap<String, serde_json::Value>> {
+        self.substitution_data.as_ref()
+    }
+
+    /// Returns the custom metadata, if any.
+    #[must_use]
+    pub fn metadata(&self) -> Option<&HashMap<String, serde_json::Value>> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the tags.
+    #[must_use]
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the unsubscribe group the email belongs to, if any.
+    #[must_use]
+    pub fn unsubscribe_group(&self) -> Option<&str> {
+        self.unsubscribe_group_id.as_deref()
+    }
+
+    /// Returns the attachments.
+    pub fn attachments(&self) -> &[Attachment] {
+        self.attachments.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the tracking and delivery options, if any were set.
+    #[must_use]
+    pub fn options(&self) -> Option<&EmailOptions> {
+        self.options.as_ref()
+    }
 }
 
 /// Tracking and delivery options for an email.
 #[must_use]
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct EmailOptions {
     /// Enable click tracking.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -357,11 +1374,19 @@ pub struct EmailOptions {
     /// Mark as transactional email.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transactional: Option<bool>,
+
+    /// Name of the [IP pool](crate::ip_pools) to send through.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_pool: Option<String>,
 }
 
 /// A file attachment for an email.
 ///
-/// Attachments must be base64-encoded.
+/// [`new`](Self::new) takes base64-encoded data, while
+/// [`from_bytes`](Self::from_bytes) and [`from_path`](Self::from_path)
+/// encode raw file contents. The content is kept in a shared [`Bytes`]
+/// buffer, so cloning an attachment, or an email holding it, does not copy
+/// the data.
 ///
 /// # Example
 ///
@@ -371,7 +1396,7 @@ pub struct EmailOptions {
 /// let attachment = Attachment::new("invoice.pdf", "application/pdf", "base64data...");
 /// ```
 #[must_use]
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Attachment {
     /// Filename of the attachment.
     pub name: String,
@@ -379,15 +1404,21 @@ pub struct Attachment {
     #[serde(rename = "type")]
     pub content_type: String,
     /// Base64-encoded file content.
-    pub data: String,
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub data: Bytes,
 }
 
 impl Attachment {
-    /// Creates a new [`Attachment`].
+    /// Creates a new [`Attachment`] from base64-encoded `data`.
+    ///
+    /// Passing a `String` or [`Bytes`] takes over its buffer without copying.
     pub fn new(
         name: impl Into<String>,
         content_type: impl Into<String>,
-        data: impl Into<String>,
+        data: impl Into<Bytes>,
     ) -> Self {
         Self {
             name: name.into(),
@@ -395,17 +1426,351 @@ impl Attachment {
             data: data.into(),
         }
     }
+
+    /// Creates a new [`Attachment`], base64-encoding the raw file `contents`.
+    ///
+    /// With the `simd` feature, the encoding uses SIMD instructions where
+    /// the CPU supports them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::Attachment;
+    ///
+    /// let attachment = Attachment::from_bytes("hello.txt", "text/plain", b"Hello!");
+    /// assert_eq!(attachment.data_str(), "SGVsbG8h");
+    /// ```
+    pub fn from_bytes(
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        contents: impl AsRef<[u8]>,
+    ) -> Self {
+        Self::new(name, content_type, encode_base64(contents.as_ref()))
+    }
+
+    /// Like [`from_bytes`](Self::from_bytes), but takes the MIME type from
+    /// the extension of `name` and, with the `infer` feature, from the magic
+    /// bytes at the start of `contents`.
+    ///
+    /// Content of an unknown type is sent as `application/octet-stream`.
+    ///
+    /// # Errors
+    ///
+    /// With the `infer` feature, returns
+    /// [`Error::InvalidOptions`](crate::Error::InvalidOptions) with
+    /// [`Problem::AttachmentTypeMismatch`] if the content does not match the
+    /// extension, e.g. a PNG image named `report.pdf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::Attachment;
+    ///
+    /// let attachment = Attachment::from_bytes_detected("hello.txt", b"Hello!")?;
+    /// assert_eq!(attachment.content_type, "text/plain");
+    /// # Ok::<(), lettr::Error>(())
+    /// ```
+    pub fn from_bytes_detected(
+        name: impl Into<String>,
+        contents: impl AsRef<[u8]>,
+    ) -> crate::Result<Self> {
+        let name = name.into();
+        let contents = contents.as_ref();
+        let content_type = content_type_of(&name, None, contents)?;
+        Ok(Self::from_bytes(name, content_type, contents))
+    }
+
+    /// Reads and base64-encodes a file, taking the attachment name from the
+    /// path and the MIME type from its extension and, with the `infer`
+    /// feature, its magic bytes.
+    ///
+    /// Files of an unknown type are sent as `application/octet-stream`. Use
+    /// [`from_path_with_limit`](Self::from_path_with_limit) to cap the size.
+    ///
+    /// Not available on WASM targets, which have no file system.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`](crate::Error::Io) if the file cannot be read,
+    /// and, with the `infer` feature,
+    /// [`Error::InvalidOptions`](crate::Error::InvalidOptions) with
+    /// [`Problem::AttachmentTypeMismatch`] if the content does not match the
+    /// extension.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        Self::from_path_with_limit(path, u64::MAX)
+    }
+
+    /// Like [`from_path`](Self::from_path), but fails for files larger than
+    /// `max_size` bytes.
+    ///
+    /// The size is checked before the file is read, and the file is encoded
+    /// in chunks, so only the base64 text is held in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`](crate::Error::Io) if the file cannot be read,
+    /// and [`Error::InvalidOptions`](crate::Error::InvalidOptions) with
+    /// [`Problem::AttachmentTooLarge`] if it is larger than `max_size` or,
+    /// with the `infer` feature, [`Problem::AttachmentTypeMismatch`] if the
+    /// content does not match the extension.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path_with_limit(
+        path: impl AsRef<std::path::Path>,
+        max_size: u64,
+    ) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let file = std::fs::File::open(path)?;
+        let size = file.metadata()?.len();
+        if size > max_size {
+            return Err(attachment_too_large(name, max_size));
+        }
+        Self::from_reader_sized(name, String::new(), file, max_size, size)
+    }
+
+    /// Reads and base64-encodes `reader`, failing as soon as it yields more
+    /// than `max_size` bytes.
+    ///
+    /// The content is encoded in chunks as it is read, so only the base64
+    /// text is held in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`](crate::Error::Io) if reading fails, and
+    /// [`Error::InvalidOptions`](crate::Error::InvalidOptions) with
+    /// [`Problem::AttachmentTooLarge`] if the content is larger than
+    /// `max_size`.
+    ///
+    /// With the `infer` feature, an empty `content_type` is detected from the
+    /// magic bytes of the content, and a `content_type` that contradicts them
+    /// fails with [`Problem::AttachmentTypeMismatch`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::Attachment;
+    ///
+    /// let attachment = Attachment::from_reader("hello.txt", "text/plain", &b"Hello!"[..], 1024)?;
+    /// assert_eq!(attachment.data_str(), "SGVsbG8h");
+    ///
+    /// assert!(Attachment::from_reader("hello.txt", "text/plain", &b"Hello!"[..], 5).is_err());
+    /// # Ok::<(), lettr::Error>(())
+    /// ```
+    pub fn from_reader(
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        reader: impl std::io::Read,
+        max_size: u64,
+    ) -> crate::Result<Self> {
+        Self::from_reader_sized(name.into(), content_type.into(), reader, max_size, 0)
+    }
+
+    /// Implements [`from_reader`](Self::from_reader), with the expected
+    /// size of the content as a hint.
+    fn from_reader_sized(
+        name: String,
+        content_type: String,
+        mut reader: impl std::io::Read,
+        max_size: u64,
+        size_hint: u64,
+    ) -> crate::Result<Self> {
+        use std::io::Read as _;
+
+        let mut head = Vec::new();
+        reader.by_ref().take(SNIFF_LEN).read_to_end(&mut head)?;
+        let content_type = content_type_of(&name, Some(content_type), &head)?;
+        let reader = std::io::Cursor::new(head).chain(reader);
+        let data = encode_reader(reader, max_size, size_hint).map_err(|e| match e {
+            EncodeError::TooLarge => attachment_too_large(name.clone(), max_size),
+            EncodeError::Io(e) => e.into(),
+        })?;
+        Ok(Self::new(name, content_type, data))
+    }
+
+    /// Returns the base64-encoded content as a string.
+    ///
+    /// Returns an empty string if the data is not valid UTF-8, which base64
+    /// text always is.
+    #[must_use]
+    pub fn data_str(&self) -> &str {
+        std::str::from_utf8(&self.data).unwrap_or_default()
+    }
+}
+
+/// Bytes read and encoded at a time by [`Attachment::from_reader`]; a
+/// multiple of 3, so chunks encode without padding.
+const ENCODE_CHUNK_SIZE: usize = 48 * 1024;
+
+fn encode_base64(contents: &[u8]) -> String {
+    let mut data = String::new();
+    encode_base64_into(contents, &mut data);
+    data
+}
+
+#[cfg(not(feature = "simd"))]
+fn encode_base64_into(contents: &[u8], data: &mut String) {
+    use base64::Engine as _;
+
+    base64::engine::general_purpose::STANDARD.encode_string(contents, data);
+}
+
+#[cfg(feature = "simd")]
+fn encode_base64_into(contents: &[u8], data: &mut String) {
+    base64_simd::STANDARD.encode_append(contents, data);
+}
+
+enum EncodeError {
+    TooLarge,
+    Io(std::io::Error),
+}
+
+/// Base64-encodes `reader` chunk by chunk, reserving room for `size_hint`
+/// bytes of content up front.
+fn encode_reader(
+    reader: impl std::io::Read,
+    max_size: u64,
+    size_hint: u64,
+) -> Result<String, EncodeError> {
+    use std::io::Read as _;
+
+    let mut reader = reader.take(max_size.saturating_add(1));
+    let capacity = usize::try_from(size_hint.saturating_add(2) / 3 * 4).unwrap_or(0);
+    let mut data = String::with_capacity(capacity);
+    let mut chunk = vec![0; ENCODE_CHUNK_SIZE];
+    let mut total = 0u64;
+    loop {
+        // Fill the whole chunk, since only the last one may be padded.
+        let mut len = 0;
+        while len < chunk.len() {
+            match reader.read(&mut chunk[len..]) {
+                Ok(0) => break,
+                Ok(n) => len += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(EncodeError::Io(e)),
+            }
+        }
+        total += len as u64;
+        if total > max_size {
+            return Err(EncodeError::TooLarge);
+        }
+        encode_base64_into(&chunk[..len], &mut data);
+        if len < chunk.len() {
+            return Ok(data);
+        }
+    }
+}
+
+/// Wraps a `Message-ID` in angle brackets, unless it already is.
+fn angle_bracketed(message_id: String) -> String {
+    let trimmed = message_id.trim();
+    if trimmed.starts_with('<') && trimmed.ends_with('>') {
+        trimmed.to_owned()
+    } else {
+        format!("<{trimmed}>")
+    }
+}
+
+fn attachment_too_large(name: String, max: u64) -> crate::Error {
+    InvalidOptions {
+        problems: vec![Problem::AttachmentTooLarge { name, max }],
+    }
+    .into()
+}
+
+/// MIME type of attachments of an unknown type.
+const OCTET_STREAM: &str = "application/octet-stream";
+
+/// Bytes at the start of an attachment inspected to detect its type.
+const SNIFF_LEN: u64 = 8 * 1024;
+
+/// Returns the MIME type of the attachment `name`: `declared`, or else the
+/// type of its extension. With the `infer` feature, the type detected from
+/// `head`, the start of the content, is used when neither is known, and
+/// must match otherwise.
+fn content_type_of(name: &str, declared: Option<String>, head: &[u8]) -> crate::Result<String> {
+    let declared = declared
+        .filter(|declared| !declared.is_empty() && declared != OCTET_STREAM)
+        .or_else(|| extension_content_type(name).map(str::to_owned));
+
+    #[cfg(feature = "infer")]
+    if let Some(detected) = infer::get(head).map(|kind| kind.mime_type()) {
+        return match declared {
+            Some(declared) if essence(&declared).eq_ignore_ascii_case(detected) => Ok(declared),
+            Some(declared) => Err(InvalidOptions {
+                problems: vec![Problem::AttachmentTypeMismatch {
+                    name: name.to_owned(),
+                    declared,
+                    detected: detected.to_owned(),
+                }],
+            }
+            .into()),
+            None => Ok(detected.to_owned()),
+        };
+    }
+    #[cfg(not(feature = "infer"))]
+    let _ = head;
+
+    Ok(declared.unwrap_or_else(|| OCTET_STREAM.to_owned()))
+}
+
+/// Returns a MIME type without its parameters, e.g. `text/plain` for
+/// `text/plain; charset=utf-8`.
+#[cfg(feature = "infer")]
+fn essence(content_type: &str) -> &str {
+    content_type
+        .split_once(';')
+        .map_or(content_type, |(essence, _)| essence)
+        .trim()
+}
+
+/// Returns the MIME type of the extension of the file `name`, if known.
+fn extension_content_type(name: &str) -> Option<&'static str> {
+    let (_, extension) = name.rsplit_once('.')?;
+    let content_type = match extension.to_ascii_lowercase().as_str() {
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "json" => "application/json",
+        "csv" => "text/csv",
+        "txt" => "text/plain",
+        "html" | "htm" => "text/html",
+        "ics" => "text/calendar",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        _ => return None,
+    };
+    Some(content_type)
+}
+
+/// Writes the attachment content as a JSON string, borrowing the buffer.
+fn serialize_base64<S: serde::Serializer>(data: &Bytes, serializer: S) -> Result<S::Ok, S::Error> {
+    let text = std::str::from_utf8(data)
+        .map_err(|_| serde::ser::Error::custom("attachment data is not base64 text"))?;
+    serializer.serialize_str(text)
+}
+
+fn deserialize_base64<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Bytes, D::Error> {
+    String::deserialize(deserializer).map(Bytes::from)
 }
 
 /// Options for listing sent emails.
 #[must_use]
 #[derive(Debug, Default, Clone)]
 pub struct ListEmailsOptions {
-    per_page: Option<u32>,
-    cursor: Option<String>,
-    recipients: Option<String>,
-    from: Option<String>,
-    to: Option<String>,
+    pub(crate) per_page: Option<u32>,
+    pub(crate) cursor: Option<String>,
+    pub(crate) recipients: Option<String>,
+    pub(crate) from: Option<String>,
+    pub(crate) to: Option<String>,
 }
 
 impl ListEmailsOptions {
@@ -435,68 +1800,254 @@ impl ListEmailsOptions {
         self
     }
 
-    /// Filters emails sent on or after this date (ISO 8601 format).
+    /// Filters emails sent on or after this date.
     #[inline]
-    pub fn from_date(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from_date(mut self, from: impl Into<Timestamp>) -> Self {
+        self.from = Some(from.into().to_string());
         self
     }
 
-    /// Filters emails sent on or before this date (ISO 8601 format).
+    /// Filters emails sent on or before this date.
     #[inline]
-    pub fn to_date(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to_date(mut self, to: impl Into<Timestamp>) -> Self {
+        self.to = Some(to.into().to_string());
         self
     }
-}
 
-// ── Response Types ─────────────────────────────────────────────────────────
-
-#[derive(Debug, Deserialize)]
-struct SendEmailResponseWrapper {
-    #[allow(dead_code)]
-    message: String,
-    data: SendEmailResponse,
+    /// Filters emails sent within `range`.
+    #[inline]
+    pub fn date_range(self, range: DateRange) -> Self {
+        self.from_date(range.from).to_date(range.to)
+    }
 }
 
-/// Successful response from sending an email.
-#[derive(Debug, Clone, Deserialize)]
-pub struct SendEmailResponse {
-    /// Unique request ID for the transmission.
-    pub request_id: String,
-    /// Number of accepted recipients.
-    pub accepted: u32,
-    /// Number of rejected recipients.
-    pub rejected: u32,
+/// Options for listing bounces.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct ListBouncesOptions {
+    pub(crate) from: String,
+    pub(crate) to: String,
+    pub(crate) per_page: Option<u32>,
+    pub(crate) cursor: Option<String>,
+    pub(crate) recipient: Option<String>,
+    pub(crate) domain: Option<String>,
+    pub(crate) category: Option<BounceCategory>,
 }
 
-#[derive(Debug, Deserialize)]
-struct ListEmailsResponseWrapper {
-    #[allow(dead_code)]
-    message: String,
-    data: ListEmailsResponse,
-}
+impl ListBouncesOptions {
+    /// Creates new [`ListBouncesOptions`] for the bounces from `from` to `to`
+    /// inclusive.
+    pub fn new(from: impl Into<Timestamp>, to: impl Into<Timestamp>) -> Self {
+        Self {
+            from: from.into().to_string(),
+            to: to.into().to_string(),
+            per_page: None,
+            cursor: None,
+            recipient: None,
+            domain: None,
+            category: None,
+        }
+    }
 
-/// Response from listing sent emails.
-#[derive(Debug, Clone, Deserialize)]
-pub struct ListEmailsResponse {
-    /// List of email events.
-    pub results: Vec<EmailEvent>,
-    /// Total number of matching emails.
-    pub total_count: u64,
-    /// Pagination information.
-    pub pagination: Pagination,
-}
+    /// Creates new [`ListBouncesOptions`] for the bounces within `range`.
+    pub fn in_range(range: DateRange) -> Self {
+        Self::new(range.from, range.to)
+    }
+
+    /// Sets the number of results per page (1-100).
+    #[inline]
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the pagination cursor from a previous response.
+    #[inline]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Only includes bounces of this recipient address.
+    #[inline]
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    /// Only includes bounces of emails sent from this domain.
+    #[inline]
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Only includes bounces of this category.
+    #[inline]
+    pub fn category(mut self, category: BounceCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+}
+
+/// Settings applied to every email sent by a client; see
+/// [`LettrBuilder::default_from`](crate::LettrBuilder::default_from) and
+/// [`LettrBuilder::default_metadata`](crate::LettrBuilder::default_metadata).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EmailDefaults {
+    pub(crate) from: Option<String>,
+    pub(crate) from_name: Option<String>,
+    pub(crate) reply_to: Vec<String>,
+    pub(crate) metadata: HashMap<String, serde_json::Value>,
+    pub(crate) locale_fallback: Vec<String>,
+}
+
+impl EmailDefaults {
+    /// Fills in the fields `email` leaves empty.
+    pub(crate) fn apply(&self, email: &mut CreateEmailOptions) {
+        if let Some(ref from) = self.from {
+            if email.from.trim().is_empty() {
+                email.from.clone_from(from);
+                // A default name only belongs to the default address.
+                if email.from_name.is_none() {
+                    email.from_name.clone_from(&self.from_name);
+                }
+            }
+        }
+        if email.reply_to.is_none() && !self.reply_to.is_empty() {
+            email.reply_to = Some(self.reply_to.clone());
+        }
+        if !self.metadata.is_empty() {
+            let metadata = email.metadata.get_or_insert_with(HashMap::new);
+            // Keys set on the email win over the defaults.
+            for (key, value) in &self.metadata {
+                metadata.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RecipientErasureRequest {
+    email: String,
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct SendEmailResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: SendEmailResponse,
+}
+
+/// Successful response from sending an email.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct SendEmailResponse {
+    /// Unique request ID for the transmission.
+    pub request_id: String,
+    /// Number of accepted recipients.
+    pub accepted: u32,
+    /// Number of rejected recipients.
+    pub rejected: u32,
+    /// `Message-ID` the email was sent with, if the API reports it. Differs
+    /// from the [requested one](CreateEmailOptions::with_message_id) if the
+    /// server rewrote it.
+    pub message_id: Option<String>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl SendEmailResponse {
+    /// Creates a response, e.g. for a mock
+    /// [`EmailsApi`](crate::services::EmailsApi) in tests.
+    pub fn new(request_id: impl Into<String>, accepted: u32, rejected: u32) -> Self {
+        Self {
+            request_id: request_id.into(),
+            accepted,
+            rejected,
+            message_id: None,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g.
+/// `abc123: 2 accepted, 0 rejected`.
+impl fmt::Display for SendEmailResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} accepted, {} rejected",
+            self.request_id, self.accepted, self.rejected
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListEmailsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListEmailsResponse,
+}
+
+/// Response from listing sent emails.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ListEmailsResponse {
+    /// List of email events.
+    pub results: Vec<EmailEvent>,
+    /// Total number of matching emails.
+    pub total_count: u64,
+    /// Pagination information.
+    pub pagination: Pagination,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+deserialize_with_extra!(ListEmailsResponse {
+    results, total_count, pagination;
+});
+
+impl ListEmailsResponse {
+    /// Creates a single page holding `results`, e.g. for a mock
+    /// [`EmailsApi`](crate::services::EmailsApi) in tests.
+    pub fn new(results: Vec<EmailEvent>) -> Self {
+        Self {
+            total_count: results.len() as u64,
+            pagination: Pagination {
+                next_cursor: None,
+                per_page: u32::try_from(results.len()).unwrap_or(u32::MAX),
+                extra: serde_json::Map::new(),
+            },
+            results,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
 
 /// Pagination metadata for list responses.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct Pagination {
     /// Cursor for fetching the next page, if available.
     pub next_cursor: Option<String>,
     /// Number of results per page.
     pub per_page: u32,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+deserialize_with_extra!(Pagination {
+    per_page;
+    next_cursor
+});
+
 #[derive(Debug, Deserialize)]
 struct GetEmailResponseWrapper {
     #[allow(dead_code)]
@@ -505,16 +2056,220 @@ struct GetEmailResponseWrapper {
 }
 
 /// Response from getting email details.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct GetEmailResponse {
     /// List of events for this email.
     pub results: Vec<EmailEventDetail>,
     /// Total number of events.
     pub total_count: u64,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+deserialize_with_extra!(GetEmailResponse {
+    results, total_count;
+});
+
+impl GetEmailResponse {
+    /// Creates a response holding `results`, e.g. for a mock
+    /// [`EmailsApi`](crate::services::EmailsApi) in tests.
+    pub fn new(results: Vec<EmailEventDetail>) -> Self {
+        Self {
+            total_count: results.len() as u64,
+            results,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+/// A free-form JSON value kept as the raw text the API sent, such as
+/// [`EmailEvent::rcpt_meta`].
+///
+/// Parsing is deferred until the value is read with [`parse`](Self::parse)
+/// or [`to_value`](Self::to_value), so listing many events does not build
+/// JSON trees nobody looks at. Two values are equal if their text is.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use lettr::types::RawJson;
+///
+/// let meta = RawJson::from_value(&serde_json::json!({"plan": "pro"})).unwrap();
+/// assert_eq!(meta.get(), r#"{"plan":"pro"}"#);
+///
+/// let typed: HashMap<String, String> = meta.parse().unwrap();
+/// assert_eq!(typed["plan"], "pro");
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RawJson(Box<RawValue>);
+
+impl RawJson {
+    /// Serializes `value` into a [`RawJson`], e.g. for test fixtures.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` cannot be serialized as JSON.
+    pub fn from_value<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<Self> {
+        serde_json::value::to_raw_value(value).map(Self)
+    }
+
+    /// Returns the raw JSON text.
+    #[must_use]
+    pub fn get(&self) -> &str {
+        self.0.get()
+    }
+
+    /// Deserializes the value into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON does not match `T`.
+    pub fn parse<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_str(self.0.get())
+    }
+
+    /// Parses the value into a [`serde_json::Value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not valid JSON, which it always is
+    /// when it came from the API.
+    pub fn to_value(&self) -> serde_json::Result<serde_json::Value> {
+        self.parse()
+    }
+}
+
+impl PartialEq for RawJson {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for RawJson {}
+
+impl fmt::Debug for RawJson {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.get())
+    }
+}
+
+impl fmt::Display for RawJson {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.get())
+    }
+}
+
+/// Type of an [`EmailEvent`], e.g. to filter
+/// [exports](crate::exports::CreateExportOptions::event_type) by.
+///
+/// Converts from the name the API uses, so `"delivery"` and
+/// [`EventType::Delivery`] are interchangeable; unknown names are kept as
+/// [`EventType::Other`].
+///
+/// # Example
+///
+/// ```
+/// use lettr::emails::EventType;
+///
+/// assert_eq!(EventType::from("out_of_band"), EventType::OutOfBand);
+/// assert_eq!(EventType::Click.as_str(), "click");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventType {
+    /// The API accepted the email.
+    Injection,
+    /// The receiving server accepted the email.
+    Delivery,
+    /// Delivery was delayed and will be retried.
+    Delay,
+    /// The receiving server rejected the email.
+    Bounce,
+    /// The receiving server reported a bounce after accepting the email.
+    OutOfBand,
+    /// The email was not sent because of a sending policy.
+    PolicyRejection,
+    /// The email could not be generated, e.g. from its template.
+    GenerationFailure,
+    /// The recipient reported the email as spam.
+    SpamComplaint,
+    /// The email was opened.
+    Open,
+    /// The email was opened for the first time.
+    InitialOpen,
+    /// A tracked link was clicked.
+    Click,
+    /// The recipient unsubscribed via the `List-Unsubscribe` header.
+    ListUnsubscribe,
+    /// The recipient unsubscribed via a link in the email.
+    LinkUnsubscribe,
+    /// An event type this version of the SDK does not know.
+    Other(String),
+}
+
+impl EventType {
+    /// Returns the name the API uses for this event type.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Injection => "injection",
+            Self::Delivery => "delivery",
+            Self::Delay => "delay",
+            Self::Bounce => "bounce",
+            Self::OutOfBand => "out_of_band",
+            Self::PolicyRejection => "policy_rejection",
+            Self::GenerationFailure => "generation_failure",
+            Self::SpamComplaint => "spam_complaint",
+            Self::Open => "open",
+            Self::InitialOpen => "initial_open",
+            Self::Click => "click",
+            Self::ListUnsubscribe => "list_unsubscribe",
+            Self::LinkUnsubscribe => "link_unsubscribe",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for EventType {
+    fn from(name: &str) -> Self {
+        match name {
+            "injection" => Self::Injection,
+            "delivery" => Self::Delivery,
+            "delay" => Self::Delay,
+            "bounce" => Self::Bounce,
+            "out_of_band" => Self::OutOfBand,
+            "policy_rejection" => Self::PolicyRejection,
+            "generation_failure" => Self::GenerationFailure,
+            "spam_complaint" => Self::SpamComplaint,
+            "open" => Self::Open,
+            "initial_open" => Self::InitialOpen,
+            "click" => Self::Click,
+            "list_unsubscribe" => Self::ListUnsubscribe,
+            "link_unsubscribe" => Self::LinkUnsubscribe,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(name: String) -> Self {
+        Self::from(name.as_str())
+    }
 }
 
 /// A sent email event (returned from list endpoint).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct EmailEvent {
     /// Unique event ID.
     pub event_id: String,
@@ -537,41 +2292,68 @@ pub struct EmailEvent {
     /// Recipient domain.
     pub recipient_domain: String,
     /// Mailbox provider (e.g. "gmail").
-    #[serde(default)]
     pub mailbox_provider: Option<String>,
     /// Mailbox provider region.
-    #[serde(default)]
     pub mailbox_provider_region: Option<String>,
     /// Sending IP address.
-    #[serde(default)]
     pub sending_ip: Option<String>,
     /// Whether click tracking is enabled.
-    #[serde(default)]
     pub click_tracking: bool,
     /// Whether open tracking is enabled.
-    #[serde(default)]
     pub open_tracking: bool,
     /// Whether this is a transactional email.
-    #[serde(default)]
     pub transactional: bool,
     /// Message size in bytes.
-    #[serde(default)]
     pub msg_size: Option<u64>,
     /// Injection time.
-    #[serde(default)]
     pub injection_time: Option<String>,
-    /// Recipient metadata.
-    #[serde(default)]
-    pub rcpt_meta: Option<serde_json::Value>,
+    /// Recipient metadata, kept as raw JSON until read.
+    pub rcpt_meta: Option<RawJson>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+deserialize_with_extra!(EmailEvent {
+    event_id, timestamp, request_id, message_id, subject, friendly_from, sending_domain,
+    rcpt_to, raw_rcpt_to, recipient_domain;
+    mailbox_provider, mailbox_provider_region, sending_ip, click_tracking, open_tracking,
+    transactional, msg_size, injection_time, rcpt_meta
+});
+
+impl EmailEvent {
+    /// Creates an event for the email `request_id` sent to `rcpt_to`, with
+    /// the other fields empty. Set them as needed, e.g. for test fixtures.
+    pub fn new(request_id: impl Into<String>, rcpt_to: impl Into<String>) -> Self {
+        let rcpt_to = rcpt_to.into();
+        Self {
+            request_id: request_id.into(),
+            raw_rcpt_to: rcpt_to.clone(),
+            rcpt_to,
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g.
+/// `2024-01-15T10:30:00+00:00 sender@example.com -> user@example.com: Hello! (abc123)`.
+impl fmt::Display for EmailEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} -> {}: {} ({})",
+            self.timestamp, self.friendly_from, self.rcpt_to, self.subject, self.request_id
+        )
+    }
 }
 
 /// Detailed email event (returned from get endpoint).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct EmailEventDetail {
     /// Unique event ID.
     pub event_id: String,
     /// Event type (e.g. "injection", "delivery", "bounce").
-    #[serde(rename = "type")]
     pub event_type: String,
     /// Timestamp of the event.
     pub timestamp: String,
@@ -592,39 +2374,579 @@ pub struct EmailEventDetail {
     /// Recipient domain.
     pub recipient_domain: String,
     /// Mailbox provider.
-    #[serde(default)]
     pub mailbox_provider: Option<String>,
     /// Mailbox provider region.
-    #[serde(default)]
     pub mailbox_provider_region: Option<String>,
     /// Sending IP address.
-    #[serde(default)]
     pub sending_ip: Option<String>,
     /// Whether click tracking is enabled.
-    #[serde(default)]
     pub click_tracking: bool,
     /// Whether open tracking is enabled.
-    #[serde(default)]
     pub open_tracking: bool,
     /// Whether this is a transactional email.
-    #[serde(default)]
     pub transactional: bool,
     /// Message size in bytes.
-    #[serde(default)]
     pub msg_size: Option<u64>,
     /// Injection time.
-    #[serde(default)]
     pub injection_time: Option<String>,
     /// Bounce or failure reason.
-    #[serde(default)]
     pub reason: Option<String>,
     /// Raw reason string.
-    #[serde(default)]
     pub raw_reason: Option<String>,
     /// Error code for bounce/failure.
-    #[serde(default)]
     pub error_code: Option<String>,
-    /// Recipient metadata.
+    /// Recipient metadata, kept as raw JSON until read.
+    pub rcpt_meta: Option<RawJson>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+deserialize_with_extra!(EmailEventDetail {
+    event_id, event_type = "type", timestamp, request_id, message_id, subject, friendly_from,
+    sending_domain, rcpt_to, raw_rcpt_to, recipient_domain;
+    mailbox_provider, mailbox_provider_region, sending_ip, click_tracking, open_tracking,
+    transactional, msg_size, injection_time, reason, raw_reason, error_code, rcpt_meta
+});
+
+impl EmailEventDetail {
+    /// Creates an event of `event_type` (e.g. `"delivery"`) for the email
+    /// `request_id` sent to `rcpt_to`, with the other fields empty. Set them
+    /// as needed, e.g. for test fixtures.
+    pub fn new(
+        event_type: impl Into<String>,
+        request_id: impl Into<String>,
+        rcpt_to: impl Into<String>,
+    ) -> Self {
+        let rcpt_to = rcpt_to.into();
+        Self {
+            event_type: event_type.into(),
+            request_id: request_id.into(),
+            raw_rcpt_to: rcpt_to.clone(),
+            rcpt_to,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the RFC 3463 enhanced status code of a bounce or failure,
+    /// read from the [error code](Self::error_code) or, failing that, the
+    /// [raw reason](Self::raw_reason).
+    #[must_use]
+    pub fn enhanced_status(&self) -> Option<EnhancedStatusCode> {
+        EnhancedStatusCode::find_in([&self.error_code, &self.raw_reason])
+    }
+}
+
+/// Filtering and sorting helpers for the events of an email.
+///
+/// Implemented for [`GetEmailResponse`] and for slices (and so vectors) of
+/// [`EmailEventDetail`].
+///
+/// # Example
+///
+/// ```
+/// use lettr::emails::{EmailEventDetail, EmailEvents, GetEmailResponse};
+///
+/// let mut bounce = EmailEventDetail::new("bounce", "req_1", "b@example.com");
+/// bounce.timestamp = "2024-01-15T10:31:00+00:00".to_owned();
+/// let mut delivery = EmailEventDetail::new("delivery", "req_1", "a@example.com");
+/// delivery.timestamp = "2024-01-15T10:30:00+00:00".to_owned();
+/// let response = GetEmailResponse::new(vec![bounce, delivery]);
+///
+/// assert_eq!(response.bounces().len(), 1);
+/// assert_eq!(response.by_recipient("A@example.com").len(), 1);
+/// assert_eq!(response.latest().unwrap().event_type, "bounce");
+/// assert_eq!(response.chronological()[0].event_type, "delivery");
+/// ```
+pub trait EmailEvents {
+    /// Returns the events.
+    fn events(&self) -> &[EmailEventDetail];
+
+    /// Returns the events of type `event_type`, such as `"delivery"`.
+    fn of_type(&self, event_type: &str) -> Vec<&EmailEventDetail> {
+        self.events()
+            .iter()
+            .filter(|event| event.event_type == event_type)
+            .collect()
+    }
+
+    /// Returns the delivery events.
+    fn deliveries(&self) -> Vec<&EmailEventDetail> {
+        self.of_type("delivery")
+    }
+
+    /// Returns the bounce events, including out-of-band bounces.
+    fn bounces(&self) -> Vec<&EmailEventDetail> {
+        self.events()
+            .iter()
+            .filter(|event| matches!(event.event_type.as_str(), "bounce" | "out_of_band"))
+            .collect()
+    }
+
+    /// Returns the open events, including initial opens.
+    fn opens(&self) -> Vec<&EmailEventDetail> {
+        self.events()
+            .iter()
+            .filter(|event| matches!(event.event_type.as_str(), "open" | "initial_open"))
+            .collect()
+    }
+
+    /// Returns the click events.
+    fn clicks(&self) -> Vec<&EmailEventDetail> {
+        self.of_type("click")
+    }
+
+    /// Returns the events for the recipient `address`, compared
+    /// case-insensitively.
+    fn by_recipient(&self, address: &str) -> Vec<&EmailEventDetail> {
+        self.events()
+            .iter()
+            .filter(|event| event.rcpt_to.eq_ignore_ascii_case(address))
+            .collect()
+    }
+
+    /// Returns the events sorted from oldest to newest.
+    ///
+    /// Timestamps are compared as strings, which orders the ISO 8601
+    /// timestamps the API sends correctly.
+    fn chronological(&self) -> Vec<&EmailEventDetail> {
+        let mut events: Vec<_> = self.events().iter().collect();
+        events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+        events
+    }
+
+    /// Returns the most recent event.
+    fn latest(&self) -> Option<&EmailEventDetail> {
+        self.events()
+            .iter()
+            .max_by(|a, b| a.timestamp.cmp(&b.timestamp))
+    }
+}
+
+impl EmailEvents for [EmailEventDetail] {
+    fn events(&self) -> &[EmailEventDetail] {
+        self
+    }
+}
+
+impl EmailEvents for GetEmailResponse {
+    fn events(&self) -> &[EmailEventDetail] {
+        &self.results
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LinkClicksResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    pub(crate) data: LinkClicksData,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LinkClicksData {
+    pub(crate) links: Vec<LinkClicks>,
+}
+
+/// Clicks on one tracked link.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct LinkClicks {
+    /// The link's target URL.
+    pub url: String,
+    /// Number of recipients who clicked the link.
+    pub unique_clicks: u64,
+    /// Number of clicks, including repeated clicks by the same recipient.
+    pub total_clicks: u64,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+deserialize_with_extra!(LinkClicks {
+    url;
+    unique_clicks, total_clicks
+});
+
+impl LinkClicks {
+    /// Creates the clicks on the link to `url`, e.g. for a mock
+    /// [`EmailsApi`](crate::services::EmailsApi) in tests.
+    pub fn new(url: impl Into<String>, unique_clicks: u64, total_clicks: u64) -> Self {
+        Self {
+            url: url.into(),
+            unique_clicks,
+            total_clicks,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListBouncesResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListBouncesResponse,
+}
+
+/// Response from listing bounces.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ListBouncesResponse {
+    /// The bounces, newest first.
+    pub results: Vec<Bounce>,
+    /// Pagination information.
+    pub pagination: Pagination,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+deserialize_with_extra!(ListBouncesResponse {
+    results, pagination;
+});
+
+impl ListBouncesResponse {
+    /// Creates a single page holding `results`, e.g. for a mock
+    /// [`EmailsApi`](crate::services::EmailsApi) in tests.
+    pub fn new(results: Vec<Bounce>) -> Self {
+        Self {
+            pagination: Pagination {
+                next_cursor: None,
+                per_page: u32::try_from(results.len()).unwrap_or(u32::MAX),
+                extra: serde_json::Map::new(),
+            },
+            results,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+/// A bounced email, as returned by [`EmailsSvc::list_bounces`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Bounce {
+    /// Unique event ID.
+    pub event_id: String,
+    /// Event type: `"bounce"`, or `"out_of_band"` for bounces reported
+    /// after the receiving server accepted the email.
+    pub event_type: String,
+    /// Timestamp of the bounce.
+    pub timestamp: String,
+    /// Transmission request ID.
+    pub request_id: String,
+    /// Message ID.
+    pub message_id: String,
+    /// Email subject.
+    pub subject: String,
+    /// Sending domain.
+    pub sending_domain: String,
+    /// Recipient email address.
+    pub rcpt_to: String,
+    /// Recipient domain.
+    pub recipient_domain: String,
+    /// Numeric bounce classification, e.g. `10` for an invalid recipient;
+    /// see [`category`](Self::category).
+    pub bounce_class: u16,
+    /// Bounce reason.
+    pub reason: Option<String>,
+    /// Raw reason string, as reported by the receiving server.
+    pub raw_reason: Option<String>,
+    /// SMTP error code.
+    pub error_code: Option<String>,
+    /// Recipient metadata, kept as raw JSON until read.
+    pub rcpt_meta: Option<RawJson>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+deserialize_with_extra!(Bounce {
+    event_id, event_type = "type", timestamp, request_id, rcpt_to, bounce_class;
+    message_id, subject, sending_domain, recipient_domain, reason, raw_reason, error_code,
+    rcpt_meta
+});
+
+impl Bounce {
+    /// Creates a bounce of class `bounce_class` for the email `request_id`
+    /// sent to `rcpt_to`, with the other fields empty. Set them as needed,
+    /// e.g. for test fixtures.
+    pub fn new(
+        request_id: impl Into<String>,
+        rcpt_to: impl Into<String>,
+        bounce_class: u16,
+    ) -> Self {
+        Self {
+            event_type: "bounce".to_owned(),
+            request_id: request_id.into(),
+            rcpt_to: rcpt_to.into(),
+            bounce_class,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the category of the [bounce class](Self::bounce_class).
+    #[must_use]
+    pub fn category(&self) -> BounceCategory {
+        BounceCategory::from_class(self.bounce_class)
+    }
+
+    /// Returns whether the address should not be sent to again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::emails::Bounce;
+    ///
+    /// assert!(Bounce::new("req_123", "gone@example.com", 10).is_hard());
+    /// assert!(!Bounce::new("req_123", "full@example.com", 22).is_hard());
+    /// ```
+    #[must_use]
+    pub fn is_hard(&self) -> bool {
+        self.category() == BounceCategory::Hard
+    }
+
+    /// Returns the RFC 3463 enhanced status code of the bounce, read from
+    /// the [error code](Self::error_code) or, failing that, the
+    /// [raw reason](Self::raw_reason).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::emails::Bounce;
+    ///
+    /// let mut bounce = Bounce::new("req_123", "full@example.com", 22);
+    /// bounce.raw_reason = Some("552 5.2.2 Mailbox full".to_owned());
+    /// assert!(bounce.enhanced_status().is_some_and(|code| code.is_mailbox_full()));
+    /// ```
+    #[must_use]
+    pub fn enhanced_status(&self) -> Option<EnhancedStatusCode> {
+        EnhancedStatusCode::find_in([&self.error_code, &self.raw_reason])
+    }
+}
+
+/// Broad category of a [`Bounce`], which decides how to react to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BounceCategory {
+    /// The address does not exist or refuses mail for good; stop sending
+    /// to it.
+    Hard,
+    /// A temporary problem, such as a full mailbox; later sends may succeed.
+    Soft,
+    /// The receiving server blocked the email, e.g. as spam; check the
+    /// content and sending reputation.
+    Block,
+    /// The email was rejected by a sending policy, e.g. a suppression.
+    Admin,
+    /// The receiving server's response could not be classified.
+    #[default]
+    Undetermined,
+}
+
+impl BounceCategory {
+    /// Returns the category of a numeric bounce class.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::emails::BounceCategory;
+    ///
+    /// assert_eq!(BounceCategory::from_class(10), BounceCategory::Hard);
+    /// assert_eq!(BounceCategory::from_class(51), BounceCategory::Block);
+    /// ```
+    #[must_use]
+    pub fn from_class(bounce_class: u16) -> Self {
+        match bounce_class {
+            10 | 30 | 90 => Self::Hard,
+            20..=24 | 40 | 60 | 70 | 100 => Self::Soft,
+            50..=54 => Self::Block,
+            25 | 26 | 80 => Self::Admin,
+            _ => Self::Undetermined,
+        }
+    }
+
+    /// Returns the name the API uses for this category.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Hard => "hard",
+            Self::Soft => "soft",
+            Self::Block => "block",
+            Self::Admin => "admin",
+            Self::Undetermined => "undetermined",
+        }
+    }
+}
+
+impl fmt::Display for BounceCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An RFC 3463 enhanced mail system status code, `class.subject.detail`,
+/// e.g. `5.1.1` for a mailbox that does not exist.
+///
+/// Unlike the reason text, which differs between mailbox providers, the
+/// code is standardized, so bounce handling can rely on it.
+///
+/// # Example
+///
+/// ```
+/// use lettr::emails::EnhancedStatusCode;
+///
+/// let code = EnhancedStatusCode::parse("550 5.1.1 <gone@example.com>: user unknown").unwrap();
+/// assert_eq!(code.to_string(), "5.1.1");
+/// assert!(code.is_permanent());
+/// assert!(code.is_bad_address());
+///
+/// assert!(EnhancedStatusCode::parse("550 rejected").is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnhancedStatusCode {
+    /// `2` for success, `4` for a transient failure, or `5` for a permanent
+    /// failure.
+    pub class: u8,
+    /// What the status is about, e.g. `1` for addressing, `2` for the
+    /// mailbox, or `7` for security and policy.
+    pub subject: u16,
+    /// The specific status within the subject.
+    pub detail: u16,
+}
+
+impl EnhancedStatusCode {
+    /// Returns the first enhanced status code in `text`, e.g. in a raw SMTP
+    /// response such as `550 5.1.1 User unknown`.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        text.split(|c: char| !c.is_ascii_digit() && c != '.')
+            .find_map(|token| Self::parse_code(token.trim_matches('.')))
+    }
+
+    /// Returns the first enhanced status code found in any of `texts`.
+    fn find_in<'a>(texts: impl IntoIterator<Item = &'a Option<String>>) -> Option<Self> {
+        texts
+            .into_iter()
+            .flatten()
+            .find_map(|text| Self::parse(text))
+    }
+
+    /// Parses a token that is exactly `class.subject.detail`.
+    fn parse_code(token: &str) -> Option<Self> {
+        let mut parts = token.split('.');
+        let (class, subject, detail) = (parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() || !matches!(class, "2" | "4" | "5") {
+            return None;
+        }
+        let number = |part: &str| {
+            if (1..=3).contains(&part.len()) {
+                part.parse().ok()
+            } else {
+                None
+            }
+        };
+        Some(Self {
+            class: class.parse().ok()?,
+            subject: number(subject)?,
+            detail: number(detail)?,
+        })
+    }
+
+    /// Returns whether the status reports a temporary failure (class `4`);
+    /// a later attempt may succeed.
+    #[must_use]
+    pub fn is_transient(self) -> bool {
+        self.class == 4
+    }
+
+    /// Returns whether the status reports a permanent failure (class `5`).
+    #[must_use]
+    pub fn is_permanent(self) -> bool {
+        self.class == 5
+    }
+
+    /// Returns whether the address is bad: the mailbox or domain does not
+    /// exist, the address is malformed, or the mailbox has moved (`X.1.1`,
+    /// `X.1.2`, `X.1.3`, `X.1.6`, or `X.1.10`).
+    #[must_use]
+    pub fn is_bad_address(self) -> bool {
+        self.subject == 1 && matches!(self.detail, 1 | 2 | 3 | 6 | 10)
+    }
+
+    /// Returns whether the mailbox is full (`X.2.2`).
+    #[must_use]
+    pub fn is_mailbox_full(self) -> bool {
+        self.subject == 2 && self.detail == 2
+    }
+
+    /// Returns whether the receiving server refused the email for security
+    /// or policy reasons, e.g. as spam or for failing authentication
+    /// (`X.7.*`).
+    #[must_use]
+    pub fn is_policy_block(self) -> bool {
+        self.subject == 7
+    }
+}
+
+/// Formats as `class.subject.detail`, e.g. `5.1.1`.
+impl fmt::Display for EnhancedStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.class, self.subject, self.detail)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RecipientErasureResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: RecipientErasure,
+}
+
+/// A request to erase the stored data of a recipient, and its progress.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct RecipientErasure {
+    /// Erasure ID.
+    pub id: String,
+    /// Status: `"pending"`, `"processing"`, `"completed"`, or `"failed"`.
+    pub status: String,
+    /// The erased address.
+    #[serde(default)]
+    pub email: String,
+    /// Creation timestamp.
     #[serde(default)]
-    pub rcpt_meta: Option<serde_json::Value>,
+    pub created_at: String,
+    /// When the erasure completed or failed.
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl RecipientErasure {
+    /// Creates an erasure with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`EmailsApi`](crate::services::EmailsApi).
+    pub fn new(id: impl Into<String>, status: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            status: status.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the recipient's data has been erased.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::types::RecipientErasure;
+    ///
+    /// assert!(!RecipientErasure::new("era_123", "pending").is_completed());
+    /// assert!(RecipientErasure::new("era_123", "completed").is_completed());
+    /// ```
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.status == "completed"
+    }
 }
@@ -1,5 +1,10 @@
 use std::collections::HashMap;
 use std::fmt;
+use std::time::Duration;
+
+use crate::meta;
+use crate::rate_limit::RateLimitInfo;
+use crate::transport::{Response, StatusCode};
 
 /// Error type for operations of a [`Lettr`](crate::Lettr) client.
 #[derive(Debug, thiserror::Error)]
@@ -8,17 +13,329 @@ pub enum Error {
     #[error("http error: {0}")]
     Http(#[from] reqwest::Error),
 
+    /// A custom [`HttpTransport`](crate::transport::HttpTransport) failed to
+    /// send a request.
+    #[error("transport error: {0}")]
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+
     /// API returned an error response.
     #[error("api error: {0}")]
-    Api(#[from] ApiError),
+    Api(#[source] Box<ApiError>),
 
     /// Validation error returned by the API.
     #[error("validation error: {0}")]
-    Validation(#[from] ValidationError),
+    Validation(#[source] Box<ValidationError>),
 
     /// Failed to parse the API response.
     #[error("failed to parse API response: {0}")]
-    Parse(String),
+    Parse(ParseError),
+
+    /// Failed to serialize the request body, e.g. because the `Serialize`
+    /// impl of a value passed to the SDK returned an error. The request was
+    /// not sent.
+    #[error("failed to serialize request body: {0}")]
+    Serialize(#[source] serde_json::Error),
+
+    /// Request options failed the checks of a `try_build` method, such as
+    /// [`CreateEmailOptions::try_build`](crate::CreateEmailOptions::try_build).
+    #[error("{0}")]
+    InvalidOptions(#[from] InvalidOptions),
+
+    /// The client could not be configured, e.g. because the API key is
+    /// missing or invalid.
+    #[error("configuration error: {0}")]
+    Config(String),
+
+    /// A [`QueueStore`](crate::queue::QueueStore) failed to read or persist
+    /// a queued email.
+    #[error("queue store error: {0}")]
+    Queue(Box<dyn std::error::Error + Send + Sync>),
+
+    /// The API rejected the request with `429 Too Many Requests`.
+    #[error("rate limited{}", .retry_after.map(|d| format!(", retry in {d:?}")).unwrap_or_default())]
+    RateLimited {
+        /// How long the API asked the client to wait, from `Retry-After` or,
+        /// failing that, `X-RateLimit-Reset`.
+        retry_after: Option<Duration>,
+        /// Maximum number of requests allowed in the current window.
+        limit: Option<u32>,
+        /// Requests remaining in the current window.
+        remaining: Option<u32>,
+    },
+
+    /// The request did not complete within the given time.
+    #[error("request timed out after {0:?}")]
+    Timeout(Duration),
+
+    /// The API answered with a different version than the client pinned with
+    /// [`LettrBuilder::api_version`](crate::LettrBuilder::api_version), so
+    /// the response was not deserialized. Only returned with
+    /// [`LettrBuilder::reject_version_mismatch`](crate::LettrBuilder::reject_version_mismatch)
+    /// enabled.
+    #[error("API version mismatch: requested {requested}, received {received}")]
+    VersionMismatch {
+        /// The pinned version.
+        requested: String,
+        /// The version the API reported.
+        received: String,
+    },
+
+    /// The client was shut down with
+    /// [`Lettr::shutdown`](crate::Lettr::shutdown), so the request was not
+    /// sent.
+    #[error("client is shut down")]
+    Shutdown,
+
+    /// The [circuit breaker](crate::circuit_breaker) is open after repeated
+    /// failures, so the request was not sent.
+    #[error("circuit breaker open, retry in {retry_in:?}")]
+    CircuitOpen {
+        /// Time until the circuit lets probe requests through again.
+        retry_in: Duration,
+    },
+
+    /// A non-transactional email was sent outside the client's
+    /// [send window](crate::send_window), which rejects such sends.
+    #[error("outside the send window, which opens in {opens_in:?}")]
+    OutsideSendWindow {
+        /// Time until the window opens.
+        opens_in: Duration,
+    },
+
+    /// A downloaded file could not be written, e.g. by
+    /// [`ExportsSvc::download`](crate::exports::ExportsSvc::download).
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A body template failed to render locally, e.g. in
+    /// `CreateEmailOptions::with_html_template`, or MJML failed to compile.
+    #[error("template rendering failed: {0}")]
+    Render(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Error {
+    /// Returns the rate-limit information the API sent with an error response.
+    ///
+    /// This is `None` for errors that did not come from an API response, or
+    /// when the response carried no rate-limit headers. Rejections for
+    /// exceeding the rate limit are reported as [`Error::RateLimited`].
+    #[must_use]
+    pub fn rate_limit(&self) -> Option<&RateLimitInfo> {
+        match self {
+            Self::Api(e) if !e.rate_limit.is_empty() => Some(&e.rate_limit),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP status code of the response that caused the error.
+    ///
+    /// This is `None` for errors that did not come from an API response,
+    /// such as connection failures or configuration errors.
+    #[must_use]
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Http(e) => e.status(),
+            Self::Api(e) => Some(e.status),
+            Self::Validation(e) => Some(e.status),
+            Self::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Self::Parse(e) => e.status,
+            _ => None,
+        }
+    }
+
+    /// Returns the start of a response body that could not be parsed: a
+    /// successful response that did not match the expected schema, or an
+    /// error response that was not JSON.
+    ///
+    /// The body is truncated to
+    /// [`LettrBuilder::raw_body_limit`](crate::LettrBuilder::raw_body_limit)
+    /// bytes.
+    #[must_use]
+    pub fn raw_body(&self) -> Option<&str> {
+        match self {
+            Self::Api(e) => e.raw_body.as_deref(),
+            Self::Parse(e) => e.raw_body.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the server-side request ID (`X-Request-Id`) of the response
+    /// that caused the error.
+    ///
+    /// Include it in support tickets so Lettr can find the failing request.
+    #[must_use]
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Api(e) => e.request_id.as_deref(),
+            Self::Validation(e) => e.request_id.as_deref(),
+            Self::Parse(e) => e.request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the call may succeed when made again later.
+    ///
+    /// This holds for rate limiting (`429`), server errors (`5xx`),
+    /// connection failures, timeouts, an open circuit breaker, and a closed
+    /// send window. The client
+    /// already retries the cases where the API did not process the request
+    /// (see [`LettrBuilder::max_retries`](crate::LettrBuilder::max_retries));
+    /// after other `5xx` responses an email may have been sent, so resending
+    /// it can deliver it twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::{Lettr, CreateEmailOptions};
+    /// # async fn run(client: Lettr, email: CreateEmailOptions) -> lettr::Result<()> {
+    /// match client.emails.send(email).await {
+    ///     Ok(response) => println!("Sent: {}", response.request_id),
+    ///     Err(e) if e.is_retryable() => eprintln!("try again later: {e}"),
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Http(e) => {
+                e.is_timeout()
+                    || crate::transport::is_connect(self)
+                    || e.status().is_some_and(is_retryable_status)
+            }
+            Self::Api(e) => is_retryable_status(e.status),
+            Self::RateLimited { .. }
+            | Self::Timeout(_)
+            | Self::CircuitOpen { .. }
+            | Self::OutsideSendWindow { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<ApiError> for Error {
+    fn from(err: ApiError) -> Self {
+        Self::Api(Box::new(err))
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(err: ValidationError) -> Self {
+        Self::Validation(Box::new(err))
+    }
+}
+
+/// Returns `true` for rate limiting and server errors.
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Problems found in request options before they were sent.
+///
+/// Returned by the `try_build` methods, e.g.
+/// [`CreateEmailOptions::try_build`](crate::CreateEmailOptions::try_build).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidOptions {
+    /// Every problem found, in field order.
+    pub problems: Vec<Problem>,
+}
+
+impl fmt::Display for InvalidOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid options: ")?;
+        for (i, problem) in self.problems.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InvalidOptions {}
+
+/// A single problem in request options; see [`InvalidOptions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Problem {
+    /// The sender address is empty.
+    NoSender,
+    /// The email has no recipients.
+    NoRecipients,
+    /// The subject is empty.
+    NoSubject,
+    /// The subject is longer than the API accepts.
+    SubjectTooLong {
+        /// Length of the subject in characters.
+        len: usize,
+        /// Maximum length in characters.
+        max: usize,
+    },
+    /// The email has no HTML body, no text body, and no template.
+    NoContent,
+    /// The email sets both a template and an HTML or text body.
+    TemplateWithContent,
+    /// The template name is empty.
+    NoName,
+    /// The template sets both HTML and editor JSON content.
+    ConflictingContent,
+    /// An attachment read with a size limit, e.g. by
+    /// [`Attachment::from_reader`](crate::Attachment::from_reader), is
+    /// larger than the limit.
+    AttachmentTooLarge {
+        /// Name of the attachment.
+        name: String,
+        /// The limit in bytes.
+        max: u64,
+    },
+    /// A [`DateRange`](crate::filter::DateRange) starts after it ends.
+    InvalidDateRange,
+    /// The content of an attachment is of a different type than declared,
+    /// as detected with the `infer` feature.
+    AttachmentTypeMismatch {
+        /// Name of the attachment.
+        name: String,
+        /// The declared MIME type.
+        declared: String,
+        /// The MIME type detected from the content.
+        detected: String,
+    },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSender => f.write_str("the sender address is empty"),
+            Self::NoRecipients => f.write_str("there are no recipients"),
+            Self::NoSubject => f.write_str("the subject is empty"),
+            Self::SubjectTooLong { len, max } => {
+                write!(
+                    f,
+                    "the subject is {len} characters long, at most {max} are allowed"
+                )
+            }
+            Self::NoContent => f.write_str("there is no HTML body, text body, or template"),
+            Self::TemplateWithContent => {
+                f.write_str("a template cannot be combined with an HTML or text body")
+            }
+            Self::NoName => f.write_str("the name is empty"),
+            Self::ConflictingContent => f.write_str("HTML and JSON content cannot both be set"),
+            Self::AttachmentTooLarge { name, max } => {
+                write!(f, "the attachment {name} is larger than {max} bytes")
+            }
+            Self::InvalidDateRange => f.write_str("the date range starts after it ends"),
+            Self::AttachmentTypeMismatch {
+                name,
+                declared,
+                detected,
+            } => write!(
+                f,
+                "the attachment {name} is declared as {declared}, but its content is {detected}"
+            ),
+        }
+    }
 }
 
 /// An error response from the Lettr API.
@@ -29,20 +346,103 @@ pub struct ApiError {
     /// Machine-readable error code.
     #[serde(default)]
     pub error_code: Option<String>,
+    /// HTTP status code of the error response.
+    #[serde(skip)]
+    pub status: StatusCode,
+    /// Server-side request ID from the `X-Request-Id` header.
+    #[serde(skip)]
+    pub request_id: Option<String>,
+    /// Rate-limit headers sent with the error response.
+    #[serde(skip)]
+    pub rate_limit: RateLimitInfo,
+    /// Start of the response body, if it was not a JSON error.
+    #[serde(skip)]
+    pub raw_body: Option<String>,
 }
 
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(ref code) = self.error_code {
-            write!(f, "[{}] {}", code, self.message)
+            write!(f, "[{}] {}", code, self.message)?;
         } else {
-            write!(f, "{}", self.message)
+            write!(f, "{}", self.message)?;
         }
+        write_request_id(f, self.request_id.as_deref())
     }
 }
 
 impl std::error::Error for ApiError {}
 
+/// A response body that could not be parsed, or a request body that could
+/// not be serialized.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    /// Description of what failed to parse.
+    pub message: String,
+    /// Path of the offending value in the response JSON, such as
+    /// `data.events[3].timestamp`, or `None` if the body is not valid JSON.
+    pub path: Option<String>,
+    /// HTTP status code of the response, if one was received.
+    pub status: Option<StatusCode>,
+    /// Server-side request ID from the `X-Request-Id` header.
+    pub request_id: Option<String>,
+    /// Start of the response body that failed to parse.
+    pub raw_body: Option<String>,
+}
+
+impl ParseError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            path: None,
+            status: None,
+            request_id: None,
+            raw_body: None,
+        }
+    }
+
+    /// Creates an error pointing at the value that failed to deserialize.
+    pub(crate) fn from_path_error(err: &serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let mut parse_error = Self::new(err.inner().to_string());
+        let path = err.path().to_string();
+        // `.` is the root, i.e. the body is not valid JSON or has the wrong shape.
+        if path != "." {
+            parse_error.path = Some(path);
+        }
+        parse_error
+    }
+
+    /// Attaches the status and request ID of the response that failed to
+    /// parse.
+    pub(crate) fn with_response(mut self, response: &Response) -> Self {
+        self.status = Some(response.status);
+        self.request_id = meta::request_id(&response.headers);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(status) = self.status {
+            write!(f, "HTTP {status}: ")?;
+        }
+        if let Some(ref path) = self.path {
+            write!(f, "{path}: ")?;
+        }
+        write!(f, "{}", self.message)?;
+        write_request_id(f, self.request_id.as_deref())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn write_request_id(f: &mut fmt::Formatter<'_>, request_id: Option<&str>) -> fmt::Result {
+    match request_id {
+        Some(id) => write!(f, " (request {id})"),
+        None => Ok(()),
+    }
+}
+
 /// A validation error response from the Lettr API.
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct ValidationError {
@@ -54,6 +454,86 @@ pub struct ValidationError {
     /// Field-level validation errors.
     #[serde(default)]
     pub errors: HashMap<String, Vec<String>>,
+    /// HTTP status code of the error response.
+    #[serde(skip)]
+    pub status: StatusCode,
+    /// Server-side request ID from the `X-Request-Id` header.
+    #[serde(skip)]
+    pub request_id: Option<String>,
+}
+
+impl ValidationError {
+    /// Returns the messages for `field`, or an empty slice if the field has
+    /// no errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use lettr::transport::StatusCode;
+    /// use lettr::types::ValidationError;
+    ///
+    /// let err = ValidationError {
+    ///     message: "The given data was invalid.".to_owned(),
+    ///     error_code: None,
+    ///     errors: HashMap::from([
+    ///         ("to".to_owned(), vec!["The to field is required.".to_owned()]),
+    ///         ("subject".to_owned(), vec!["The subject is too long.".to_owned()]),
+    ///     ]),
+    ///     status: StatusCode::UNPROCESSABLE_ENTITY,
+    ///     request_id: None,
+    /// };
+    ///
+    /// assert_eq!(err.field("to"), ["The to field is required."]);
+    /// assert!(err.field("from").is_empty());
+    /// assert_eq!(err.fields(), ["subject", "to"]);
+    /// assert_eq!(err.first_message(), "The subject is too long.");
+    /// assert_eq!(
+    ///     err.into_field_messages(),
+    ///     [
+    ///         ("subject".to_owned(), "The subject is too long.".to_owned()),
+    ///         ("to".to_owned(), "The to field is required.".to_owned()),
+    ///     ],
+    /// );
+    /// ```
+    #[must_use]
+    pub fn field(&self, field: &str) -> &[String] {
+        self.errors.get(field).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the names of all fields with errors, sorted.
+    #[must_use]
+    pub fn fields(&self) -> Vec<&str> {
+        let mut fields: Vec<&str> = self.errors.keys().map(String::as_str).collect();
+        fields.sort_unstable();
+        fields
+    }
+
+    /// Returns the first field-level message, in field order, or the overall
+    /// [`message`](Self::message) if no field has errors.
+    #[must_use]
+    pub fn first_message(&self) -> &str {
+        self.fields()
+            .into_iter()
+            .find_map(|field| self.field(field).first())
+            .map_or(&self.message, String::as_str)
+    }
+
+    /// Flattens the field errors into `(field, message)` pairs, sorted by
+    /// field, e.g. to attach them to form inputs.
+    #[must_use]
+    pub fn into_field_messages(self) -> Vec<(String, String)> {
+        let mut errors: Vec<_> = self.errors.into_iter().collect();
+        errors.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        errors
+            .into_iter()
+            .flat_map(|(field, messages)| {
+                messages
+                    .into_iter()
+                    .map(move |message| (field.clone(), message))
+            })
+            .collect()
+    }
 }
 
 impl fmt::Display for ValidationError {
@@ -78,22 +558,48 @@ pub(crate) struct RawErrorResponse {
     pub error_code: Option<String>,
     #[serde(default)]
     pub errors: Option<HashMap<String, Vec<String>>>,
+    #[serde(skip)]
+    pub raw_body: Option<String>,
 }
 
 impl RawErrorResponse {
     /// Convert into the appropriate [`Error`] variant.
-    pub fn into_error(self) -> Error {
+    pub fn into_error(self, response: &Response, rate_limit: RateLimitInfo) -> Error {
+        let status = response.status;
+        let request_id = meta::request_id(&response.headers);
         if let Some(errors) = self.errors {
-            Error::Validation(ValidationError {
+            ValidationError {
                 message: self.message,
                 error_code: self.error_code,
                 errors,
-            })
+                status,
+                request_id,
+            }
+            .into()
         } else {
-            Error::Api(ApiError {
+            ApiError {
                 message: self.message,
                 error_code: self.error_code,
-            })
+                status,
+                request_id,
+                rate_limit,
+                raw_body: self.raw_body,
+            }
+            .into()
+        }
+    }
+
+    /// Stands in for an error response whose body is not JSON, such as an
+    /// HTML page from a proxy, using the status reason as the message.
+    pub fn unparseable(status: StatusCode, raw_body: Option<String>) -> Self {
+        Self {
+            message: status
+                .canonical_reason()
+                .unwrap_or("unknown error")
+                .to_owned(),
+            error_code: None,
+            errors: None,
+            raw_body,
         }
     }
 }
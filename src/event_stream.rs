@@ -0,0 +1,466 @@
+//! Live feed of email events, for deployments that cannot receive webhooks.
+//!
+//! [`EmailsSvc::stream_events`](crate::emails::EmailsSvc::stream_events)
+//! long-polls the `/events/stream` endpoint and yields each
+//! [`EmailEventDetail`] as it happens. Transient failures such as dropped
+//! connections or server errors are retried with backoff, resuming from the
+//! last cursor, so no event is skipped.
+//!
+//! [`EventStream`] implements [`futures_core::Stream`]; with the `blocking`
+//! feature, the stream of `lettr::blocking::Client` implements [`Iterator`].
+//!
+//! For consumers that run periodically instead, such as a cron job,
+//! [`EmailsSvc::events_since`](crate::emails::EmailsSvc::events_since)
+//! fetches the events after a saved cursor in one call, as an
+//! [`EventBatch`].
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::de::deserialize_with_extra;
+use crate::emails::EmailEventDetail;
+use crate::meta::WithMeta;
+
+/// How long the API holds a poll open when no event arrives, unless set
+/// with [`StreamEventsOptions::wait`].
+const DEFAULT_WAIT: Duration = Duration::from_secs(30);
+
+/// Upper bound for the delay before reconnecting after a failed poll.
+const MAX_RECONNECT_DELAY_SECS: u64 = 30;
+
+/// Options for [`EmailsSvc::stream_events`](crate::emails::EmailsSvc::stream_events)
+/// and [`EmailsSvc::events_since`](crate::emails::EmailsSvc::events_since).
+#[must_use]
+#[derive(Debug, Default, Clone)]
+pub struct StreamEventsOptions {
+    pub(crate) cursor: Option<String>,
+    pub(crate) recipients: Option<String>,
+    pub(crate) wait: Option<Duration>,
+}
+
+impl StreamEventsOptions {
+    /// Creates new [`StreamEventsOptions`] starting with events that happen
+    /// from now on.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resumes after the events already seen, using a cursor saved from
+    /// [`EventStream::cursor`].
+    #[inline]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Only streams events for this recipient email address.
+    #[inline]
+    pub fn recipients(mut self, recipients: impl Into<String>) -> Self {
+        self.recipients = Some(recipients.into());
+        self
+    }
+
+    /// Sets how long the API holds each poll open when no event arrives
+    /// (default 30 seconds for a stream and none for
+    /// [`events_since`](crate::emails::EmailsSvc::events_since), at most
+    /// 60).
+    ///
+    /// A client [`timeout`](crate::LettrBuilder::timeout) must be longer
+    /// than this, or every idle poll fails.
+    #[inline]
+    pub fn wait(mut self, wait: Duration) -> Self {
+        self.wait = Some(wait);
+        self
+    }
+}
+
+/// A live feed of email events, returned by
+/// [`EmailsSvc::stream_events`](crate::emails::EmailsSvc::stream_events).
+///
+/// Never ends on its own: it waits for new events while idle, and reconnects
+/// after transient failures. It ends after yielding an error that retrying
+/// cannot fix, such as an invalid API key, or once the client is
+/// [shut down](crate::Lettr::shutdown).
+pub struct EventStream {
+    feed: Option<Feed>,
+    /// Resume cursor as of the last yielded event, kept outside the feed
+    /// since a poll in progress owns it.
+    cursor: Option<String>,
+    pending: Option<PendingPoll>,
+}
+
+/// A poll of [`Feed::next`] in progress, owning the feed until it finishes.
+#[cfg(not(target_arch = "wasm32"))]
+type PendingPoll = std::pin::Pin<
+    Box<dyn std::future::Future<Output = (Feed, Option<crate::Result<EmailEventDetail>>)> + Send>,
+>;
+#[cfg(target_arch = "wasm32")]
+type PendingPoll = std::pin::Pin<
+    Box<dyn std::future::Future<Output = (Feed, Option<crate::Result<EmailEventDetail>>)>>,
+>;
+
+impl std::fmt::Debug for EventStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventStream")
+            .field("cursor", &self.cursor)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventStream {
+    pub(crate) fn new(config: Arc<Config>, options: StreamEventsOptions) -> Self {
+        Self {
+            cursor: options.cursor.clone(),
+            feed: Some(Feed {
+                config,
+                page_cursor: options.cursor.clone(),
+                options,
+                buffer: VecDeque::new(),
+                failures: 0,
+                finished: false,
+            }),
+            pending: None,
+        }
+    }
+
+    /// Returns a cursor to resume the feed from with
+    /// [`StreamEventsOptions::cursor`], e.g. after a restart.
+    ///
+    /// Events are delivered at least once: resuming may repeat some events
+    /// yielded since the cursor last changed, so deduplicate by
+    /// [`event_id`](EmailEventDetail::event_id) if that matters. Returns
+    /// `None` before the first poll finished, unless the stream was started
+    /// from a cursor.
+    #[must_use]
+    pub fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    /// Takes note of the resume cursor after the feed yielded an event.
+    fn update_cursor(&mut self) {
+        if let Some(cursor) = self.feed.as_ref().and_then(Feed::resume_cursor) {
+            if self.cursor.as_deref() != Some(cursor) {
+                self.cursor = Some(cursor.to_owned());
+            }
+        }
+    }
+
+    /// Waits for the next event.
+    ///
+    /// Returns `None` once the stream has ended.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::event_stream::StreamEventsOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let mut events = client.emails.stream_events(StreamEventsOptions::new());
+    /// while let Some(event) = events.next().await {
+    ///     let event = event?;
+    ///     println!("{}: {} for {}", event.timestamp, event.event_type, event.rcpt_to);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub async fn next(&mut self) -> Option<crate::Result<EmailEventDetail>> {
+        std::future::poll_fn(|cx| {
+            futures_core::Stream::poll_next(std::pin::Pin::new(&mut *self), cx)
+        })
+        .await
+    }
+}
+
+impl futures_core::Stream for EventStream {
+    type Item = crate::Result<EmailEventDetail>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let this = &mut *self;
+        if this.pending.is_none() {
+            let Some(mut feed) = this.feed.take() else {
+                return Poll::Ready(None);
+            };
+            // Serve buffered events without allocating a future.
+            if let Some(event) = feed.buffer.pop_front() {
+                this.feed = Some(feed);
+                this.update_cursor();
+                return Poll::Ready(Some(Ok(event)));
+            }
+            this.pending = Some(Box::pin(async move {
+                let item = feed.next().await;
+                (feed, item)
+            }));
+        }
+
+        let Some(pending) = this.pending.as_mut() else {
+            return Poll::Ready(None);
+        };
+        match pending.as_mut().poll(cx) {
+            Poll::Ready((feed, item)) => {
+                this.pending = None;
+                this.feed = Some(feed);
+                this.update_cursor();
+                Poll::Ready(item)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// State of an [`EventStream`] between polls.
+struct Feed {
+    config: Arc<Config>,
+    /// Options of the next poll; the cursor points past the buffered events.
+    options: StreamEventsOptions,
+    /// Cursor the buffered events were fetched with.
+    page_cursor: Option<String>,
+    buffer: VecDeque<EmailEventDetail>,
+    /// Number of polls in a row that failed with a retryable error.
+    failures: u32,
+    finished: bool,
+}
+
+impl Feed {
+    /// Returns the cursor that resumes without skipping buffered events.
+    fn resume_cursor(&self) -> Option<&str> {
+        if self.buffer.is_empty() {
+            self.options.cursor.as_deref()
+        } else {
+            self.page_cursor.as_deref()
+        }
+    }
+
+    /// Returns the next event, polling and reconnecting as needed.
+    async fn next(&mut self) -> Option<crate::Result<EmailEventDetail>> {
+        loop {
+            if let Some(event) = self.buffer.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.finished || self.config.is_shut_down() {
+                return None;
+            }
+
+            match self.poll().await {
+                Ok(page) => {
+                    self.failures = 0;
+                    self.page_cursor = self.options.cursor.replace(page.next_cursor);
+                    self.buffer.extend(page.events);
+                }
+                Err(err) if err.is_retryable() => {
+                    self.failures += 1;
+                    let mut delay = Duration::from_secs(reconnect_delay_secs(self.failures));
+                    if let crate::Error::RateLimited {
+                        retry_after: Some(retry_after),
+                        ..
+                    } = err
+                    {
+                        delay = delay.max(retry_after);
+                    }
+                    log::warn!("event stream poll failed, reconnecting in {delay:?}: {err}");
+                    self.config.clock().sleep(delay).await;
+                }
+                Err(err) => {
+                    self.finished = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+
+    /// Long-polls the API for the events after the current cursor.
+    async fn poll(&self) -> crate::Result<EventBatch> {
+        let wait = self.options.wait.unwrap_or(DEFAULT_WAIT);
+        fetch(&self.config, &self.options, wait)
+            .await
+            .map(WithMeta::into_data)
+    }
+}
+
+/// Fetches the events after the cursor of `options`, holding the request
+/// open for up to `wait` while there are none.
+pub(crate) async fn fetch(
+    config: &Config,
+    options: &StreamEventsOptions,
+    wait: Duration,
+) -> crate::Result<WithMeta<EventBatch>> {
+    // Never cached: an idle poll would otherwise be answered with the same
+    // empty batch until the entry expires, without waiting on the API.
+    let mut request = config
+        .build(Method::GET, "/events/stream")
+        .no_cache()
+        .query(&[("wait", wait.as_secs().to_string())]);
+    if let Some(ref cursor) = options.cursor {
+        request = request.query(&[("cursor", cursor.as_str())]);
+    }
+    if let Some(ref recipients) = options.recipients {
+        request = request.query(&[("recipients", recipients.as_str())]);
+    }
+
+    let response: WithMeta<EventBatchResponseWrapper> = config.send_json_with_meta(request).await?;
+    Ok(response.map(|wrapper| wrapper.data))
+}
+
+/// Delay in seconds before reconnecting after `failures` failed polls.
+fn reconnect_delay_secs(failures: u32) -> u64 {
+    2u64.saturating_pow(failures.saturating_sub(1))
+        .min(MAX_RECONNECT_DELAY_SECS)
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct EventBatchResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: EventBatch,
+}
+
+/// The events after a cursor, returned by
+/// [`EmailsSvc::events_since`](crate::emails::EmailsSvc::events_since).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EventBatch {
+    /// The events, in the order they happened.
+    pub events: Vec<EmailEventDetail>,
+    /// Cursor to fetch the events after this batch with; save it to resume
+    /// from.
+    pub next_cursor: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+deserialize_with_extra!(EventBatch {
+    events, next_cursor;
+});
+
+impl EventBatch {
+    /// Creates a batch holding `events`, e.g. for a mock
+    /// [`EmailsApi`](crate::services::EmailsApi) in tests.
+    pub fn new(events: Vec<EmailEventDetail>, next_cursor: impl Into<String>) -> Self {
+        Self {
+            events,
+            next_cursor: next_cursor.into(),
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::StreamEventsOptions;
+    use crate::cache::CacheConfig;
+    use crate::transport::{async_trait, HttpTransport, Request, Response, StatusCode};
+    use crate::Lettr;
+
+    /// Answers polls with an empty batch and an unchanged cursor, except
+    /// for poll number `event_on`, which gets one event.
+    struct IdleFeed {
+        polls: Arc<AtomicUsize>,
+        event_on: usize,
+    }
+
+    #[async_trait]
+    impl HttpTransport for IdleFeed {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            let poll = self.polls.fetch_add(1, Ordering::SeqCst) + 1;
+            let events = if poll == self.event_on {
+                json!([{
+                    "event_id": "event-1",
+                    "type": "delivery",
+                    "timestamp": "2024-01-15T10:30:00+00:00",
+                    "request_id": "request-1",
+                    "message_id": "message-1",
+                    "subject": "Hello!",
+                    "friendly_from": "sender@example.com",
+                    "sending_domain": "example.com",
+                    "rcpt_to": "user@example.com",
+                    "raw_rcpt_to": "user@example.com",
+                    "recipient_domain": "example.com",
+                    "click_tracking": true,
+                    "open_tracking": true,
+                    "transactional": true,
+                    "msg_size": 1024,
+                    "injection_time": "2024-01-15T10:30:00+00:00",
+                }])
+            } else {
+                json!([])
+            };
+            let body = json!({
+                "message": "Events retrieved successfully.",
+                "data": { "events": events, "next_cursor": "cursor-1" },
+            });
+            Ok(Response::new(
+                StatusCode::OK,
+                Default::default(),
+                body.to_string(),
+            ))
+        }
+    }
+
+    /// Returns a client with caching enabled, polling an [`IdleFeed`].
+    fn cached_client(event_on: usize) -> (Lettr, Arc<AtomicUsize>) {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let client = Lettr::builder("test-key")
+            .transport(IdleFeed {
+                polls: polls.clone(),
+                event_on,
+            })
+            .cache(CacheConfig::new(Duration::from_secs(60)))
+            .build();
+        (client, polls)
+    }
+
+    #[tokio::test]
+    async fn idle_stream_polls_the_api_with_a_cache() {
+        // The second and third polls send the same cursor, so a cached
+        // third poll would never see the event.
+        let (client, polls) = cached_client(3);
+        let mut stream = client
+            .emails
+            .stream_events(StreamEventsOptions::new().wait(Duration::from_secs(1)));
+
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.event_id, "event-1");
+        assert_eq!(polls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn events_since_fetches_new_events_on_the_same_cursor() {
+        let (client, polls) = cached_client(2);
+
+        let first = client
+            .emails
+            .events_since(Some("cursor-1"), StreamEventsOptions::new())
+            .await
+            .unwrap();
+        assert!(first.events.is_empty());
+
+        let second = client
+            .emails
+            .events_since(Some(&first.next_cursor), StreamEventsOptions::new())
+            .await
+            .unwrap();
+        assert_eq!(second.events.len(), 1);
+        assert_eq!(polls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,437 @@
+use std::fmt;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::emails::EventType;
+use crate::filter::{DateRange, Timestamp};
+use crate::meta::WithMeta;
+
+/// Service for the `/exports` endpoints.
+///
+/// Exports collect the email events of a date range into a single file in
+/// the background, for ranges too large to page through
+/// [`EmailsSvc::list`](crate::EmailsSvc::list). Request one with
+/// [`create`](Self::create), wait for it with [`wait`](Self::wait), then
+/// [`download`](Self::download) the file.
+#[derive(Clone, Debug)]
+pub struct ExportsSvc(pub(crate) Arc<Config>);
+
+impl ExportsSvc {
+    /// Request an export of events. The export starts out
+    /// [pending](ExportJob::is_finished) and runs in the background.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::exports::{CreateExportOptions, ExportFormat};
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = CreateExportOptions::new("2024-01-01", "2024-01-31")
+    ///     .format(ExportFormat::Jsonl)
+    ///     .event_type("bounce");
+    /// let job = client.exports.create(options).await?;
+    /// println!("Export {} is {}", job.id, job.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(&self, options: CreateExportOptions) -> crate::Result<ExportJob> {
+        self.create_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`create`](Self::create), but also returns the HTTP status,
+    /// headers, and rate-limit information of the response.
+    pub async fn create_with_meta(
+        &self,
+        options: CreateExportOptions,
+    ) -> crate::Result<WithMeta<ExportJob>> {
+        let request = self.0.build(Method::POST, "/exports").json(&options);
+        let response: WithMeta<ExportJobResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve the current status of an export.
+    ///
+    /// Never served from the response cache, since the status changes while
+    /// the export runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let job = client.exports.get("export-id").await?;
+    /// if job.is_completed() {
+    ///     println!("{} events ready", job.event_count.unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, export_id: &str) -> crate::Result<ExportJob> {
+        self.get_with_meta(export_id).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, export_id: &str) -> crate::Result<WithMeta<ExportJob>> {
+        let path = format!("/exports/{export_id}");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/exports/{id}")
+            .no_cache();
+        let response: WithMeta<ExportJobResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Poll an export every `poll_interval` until it has completed or
+    /// failed, and return it.
+    ///
+    /// Waits using the client's [`Clock`](crate::clock::Clock), so tests with
+    /// a [`MockClock`](crate::clock::MockClock) do not actually sleep. Wrap
+    /// the call in a timeout to give up on exports that take too long.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let job = client
+    ///     .exports
+    ///     .wait("export-id", Duration::from_secs(10))
+    ///     .await?;
+    /// if let Some(error) = &job.error {
+    ///     eprintln!("Export failed: {error}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait(&self, export_id: &str, poll_interval: Duration) -> crate::Result<ExportJob> {
+        loop {
+            let job = self.get(export_id).await?;
+            if job.is_finished() {
+                return Ok(job);
+            }
+            self.0.clock().sleep(poll_interval).await;
+        }
+    }
+
+    /// Download the file of a completed export into `writer`, returning the
+    /// number of bytes written.
+    ///
+    /// The transport buffers the whole response before it is written, so
+    /// memory use grows with the size of the export; narrow the date range
+    /// or event types for very large exports.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`](crate::Error::Io) if writing fails, besides the
+    /// usual API errors, e.g. for an export that has not completed yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::fs::File;
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let mut file = File::create("events-january.csv")?;
+    /// let bytes = client.exports.download("export-id", &mut file).await?;
+    /// println!("Wrote {bytes} bytes");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download<W>(&self, export_id: &str, writer: &mut W) -> crate::Result<u64>
+    where
+        W: Write + Send + ?Sized,
+    {
+        self.download_with_meta(export_id, writer)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`download`](Self::download), but also returns the HTTP status,
+    /// headers, and rate-limit information of the response.
+    pub async fn download_with_meta<W>(
+        &self,
+        export_id: &str,
+        writer: &mut W,
+    ) -> crate::Result<WithMeta<u64>>
+    where
+        W: Write + Send + ?Sized,
+    {
+        let path = format!("/exports/{export_id}/download");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/exports/{id}/download")
+            .no_cache();
+        let response = self.0.send(request).await?;
+        writer.write_all(&response.body)?;
+        writer.flush()?;
+        let written = response.body.len() as u64;
+        Ok(WithMeta::new(written, response))
+    }
+
+    /// Drops cached `/exports` responses, so the next call fetches fresh
+    /// data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/exports");
+    }
+}
+
+/// The `/exports` operations of [`ExportsSvc`], as a trait.
+///
+/// Application code can depend on `dyn ExportsApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait ExportsApi: Send + Sync {
+    /// See [`ExportsSvc::create`].
+    async fn create(&self, options: CreateExportOptions) -> crate::Result<ExportJob>;
+
+    /// See [`ExportsSvc::get`].
+    async fn get(&self, export_id: &str) -> crate::Result<ExportJob>;
+
+    /// See [`ExportsSvc::download`].
+    async fn download(
+        &self,
+        export_id: &str,
+        writer: &mut (dyn Write + Send),
+    ) -> crate::Result<u64>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl ExportsApi for ExportsSvc {
+    async fn create(&self, options: CreateExportOptions) -> crate::Result<ExportJob> {
+        ExportsSvc::create(self, options).await
+    }
+
+    async fn get(&self, export_id: &str) -> crate::Result<ExportJob> {
+        ExportsSvc::get(self, export_id).await
+    }
+
+    async fn download(
+        &self,
+        export_id: &str,
+        writer: &mut (dyn Write + Send),
+    ) -> crate::Result<u64> {
+        ExportsSvc::download(self, export_id, writer).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// File format of an export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row.
+    #[default]
+    Csv,
+    /// One JSON object per line.
+    Jsonl,
+}
+
+impl ExportFormat {
+    /// Returns the name the API uses for this format, which is also the
+    /// usual file extension.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::exports::ExportFormat;
+    ///
+    /// assert_eq!(ExportFormat::Jsonl.as_str(), "jsonl");
+    /// ```
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Jsonl => "jsonl",
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Options for requesting an export.
+#[must_use]
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateExportOptions {
+    /// Start of the range (ISO 8601 format).
+    pub(crate) from: String,
+
+    /// End of the range, inclusive (ISO 8601 format).
+    pub(crate) to: String,
+
+    /// File format.
+    pub(crate) format: ExportFormat,
+
+    /// Event types to include; all when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) event_types: Vec<String>,
+
+    /// Sending domain to include events of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) domain: Option<String>,
+}
+
+impl CreateExportOptions {
+    /// Creates new [`CreateExportOptions`] for all events from `from` to
+    /// `to` inclusive, as CSV.
+    pub fn new(from: impl Into<Timestamp>, to: impl Into<Timestamp>) -> Self {
+        Self {
+            from: from.into().to_string(),
+            to: to.into().to_string(),
+            format: ExportFormat::default(),
+            event_types: Vec::new(),
+            domain: None,
+        }
+    }
+
+    /// Creates new [`CreateExportOptions`] for all events within `range`, as
+    /// CSV.
+    pub fn in_range(range: DateRange) -> Self {
+        Self::new(range.from, range.to)
+    }
+
+    /// Sets the file format.
+    #[inline]
+    pub fn format(mut self, format: ExportFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Includes events of this type, e.g. [`EventType::Delivery`]. Call
+    /// repeatedly to include several types.
+    #[inline]
+    pub fn event_type(mut self, event_type: impl Into<EventType>) -> Self {
+        self.event_types.push(event_type.into().as_str().to_owned());
+        self
+    }
+
+    /// Only includes events of emails sent from this domain.
+    #[inline]
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ExportJobResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ExportJob,
+}
+
+/// An export and its progress.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct ExportJob {
+    /// Export ID.
+    pub id: String,
+    /// Status: `"pending"`, `"processing"`, `"completed"`, or `"failed"`.
+    pub status: String,
+    /// File format.
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// Start of the exported range.
+    pub from: String,
+    /// End of the exported range.
+    pub to: String,
+    /// Number of exported events, once completed.
+    #[serde(default)]
+    pub event_count: Option<u64>,
+    /// Size of the file in bytes, once completed.
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// Why the export failed, if it did.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// When the file is deleted, once completed.
+    #[serde(default)]
+    pub expires_at: Option<String>,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// When the export completed or failed.
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ExportJob {
+    /// Creates an export with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`ExportsApi`].
+    pub fn new(id: impl Into<String>, status: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            status: status.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the file is ready to [download](ExportsSvc::download).
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.status == "completed"
+    }
+
+    /// Returns whether the export has stopped running, successfully or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::exports::ExportJob;
+    ///
+    /// assert!(!ExportJob::new("exp_123", "processing").is_finished());
+    /// assert!(ExportJob::new("exp_123", "failed").is_finished());
+    /// ```
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.is_completed() || self.status == "failed"
+    }
+}
+
+/// Formats as a one-line summary, e.g. `exp_123 (completed, 2024-01-01 to
+/// 2024-01-31, csv)`.
+impl fmt::Display for ExportJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {} to {}, {})",
+            self.id, self.status, self.from, self.to, self.format
+        )
+    }
+}
@@ -0,0 +1,181 @@
+//! Sending through an SMTP relay while the Lettr API is unavailable.
+//!
+//! A [`FailoverSender`] sends each [`lettre::Message`] through the API and
+//! falls back to a lettre transport, typically an SMTP relay, when the call
+//! fails with a [retryable](Error::is_retryable) error. Emails the API
+//! rejects, e.g. as invalid, are not relayed.
+//!
+//! Only available with the `lettre` feature.
+
+use std::fmt;
+use std::sync::Arc;
+
+use ::lettre::Message;
+
+use crate::emails::{CreateEmailOptions, EmailsSvc, SendEmailResponse};
+use crate::{Error, Lettr};
+
+/// The path a [`FailoverSender`] delivered an email through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route {
+    /// The Lettr API.
+    Api,
+    /// The fallback transport.
+    Relay,
+}
+
+/// Outcome of a successful [`FailoverSender::send`].
+#[derive(Debug)]
+pub enum Delivery<T> {
+    /// The API accepted the email.
+    Api(SendEmailResponse),
+    /// The fallback transport accepted the email after the API failed.
+    Relay {
+        /// Response of the fallback transport.
+        response: T,
+        /// The error that made the API call fail.
+        api_error: Error,
+    },
+}
+
+impl<T> Delivery<T> {
+    /// Returns the path the email was delivered through.
+    #[must_use]
+    pub fn route(&self) -> Route {
+        match self {
+            Self::Api(_) => Route::Api,
+            Self::Relay { .. } => Route::Relay,
+        }
+    }
+}
+
+/// Error returned by [`FailoverSender::send`].
+#[derive(Debug, thiserror::Error)]
+pub enum FailoverError<E> {
+    /// The API rejected the email, or it could not be converted; it was not
+    /// relayed.
+    #[error(transparent)]
+    Api(Error),
+
+    /// The API was unavailable and the fallback transport failed too.
+    #[error("relay failed after the API was unavailable ({api}): {relay}")]
+    Relay {
+        /// The error that made the API call fail.
+        api: Error,
+        /// The error of the fallback transport.
+        #[source]
+        relay: E,
+    },
+}
+
+type RouteHookFn = dyn Fn(Route) + Send + Sync;
+
+/// Sends emails through the Lettr API, falling back to a lettre transport
+/// while the API is unavailable.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use lettr::failover::FailoverSender;
+/// use lettr::Lettr;
+/// use lettre::{AsyncSmtpTransport, Tokio1Executor};
+///
+/// # async fn run(message: lettre::Message) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Lettr::new("your-api-key");
+/// let relay = AsyncSmtpTransport::<Tokio1Executor>::relay("smtp.example.com")?.build();
+/// let sender = FailoverSender::new(&client, relay)
+///     .on_route(|route| log::info!("email delivered via {route:?}"));
+///
+/// sender.send(message).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FailoverSender<T> {
+    emails: EmailsSvc,
+    relay: T,
+    hook: Option<Arc<RouteHookFn>>,
+}
+
+impl<T> FailoverSender<T> {
+    /// Creates a [`FailoverSender`] sending through `client`, and through
+    /// `relay` while the API is unavailable.
+    pub fn new(client: &Lettr, relay: T) -> Self {
+        Self {
+            emails: client.emails.clone(),
+            relay,
+            hook: None,
+        }
+    }
+
+    /// Registers a callback that is called with the path of every delivered
+    /// email.
+    #[must_use]
+    pub fn on_route(mut self, hook: impl Fn(Route) + Send + Sync + 'static) -> Self {
+        self.hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Returns the fallback transport.
+    pub fn relay(&self) -> &T {
+        &self.relay
+    }
+
+    async fn send_api(&self, message: &Message) -> crate::Result<SendEmailResponse> {
+        let email = CreateEmailOptions::try_from(message)?;
+        self.emails.send(email).await
+    }
+
+    fn report(&self, route: Route) {
+        if let Some(hook) = &self.hook {
+            hook(route);
+        }
+    }
+}
+
+impl<T> FailoverSender<T>
+where
+    T: ::lettre::AsyncTransport + Sync,
+{
+    /// Sends `message` through the API, or through the fallback transport if
+    /// the API call fails with a [retryable](Error::is_retryable) error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FailoverError::Api`] if the message cannot be converted
+    /// (see [`CreateEmailOptions::try_from`]) or the API rejected it, and
+    /// [`FailoverError::Relay`] if both paths failed.
+    pub async fn send(&self, message: Message) -> Result<Delivery<T::Ok>, FailoverError<T::Error>> {
+        let api_error = match self.send_api(&message).await {
+            Ok(response) => {
+                self.report(Route::Api);
+                return Ok(Delivery::Api(response));
+            }
+            Err(e) if !e.is_retryable() => return Err(FailoverError::Api(e)),
+            Err(e) => e,
+        };
+
+        log::warn!("Lettr API unavailable, sending through the relay: {api_error}");
+        match self.relay.send(message).await {
+            Ok(response) => {
+                self.report(Route::Relay);
+                Ok(Delivery::Relay {
+                    response,
+                    api_error,
+                })
+            }
+            Err(relay) => Err(FailoverError::Relay {
+                api: api_error,
+                relay,
+            }),
+        }
+    }
+}
+
+impl<T> fmt::Debug for FailoverSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FailoverSender")
+            .field("emails", &self.emails)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,253 @@
+//! Typed dates for list filters.
+//!
+//! Options that filter by date, such as
+//! [`ListEmailsOptions::from_date`](crate::emails::ListEmailsOptions::from_date) or
+//! [`StatsOptions::new`](crate::stats::StatsOptions::new), take a
+//! [`Timestamp`], which is written to the query in the ISO 8601 format the
+//! API expects. A timestamp converts from a [`SystemTime`], from a
+//! `chrono` or `time` value with the `chrono` or `time` feature, or, as
+//! before, from an ISO 8601 string, which is passed through unchecked.
+//!
+//! A [`DateRange`] checks that its start is not after its end.
+
+use std::fmt;
+
+use crate::error::{InvalidOptions, Problem};
+use crate::time::{SystemTime, UNIX_EPOCH};
+
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A point in time, or a whole day, for a list filter.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use lettr::filter::Timestamp;
+///
+/// let instant = Timestamp::from(UNIX_EPOCH + Duration::from_secs(1_706_702_400));
+/// assert_eq!(instant.to_string(), "2024-01-31T12:00:00Z");
+///
+/// let day = Timestamp::from_ymd(2024, 2, 29).unwrap();
+/// assert_eq!(day.to_string(), "2024-02-29");
+/// assert!(Timestamp::from_ymd(2023, 2, 29).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Timestamp(Repr);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Repr {
+    /// Seconds since the Unix epoch.
+    Seconds(i64),
+    /// Days since the Unix epoch.
+    Day(i64),
+    /// A string passed through as is.
+    Raw(String),
+}
+
+impl Timestamp {
+    /// Creates a timestamp `seconds` after the Unix epoch.
+    #[must_use]
+    pub fn from_unix(seconds: i64) -> Self {
+        Self(Repr::Seconds(seconds))
+    }
+
+    /// Creates a timestamp for a whole day, written as `YYYY-MM-DD`. Returns
+    /// `None` if the date does not exist.
+    #[must_use]
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        let days = days_from_civil(i64::from(year), month, day);
+        (civil_from_days(days) == (i64::from(year), month, day)).then_some(Self(Repr::Day(days)))
+    }
+
+    /// Returns the seconds since the Unix epoch, or `None` for a string
+    /// timestamp. A day starts at midnight UTC.
+    fn seconds(&self) -> Option<i64> {
+        match self.0 {
+            Repr::Seconds(seconds) => Some(seconds),
+            Repr::Day(days) => Some(days * SECS_PER_DAY),
+            Repr::Raw(_) => None,
+        }
+    }
+}
+
+/// Formats as ISO 8601: `2024-01-31T12:00:00Z`, or `2024-01-31` for a day.
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Repr::Seconds(seconds) => {
+                let (year, month, day) = civil_from_days(seconds.div_euclid(SECS_PER_DAY));
+                let second = seconds.rem_euclid(SECS_PER_DAY);
+                write!(
+                    f,
+                    "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+                    second / 3600,
+                    second / 60 % 60,
+                    second % 60,
+                )
+            }
+            Repr::Day(days) => {
+                let (year, month, day) = civil_from_days(days);
+                write!(f, "{year:04}-{month:02}-{day:02}")
+            }
+            Repr::Raw(ref raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        let seconds = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_secs()).unwrap_or(i64::MAX),
+            Err(before) => {
+                let before = before.duration();
+                // Round down, so a time just before the epoch is second -1.
+                let seconds = i64::try_from(before.as_secs()).unwrap_or(i64::MAX);
+                -seconds - i64::from(before.subsec_nanos() > 0)
+            }
+        };
+        Self::from_unix(seconds)
+    }
+}
+
+impl From<&str> for Timestamp {
+    fn from(raw: &str) -> Self {
+        Self(Repr::Raw(raw.to_owned()))
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(raw: String) -> Self {
+        Self(Repr::Raw(raw))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    fn from(time: chrono::DateTime<Tz>) -> Self {
+        Self::from_unix(time.timestamp())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Timestamp {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+
+        /// Days from 0001-01-01 to 1970-01-01.
+        const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+        Self(Repr::Day(
+            i64::from(date.num_days_from_ce()) - UNIX_EPOCH_DAYS_FROM_CE,
+        ))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(time: time::OffsetDateTime) -> Self {
+        Self::from_unix(time.unix_timestamp())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for Timestamp {
+    fn from(date: time::Date) -> Self {
+        /// Julian day number of 1970-01-01.
+        const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+        Self(Repr::Day(
+            i64::from(date.to_julian_day()) - UNIX_EPOCH_JULIAN_DAY,
+        ))
+    }
+}
+
+/// A range of time from a start to an end, inclusive.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use lettr::filter::{DateRange, Timestamp};
+/// use lettr::emails::ListEmailsOptions;
+///
+/// let now = SystemTime::now();
+/// let last_day = DateRange::new(now - Duration::from_secs(24 * 60 * 60), now)?;
+/// let options = ListEmailsOptions::new().date_range(last_day);
+///
+/// let backwards = DateRange::new(
+///     Timestamp::from_ymd(2024, 2, 1).unwrap(),
+///     Timestamp::from_ymd(2024, 1, 1).unwrap(),
+/// );
+/// assert!(backwards.is_err());
+/// # Ok::<(), lettr::types::InvalidOptions>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    pub(crate) from: Timestamp,
+    pub(crate) to: Timestamp,
+}
+
+impl DateRange {
+    /// Creates the range from `from` to `to`, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Problem::InvalidDateRange`] if `from` is after `to`. Ranges
+    /// with a string end are not checked.
+    pub fn new(
+        from: impl Into<Timestamp>,
+        to: impl Into<Timestamp>,
+    ) -> Result<Self, InvalidOptions> {
+        let (from, to) = (from.into(), to.into());
+        if let (Some(start), Some(end)) = (from.seconds(), to.seconds()) {
+            if start > end {
+                return Err(InvalidOptions {
+                    problems: vec![Problem::InvalidDateRange],
+                });
+            }
+        }
+        Ok(Self { from, to })
+    }
+
+    /// Returns the start of the range.
+    #[must_use]
+    pub fn from(&self) -> &Timestamp {
+        &self.from
+    }
+
+    /// Returns the end of the range.
+    #[must_use]
+    pub fn to(&self) -> &Timestamp {
+        &self.to
+    }
+}
+
+/// Returns the days since the Unix epoch of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let (month, day) = (i64::from(month), i64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the proleptic Gregorian date of a day since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    // Both are in range by construction.
+    (year, month as u32, day as u32)
+}
@@ -0,0 +1,307 @@
+//! Calendar invites as email attachments.
+//!
+//! A [`CalendarEvent`] renders to an iCalendar (RFC 5545) file and, with
+//! [`invite`](CalendarEvent::invite) or
+//! [`cancellation`](CalendarEvent::cancellation), to a `text/calendar`
+//! [`Attachment`] with the `method` parameter mail clients look for, so
+//! booking and meeting confirmations show up as real invites with accept and
+//! decline buttons.
+//!
+//! To update an event, send a new invite with the same
+//! [`uid`](CalendarEvent::new) and a higher [`sequence`](CalendarEvent::sequence).
+
+use std::fmt::Write as _;
+
+use crate::emails::Attachment;
+use crate::filter::Timestamp;
+use crate::time::SystemTime;
+
+/// Longest line of an iCalendar file in bytes, without the line break.
+const MAX_LINE_LEN: usize = 75;
+
+/// What a calendar attachment asks the recipient's calendar to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CalendarMethod {
+    /// Add or update the event.
+    Request,
+    /// Cancel the event.
+    Cancel,
+}
+
+impl CalendarMethod {
+    /// Returns the iCalendar name of the method.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Request => "REQUEST",
+            Self::Cancel => "CANCEL",
+        }
+    }
+}
+
+/// An event to send as a calendar invite.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use lettr::CreateEmailOptions;
+/// use lettr::ics::{CalendarEvent, CalendarMethod};
+///
+/// let start = SystemTime::now() + Duration::from_secs(24 * 60 * 60);
+/// let event = CalendarEvent::new(
+///     "booking-1234@example.com",
+///     "Haircut with Sam",
+///     start,
+///     start + Duration::from_secs(45 * 60),
+/// )
+/// .location("12 Main Street")
+/// .organizer("bookings@example.com", "Example Salon")
+/// .attendee("customer@example.com", "Alex");
+///
+/// let email = CreateEmailOptions::new(
+///     "bookings@example.com",
+///     ["customer@example.com"],
+///     "Your booking is confirmed",
+/// )
+/// .with_text("See you soon!")
+/// .with_attachment(event.invite());
+///
+/// assert!(event.to_ics(CalendarMethod::Request).contains("SUMMARY:Haircut with Sam"));
+/// ```
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    uid: String,
+    summary: String,
+    start: SystemTime,
+    end: SystemTime,
+    description: Option<String>,
+    location: Option<String>,
+    url: Option<String>,
+    organizer: Option<Party>,
+    attendees: Vec<Party>,
+    sequence: u32,
+    created: Option<SystemTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Party {
+    email: String,
+    name: String,
+}
+
+impl CalendarEvent {
+    /// Creates an event from `start` to `end`.
+    ///
+    /// `uid` identifies the event across updates and cancellations, and
+    /// should be globally unique, e.g. `booking-1234@example.com`.
+    pub fn new(
+        uid: impl Into<String>,
+        summary: impl Into<String>,
+        start: SystemTime,
+        end: SystemTime,
+    ) -> Self {
+        Self {
+            uid: uid.into(),
+            summary: summary.into(),
+            start,
+            end,
+            description: None,
+            location: None,
+            url: None,
+            organizer: None,
+            attendees: Vec::new(),
+            sequence: 0,
+            created: None,
+        }
+    }
+
+    /// Sets the description of the event.
+    #[inline]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets where the event takes place.
+    #[inline]
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Sets a link to more details, e.g. to manage a booking.
+    #[inline]
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets the organizer; an empty `name` means none. Replies from the
+    /// recipient's calendar go to this address.
+    #[inline]
+    pub fn organizer(mut self, email: impl Into<String>, name: impl Into<String>) -> Self {
+        self.organizer = Some(Party {
+            email: email.into(),
+            name: name.into(),
+        });
+        self
+    }
+
+    /// Adds an attendee who is asked to reply; an empty `name` means none.
+    #[inline]
+    pub fn attendee(mut self, email: impl Into<String>, name: impl Into<String>) -> Self {
+        self.attendees.push(Party {
+            email: email.into(),
+            name: name.into(),
+        });
+        self
+    }
+
+    /// Sets the revision of the event, `0` by default. Increase it for
+    /// every update or cancellation sent, so calendars apply it.
+    #[inline]
+    pub fn sequence(mut self, sequence: u32) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    /// Sets when this revision of the event was created, the current time by
+    /// default.
+    #[inline]
+    pub fn created(mut self, created: SystemTime) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    /// Returns an attachment inviting the recipient to the event, or
+    /// updating it.
+    pub fn invite(&self) -> Attachment {
+        self.to_attachment(CalendarMethod::Request)
+    }
+
+    /// Returns an attachment cancelling the event.
+    pub fn cancellation(&self) -> Attachment {
+        self.to_attachment(CalendarMethod::Cancel)
+    }
+
+    /// Returns the event as an `invite.ics` attachment with `method`.
+    pub fn to_attachment(&self, method: CalendarMethod) -> Attachment {
+        Attachment::from_bytes(
+            "invite.ics",
+            format!("text/calendar; charset=UTF-8; method={}", method.as_str()),
+            self.to_ics(method),
+        )
+    }
+
+    /// Renders the event as an iCalendar file with `method`.
+    #[must_use]
+    pub fn to_ics(&self, method: CalendarMethod) -> String {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_owned(),
+            "VERSION:2.0".to_owned(),
+            concat!(
+                "PRODID:-//Lettr//lettr-rust ",
+                env!("CARGO_PKG_VERSION"),
+                "//EN"
+            )
+            .to_owned(),
+            "CALSCALE:GREGORIAN".to_owned(),
+            format!("METHOD:{}", method.as_str()),
+            "BEGIN:VEVENT".to_owned(),
+            format!("UID:{}", escape(&self.uid)),
+            format!("SEQUENCE:{}", self.sequence),
+            format!(
+                "DTSTAMP:{}",
+                date_time(self.created.unwrap_or_else(SystemTime::now))
+            ),
+            format!("DTSTART:{}", date_time(self.start)),
+            format!("DTEND:{}", date_time(self.end)),
+            format!("SUMMARY:{}", escape(&self.summary)),
+        ];
+        if let Some(description) = &self.description {
+            lines.push(format!("DESCRIPTION:{}", escape(description)));
+        }
+        if let Some(location) = &self.location {
+            lines.push(format!("LOCATION:{}", escape(location)));
+        }
+        if let Some(url) = &self.url {
+            lines.push(format!("URL:{url}"));
+        }
+        if let Some(organizer) = &self.organizer {
+            lines.push(format!("ORGANIZER{}", organizer.address()));
+        }
+        for attendee in &self.attendees {
+            lines.push(format!(
+                "ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=NEEDS-ACTION;RSVP=TRUE{}",
+                attendee.address()
+            ));
+        }
+        let status = match method {
+            CalendarMethod::Request => "CONFIRMED",
+            CalendarMethod::Cancel => "CANCELLED",
+        };
+        lines.push(format!("STATUS:{status}"));
+        lines.push("END:VEVENT".to_owned());
+        lines.push("END:VCALENDAR".to_owned());
+
+        let mut ics = String::new();
+        for line in &lines {
+            fold(&mut ics, line);
+        }
+        ics
+    }
+}
+
+impl Party {
+    /// Returns the `CN` parameter, if any, and the `mailto:` value.
+    fn address(&self) -> String {
+        let mut address = String::new();
+        if !self.name.is_empty() {
+            // Quoted parameter values cannot contain quotes.
+            let _ = write!(address, ";CN=\"{}\"", self.name.replace('"', "'"));
+        }
+        let _ = write!(address, ":mailto:{}", self.email);
+        address
+    }
+}
+
+/// Formats `time` as a UTC iCalendar date-time, e.g. `20240131T120000Z`.
+fn date_time(time: SystemTime) -> String {
+    Timestamp::from(time).to_string().replace(['-', ':'], "")
+}
+
+/// Escapes a text value.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends `line` to `ics`, folded into lines of at most
+/// [`MAX_LINE_LEN`] bytes, each ended with CRLF.
+fn fold(ics: &mut String, line: &str) {
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > MAX_LINE_LEN {
+            // Continuation lines start with a space, which counts.
+            ics.push_str("\r\n ");
+            len = 1;
+        }
+        ics.push(c);
+        len += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
@@ -0,0 +1,1000 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::emails::Pagination;
+use crate::filter::Timestamp;
+use crate::meta::WithMeta;
+
+/// Service for the `/inbound` endpoints.
+///
+/// Inbound routes tell Lettr which incoming mail to accept, e.g. replies to
+/// `support@reply.example.com`. Accepted messages are parsed and kept, so
+/// they can be retrieved here or forwarded to a URL as they arrive.
+///
+/// Inbound rules refine what happens to accepted mail by recipient address,
+/// e.g. forwarding `reply+*@reply.example.com` to one URL and dropping
+/// `noreply@reply.example.com`.
+#[derive(Clone, Debug)]
+pub struct InboundSvc(pub(crate) Arc<Config>);
+
+impl InboundSvc {
+    /// List all inbound routes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let routes = client.inbound.list_routes().await?;
+    /// for route in &routes {
+    ///     println!("{route}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_routes(&self) -> crate::Result<Vec<InboundRoute>> {
+        self.list_routes_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list_routes`](Self::list_routes), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn list_routes_with_meta(&self) -> crate::Result<WithMeta<Vec<InboundRoute>>> {
+        let request = self.0.build(Method::GET, "/inbound/routes");
+        let response: WithMeta<ListInboundRoutesResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.routes))
+    }
+
+    /// Create an inbound route.
+    ///
+    /// Mail only arrives once the domain's MX record points at
+    /// [`InboundRoute::mx_host`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::inbound::CreateInboundRouteOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = CreateInboundRouteOptions::new("reply.example.com")
+    ///     .with_recipient("support")
+    ///     .with_forward_url("https://example.com/hooks/inbound");
+    /// let route = client.inbound.create_route(options).await?;
+    /// println!("Point the MX record at {}", route.mx_host);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_route(
+        &self,
+        options: CreateInboundRouteOptions,
+    ) -> crate::Result<InboundRoute> {
+        self.create_route_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`create_route`](Self::create_route), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn create_route_with_meta(
+        &self,
+        options: CreateInboundRouteOptions,
+    ) -> crate::Result<WithMeta<InboundRoute>> {
+        let request = self.0.build(Method::POST, "/inbound/routes").json(&options);
+        let response: WithMeta<InboundRouteResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Delete an inbound route. Mail matching only this route is rejected
+    /// from then on; messages already received are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.inbound.delete_route("route-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_route(&self, route_id: &str) -> crate::Result<()> {
+        self.delete_route_with_meta(route_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`delete_route`](Self::delete_route), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn delete_route_with_meta(&self, route_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/inbound/routes/{route_id}");
+        let request = self
+            .0
+            .build(Method::DELETE, &path)
+            .route("/inbound/routes/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// List all inbound rules, in the order they are evaluated.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// for rule in client.inbound.list_rules().await? {
+    ///     println!("{rule}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_rules(&self) -> crate::Result<Vec<InboundRule>> {
+        self.list_rules_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list_rules`](Self::list_rules), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn list_rules_with_meta(&self) -> crate::Result<WithMeta<Vec<InboundRule>>> {
+        let request = self.0.build(Method::GET, "/inbound/rules");
+        let response: WithMeta<ListInboundRulesResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.rules))
+    }
+
+    /// Create an inbound rule.
+    ///
+    /// Each accepted message is handled by the first rule, by ascending
+    /// priority, whose pattern matches one of its recipients. Messages no
+    /// rule matches are handled as their route says.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::inbound::CreateInboundRuleOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = CreateInboundRuleOptions::forward(
+    ///     "reply+*@reply.example.com",
+    ///     "https://example.com/hooks/replies",
+    /// );
+    /// client.inbound.create_rule(options).await?;
+    ///
+    /// let options = CreateInboundRuleOptions::drop("noreply@reply.example.com").with_priority(0);
+    /// client.inbound.create_rule(options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_rule(
+        &self,
+        options: CreateInboundRuleOptions,
+    ) -> crate::Result<InboundRule> {
+        self.create_rule_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`create_rule`](Self::create_rule), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn create_rule_with_meta(
+        &self,
+        options: CreateInboundRuleOptions,
+    ) -> crate::Result<WithMeta<InboundRule>> {
+        let request = self.0.build(Method::POST, "/inbound/rules").json(&options);
+        let response: WithMeta<InboundRuleResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Delete an inbound rule. Matching messages fall through to the next
+    /// rule from then on.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.inbound.delete_rule("rule-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_rule(&self, rule_id: &str) -> crate::Result<()> {
+        self.delete_rule_with_meta(rule_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`delete_rule`](Self::delete_rule), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn delete_rule_with_meta(&self, rule_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/inbound/rules/{rule_id}");
+        let request = self
+            .0
+            .build(Method::DELETE, &path)
+            .route("/inbound/rules/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// List received messages, newest first.
+    ///
+    /// Listed messages include their bodies, but attachment content is
+    /// only included by [`get_message`](Self::get_message).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::inbound::ListInboundMessagesOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = ListInboundMessagesOptions::new()
+    ///     .route("route-id")
+    ///     .since("2024-01-01");
+    /// let page = client.inbound.list_messages(options).await?;
+    ///
+    /// for message in &page.messages {
+    ///     println!("{message}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_messages(
+        &self,
+        options: ListInboundMessagesOptions,
+    ) -> crate::Result<ListInboundMessagesResponse> {
+        self.list_messages_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`list_messages`](Self::list_messages), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn list_messages_with_meta(
+        &self,
+        options: ListInboundMessagesOptions,
+    ) -> crate::Result<WithMeta<ListInboundMessagesResponse>> {
+        let mut request = self.0.build(Method::GET, "/inbound/messages");
+
+        if let Some(ref route_id) = options.route_id {
+            request = request.query(&[("route_id", route_id.as_str())]);
+        }
+        if let Some(ref since) = options.since {
+            request = request.query(&[("since", since.as_str())]);
+        }
+        if let Some(per_page) = options.per_page {
+            request = request.query(&[("per_page", per_page.to_string())]);
+        }
+        if let Some(ref cursor) = options.cursor {
+            request = request.query(&[("cursor", cursor.as_str())]);
+        }
+
+        let response: WithMeta<ListInboundMessagesResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve a received message, including attachment content.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let message = client.inbound.get_message("message-id").await?;
+    /// if let Some(original) = message.in_reply_to() {
+    ///     println!("Reply to {original}");
+    /// }
+    /// for attachment in &message.attachments {
+    ///     println!("{} ({} bytes)", attachment.filename, attachment.size);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_message(&self, message_id: &str) -> crate::Result<InboundMessage> {
+        self.get_message_with_meta(message_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`get_message`](Self::get_message), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn get_message_with_meta(
+        &self,
+        message_id: &str,
+    ) -> crate::Result<WithMeta<InboundMessage>> {
+        let path = format!("/inbound/messages/{message_id}");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/inbound/messages/{id}");
+        let response: WithMeta<InboundMessageResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Delete a received message, e.g. once it has been processed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.inbound.delete_message("message-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_message(&self, message_id: &str) -> crate::Result<()> {
+        self.delete_message_with_meta(message_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`delete_message`](Self::delete_message), but also returns the
+    /// HTTP status, headers, and rate-limit information of the response.
+    pub async fn delete_message_with_meta(&self, message_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/inbound/messages/{message_id}");
+        let request = self
+            .0
+            .build(Method::DELETE, &path)
+            .route("/inbound/messages/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Drops cached `/inbound` responses, so the next call fetches fresh
+    /// data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/inbound");
+    }
+}
+
+/// The `/inbound` operations of [`InboundSvc`], as a trait.
+///
+/// Application code can depend on `dyn InboundApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait InboundApi: Send + Sync {
+    /// See [`InboundSvc::list_routes`].
+    async fn list_routes(&self) -> crate::Result<Vec<InboundRoute>>;
+
+    /// See [`InboundSvc::create_route`].
+    async fn create_route(&self, options: CreateInboundRouteOptions)
+        -> crate::Result<InboundRoute>;
+
+    /// See [`InboundSvc::delete_route`].
+    async fn delete_route(&self, route_id: &str) -> crate::Result<()>;
+
+    /// See [`InboundSvc::list_rules`].
+    async fn list_rules(&self) -> crate::Result<Vec<InboundRule>>;
+
+    /// See [`InboundSvc::create_rule`].
+    async fn create_rule(&self, options: CreateInboundRuleOptions) -> crate::Result<InboundRule>;
+
+    /// See [`InboundSvc::delete_rule`].
+    async fn delete_rule(&self, rule_id: &str) -> crate::Result<()>;
+
+    /// See [`InboundSvc::list_messages`].
+    async fn list_messages(
+        &self,
+        options: ListInboundMessagesOptions,
+    ) -> crate::Result<ListInboundMessagesResponse>;
+
+    /// See [`InboundSvc::get_message`].
+    async fn get_message(&self, message_id: &str) -> crate::Result<InboundMessage>;
+
+    /// See [`InboundSvc::delete_message`].
+    async fn delete_message(&self, message_id: &str) -> crate::Result<()>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl InboundApi for InboundSvc {
+    async fn list_routes(&self) -> crate::Result<Vec<InboundRoute>> {
+        InboundSvc::list_routes(self).await
+    }
+
+    async fn create_route(
+        &self,
+        options: CreateInboundRouteOptions,
+    ) -> crate::Result<InboundRoute> {
+        InboundSvc::create_route(self, options).await
+    }
+
+    async fn delete_route(&self, route_id: &str) -> crate::Result<()> {
+        InboundSvc::delete_route(self, route_id).await
+    }
+
+    async fn list_rules(&self) -> crate::Result<Vec<InboundRule>> {
+        InboundSvc::list_rules(self).await
+    }
+
+    async fn create_rule(&self, options: CreateInboundRuleOptions) -> crate::Result<InboundRule> {
+        InboundSvc::create_rule(self, options).await
+    }
+
+    async fn delete_rule(&self, rule_id: &str) -> crate::Result<()> {
+        InboundSvc::delete_rule(self, rule_id).await
+    }
+
+    async fn list_messages(
+        &self,
+        options: ListInboundMessagesOptions,
+    ) -> crate::Result<ListInboundMessagesResponse> {
+        InboundSvc::list_messages(self, options).await
+    }
+
+    async fn get_message(&self, message_id: &str) -> crate::Result<InboundMessage> {
+        InboundSvc::get_message(self, message_id).await
+    }
+
+    async fn delete_message(&self, message_id: &str) -> crate::Result<()> {
+        InboundSvc::delete_message(self, message_id).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// Options for creating an inbound route.
+#[must_use]
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateInboundRouteOptions {
+    /// Domain to receive mail for.
+    pub(crate) domain: String,
+
+    /// Local part to accept; all addresses when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) recipient: Option<String>,
+
+    /// URL each parsed message is posted to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) forward_url: Option<String>,
+}
+
+impl CreateInboundRouteOptions {
+    /// Creates new [`CreateInboundRouteOptions`] accepting all mail for
+    /// `domain`.
+    pub fn new(domain: impl Into<String>) -> Self {
+        Self {
+            domain: domain.into(),
+            recipient: None,
+            forward_url: None,
+        }
+    }
+
+    /// Only accepts mail for this local part, e.g. `"support"` for
+    /// `support@<domain>`.
+    #[inline]
+    pub fn with_recipient(mut self, local_part: impl Into<String>) -> Self {
+        self.recipient = Some(local_part.into());
+        self
+    }
+
+    /// Posts each parsed message to this URL as it arrives, in addition to
+    /// keeping it for [`InboundSvc::list_messages`].
+    #[inline]
+    pub fn with_forward_url(mut self, url: impl Into<String>) -> Self {
+        self.forward_url = Some(url.into());
+        self
+    }
+}
+
+/// Options for creating an inbound rule.
+///
+/// Patterns match recipient addresses case-insensitively, where `*` matches
+/// any run of characters, e.g. `reply+*@reply.example.com` or
+/// `*@support.example.com`.
+#[must_use]
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateInboundRuleOptions {
+    /// Recipient address pattern.
+    pub(crate) pattern: String,
+
+    /// `"forward"`, `"store"`, or `"drop"`.
+    pub(crate) action: &'static str,
+
+    /// URL matching messages are posted to, for `"forward"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) forward_url: Option<String>,
+
+    /// Evaluation order; lower runs first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) priority: Option<u32>,
+}
+
+impl CreateInboundRuleOptions {
+    /// Creates a rule posting matching messages to `url` and keeping them
+    /// for [`InboundSvc::list_messages`].
+    pub fn forward(pattern: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            action: "forward",
+            forward_url: Some(url.into()),
+            priority: None,
+        }
+    }
+
+    /// Creates a rule only keeping matching messages for
+    /// [`InboundSvc::list_messages`], without forwarding them.
+    pub fn store(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            action: "store",
+            forward_url: None,
+            priority: None,
+        }
+    }
+
+    /// Creates a rule discarding matching messages.
+    pub fn drop(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            action: "drop",
+            forward_url: None,
+            priority: None,
+        }
+    }
+
+    /// Sets the evaluation order; rules with a lower priority run first.
+    /// New rules run after all existing ones by default.
+    #[inline]
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+}
+
+/// Options for listing received messages.
+#[must_use]
+#[derive(Debug, Default, Clone)]
+pub struct ListInboundMessagesOptions {
+    pub(crate) route_id: Option<String>,
+    pub(crate) since: Option<String>,
+    pub(crate) per_page: Option<u32>,
+    pub(crate) cursor: Option<String>,
+}
+
+impl ListInboundMessagesOptions {
+    /// Creates new [`ListInboundMessagesOptions`] with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lists messages received through this route.
+    #[inline]
+    pub fn route(mut self, route_id: impl Into<String>) -> Self {
+        self.route_id = Some(route_id.into());
+        self
+    }
+
+    /// Only lists messages received on or after this date.
+    #[inline]
+    pub fn since(mut self, since: impl Into<Timestamp>) -> Self {
+        self.since = Some(since.into().to_string());
+        self
+    }
+
+    /// Sets the number of results per page (1-100).
+    #[inline]
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the pagination cursor from a previous response.
+    #[inline]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListInboundRoutesResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListInboundRoutesData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListInboundRoutesData {
+    routes: Vec<InboundRoute>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InboundRouteResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: InboundRoute,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListInboundRulesResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListInboundRulesData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListInboundRulesData {
+    rules: Vec<InboundRule>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InboundRuleResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: InboundRule,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListInboundMessagesResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListInboundMessagesResponse,
+}
+
+#[derive(Debug, Deserialize)]
+struct InboundMessageResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: InboundMessage,
+}
+
+/// A rule for accepting incoming mail.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct InboundRoute {
+    /// Route ID.
+    pub id: String,
+    /// Domain mail is received for.
+    pub domain: String,
+    /// Local part accepted, or `None` for all addresses.
+    #[serde(default)]
+    pub recipient: Option<String>,
+    /// URL each parsed message is posted to, if any.
+    #[serde(default)]
+    pub forward_url: Option<String>,
+    /// Host the domain's MX record must point at.
+    pub mx_host: String,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl InboundRoute {
+    /// Creates a route with the other fields empty. Set them as needed, e.g.
+    /// for a mock [`InboundApi`].
+    pub fn new(id: impl Into<String>, domain: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            domain: domain.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the address pattern the route accepts, e.g.
+    /// `support@reply.example.com` or `*@reply.example.com`.
+    #[must_use]
+    pub fn address(&self) -> String {
+        format!(
+            "{}@{}",
+            self.recipient.as_deref().unwrap_or("*"),
+            self.domain
+        )
+    }
+}
+
+/// Formats as a one-line summary, e.g. `support@reply.example.com (rt_123)`.
+impl fmt::Display for InboundRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.address(), self.id)
+    }
+}
+
+/// A rule deciding what happens to accepted mail for matching recipients.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct InboundRule {
+    /// Rule ID.
+    pub id: String,
+    /// Recipient address pattern; see [`CreateInboundRuleOptions`].
+    pub pattern: String,
+    /// `"forward"`, `"store"`, or `"drop"`.
+    pub action: String,
+    /// URL matching messages are posted to, for `"forward"`.
+    #[serde(default)]
+    pub forward_url: Option<String>,
+    /// Evaluation order; lower runs first.
+    #[serde(default)]
+    pub priority: u32,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl InboundRule {
+    /// Creates a rule with the other fields empty. Set them as needed, e.g.
+    /// for a mock [`InboundApi`].
+    pub fn new(
+        id: impl Into<String>,
+        pattern: impl Into<String>,
+        action: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            pattern: pattern.into(),
+            action: action.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the rule's pattern matches `address`, ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::inbound::InboundRule;
+    ///
+    /// let rule = InboundRule::new("rule-id", "reply+*@reply.example.com", "store");
+    /// assert!(rule.matches("Reply+42@reply.example.com"));
+    /// assert!(!rule.matches("support@reply.example.com"));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, address: &str) -> bool {
+        glob_matches(
+            &self.pattern.to_ascii_lowercase(),
+            &address.to_ascii_lowercase(),
+        )
+    }
+}
+
+/// Formats as a one-line summary, e.g. `reply+*@reply.example.com -> forward
+/// https://example.com/hooks (rl_123)`.
+impl fmt::Display for InboundRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.pattern, self.action)?;
+        if let Some(url) = &self.forward_url {
+            write!(f, " {url}")?;
+        }
+        write!(f, " ({})", self.id)
+    }
+}
+
+/// Returns whether `text` matches `pattern`, where `*` matches any run of
+/// characters.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No `*` in the pattern.
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// Response from listing received messages.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct ListInboundMessagesResponse {
+    /// Messages on this page.
+    pub messages: Vec<InboundMessage>,
+    /// Pagination information.
+    pub pagination: Pagination,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ListInboundMessagesResponse {
+    /// Creates a single page holding `messages`, e.g. for a mock
+    /// [`InboundApi`] in tests.
+    pub fn new(messages: Vec<InboundMessage>) -> Self {
+        Self {
+            pagination: Pagination {
+                next_cursor: None,
+                per_page: u32::try_from(messages.len()).unwrap_or(u32::MAX),
+                extra: serde_json::Map::new(),
+            },
+            messages,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+/// A received and parsed email.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct InboundMessage {
+    /// Message ID.
+    pub id: String,
+    /// ID of the route the message was received through.
+    pub route_id: String,
+    /// Sender, as in the `From` header.
+    pub from: String,
+    /// Recipients, as in the `To` header.
+    #[serde(default)]
+    pub to: Vec<String>,
+    /// Carbon copy recipients.
+    #[serde(default)]
+    pub cc: Vec<String>,
+    /// Subject line.
+    #[serde(default)]
+    pub subject: String,
+    /// All headers, in the order they appear in the message.
+    #[serde(default)]
+    pub headers: Vec<InboundHeader>,
+    /// Plain text body, if the message has one.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// HTML body, if the message has one.
+    #[serde(default)]
+    pub html: Option<String>,
+    /// Attached files.
+    #[serde(default)]
+    pub attachments: Vec<InboundAttachment>,
+    /// When Lettr received the message.
+    pub received_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl InboundMessage {
+    /// Creates a message with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`InboundApi`].
+    pub fn new(id: impl Into<String>, from: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            from: from.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the value of the first header named `name`, ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::inbound::{InboundHeader, InboundMessage};
+    ///
+    /// let mut message = InboundMessage::new("msg_123", "jane@example.org");
+    /// message.headers.push(InboundHeader::new("X-Priority", "1"));
+    /// assert_eq!(message.header("x-priority"), Some("1"));
+    /// ```
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|header| header.name.eq_ignore_ascii_case(name))
+            .map(|header| header.value.as_str())
+    }
+
+    /// Returns the `Message-ID` header.
+    #[must_use]
+    pub fn message_id(&self) -> Option<&str> {
+        self.header("Message-ID")
+    }
+
+    /// Returns the `In-Reply-To` header: the `Message-ID` of the email this
+    /// message replies to, for matching replies to what was sent.
+    #[must_use]
+    pub fn in_reply_to(&self) -> Option<&str> {
+        self.header("In-Reply-To")
+    }
+}
+
+/// Formats as a one-line summary, e.g. `Re: Order #42 (msg_123, from
+/// jane@example.org)`.
+impl fmt::Display for InboundMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}, from {})", self.subject, self.id, self.from)
+    }
+}
+
+/// A header of a received message.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct InboundHeader {
+    /// Header name, as sent.
+    pub name: String,
+    /// Header value, decoded.
+    pub value: String,
+}
+
+impl InboundHeader {
+    /// Creates a new header.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// A file attached to a received message.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct InboundAttachment {
+    /// Filename of the attachment.
+    pub filename: String,
+    /// MIME type (e.g. `"application/pdf"`).
+    pub content_type: String,
+    /// Size of the decoded content in bytes.
+    #[serde(default)]
+    pub size: u64,
+    /// `Content-ID` for inline images referenced from the HTML body.
+    #[serde(default)]
+    pub content_id: Option<String>,
+    /// Base64-encoded file content. Only included by
+    /// [`InboundSvc::get_message`].
+    #[serde(default)]
+    pub data: Option<String>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl InboundAttachment {
+    /// Creates an attachment with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`InboundApi`].
+    pub fn new(filename: impl Into<String>, content_type: impl Into<String>) -> Self {
+        Self {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the attachment is shown inline in the HTML body
+    /// rather than as a separate file.
+    #[must_use]
+    pub fn is_inline(&self) -> bool {
+        self.content_id.is_some()
+    }
+}
@@ -0,0 +1,322 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::meta::WithMeta;
+
+/// Service for the `/ip-pools` endpoints.
+///
+/// IP pools group the dedicated IPs of an account. Each sending domain sends
+/// through one pool, so e.g. marketing and transactional mail can keep
+/// separate reputations. Pools are provisioned by Lettr; this service reads
+/// them and assigns domains.
+#[derive(Clone, Debug)]
+pub struct IpPoolsSvc(pub(crate) Arc<Config>);
+
+impl IpPoolsSvc {
+    /// List all IP pools with their IPs and assigned domains.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let pools = client.ip_pools.list().await?;
+    /// for pool in &pools {
+    ///     println!("{pool}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<Vec<IpPool>> {
+        self.list_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<IpPool>>> {
+        let request = self.0.build(Method::GET, "/ip-pools");
+        let response: WithMeta<ListIpPoolsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.pools))
+    }
+
+    /// Retrieve a single IP pool, including the warm-up status of its IPs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let pool = client.ip_pools.get("pool-id").await?;
+    /// for ip in &pool.ips {
+    ///     println!("{ip}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, pool_id: &str) -> crate::Result<IpPool> {
+        self.get_with_meta(pool_id).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, pool_id: &str) -> crate::Result<WithMeta<IpPool>> {
+        let path = format!("/ip-pools/{pool_id}");
+        let request = self.0.build(Method::GET, &path).route("/ip-pools/{id}");
+        let response: WithMeta<IpPoolResponseWrapper> = self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Assign a sending domain to a pool. The domain is moved out of the pool
+    /// it was in before, and sends through the new pool from then on.
+    ///
+    /// Returns the updated pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let pool = client
+    ///     .ip_pools
+    ///     .assign_domain("pool-id", "news.example.com")
+    ///     .await?;
+    /// println!("{} now sends: {}", pool.name, pool.domains.join(", "));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn assign_domain(&self, pool_id: &str, domain: &str) -> crate::Result<IpPool> {
+        self.assign_domain_with_meta(pool_id, domain)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`assign_domain`](Self::assign_domain), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn assign_domain_with_meta(
+        &self,
+        pool_id: &str,
+        domain: &str,
+    ) -> crate::Result<WithMeta<IpPool>> {
+        let path = format!("/ip-pools/{pool_id}/domains");
+        let body = AssignDomainRequest {
+            domain: domain.to_owned(),
+        };
+        let request = self
+            .0
+            .build(Method::POST, &path)
+            .route("/ip-pools/{id}/domains")
+            .json(&body);
+        let response: WithMeta<IpPoolResponseWrapper> = self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Drops cached `/ip-pools` responses, so the next call fetches fresh
+    /// data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/ip-pools");
+    }
+}
+
+/// The `/ip-pools` operations of [`IpPoolsSvc`], as a trait.
+///
+/// Application code can depend on `dyn IpPoolsApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait IpPoolsApi: Send + Sync {
+    /// See [`IpPoolsSvc::list`].
+    async fn list(&self) -> crate::Result<Vec<IpPool>>;
+
+    /// See [`IpPoolsSvc::get`].
+    async fn get(&self, pool_id: &str) -> crate::Result<IpPool>;
+
+    /// See [`IpPoolsSvc::assign_domain`].
+    async fn assign_domain(&self, pool_id: &str, domain: &str) -> crate::Result<IpPool>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl IpPoolsApi for IpPoolsSvc {
+    async fn list(&self) -> crate::Result<Vec<IpPool>> {
+        IpPoolsSvc::list(self).await
+    }
+
+    async fn get(&self, pool_id: &str) -> crate::Result<IpPool> {
+        IpPoolsSvc::get(self, pool_id).await
+    }
+
+    async fn assign_domain(&self, pool_id: &str, domain: &str) -> crate::Result<IpPool> {
+        IpPoolsSvc::assign_domain(self, pool_id, domain).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize)]
+struct AssignDomainRequest {
+    domain: String,
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListIpPoolsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListIpPoolsData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListIpPoolsData {
+    pools: Vec<IpPool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IpPoolResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: IpPool,
+}
+
+/// A pool of dedicated sending IPs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct IpPool {
+    /// Pool ID.
+    pub id: String,
+    /// Pool name.
+    pub name: String,
+    /// Whether domains not assigned to any pool send through this one.
+    #[serde(default)]
+    pub is_default: bool,
+    /// Dedicated IPs in the pool.
+    #[serde(default)]
+    pub ips: Vec<DedicatedIp>,
+    /// Sending domains assigned to the pool.
+    #[serde(default)]
+    pub domains: Vec<String>,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl IpPool {
+    /// Creates a pool with the other fields empty. Set them as needed, e.g.
+    /// for a mock [`IpPoolsApi`].
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether every IP in the pool has finished warming up.
+    #[must_use]
+    pub fn is_warm(&self) -> bool {
+        self.ips.iter().all(DedicatedIp::is_warm)
+    }
+}
+
+/// Formats as a one-line summary, e.g. `Marketing (pool_123, 2 IPs, 3
+/// domains)`.
+impl fmt::Display for IpPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ips = if self.ips.len() == 1 { "IP" } else { "IPs" };
+        let domains = if self.domains.len() == 1 {
+            "domain"
+        } else {
+            "domains"
+        };
+        write!(
+            f,
+            "{} ({}, {} {ips}, {} {domains})",
+            self.name,
+            self.id,
+            self.ips.len(),
+            self.domains.len()
+        )
+    }
+}
+
+/// A dedicated sending IP and its warm-up status.
+///
+/// New IPs start with a low daily sending limit that rises over a few weeks,
+/// so mailbox providers can build up trust in them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct DedicatedIp {
+    /// IP address.
+    pub ip: String,
+    /// Reverse DNS hostname.
+    #[serde(default)]
+    pub hostname: Option<String>,
+    /// Warm-up status: `"warming"`, `"warm"`, or `"paused"`.
+    pub warmup_status: String,
+    /// Warm-up progress, from 0 to 100.
+    #[serde(default)]
+    pub warmup_progress: u8,
+    /// Number of emails the IP may send today while warming up, or `None`
+    /// once warm.
+    #[serde(default)]
+    pub daily_limit: Option<u64>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl DedicatedIp {
+    /// Creates an IP entry with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`IpPoolsApi`].
+    pub fn new(ip: impl Into<String>, warmup_status: impl Into<String>) -> Self {
+        Self {
+            ip: ip.into(),
+            warmup_status: warmup_status.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the IP has finished warming up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::ip_pools::DedicatedIp;
+    ///
+    /// assert!(DedicatedIp::new("192.0.2.10", "warm").is_warm());
+    /// assert!(!DedicatedIp::new("192.0.2.11", "warming").is_warm());
+    /// ```
+    #[must_use]
+    pub fn is_warm(&self) -> bool {
+        self.warmup_status == "warm"
+    }
+}
+
+/// Formats as a one-line summary, e.g. `192.0.2.10 (warming, 40%)`.
+impl fmt::Display for DedicatedIp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {}%)",
+            self.ip, self.warmup_status, self.warmup_progress
+        )
+    }
+}
@@ -0,0 +1,134 @@
+//! Conversion from [`lettre::Message`], for code moving from SMTP to the API.
+//!
+//! Only available with the `lettre` feature.
+
+use ::lettre::message::header::{From, ReplyTo, Subject};
+use ::lettre::message::Mailboxes;
+use ::lettre::Message;
+use mail_parser::{MessageParser, MimeHeaders, PartType};
+
+use crate::emails::{Attachment, CreateEmailOptions};
+use crate::error::InvalidOptions;
+
+/// Converts a message built with lettre, so code that builds messages for
+/// SMTP can send them through the API instead.
+///
+/// Maps the first `From` mailbox to the sender, every envelope recipient
+/// (`To`, `Cc`, and `Bcc`) to [`to`](CreateEmailOptions::to), `Reply-To`,
+/// the subject, `In-Reply-To` and `References`, the first `text/plain` and
+/// `text/html` bodies, and all attachments. Inline images are sent as regular
+/// attachments. Other headers, including the `Message-ID` lettre generates,
+/// are dropped.
+///
+/// # Errors
+///
+/// Returns the problems [`CreateEmailOptions::try_build`] finds in the
+/// converted email, e.g. [`Problem::NoContent`](crate::types::Problem::NoContent)
+/// for a message without a body.
+///
+/// # Example
+///
+/// ```
+/// use lettr::CreateEmailOptions;
+///
+/// let message = lettre::Message::builder()
+///     .from("Acme <hello@example.com>".parse().unwrap())
+///     .to("user@example.com".parse().unwrap())
+///     .subject("Welcome!")
+///     .body(String::from("Thanks for signing up."))
+///     .unwrap();
+///
+/// let email = CreateEmailOptions::try_from(message).unwrap();
+/// assert_eq!(email.from_address(), "hello@example.com");
+/// assert_eq!(email.from_name(), Some("Acme"));
+/// assert_eq!(email.to(), ["user@example.com"]);
+/// assert_eq!(email.text(), Some("Thanks for signing up."));
+/// ```
+impl TryFrom<&Message> for CreateEmailOptions {
+    type Error = InvalidOptions;
+
+    fn try_from(message: &Message) -> Result<Self, Self::Error> {
+        let headers = message.headers();
+        let sender = headers
+            .get::<From>()
+            .and_then(|from| Mailboxes::from(from).into_iter().next());
+        let subject = headers
+            .get::<Subject>()
+            .map(|subject| subject.as_ref().to_owned())
+            .unwrap_or_default();
+        let recipients = message.envelope().to().iter().map(ToString::to_string);
+
+        let from = sender
+            .as_ref()
+            .map(|mailbox| mailbox.email.to_string())
+            .unwrap_or_default();
+        let mut email = CreateEmailOptions::new(from, recipients, subject);
+        if let Some(name) = sender.and_then(|mailbox| mailbox.name) {
+            email = email.with_from_name(name);
+        }
+        if let Some(reply_to) = headers.get::<ReplyTo>() {
+            for mailbox in Mailboxes::from(reply_to) {
+                email = email.with_reply_to(mailbox.email.to_string());
+            }
+        }
+
+        let formatted = message.formatted();
+        if let Some(parsed) = MessageParser::default().parse(&formatted) {
+            if let Some(references) = parsed.references().as_text_list() {
+                email = email.with_references(references.iter().map(ToString::to_string));
+            }
+            if let Some(in_reply_to) = parsed.in_reply_to().as_text() {
+                email = email.with_in_reply_to(in_reply_to);
+            }
+            // `text_body` falls back to HTML parts, and `html_body` to text
+            // parts, when a message lacks one of them.
+            let text = parsed
+                .text_body
+                .iter()
+                .filter_map(|&id| parsed.part(id))
+                .find_map(|part| match &part.body {
+                    PartType::Text(text) => Some(text.as_ref()),
+                    _ => None,
+                });
+            if let Some(text) = text {
+                email = email.with_text(text);
+            }
+            let html = parsed
+                .html_body
+                .iter()
+                .filter_map(|&id| parsed.part(id))
+                .find_map(|part| match &part.body {
+                    PartType::Html(html) => Some(html.as_ref()),
+                    _ => None,
+                });
+            if let Some(html) = html {
+                email = email.with_html(html);
+            }
+            for part in parsed.attachments() {
+                let content_type = part.content_type().map_or_else(
+                    || "application/octet-stream".to_owned(),
+                    |content_type| match &content_type.c_subtype {
+                        Some(subtype) => format!("{}/{subtype}", content_type.c_type),
+                        None => content_type.c_type.to_string(),
+                    },
+                );
+                email = email.with_attachment(Attachment::from_bytes(
+                    part.attachment_name().unwrap_or("attachment"),
+                    content_type,
+                    part.contents(),
+                ));
+            }
+        }
+
+        email.try_build()
+    }
+}
+
+/// See the conversion from `&Message`.
+impl TryFrom<Message> for CreateEmailOptions {
+    type Error = InvalidOptions;
+
+    fn try_from(message: Message) -> Result<Self, Self::Error> {
+        Self::try_from(&message)
+    }
+}
@@ -1,46 +1,181 @@
 #![forbid(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
-pub use client::Lettr;
+pub use client::{Lettr, LettrBuilder, Region, RequestOptions};
 pub use emails::{Attachment, CreateEmailOptions};
 pub use error::Error;
+pub use meta::WithMeta;
 
+/// The async Lettr API client; an alias of [`Lettr`] matching
+/// `blocking::Client`.
+pub type Client = Lettr;
+
+#[cfg(feature = "secrecy")]
+pub use secrecy;
+
+mod api_key;
+pub mod assets;
+pub mod audiences;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+pub mod blocklists;
+pub mod broadcasts;
+pub mod cache;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cassette;
+pub mod circuit_breaker;
 mod client;
+pub mod clock;
+mod concurrency;
 pub(crate) mod config;
+pub mod contacts;
+mod de;
+#[cfg(feature = "miette")]
+pub mod diagnostics;
 pub mod domains;
 pub mod emails;
 pub mod error;
+pub mod event_stream;
+pub mod exports;
+#[cfg(feature = "lettre")]
+pub mod failover;
+pub mod filter;
+pub mod ics;
+pub mod inbound;
+pub mod ip_pools;
+#[cfg(feature = "lettre")]
+mod lettre;
+pub mod meta;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod middleware;
+#[cfg(feature = "mjml")]
+mod mjml;
+mod profile;
+pub mod queue;
+pub mod rate_limit;
+#[cfg(any(feature = "askama", feature = "tera"))]
+mod render;
+pub mod retry;
+pub mod send_job;
+pub mod send_window;
+pub mod smtp;
+pub mod stats;
+pub mod team;
 pub mod templates;
+#[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+pub mod testing;
+mod time;
+#[cfg(all(
+    any(feature = "rustls", feature = "native-tls"),
+    not(target_arch = "wasm32")
+))]
+pub mod tls;
+#[cfg(feature = "tower")]
+pub mod tower;
+pub mod transport;
+pub mod unsubscribe_groups;
 pub mod webhooks;
+mod wire_log;
 
 pub mod services {
     //! Re-exports of all service types for convenient access.
+    //!
+    //! Each service implements a trait (e.g. [`EmailsApi`]) that application
+    //! code can depend on to swap in a mock in tests.
 
-    pub use super::domains::DomainsSvc;
-    pub use super::emails::EmailsSvc;
-    pub use super::templates::TemplatesSvc;
-    pub use super::webhooks::WebhooksSvc;
+    pub use super::assets::{AssetsApi, AssetsSvc};
+    pub use super::audiences::{AudiencesApi, AudiencesSvc};
+    pub use super::blocklists::{BlocklistsApi, BlocklistsSvc};
+    pub use super::broadcasts::{BroadcastsApi, BroadcastsSvc};
+    pub use super::contacts::{ContactsApi, ContactsSvc};
+    pub use super::domains::{DomainsApi, DomainsSvc};
+    pub use super::emails::{EmailSender, EmailsApi, EmailsSvc};
+    pub use super::exports::{ExportsApi, ExportsSvc};
+    pub use super::inbound::{InboundApi, InboundSvc};
+    pub use super::ip_pools::{IpPoolsApi, IpPoolsSvc};
+    pub use super::smtp::{SmtpCredentialsApi, SmtpCredentialsSvc};
+    pub use super::stats::{StatsApi, StatsSvc};
+    pub use super::team::{TeamApi, TeamSvc};
+    pub use super::templates::{TemplatesApi, TemplatesSvc};
+    pub use super::unsubscribe_groups::{UnsubscribeGroupsApi, UnsubscribeGroupsSvc};
+    pub use super::webhooks::{WebhooksApi, WebhooksSvc};
 }
 
 pub mod types {
     //! Re-exports of commonly used request and response types.
 
     // Client
-    pub use super::client::{AuthCheckData, AuthCheckResponse, HealthData, HealthResponse};
+    pub use super::client::{
+        AuthCheckData, AuthCheckResponse, HealthData, HealthResponse, Readiness,
+    };
 
     // Emails
     pub use super::emails::{
-        Attachment, CreateEmailOptions, EmailEvent, EmailEventDetail, EmailOptions,
-        GetEmailResponse, ListEmailsOptions, ListEmailsResponse, Pagination, SendEmailResponse,
+        Attachment, Bounce, BounceCategory, CreateEmailOptions, EmailEvent, EmailEventDetail,
+        EmailEvents, EmailOptions, EnhancedStatusCode, EventType, GetEmailResponse, LinkClicks,
+        ListBouncesOptions, ListBouncesResponse, ListEmailsOptions, ListEmailsResponse, Pagination,
+        RawJson, RecipientErasure, SendEmailResponse,
     };
 
+    // Filters
+    pub use super::filter::{DateRange, Timestamp};
+
     // Domains
     pub use super::domains::{
         CreateDomainResponse, DkimDnsRecord, DkimInfo, DnsRecords, Domain, DomainDetail,
     };
 
     // Webhooks
-    pub use super::webhooks::Webhook;
+    pub use super::webhooks::{Webhook, WebhookSecret, WebhookUrlValidation};
+
+    // Contacts
+    pub use super::contacts::{
+        Contact, ContactPagination, CreateContactOptions, ListContactsOptions,
+        ListContactsResponse, UpdateContactOptions,
+    };
+
+    // Audiences
+    pub use super::audiences::{Audience, CreateAudienceOptions};
+
+    // Broadcasts
+    pub use super::broadcasts::{
+        Broadcast, BroadcastPagination, BroadcastStats, BroadcastStatus, CreateBroadcastOptions,
+        ListBroadcastsOptions, ListBroadcastsResponse,
+    };
+
+    // Inbound
+    pub use super::inbound::{
+        CreateInboundRouteOptions, CreateInboundRuleOptions, InboundAttachment, InboundHeader,
+        InboundMessage, InboundRoute, InboundRule, ListInboundMessagesOptions,
+        ListInboundMessagesResponse,
+    };
+
+    // IP Pools
+    pub use super::ip_pools::{DedicatedIp, IpPool};
+
+    // Team
+    pub use super::team::{InviteTeamMemberOptions, TeamInvitation, TeamMember, TeamRole};
+
+    // Exports
+    pub use super::exports::{CreateExportOptions, ExportFormat, ExportJob};
+
+    // Blocklists
+    pub use super::blocklists::{BlocklistListing, BlocklistStatus};
+
+    // Assets
+    pub use super::assets::{Asset, UploadAssetOptions};
+
+    // Unsubscribe Groups
+    pub use super::unsubscribe_groups::{
+        CreateUnsubscribeGroupOptions, GroupPreference, RecipientPreferences, UnsubscribeGroup,
+    };
+
+    // SMTP
+    pub use super::smtp::{CreateSmtpCredentialOptions, NewSmtpCredential, SmtpCredential};
+
+    // Stats
+    pub use super::stats::{StatsBucket, StatsCounts, StatsInterval, StatsOptions, StatsResponse};
 
     // Templates
     pub use super::templates::{
@@ -49,7 +184,47 @@ pub mod types {
     };
 
     // Errors
-    pub use super::error::{ApiError, ValidationError};
+    pub use super::error::{ApiError, InvalidOptions, ParseError, Problem, ValidationError};
+}
+
+pub mod prelude {
+    //! The types most applications need, importable in one go.
+    //!
+    //! [`Result`](crate::Result) is left out so the glob import does not
+    //! shadow [`std::result::Result`].
+    //!
+    //! ```
+    //! use lettr::prelude::*;
+    //! ```
+
+    pub use super::audiences::{Audience, CreateAudienceOptions};
+    pub use super::broadcasts::{
+        Broadcast, BroadcastStats, CreateBroadcastOptions, ListBroadcastsOptions,
+        ListBroadcastsResponse,
+    };
+    pub use super::client::{Lettr, LettrBuilder, Region, RequestOptions};
+    pub use super::contacts::{
+        Contact, CreateContactOptions, ListContactsOptions, ListContactsResponse,
+        UpdateContactOptions,
+    };
+    pub use super::domains::{CreateDomainResponse, Domain, DomainDetail};
+    pub use super::emails::{
+        Attachment, CreateEmailOptions, EmailEvent, EmailEventDetail, EmailEvents,
+        GetEmailResponse, ListEmailsOptions, ListEmailsResponse, SendEmailResponse,
+    };
+    pub use super::error::{ApiError, Error, ValidationError};
+    pub use super::meta::WithMeta;
+    pub use super::services::{
+        AudiencesApi, BroadcastsApi, ContactsApi, DomainsApi, EmailsApi, StatsApi, TemplatesApi,
+        WebhooksApi,
+    };
+    pub use super::stats::{StatsInterval, StatsOptions, StatsResponse};
+    pub use super::templates::{
+        CreateTemplateOptions, CreateTemplateResponse, ListTemplatesOptions, ListTemplatesResponse,
+        Template,
+    };
+    pub use super::webhooks::Webhook;
+    pub use super::Client;
 }
 
 /// Specialized [`Result`] type for [`Error`].
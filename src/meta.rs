@@ -0,0 +1,110 @@
+//! Response metadata returned by the `*_with_meta` service methods.
+
+use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::rate_limit::RateLimitInfo;
+use crate::transport::{Bytes, Response};
+
+/// Header carrying the server-side request ID.
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Header carrying the API version, sent when a client pins one with
+/// [`LettrBuilder::api_version`](crate::LettrBuilder::api_version).
+pub const API_VERSION_HEADER: HeaderName = HeaderName::from_static("lettr-version");
+
+/// Deserialized response data together with the HTTP metadata of the response.
+///
+/// Returned by the `*_with_meta` variants of the service methods, e.g.
+/// [`EmailsSvc::send_with_meta`](crate::services::EmailsSvc::send_with_meta).
+/// Include [`request_id`](WithMeta::request_id) in support tickets so Lettr
+/// can find the exact request.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use lettr::{Lettr, CreateEmailOptions};
+/// # async fn run() -> lettr::Result<()> {
+/// let client = Lettr::new("your-api-key");
+///
+/// let email = CreateEmailOptions::new("sender@example.com", ["user@example.com"], "Hello!")
+///     .with_text("Hello!");
+///
+/// let response = client.emails.send_with_meta(email).await?;
+/// println!(
+///     "{} (status {}, server request {:?}, {:?} requests left)",
+///     response.data.request_id,
+///     response.status,
+///     response.request_id,
+///     response.rate_limit.remaining,
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithMeta<T> {
+    /// The deserialized response data.
+    pub data: T,
+    /// HTTP status code of the response.
+    pub status: StatusCode,
+    /// All response headers.
+    pub headers: HeaderMap,
+    /// Server-side request ID from the `X-Request-Id` header.
+    pub request_id: Option<String>,
+    /// Rate-limit headers sent with the response.
+    pub rate_limit: RateLimitInfo,
+    body: Bytes,
+}
+
+impl<T> WithMeta<T> {
+    pub(crate) fn new(data: T, response: Response) -> Self {
+        Self {
+            data,
+            request_id: request_id(&response.headers),
+            rate_limit: RateLimitInfo::from_headers(&response.headers),
+            status: response.status,
+            headers: response.headers,
+            body: response.body,
+        }
+    }
+
+    /// Returns the status message the API sent alongside the data, if any.
+    #[must_use]
+    pub fn message(&self) -> Option<String> {
+        #[derive(Deserialize)]
+        struct Message {
+            message: Option<String>,
+        }
+
+        serde_json::from_slice::<Message>(&self.body)
+            .ok()
+            .and_then(|m| m.message)
+    }
+
+    /// Transforms the data, keeping the metadata.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> WithMeta<U> {
+        WithMeta {
+            data: f(self.data),
+            status: self.status,
+            headers: self.headers,
+            request_id: self.request_id,
+            rate_limit: self.rate_limit,
+            body: self.body,
+        }
+    }
+
+    /// Discards the metadata and returns the data.
+    #[must_use]
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+/// Returns the server-side request ID of a response.
+pub(crate) fn request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
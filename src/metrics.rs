@@ -0,0 +1,202 @@
+//! Request metrics emitted through the [`metrics`](https://docs.rs/metrics) facade.
+//!
+//! Available with the `metrics` feature. Install any `metrics` recorder (for
+//! example `metrics-exporter-prometheus`) and the client reports:
+//!
+//! | Metric                           | Type      | Labels                              |
+//! |----------------------------------|-----------|-------------------------------------|
+//! | [`REQUESTS_TOTAL`]               | counter   | `method`, `endpoint`, `status_class` |
+//! | [`RETRIES_TOTAL`]                | counter   | `method`, `endpoint`                |
+//! | [`REQUEST_DURATION_SECONDS`]     | histogram | `method`, `endpoint`                |
+//! | [`REQUEST_BODY_BYTES`]           | histogram | `method`, `endpoint`                |
+//! | [`RESPONSE_BODY_BYTES`]          | histogram | `method`, `endpoint`                |
+//!
+//! `endpoint` is the route template (e.g. `/emails/{id}`) rather than the
+//! concrete path, keeping label cardinality bounded. `status_class` is `2xx`,
+//! `4xx`, `5xx`, etc., or `error` when no response was received.
+
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+/// Number of requests sent, by response status class.
+pub const REQUESTS_TOTAL: &str = "lettr_requests_total";
+/// Number of automatic retries.
+pub const RETRIES_TOTAL: &str = "lettr_retries_total";
+/// Time from sending a request until its response headers arrived.
+pub const REQUEST_DURATION_SECONDS: &str = "lettr_request_duration_seconds";
+/// Size of request bodies in bytes.
+pub const REQUEST_BODY_BYTES: &str = "lettr_request_body_bytes";
+/// Size of response bodies in bytes.
+pub const RESPONSE_BODY_BYTES: &str = "lettr_response_body_bytes";
+
+/// Labels identifying the endpoint a request was sent to.
+#[derive(Debug, Clone)]
+pub(crate) struct Endpoint {
+    method: String,
+    endpoint: String,
+}
+
+impl Endpoint {
+    /// Labels a request with its route template, e.g. `/emails/{id}`, set
+    /// where the request is built.
+    pub fn new(method: &Method, route: &str) -> Self {
+        Self {
+            method: method.as_str().to_owned(),
+            endpoint: route.to_owned(),
+        }
+    }
+
+    fn labels(&self) -> [(&'static str, String); 2] {
+        [
+            ("method", self.method.clone()),
+            ("endpoint", self.endpoint.clone()),
+        ]
+    }
+
+    /// Record the size of an outgoing request body.
+    pub fn record_request_body(&self, len: usize) {
+        ::metrics::histogram!(REQUEST_BODY_BYTES, &self.labels()).record(len as f64);
+    }
+
+    /// Record a received response.
+    pub fn record_response(&self, status: StatusCode, elapsed: Duration, len: usize) {
+        let class = format!("{}xx", status.as_u16() / 100);
+        self.record_completed(class, elapsed);
+        ::metrics::histogram!(RESPONSE_BODY_BYTES, &self.labels()).record(len as f64);
+    }
+
+    /// Record a request that failed without a response.
+    pub fn record_failure(&self, elapsed: Duration) {
+        self.record_completed("error".to_owned(), elapsed);
+    }
+
+    fn record_completed(&self, status_class: String, elapsed: Duration) {
+        let [method, endpoint] = self.labels();
+        ::metrics::counter!(
+            REQUESTS_TOTAL,
+            &[
+                method.clone(),
+                endpoint.clone(),
+                ("status_class", status_class)
+            ]
+        )
+        .increment(1);
+        ::metrics::histogram!(REQUEST_DURATION_SECONDS, &[method, endpoint])
+            .record(elapsed.as_secs_f64());
+    }
+
+    /// Record an automatic retry.
+    pub fn record_retry(&self) {
+        ::metrics::counter!(RETRIES_TOTAL, &self.labels()).increment(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use metrics::{
+        Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+    };
+
+    use super::REQUESTS_TOTAL;
+    use crate::emails::ListBouncesOptions;
+    use crate::inbound::ListInboundMessagesOptions;
+    use crate::transport::{async_trait, HttpTransport, Request, Response, StatusCode};
+    use crate::Lettr;
+
+    /// Keeps the `method` and `endpoint` labels of every counted request.
+    #[derive(Default)]
+    struct Endpoints(Mutex<Vec<String>>);
+
+    impl Recorder for Endpoints {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            if key.name() == REQUESTS_TOTAL {
+                let label = |name| {
+                    key.labels()
+                        .find(|label| label.key() == name)
+                        .map(|label| label.value().to_owned())
+                        .unwrap_or_default()
+                };
+                let endpoint = format!("{} {}", label("method"), label("endpoint"));
+                self.0.lock().unwrap().push(endpoint);
+            }
+            Counter::noop()
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::noop()
+        }
+    }
+
+    /// Answers every request with an empty success response.
+    struct Empty;
+
+    #[async_trait]
+    impl HttpTransport for Empty {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            Ok(Response::new(StatusCode::OK, Default::default(), "{}"))
+        }
+    }
+
+    #[tokio::test]
+    async fn static_sub_resources_are_not_labeled_as_ids() {
+        let recorder = Endpoints::default();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+        let client = Lettr::builder("test-key").transport(Empty).build();
+
+        // Only the labels matter; the empty responses fail to decode.
+        let _ = client.emails.get("request-1").await;
+        let _ = client
+            .emails
+            .list_bounces(ListBouncesOptions::new("2024-01-01", "2024-01-31"))
+            .await;
+        let _ = client.emails.get_recipient_erasure("erasure-1").await;
+        let _ = client.team.list_members().await;
+        let _ = client.team.list_roles().await;
+        let _ = client.team.list_invitations().await;
+        let _ = client
+            .inbound
+            .list_messages(ListInboundMessagesOptions::new())
+            .await;
+        let _ = client.inbound.list_routes().await;
+        let _ = client.inbound.list_rules().await;
+        let _ = client.webhooks.get("webhook-1").await;
+        let _ = client.webhooks.validate_url("https://example.com").await;
+        let _ = client.webhooks.rotate_signing_secret("webhook-1").await;
+        let _ = client.unsubscribe_groups.get("group-1").await;
+        let _ = client
+            .unsubscribe_groups
+            .preferences("user@example.com")
+            .await;
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [
+                "GET /emails/{id}",
+                "GET /emails/bounces",
+                "GET /emails/erasures/{id}",
+                "GET /team/members",
+                "GET /team/roles",
+                "GET /team/invitations",
+                "GET /inbound/messages",
+                "GET /inbound/routes",
+                "GET /inbound/rules",
+                "GET /webhooks/{id}",
+                "POST /webhooks/validate",
+                "POST /webhooks/{id}/secret/rotate",
+                "GET /unsubscribe-groups/{id}",
+                "GET /unsubscribe-groups/preferences",
+            ]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Request and response hooks.
+//!
+//! A [`Middleware`] registered with
+//! [`LettrBuilder::middleware`](crate::LettrBuilder::middleware) sees every
+//! request right before it is sent and every response right after it arrives.
+//! This is enough to add correlation IDs, custom signing, or latency logging
+//! without forking the crate.
+//!
+//! Error hooks registered with
+//! [`LettrBuilder::on_error`](crate::LettrBuilder::on_error) see every failed
+//! call once, after retries, together with a [`RequestContext`].
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use crate::transport::{Method, Request, Response};
+
+/// Hooks invoked around every HTTP request sent by a client.
+///
+/// Both methods have empty default implementations, so implementors only
+/// override the hooks they need. Hooks run once per attempt, so a retried
+/// request passes through them again.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use lettr::Lettr;
+/// use lettr::middleware::{Middleware, Request, Response};
+///
+/// struct CorrelationId;
+///
+/// impl Middleware for CorrelationId {
+///     fn on_request(&self, request: &mut Request) {
+///         request
+///             .headers
+///             .insert("x-correlation-id", "job-42".parse().unwrap());
+///     }
+///
+///     fn on_response(&self, response: &Response, elapsed: Duration) {
+///         println!("{} in {elapsed:?}", response.status);
+///     }
+/// }
+///
+/// let client = Lettr::builder("your-api-key")
+///     .middleware(CorrelationId)
+///     .build();
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called with the fully built request right before it is handed to the
+    /// [`HttpTransport`](crate::transport::HttpTransport).
+    fn on_request(&self, request: &mut Request) {
+        let _ = request;
+    }
+
+    /// Called when a response arrives, with the time elapsed since sending.
+    ///
+    /// This is not called when the request failed without a response, but it
+    /// is called for error statuses.
+    fn on_response(&self, response: &Response, elapsed: Duration) {
+        let _ = (response, elapsed);
+    }
+}
+
+impl fmt::Debug for dyn Middleware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Middleware")
+    }
+}
+
+/// Describes a failed call to an error hook.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RequestContext {
+    /// Request method.
+    pub method: Method,
+    /// Endpoint path relative to the API base URL, without the query string,
+    /// e.g. `/emails/abc123`.
+    pub path: String,
+    /// Number of attempts made, including retries. `0` if the call failed
+    /// before a request was sent, e.g. because the client was shut down.
+    pub attempts: u32,
+    /// Time from the start of the call until it failed, including retry
+    /// delays.
+    pub elapsed: Duration,
+}
+
+type ErrorHookFn = dyn Fn(&crate::Error, &RequestContext) + Send + Sync;
+
+/// Callback registered with [`LettrBuilder::on_error`](crate::LettrBuilder::on_error).
+#[derive(Clone)]
+pub(crate) struct ErrorHook(Arc<ErrorHookFn>);
+
+impl ErrorHook {
+    pub fn new(hook: impl Fn(&crate::Error, &RequestContext) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+
+    pub fn call(&self, err: &crate::Error, context: &RequestContext) {
+        (self.0)(err, context);
+    }
+}
+
+impl fmt::Debug for ErrorHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ErrorHook")
+    }
+}
@@ -0,0 +1,56 @@
+//! Compilation of [MJML](https://mjml.io) markup into responsive HTML, using
+//! [`mrml`](https://docs.rs/mrml).
+//!
+//! Only available with the `mjml` feature.
+
+use mrml::prelude::render::RenderOptions;
+
+use crate::emails::CreateEmailOptions;
+use crate::templates::CreateTemplateOptions;
+use crate::Error;
+
+fn compile(source: &str) -> crate::Result<String> {
+    let parsed = mrml::parse(source).map_err(|e| Error::Render(Box::new(e)))?;
+    parsed
+        .element
+        .render(&RenderOptions::default())
+        .map_err(|e| Error::Render(Box::new(e)))
+}
+
+impl CreateEmailOptions {
+    /// Compiles MJML source to HTML and sets the result as the HTML body.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Render`] if `mjml` cannot be parsed or compiled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let email = CreateEmailOptions::new("hello@example.com", ["user@example.com"], "Welcome!")
+    ///     .with_mjml(
+    ///         "<mjml><mj-body><mj-section><mj-column>\
+    ///          <mj-text>Welcome, Ada!</mj-text>\
+    ///          </mj-column></mj-section></mj-body></mjml>",
+    ///     )?;
+    /// assert!(email.html().unwrap().contains("Welcome, Ada!"));
+    /// # Ok::<(), lettr::Error>(())
+    /// ```
+    pub fn with_mjml(self, mjml: impl AsRef<str>) -> crate::Result<Self> {
+        Ok(self.with_html(compile(mjml.as_ref())?))
+    }
+}
+
+impl CreateTemplateOptions {
+    /// Compiles MJML source to HTML and sets the result as the template's
+    /// HTML content.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Render`] if `mjml` cannot be parsed or compiled.
+    pub fn with_mjml(self, mjml: impl AsRef<str>) -> crate::Result<Self> {
+        Ok(self.with_html(compile(mjml.as_ref())?))
+    }
+}
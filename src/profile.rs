@@ -0,0 +1,120 @@
+//! Loading client settings from a TOML file or prefixed environment variables.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::client::{LettrBuilder, Region};
+
+/// Client settings that can be loaded from outside the code.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Profile {
+    api_key: Option<String>,
+    region: Option<String>,
+    base_url: Option<String>,
+    api_version: Option<String>,
+    timeout_secs: Option<f64>,
+    connect_timeout_secs: Option<f64>,
+    max_retries: Option<u32>,
+}
+
+impl Profile {
+    /// Reads a profile from a TOML file.
+    #[cfg(all(feature = "toml", not(target_arch = "wasm32")))]
+    pub fn from_file(path: &std::path::Path) -> crate::Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| crate::Error::Config(format!("failed to read {}: {e}", path.display())))?;
+        toml::from_str(&source)
+            .map_err(|e| crate::Error::Config(format!("invalid config {}: {e}", path.display())))
+    }
+
+    /// Reads a profile from environment variables named `{prefix}API_KEY`,
+    /// `{prefix}REGION`, and so on.
+    pub fn from_env(prefix: &str) -> crate::Result<Self> {
+        let var = |name: &str| std::env::var(format!("{prefix}{name}")).ok();
+        let parse = |name: &str| -> crate::Result<Option<f64>> {
+            var(name)
+                .map(|value| {
+                    value.parse().map_err(|_| {
+                        crate::Error::Config(format!("{prefix}{name} must be a number"))
+                    })
+                })
+                .transpose()
+        };
+
+        Ok(Self {
+            api_key: var("API_KEY"),
+            region: var("REGION"),
+            base_url: var("BASE_URL"),
+            api_version: var("API_VERSION"),
+            timeout_secs: parse("TIMEOUT_SECS")?,
+            connect_timeout_secs: parse("CONNECT_TIMEOUT_SECS")?,
+            max_retries: var("MAX_RETRIES")
+                .map(|value| {
+                    value.parse().map_err(|_| {
+                        crate::Error::Config(format!("{prefix}MAX_RETRIES must be an integer"))
+                    })
+                })
+                .transpose()?,
+        })
+    }
+
+    /// Turns the profile into a builder. `source` names the file or prefix in
+    /// error messages.
+    pub fn into_builder(self, source: &str) -> crate::Result<LettrBuilder> {
+        let api_key = self
+            .api_key
+            .ok_or_else(|| crate::Error::Config(format!("no API key set in {source}")))?;
+        let mut builder = LettrBuilder::new(api_key);
+
+        let region = match (self.base_url, self.region.as_deref()) {
+            (Some(base_url), _) => Region::Custom(base_url),
+            (None, Some(region)) if region.eq_ignore_ascii_case("us") => Region::Us,
+            (None, Some(region)) => {
+                return Err(crate::Error::Config(format!(
+                    "unknown region `{region}` in {source}, expected `us`"
+                )));
+            }
+            (None, None) => Region::default(),
+        };
+        builder = builder.region(region);
+
+        if let Some(version) = self.api_version {
+            builder = builder.api_version(version);
+        }
+        if let Some(max_retries) = self.max_retries {
+            builder = builder.max_retries(max_retries);
+        }
+
+        let timeout = secs(self.timeout_secs, "timeout", source)?;
+        let connect_timeout = secs(self.connect_timeout_secs, "connect timeout", source)?;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(timeout) = timeout {
+                builder = builder.timeout(timeout);
+            }
+            if let Some(timeout) = connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        if timeout.is_some() || connect_timeout.is_some() {
+            return Err(crate::Error::Config(
+                "timeouts are not supported on WASM targets".to_owned(),
+            ));
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Converts a number of seconds into a [`Duration`].
+fn secs(value: Option<f64>, what: &str, source: &str) -> crate::Result<Option<Duration>> {
+    value
+        .map(|secs| {
+            Duration::try_from_secs_f64(secs)
+                .map_err(|_| crate::Error::Config(format!("invalid {what} `{secs}` in {source}")))
+        })
+        .transpose()
+}
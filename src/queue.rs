@@ -0,0 +1,437 @@
+//! Durable queue for sending emails while the API is unreachable.
+//!
+//! [`SendQueue::enqueue`] persists an email to a [`QueueStore`] and returns
+//! immediately. A worker calling [`SendQueue::drain`] or [`SendQueue::run`]
+//! then sends the queued emails, keeping those that failed for a later pass
+//! with exponential backoff. An email is only removed from the store once the
+//! API accepted it, or failed it with an error that is not
+//! [retryable](crate::Error::is_retryable).
+//!
+//! [`FileStore`] keeps one JSON file per email in a directory. Implement
+//! [`QueueStore`] to keep the queue in a database instead.
+//!
+//! Only one worker should drain a store at a time, otherwise an email may be
+//! sent twice.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::clock::unix_now;
+use crate::emails::{CreateEmailOptions, EmailsSvc};
+use crate::{Error, Lettr};
+
+/// Upper bound for the delay between two attempts of a queued email.
+const MAX_BACKOFF_SECS: u64 = 60 * 60;
+
+/// An email waiting in a [`QueueStore`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedEmail {
+    /// Unique ID of the entry; IDs sort in enqueue order.
+    pub id: String,
+    /// The email to send.
+    pub email: CreateEmailOptions,
+    /// Number of failed send attempts so far.
+    pub attempts: u32,
+    /// Unix timestamp (seconds) before which the email is not retried.
+    pub next_attempt_at: u64,
+}
+
+/// Storage backend of a [`SendQueue`].
+///
+/// Implementations must persist an entry before [`push`](Self::push) returns
+/// so queued emails survive a process restart.
+pub trait QueueStore: Send + Sync {
+    /// Stores `entry`, replacing an existing entry with the same ID.
+    fn push(&self, entry: &QueuedEmail) -> crate::Result<()>;
+
+    /// Returns all stored entries, ordered by ID.
+    fn pending(&self) -> crate::Result<Vec<QueuedEmail>>;
+
+    /// Removes the entry with the given ID. Removing a missing entry is not
+    /// an error.
+    fn remove(&self, id: &str) -> crate::Result<()>;
+}
+
+impl fmt::Debug for dyn QueueStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("QueueStore")
+    }
+}
+
+/// A [`QueueStore`] keeping one JSON file per email in a directory.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStore {
+    /// Creates a [`FileStore`] in `dir`, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Queue`] if the directory cannot be created.
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> crate::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir).map_err(|e| Error::Queue(Box::new(e)))?;
+        Ok(Self { dir })
+    }
+
+    fn path(&self, id: &str) -> std::path::PathBuf {
+        self.dir.join(format!("{id}.json"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl QueueStore for FileStore {
+    fn push(&self, entry: &QueuedEmail) -> crate::Result<()> {
+        use std::io::Write;
+
+        let json = serde_json::to_vec(entry).map_err(|e| Error::Queue(Box::new(e)))?;
+
+        // Write to a temporary file first, so a crash never leaves a
+        // half-written entry behind.
+        let tmp = self.dir.join(format!("{}.tmp", entry.id));
+        let write = || -> std::io::Result<()> {
+            let mut file = std::fs::File::create(&tmp)?;
+            file.write_all(&json)?;
+            file.sync_all()?;
+            std::fs::rename(&tmp, self.path(&entry.id))
+        };
+        write().map_err(|e| Error::Queue(Box::new(e)))
+    }
+
+    fn pending(&self) -> crate::Result<Vec<QueuedEmail>> {
+        let dir = std::fs::read_dir(&self.dir).map_err(|e| Error::Queue(Box::new(e)))?;
+
+        let mut entries = Vec::new();
+        for file in dir {
+            let path = file.map_err(|e| Error::Queue(Box::new(e)))?.path();
+            if path.extension().map_or(true, |ext| ext != "json") {
+                continue;
+            }
+            let json = std::fs::read(&path).map_err(|e| Error::Queue(Box::new(e)))?;
+            match serde_json::from_slice(&json) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => log::warn!("skipping unreadable queue entry {}: {e}", path.display()),
+            }
+        }
+
+        entries.sort_by(|a: &QueuedEmail, b| a.id.cmp(&b.id));
+        Ok(entries)
+    }
+
+    fn remove(&self, id: &str) -> crate::Result<()> {
+        match std::fs::remove_file(self.path(id)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(Error::Queue(Box::new(e))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Outcome of a [`SendQueue::drain`] pass.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DrainReport {
+    /// Number of emails the API accepted.
+    pub sent: usize,
+    /// Emails that failed with an error that is not
+    /// [retryable](Error::is_retryable), e.g. a `400` or `422` response. They
+    /// were removed from the store, since sending them again would fail the
+    /// same way.
+    pub rejected: Vec<(QueuedEmail, Error)>,
+    /// Number of emails still waiting in the store.
+    pub remaining: usize,
+}
+
+/// A durable send queue backed by a [`QueueStore`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use lettr::{Lettr, CreateEmailOptions};
+/// use lettr::queue::{FileStore, SendQueue};
+///
+/// # async fn run() -> lettr::Result<()> {
+/// let client = Lettr::new("your-api-key");
+/// let queue = SendQueue::new(&client, FileStore::new("/var/lib/myapp/outbox")?);
+///
+/// let email = CreateEmailOptions::new("sender@example.com", ["user@example.com"], "Hello!")
+///     .with_text("Hello!");
+/// queue.enqueue(email)?;
+///
+/// // Send queued emails every 10 seconds in the background.
+/// tokio::spawn({
+///     let queue = queue.clone();
+///     async move { queue.run(Duration::from_secs(10)).await }
+/// });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SendQueue {
+    emails: EmailsSvc,
+    store: Arc<dyn QueueStore>,
+    counter: Arc<AtomicU32>,
+}
+
+impl SendQueue {
+    /// Creates a [`SendQueue`] sending through `client`.
+    pub fn new(client: &Lettr, store: impl QueueStore + 'static) -> Self {
+        Self {
+            emails: client.emails.clone(),
+            store: Arc::new(store),
+            counter: Arc::new(AtomicU32::new(0)),
+        }
+    }
+
+    /// Persists `email` to the store and returns the ID of its entry.
+    ///
+    /// The email is sent by the next [`drain`](Self::drain). Emails can still
+    /// be enqueued after the client was shut down; they are sent once a new
+    /// worker drains the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot persist the email.
+    pub fn enqueue(&self, email: CreateEmailOptions) -> crate::Result<String> {
+        let now = unix_now(self.emails.0.clock()).unwrap_or_default();
+        let sequence = self.counter.fetch_add(1, Ordering::Relaxed);
+        let entry = QueuedEmail {
+            id: format!("{:020}-{sequence:010}", now.as_nanos()),
+            email,
+            attempts: 0,
+            next_attempt_at: 0,
+        };
+        self.store.push(&entry)?;
+        Ok(entry.id)
+    }
+
+    /// Sends all queued emails that are due.
+    ///
+    /// Stops at the first email that failed with a
+    /// [retryable](Error::is_retryable) error, since the API is likely
+    /// unreachable; that email is retried after a backoff delay. Emails
+    /// failing with other errors are rejected and removed.
+    ///
+    /// An email the API answered with a `2xx` status is removed even if the
+    /// response could not be parsed, since sending it again would deliver it
+    /// twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read or updated.
+    pub async fn drain(&self) -> crate::Result<DrainReport> {
+        let pending = self.store.pending()?;
+        let now = unix_now(self.emails.0.clock())
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut report = DrainReport {
+            sent: 0,
+            rejected: Vec::new(),
+            remaining: pending.len(),
+        };
+
+        for mut entry in pending {
+            if self.emails.0.is_shut_down() {
+                break;
+            }
+            if entry.next_attempt_at > now {
+                continue;
+            }
+
+            match self.emails.send(entry.email.clone()).await {
+                Ok(_) => {
+                    self.store.remove(&entry.id)?;
+                    report.sent += 1;
+                }
+                Err(err) if err.status().is_some_and(|status| status.is_success()) => {
+                    log::warn!("queued email {} accepted, but: {err}", entry.id);
+                    self.store.remove(&entry.id)?;
+                    report.sent += 1;
+                }
+                // The email was not sent, so keep it for the next worker.
+                Err(Error::Shutdown) => break,
+                Err(err) if !err.is_retryable() => {
+                    self.store.remove(&entry.id)?;
+                    report.rejected.push((entry, err));
+                }
+                Err(err) => {
+                    log::debug!("queued email {} not sent: {err}", entry.id);
+                    entry.attempts += 1;
+                    let mut delay = backoff_secs(entry.attempts);
+                    if let Error::RateLimited {
+                        retry_after: Some(retry_after),
+                        ..
+                    } = err
+                    {
+                        delay = delay.max(retry_after.as_secs());
+                    }
+                    entry.next_attempt_at = now + delay;
+                    self.store.push(&entry)?;
+                    break;
+                }
+            }
+            report.remaining -= 1;
+        }
+
+        Ok(report)
+    }
+
+    /// Drains the queue every `interval` until the client is
+    /// [shut down](crate::Lettr::shutdown).
+    ///
+    /// Store errors and rejected emails are logged. Use [`drain`](Self::drain)
+    /// in a custom loop to handle them yourself.
+    pub async fn run(&self, interval: Duration) {
+        while !self.emails.0.is_shut_down() {
+            match self.drain().await {
+                Ok(report) => {
+                    for (entry, err) in report.rejected {
+                        log::error!("dropping queued email {}: {err}", entry.id);
+                    }
+                }
+                Err(err) => log::warn!("failed to drain send queue: {err}"),
+            }
+            self.emails.0.clock().sleep(interval).await;
+        }
+    }
+}
+
+/// Delay in seconds before the next attempt after `attempts` failures.
+fn backoff_secs(attempts: u32) -> u64 {
+    2u64.saturating_pow(attempts).min(MAX_BACKOFF_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use super::{QueueStore, QueuedEmail, SendQueue};
+    use crate::clock::MockClock;
+    use crate::transport::{async_trait, HeaderMap, HttpTransport, Request, Response, StatusCode};
+    use crate::{CreateEmailOptions, Error, Lettr};
+
+    /// Keeps entries in memory.
+    #[derive(Default)]
+    struct MemoryStore(Mutex<Vec<QueuedEmail>>);
+
+    impl QueueStore for MemoryStore {
+        fn push(&self, entry: &QueuedEmail) -> crate::Result<()> {
+            let mut entries = self.0.lock().unwrap();
+            entries.retain(|e| e.id != entry.id);
+            entries.push(entry.clone());
+            entries.sort_by(|a, b| a.id.cmp(&b.id));
+            Ok(())
+        }
+
+        fn pending(&self) -> crate::Result<Vec<QueuedEmail>> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        fn remove(&self, id: &str) -> crate::Result<()> {
+            self.0.lock().unwrap().retain(|e| e.id != id);
+            Ok(())
+        }
+    }
+
+    /// Answers each email by its subject: `bad` gets a `400`, `garbled` a
+    /// `200` with an unreadable body, `down` a `503`, and anything else is
+    /// accepted.
+    struct BySubject {
+        requests: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for BySubject {
+        async fn execute(&self, request: Request) -> crate::Result<Response> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            let body = String::from_utf8_lossy(request.body.as_deref().unwrap_or_default());
+            let (status, body) = if body.contains(r#""subject":"bad""#) {
+                (StatusCode::BAD_REQUEST, r#"{"message":"Bad request."}"#)
+            } else if body.contains(r#""subject":"garbled""#) {
+                (StatusCode::OK, "<html>")
+            } else if body.contains(r#""subject":"down""#) {
+                (StatusCode::SERVICE_UNAVAILABLE, r#"{"message":"Down."}"#)
+            } else {
+                (
+                    StatusCode::OK,
+                    r#"{"message":"Email queued for delivery.","data":{"request_id":"request-1","accepted":1,"rejected":0}}"#,
+                )
+            };
+            Ok(Response::new(status, HeaderMap::new(), body))
+        }
+    }
+
+    /// Returns a queue holding one email per subject, in order, and a counter
+    /// of the requests it sends.
+    fn queue(subjects: &[&str]) -> (SendQueue, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let client = Lettr::builder("test-key")
+            .transport(BySubject {
+                requests: requests.clone(),
+            })
+            .clock(MockClock::new())
+            .max_retries(0)
+            .build();
+        let queue = SendQueue::new(&client, MemoryStore::default());
+        for subject in subjects {
+            let email =
+                CreateEmailOptions::new("sender@example.com", ["user@example.com"], *subject)
+                    .with_text("Hello!");
+            queue.enqueue(email).unwrap();
+        }
+        (queue, requests)
+    }
+
+    #[tokio::test]
+    async fn rejects_emails_failing_with_a_client_error() {
+        let (queue, _) = queue(&["bad", "hello"]);
+
+        let report = queue.drain().await.unwrap();
+        assert_eq!(report.sent, 1);
+        assert_eq!(report.rejected.len(), 1);
+        assert!(
+            matches!(report.rejected[0].1, Error::Api(ref e) if e.status == StatusCode::BAD_REQUEST)
+        );
+        assert_eq!(report.remaining, 0);
+        assert!(queue.store.pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn does_not_resend_an_email_accepted_with_an_unreadable_response() {
+        let (queue, requests) = queue(&["garbled"]);
+
+        let report = queue.drain().await.unwrap();
+        assert_eq!(report.sent, 1);
+        assert!(report.rejected.is_empty());
+        assert!(queue.store.pending().unwrap().is_empty());
+
+        queue.drain().await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn keeps_emails_after_a_retryable_error() {
+        let (queue, requests) = queue(&["down", "hello"]);
+
+        let report = queue.drain().await.unwrap();
+        assert_eq!(report.sent, 0);
+        assert!(report.rejected.is_empty());
+        assert_eq!(report.remaining, 2);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let pending = queue.store.pending().unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].attempts, 1);
+        assert!(pending[0].next_attempt_at > 0);
+    }
+}
@@ -0,0 +1,331 @@
+//! Client-side request rate limiting.
+//!
+//! A [`RateLimit`] can be attached to a client via
+//! [`LettrBuilder::rate_limit`](crate::LettrBuilder::rate_limit). Every request
+//! sent through that client then draws a token from a shared token bucket,
+//! waiting when the bucket is empty instead of hitting the API's limits.
+//!
+//! The limits reported by the API itself are parsed into [`RateLimitInfo`].
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, RETRY_AFTER};
+
+use crate::time::{unix_now, Instant};
+
+const X_RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const X_RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+const X_RATELIMIT_RESET: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+/// Token-bucket rate limit configuration.
+///
+/// # Example
+///
+/// ```
+/// use lettr::rate_limit::RateLimit;
+///
+/// // Sustain 10 requests per second, allowing short bursts of up to 20.
+/// let limit = RateLimit::per_second(10).burst(20);
+/// assert_eq!(limit.requests_per_second(), 10);
+/// assert_eq!(limit.burst_size(), 20);
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    requests_per_second: u32,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Creates a new [`RateLimit`] allowing `requests_per_second` requests per second.
+    ///
+    /// The burst size defaults to the same value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_second` is zero.
+    pub fn per_second(requests_per_second: u32) -> Self {
+        assert!(
+            requests_per_second > 0,
+            "requests_per_second must be non-zero"
+        );
+        Self {
+            requests_per_second,
+            burst: requests_per_second,
+        }
+    }
+
+    /// Sets the maximum number of requests that may be sent back-to-back
+    /// before the sustained rate applies.
+    ///
+    /// Values below one are treated as one.
+    #[inline]
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Returns the sustained number of requests per second.
+    #[must_use]
+    pub const fn requests_per_second(&self) -> u32 {
+        self.requests_per_second
+    }
+
+    /// Returns the burst size.
+    #[must_use]
+    pub const fn burst_size(&self) -> u32 {
+        self.burst
+    }
+}
+
+/// Shared token bucket enforcing a [`RateLimit`].
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    state: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a new [`RateLimiter`] with a full bucket.
+    pub fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            state: Mutex::new(Bucket {
+                tokens: f64::from(limit.burst),
+                last_refill: now,
+            }),
+        }
+    }
+
+    /// Reserve a token, returning how long the caller must wait before sending.
+    ///
+    /// The token is taken immediately, so concurrent callers queue up behind
+    /// each other rather than all waking at the same instant.
+    pub fn reserve(&self, now: Instant) -> Duration {
+        let rate = f64::from(self.limit.requests_per_second);
+        let capacity = f64::from(self.limit.burst);
+
+        let mut bucket = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(capacity);
+        bucket.last_refill = now;
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / rate)
+        }
+    }
+}
+
+/// Rate-limit information reported by the API in response headers.
+///
+/// Every field is optional because the API only sends the headers it applies
+/// to a given response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Maximum number of requests allowed in the current window (`X-RateLimit-Limit`).
+    pub limit: Option<u32>,
+    /// Requests remaining in the current window (`X-RateLimit-Remaining`).
+    pub remaining: Option<u32>,
+    /// When the current window resets (`X-RateLimit-Reset`), as sent by the API.
+    ///
+    /// This is a Unix timestamp in seconds, or a number of seconds from now
+    /// for values too small to be a timestamp.
+    pub reset: Option<u64>,
+    /// How long to wait before retrying (`Retry-After`), relative to when the
+    /// response was received.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitInfo {
+    /// Parse rate-limit information from response headers.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            limit: parse_header(headers, &X_RATELIMIT_LIMIT),
+            remaining: parse_header(headers, &X_RATELIMIT_REMAINING),
+            reset: parse_header(headers, &X_RATELIMIT_RESET),
+            retry_after: headers
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_retry_after),
+        }
+    }
+
+    /// Returns `true` if the response carried none of the rate-limit headers.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns how long the API asked the client to wait, if anything.
+    ///
+    /// `Retry-After` takes precedence. Otherwise, when the window is
+    /// exhausted, this is the time until `X-RateLimit-Reset`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use lettr::rate_limit::RateLimitInfo;
+    ///
+    /// let info = RateLimitInfo {
+    ///     remaining: Some(0),
+    ///     reset: Some(30),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(info.suggested_delay(), Some(Duration::from_secs(30)));
+    /// ```
+    #[must_use]
+    pub fn suggested_delay(&self) -> Option<Duration> {
+        self.suggested_delay_at(unix_now())
+    }
+
+    /// Like [`suggested_delay`](Self::suggested_delay), with the current
+    /// time since the Unix epoch given by the caller.
+    pub(crate) fn suggested_delay_at(&self, now: Option<Duration>) -> Option<Duration> {
+        if let Some(retry_after) = self.retry_after {
+            return Some(retry_after);
+        }
+        if self.remaining != Some(0) {
+            return None;
+        }
+
+        // Anything below this is a relative number of seconds, not a timestamp.
+        const TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+        let reset = self.reset?;
+        if reset < TIMESTAMP_THRESHOLD {
+            return Some(Duration::from_secs(reset));
+        }
+        let now = now?.as_secs();
+        Some(Duration::from_secs(reset.saturating_sub(now)))
+    }
+}
+
+fn parse_header<T: std::str::FromStr>(headers: &HeaderMap, name: &HeaderName) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Parse a `Retry-After` value, which is either delay-seconds or an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value)
+        .ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?;
+    Some(date.saturating_sub(unix_now()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{RateLimit, RateLimitInfo, RateLimiter};
+    use crate::clock::MockClock;
+    use crate::time::Instant;
+    use crate::transport::{async_trait, HeaderMap, HttpTransport, Request, Response, StatusCode};
+    use crate::Lettr;
+
+    /// Answers every request with a healthy status.
+    struct Healthy;
+
+    #[async_trait]
+    impl HttpTransport for Healthy {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            let body = r#"{"message":"OK","data":{"status":"ok","timestamp":"now"}}"#;
+            Ok(Response::new(StatusCode::OK, HeaderMap::new(), body))
+        }
+    }
+
+    #[test]
+    fn reserve_allows_a_burst_then_paces() {
+        let start = Instant::now();
+        let limiter = RateLimiter::new(RateLimit::per_second(1).burst(2), start);
+
+        assert_eq!(limiter.reserve(start), Duration::ZERO);
+        assert_eq!(limiter.reserve(start), Duration::ZERO);
+        assert_eq!(limiter.reserve(start), Duration::from_secs(1));
+        // The third caller took the token refilled after one second.
+        assert_eq!(
+            limiter.reserve(start + Duration::from_secs(1)),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            limiter.reserve(start + Duration::from_secs(4)),
+            Duration::ZERO
+        );
+    }
+
+    #[tokio::test]
+    async fn client_sleeps_for_reserved_tokens() {
+        let clock = MockClock::new();
+        let client = Lettr::builder("test-key")
+            .transport(Healthy)
+            .clock(clock.clone())
+            .rate_limit(RateLimit::per_second(2).burst(1))
+            .build();
+
+        for _ in 0..3 {
+            client.health().await.unwrap();
+        }
+        assert_eq!(clock.sleeps(), [Duration::from_millis(500); 2]);
+    }
+
+    fn exhausted(reset: u64) -> RateLimitInfo {
+        RateLimitInfo {
+            remaining: Some(0),
+            reset: Some(reset),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn small_resets_are_relative() {
+        let info = exhausted(999_999_999);
+        assert_eq!(
+            info.suggested_delay_at(None),
+            Some(Duration::from_secs(999_999_999))
+        );
+    }
+
+    #[test]
+    fn large_resets_are_timestamps() {
+        let now = Some(Duration::from_secs(1_700_000_000));
+        assert_eq!(
+            exhausted(1_700_000_060).suggested_delay_at(now),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            exhausted(1_000_000_000).suggested_delay_at(now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(exhausted(1_700_000_060).suggested_delay_at(None), None);
+    }
+
+    #[test]
+    fn retry_after_takes_precedence() {
+        let info = RateLimitInfo {
+            retry_after: Some(Duration::from_secs(5)),
+            ..exhausted(30)
+        };
+        assert_eq!(info.suggested_delay_at(None), Some(Duration::from_secs(5)));
+
+        let info = RateLimitInfo {
+            remaining: Some(1),
+            ..exhausted(30)
+        };
+        assert_eq!(info.suggested_delay_at(None), None);
+    }
+}
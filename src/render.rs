@@ -0,0 +1,111 @@
+//! Local rendering of email bodies with [Askama](https://docs.rs/askama) or
+//! [Tera](https://docs.rs/tera) templates.
+//!
+//! Unlike [`CreateEmailOptions::with_template`], which renders a template
+//! stored in Lettr, these methods render the body in the application before
+//! sending, so templates can live next to the code that fills them.
+//!
+//! Only available with the `askama` or `tera` feature.
+
+use crate::emails::CreateEmailOptions;
+use crate::Error;
+
+#[cfg(feature = "askama")]
+impl CreateEmailOptions {
+    /// Renders an Askama template and sets the result as the HTML body.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Render`] if the template fails to render.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use askama::Template;
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// #[derive(Template)]
+    /// #[template(source = "<h1>Welcome, {{ name }}!</h1>", ext = "html")]
+    /// struct Welcome<'a> {
+    ///     name: &'a str,
+    /// }
+    ///
+    /// let email = CreateEmailOptions::new("hello@example.com", ["user@example.com"], "Welcome!")
+    ///     .with_html_template(&Welcome { name: "Ada" })?;
+    /// assert_eq!(email.html(), Some("<h1>Welcome, Ada!</h1>"));
+    /// # Ok::<(), lettr::Error>(())
+    /// ```
+    pub fn with_html_template<T: askama::Template>(self, template: &T) -> crate::Result<Self> {
+        let html = template.render().map_err(|e| Error::Render(Box::new(e)))?;
+        Ok(self.with_html(html))
+    }
+
+    /// Renders an Askama template and sets the result as the plain text
+    /// body.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Render`] if the template fails to render.
+    pub fn with_text_template<T: askama::Template>(self, template: &T) -> crate::Result<Self> {
+        let text = template.render().map_err(|e| Error::Render(Box::new(e)))?;
+        Ok(self.with_text(text))
+    }
+}
+
+#[cfg(feature = "tera")]
+impl CreateEmailOptions {
+    /// Renders the Tera template named `name` with `context` and sets the
+    /// result as the HTML body.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Render`] if there is no template named `name` or it
+    /// fails to render.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// let mut tera = tera::Tera::default();
+    /// tera.add_raw_template("welcome.html", "<h1>Welcome, {{ name }}!</h1>")
+    ///     .unwrap();
+    /// let mut context = tera::Context::new();
+    /// context.insert("name", "Ada");
+    ///
+    /// let email = CreateEmailOptions::new("hello@example.com", ["user@example.com"], "Welcome!")
+    ///     .with_tera_html(&tera, "welcome.html", &context)?;
+    /// assert_eq!(email.html(), Some("<h1>Welcome, Ada!</h1>"));
+    /// # Ok::<(), lettr::Error>(())
+    /// ```
+    pub fn with_tera_html(
+        self,
+        tera: &tera::Tera,
+        name: &str,
+        context: &tera::Context,
+    ) -> crate::Result<Self> {
+        let html = tera
+            .render(name, context)
+            .map_err(|e| Error::Render(Box::new(e)))?;
+        Ok(self.with_html(html))
+    }
+
+    /// Renders the Tera template named `name` with `context` and sets the
+    /// result as the plain text body.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Render`] if there is no template named `name` or it
+    /// fails to render.
+    pub fn with_tera_text(
+        self,
+        tera: &tera::Tera,
+        name: &str,
+        context: &tera::Context,
+    ) -> crate::Result<Self> {
+        let text = tera
+            .render(name, context)
+            .map_err(|e| Error::Render(Box::new(e)))?;
+        Ok(self.with_text(text))
+    }
+}
@@ -0,0 +1,253 @@
+//! Which failed requests are retried.
+//!
+//! Once retries are enabled with
+//! [`LettrBuilder::max_retries`](crate::LettrBuilder::max_retries), a
+//! [`RetryPolicy`] decides which failed requests are sent again. The default
+//! policy only retries failures where the API did not process the request:
+//! connection failures and `429 Too Many Requests` or
+//! `503 Service Unavailable` responses, for every method.
+//!
+//! A policy attached via
+//! [`LettrBuilder::retry_policy`](crate::LettrBuilder::retry_policy) can
+//! change the methods and statuses that are retried, with overrides for
+//! single endpoints. Retrying other statuses, such as `502 Bad Gateway`, can
+//! deliver an email twice; with
+//! [`sends_require_idempotency_key`](RetryPolicy::sends_require_idempotency_key),
+//! `POST /emails` is only retried when the request carries an
+//! [`Idempotency-Key`](IDEMPOTENCY_KEY_HEADER) header, e.g. added by a
+//! [middleware](crate::middleware).
+
+use crate::transport::{Method, StatusCode};
+
+/// Header marking a request as safe to send more than once.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Rules for which failed requests are retried.
+///
+/// # Example
+///
+/// ```
+/// use lettr::Lettr;
+/// use lettr::retry::RetryPolicy;
+/// use lettr::transport::{Method, StatusCode};
+///
+/// let client = Lettr::builder("your-api-key")
+///     .max_retries(3)
+///     .retry_policy(
+///         RetryPolicy::new()
+///             .statuses([
+///                 StatusCode::TOO_MANY_REQUESTS,
+///                 StatusCode::BAD_GATEWAY,
+///                 StatusCode::SERVICE_UNAVAILABLE,
+///             ])
+///             .sends_require_idempotency_key(true)
+///             // Reading email events is safe after any server error.
+///             .endpoint(
+///                 Method::GET,
+///                 "/emails",
+///                 RetryPolicy::new().statuses([
+///                     StatusCode::TOO_MANY_REQUESTS,
+///                     StatusCode::INTERNAL_SERVER_ERROR,
+///                     StatusCode::BAD_GATEWAY,
+///                     StatusCode::SERVICE_UNAVAILABLE,
+///                 ]),
+///             ),
+///     )
+///     .build();
+/// ```
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    methods: Option<Vec<Method>>,
+    statuses: Vec<StatusCode>,
+    connect_errors: bool,
+    sends_require_idempotency_key: bool,
+    overrides: Vec<Override>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Override {
+    method: Method,
+    path: String,
+    policy: RetryPolicy,
+}
+
+/// Why a request attempt failed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Failure {
+    /// The request could not connect, so it never reached the API.
+    Connect,
+    /// The API answered with an error status.
+    Status(StatusCode),
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            methods: None,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::SERVICE_UNAVAILABLE,
+            ],
+            connect_errors: true,
+            sends_require_idempotency_key: false,
+            overrides: Vec::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates the default [`RetryPolicy`]: connection failures, `429`, and
+    /// `503` are retried for every method.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`RetryPolicy`] that retries nothing, e.g. as the base for
+    /// a policy that only retries a few [endpoints](Self::endpoint).
+    pub fn none() -> Self {
+        Self {
+            methods: None,
+            statuses: Vec::new(),
+            connect_errors: false,
+            sends_require_idempotency_key: false,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Only retries requests with these methods. All methods are retried by
+    /// default.
+    #[inline]
+    pub fn methods(mut self, methods: impl IntoIterator<Item = Method>) -> Self {
+        self.methods = Some(methods.into_iter().collect());
+        self
+    }
+
+    /// Sets the response statuses that are retried, replacing the default
+    /// `429` and `503`.
+    #[inline]
+    pub fn statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Sets whether requests that could not connect are retried. Enabled by
+    /// default.
+    #[inline]
+    pub fn connect_errors(mut self, enabled: bool) -> Self {
+        self.connect_errors = enabled;
+        self
+    }
+
+    /// Only retries `POST /emails` when the request has an
+    /// [`Idempotency-Key`](IDEMPOTENCY_KEY_HEADER) header. Disabled by
+    /// default.
+    #[inline]
+    pub fn sends_require_idempotency_key(mut self, enabled: bool) -> Self {
+        self.sends_require_idempotency_key = enabled;
+        self
+    }
+
+    /// Uses `policy` instead for `method` requests to `path` and the paths
+    /// below it, e.g. `/emails` also covers `/emails/erasures`.
+    ///
+    /// The first matching override applies; overrides of `policy` itself
+    /// are ignored.
+    #[inline]
+    pub fn endpoint(
+        mut self,
+        method: Method,
+        path: impl Into<String>,
+        policy: RetryPolicy,
+    ) -> Self {
+        self.overrides.push(Override {
+            method,
+            path: path.into(),
+            policy,
+        });
+        self
+    }
+
+    /// Returns `true` if a `method` request to `path` that failed with
+    /// `failure` may be retried. `has_idempotency_key` tells whether the
+    /// request carried an [`IDEMPOTENCY_KEY_HEADER`].
+    pub(crate) fn allows(
+        &self,
+        method: &Method,
+        path: &str,
+        has_idempotency_key: bool,
+        failure: Failure,
+    ) -> bool {
+        let policy = self
+            .overrides
+            .iter()
+            .find(|o| o.method == method && covers(&o.path, path))
+            .map_or(self, |o| &o.policy);
+
+        if policy
+            .methods
+            .as_ref()
+            .is_some_and(|methods| !methods.contains(method))
+        {
+            return false;
+        }
+        if policy.sends_require_idempotency_key
+            && method == Method::POST
+            && path == "/emails"
+            && !has_idempotency_key
+        {
+            return false;
+        }
+        match failure {
+            Failure::Connect => policy.connect_errors,
+            Failure::Status(status) => policy.statuses.contains(&status),
+        }
+    }
+}
+
+/// Returns `true` if `path` is `prefix` or below it.
+fn covers(prefix: &str, path: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{covers, Failure, RetryPolicy};
+    use crate::transport::{Method, StatusCode};
+
+    #[test]
+    fn covers_the_path_and_paths_below_it() {
+        assert!(covers("/emails", "/emails"));
+        assert!(covers("/emails", "/emails/erasures"));
+        assert!(covers("/emails/", "/emails/erasures"));
+        assert!(!covers("/emails", "/emailsx"));
+        assert!(!covers("/emails/erasures", "/emails"));
+    }
+
+    #[test]
+    fn endpoint_overrides_apply_below_their_path() {
+        let policy = RetryPolicy::none().endpoint(
+            Method::GET,
+            "/emails",
+            RetryPolicy::new().statuses([StatusCode::BAD_GATEWAY]),
+        );
+        let bad_gateway = Failure::Status(StatusCode::BAD_GATEWAY);
+
+        assert!(policy.allows(&Method::GET, "/emails/123", false, bad_gateway));
+        assert!(!policy.allows(&Method::GET, "/emailsx", false, bad_gateway));
+        assert!(!policy.allows(&Method::POST, "/emails", false, bad_gateway));
+    }
+
+    #[test]
+    fn sends_can_require_an_idempotency_key() {
+        let policy = RetryPolicy::new().sends_require_idempotency_key(true);
+        let unavailable = Failure::Status(StatusCode::SERVICE_UNAVAILABLE);
+
+        assert!(!policy.allows(&Method::POST, "/emails", false, unavailable));
+        assert!(policy.allows(&Method::POST, "/emails", true, unavailable));
+        assert!(policy.allows(&Method::POST, "/templates", false, unavailable));
+        assert!(policy.allows(&Method::GET, "/emails", false, Failure::Connect));
+    }
+}
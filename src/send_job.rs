@@ -0,0 +1,305 @@
+//! Paced sending of many emails.
+//!
+//! A [`SendJob`] sends the emails of an iterator one by one at a fixed rate,
+//! e.g. 500 per hour, for IP warm-up and large announcements that must not
+//! go out in one burst. Progress is reported to hooks after every email, and
+//! the number of emails handled so far can be persisted, so an interrupted
+//! job [resumes](SendJob::resume_from) where it stopped. A [`CancelHandle`]
+//! stops the job from another task.
+//!
+//! Failed emails are recorded in the [`SendJobReport`] and do not stop the
+//! job. Transient errors are already retried by the client if
+//! [`LettrBuilder::max_retries`](crate::LettrBuilder::max_retries) is set.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::clock::Clock;
+use crate::emails::{CreateEmailOptions, EmailSender, SendEmailResponse};
+use crate::Error;
+
+/// Sends emails from an iterator at a fixed rate.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use lettr::{Lettr, CreateEmailOptions};
+/// use lettr::send_job::SendJob;
+///
+/// # async fn run(subscribers: Vec<String>, done: usize) -> lettr::Result<()> {
+/// let client = Lettr::new("your-api-key");
+///
+/// let emails = subscribers.into_iter().map(|to| {
+///     CreateEmailOptions::new("news@example.com", [to], "We moved!").with_text("...")
+/// });
+/// let job = SendJob::new(client.emails.clone(), emails)
+///     .rate(500, Duration::from_secs(60 * 60))
+///     .resume_from(done)
+///     .on_progress(|progress| {
+///         if let Err(err) = progress.outcome {
+///             eprintln!("email {} failed: {err}", progress.position);
+///         }
+///     })
+///     .on_checkpoint(|position| {
+///         // Persist `position`, then pass it to `resume_from` after a restart.
+///     });
+///
+/// let cancel = job.cancel_handle();
+/// // Call `cancel.cancel()` from elsewhere to stop the job.
+///
+/// let report = job.run().await;
+/// println!("{} sent, {} failed", report.sent, report.failed.len());
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub struct SendJob<I> {
+    sender: Box<dyn EmailSender>,
+    emails: I,
+    interval: Duration,
+    skip: usize,
+    clock: Arc<dyn Clock>,
+    cancel: CancelHandle,
+    progress_hooks: Vec<Box<ProgressHookFn>>,
+    checkpoint_hooks: Vec<Box<CheckpointHookFn>>,
+}
+
+type ProgressHookFn = dyn FnMut(&SendProgress<'_>) + Send;
+type CheckpointHookFn = dyn FnMut(usize) + Send;
+
+impl<I> SendJob<I>
+where
+    I: Iterator<Item = CreateEmailOptions> + Send,
+{
+    /// Creates a job sending `emails` through `sender`, e.g. a client's
+    /// [`emails`](crate::Lettr::emails) service, one per second.
+    ///
+    /// The job paces emails with the [clock](EmailSender::clock) of
+    /// `sender`, so it follows a clock set with
+    /// [`LettrBuilder::clock`](crate::LettrBuilder::clock).
+    pub fn new<E>(sender: impl EmailSender + 'static, emails: E) -> Self
+    where
+        E: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            clock: sender.clock(),
+            sender: Box::new(sender),
+            emails: emails.into_iter(),
+            interval: Duration::from_secs(1),
+            skip: 0,
+            cancel: CancelHandle::default(),
+            progress_hooks: Vec::new(),
+            checkpoint_hooks: Vec::new(),
+        }
+    }
+
+    /// Sends at most `count` emails `per` period, evenly spaced, e.g.
+    /// `rate(500, Duration::from_secs(3600))` for 500 per hour.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is zero.
+    #[inline]
+    pub fn rate(mut self, count: u32, per: Duration) -> Self {
+        assert!(count > 0, "send job rate must allow at least one email");
+        self.interval = per / count;
+        self
+    }
+
+    /// Skips the first `position` emails, e.g. the position last passed to
+    /// an [`on_checkpoint`](Self::on_checkpoint) hook before a restart.
+    #[inline]
+    pub fn resume_from(mut self, position: usize) -> Self {
+        self.skip = position;
+        self
+    }
+
+    /// Reads the time and sleeps through `clock` instead of the sender's
+    /// clock, e.g. a [`MockClock`](crate::clock::MockClock) in tests.
+    #[inline]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Registers a hook called after every email with its outcome and the
+    /// totals so far.
+    #[inline]
+    pub fn on_progress(mut self, hook: impl FnMut(&SendProgress<'_>) + Send + 'static) -> Self {
+        self.progress_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Registers a hook called after every email with the number of emails
+    /// handled so far, including skipped ones. Persist it to
+    /// [resume](Self::resume_from) the job after a restart.
+    #[inline]
+    pub fn on_checkpoint(mut self, hook: impl FnMut(usize) + Send + 'static) -> Self {
+        self.checkpoint_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Returns a handle that stops the job before its next email.
+    pub fn cancel_handle(&self) -> CancelHandle {
+        self.cancel.clone()
+    }
+
+    /// Sends the emails until they run out or the job is cancelled.
+    pub async fn run(mut self) -> SendJobReport {
+        let mut report = SendJobReport {
+            position: self.skip,
+            sent: 0,
+            failed: Vec::new(),
+            cancelled: false,
+        };
+        let mut emails = self.emails.by_ref().skip(self.skip);
+        let mut next_at = self.clock.now();
+
+        loop {
+            if self.cancel.is_cancelled() {
+                report.cancelled = true;
+                break;
+            }
+            let Some(email) = emails.next() else {
+                break;
+            };
+            let wait = next_at.saturating_duration_since(self.clock.now());
+            if !wait.is_zero() {
+                self.clock.sleep(wait).await;
+                if self.cancel.is_cancelled() {
+                    report.cancelled = true;
+                    break;
+                }
+            }
+            next_at = self.clock.now() + self.interval;
+
+            let position = report.position;
+            let result = self.sender.send(email).await;
+            report.position += 1;
+            match &result {
+                Ok(_) => report.sent += 1,
+                Err(err) => log::debug!("send job email {position} failed: {err}"),
+            }
+            let progress = SendProgress {
+                position,
+                outcome: result.as_ref(),
+                sent: report.sent,
+                failed: report.failed.len() + usize::from(result.is_err()),
+            };
+            for hook in &mut self.progress_hooks {
+                hook(&progress);
+            }
+            for hook in &mut self.checkpoint_hooks {
+                hook(report.position);
+            }
+            if let Err(err) = result {
+                report.failed.push((position, err));
+            }
+        }
+        report
+    }
+}
+
+impl<I> fmt::Debug for SendJob<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendJob")
+            .field("interval", &self.interval)
+            .field("skip", &self.skip)
+            .field("cancelled", &self.cancel.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Progress of a [`SendJob`], passed to
+/// [`on_progress`](SendJob::on_progress) hooks after every email.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SendProgress<'a> {
+    /// Position of the email in the iterator, counting from zero.
+    pub position: usize,
+    /// What the API answered for the email.
+    pub outcome: Result<&'a SendEmailResponse, &'a Error>,
+    /// Number of emails sent so far by this run.
+    pub sent: usize,
+    /// Number of emails that failed so far in this run.
+    pub failed: usize,
+}
+
+/// Outcome of [`SendJob::run`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SendJobReport {
+    /// Number of emails handled, including skipped ones; pass it to
+    /// [`SendJob::resume_from`] to continue a cancelled job.
+    pub position: usize,
+    /// Number of emails the API accepted.
+    pub sent: usize,
+    /// Position and error of every email that failed.
+    pub failed: Vec<(usize, Error)>,
+    /// Whether the job stopped because it was cancelled.
+    pub cancelled: bool,
+}
+
+/// Stops a [`SendJob`]; see [`SendJob::cancel_handle`].
+#[derive(Debug, Clone, Default)]
+pub struct CancelHandle(Arc<AtomicBool>);
+
+impl CancelHandle {
+    /// Stops the job before its next email. An email being sent is not
+    /// interrupted.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether [`cancel`](Self::cancel) was called.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::SendJob;
+    use crate::clock::MockClock;
+    use crate::transport::{async_trait, HeaderMap, HttpTransport, Request, Response, StatusCode};
+    use crate::{CreateEmailOptions, Lettr};
+
+    /// Accepts every email.
+    struct Accepting;
+
+    #[async_trait]
+    impl HttpTransport for Accepting {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            let body =
+                r#"{"message":"OK","data":{"request_id":"request-1","accepted":1,"rejected":0}}"#;
+            Ok(Response::new(StatusCode::OK, HeaderMap::new(), body))
+        }
+    }
+
+    #[tokio::test]
+    async fn paces_emails_with_the_client_clock() {
+        let clock = MockClock::new();
+        let client = Lettr::builder("test-key")
+            .transport(Accepting)
+            .clock(clock.clone())
+            .build();
+        let emails = (0..3).map(|i| {
+            CreateEmailOptions::new("sender@example.com", ["user@example.com"], "News")
+                .with_text(format!("Email {i}"))
+        });
+
+        let report = SendJob::new(client.emails.clone(), emails)
+            .rate(2, Duration::from_secs(1))
+            .run()
+            .await;
+
+        assert_eq!(report.sent, 3);
+        assert_eq!(clock.sleeps(), [Duration::from_millis(500); 2]);
+    }
+}
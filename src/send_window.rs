@@ -0,0 +1,347 @@
+//! Quiet hours for non-transactional email.
+//!
+//! With a [`SendWindow`] attached via
+//! [`LettrBuilder::send_window`](crate::LettrBuilder::send_window),
+//! [`EmailsSvc::send`](crate::emails::EmailsSvc::send) only sends
+//! non-transactional emails, those marked with
+//! [`with_transactional(false)`](crate::CreateEmailOptions::with_transactional),
+//! on the allowed days and hours. Outside the window the client waits until
+//! it opens, reporting the delay to hooks registered with
+//! [`LettrBuilder::on_send_deferred`](crate::LettrBuilder::on_send_deferred),
+//! or fails with [`Error::OutsideSendWindow`](crate::Error::OutsideSendWindow)
+//! if the window [rejects](SendWindow::reject_outside) such sends.
+//!
+//! Transactional emails are always sent right away.
+//!
+//! The hours are local to a fixed [UTC offset](SendWindow::utc_offset), not
+//! a timezone: the window does not follow daylight saving time changes. In a
+//! zone observing DST, either set the offset that keeps the window within
+//! the allowed hours all year, or build a new client with the new offset
+//! when it changes.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::emails::CreateEmailOptions;
+
+const SECS_PER_HOUR: u64 = 60 * 60;
+const SECS_PER_DAY: u64 = 24 * SECS_PER_HOUR;
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+}
+
+impl Weekday {
+    /// Monday through Friday.
+    pub const WEEKDAYS: [Self; 5] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+    ];
+
+    /// Returns the day of the week of the `day`th day since the Unix epoch,
+    /// which was a Thursday.
+    fn from_days_since_epoch(day: i64) -> Self {
+        match (day + 3).rem_euclid(7) {
+            0 => Self::Monday,
+            1 => Self::Tuesday,
+            2 => Self::Wednesday,
+            3 => Self::Thursday,
+            4 => Self::Friday,
+            5 => Self::Saturday,
+            _ => Self::Sunday,
+        }
+    }
+}
+
+/// Days and hours in which non-transactional emails may be sent.
+///
+/// The hours are local to a fixed UTC offset, set with
+/// [`utc_offset`](Self::utc_offset); daylight saving time is not applied.
+///
+/// # Example
+///
+/// ```
+/// use lettr::Lettr;
+/// use lettr::send_window::{SendWindow, Weekday};
+///
+/// // Weekdays from 9:00 to 20:00 at UTC+1, e.g. Central European Time
+/// // outside daylight saving time.
+/// let client = Lettr::builder("your-api-key")
+///     .send_window(
+///         SendWindow::hours(9, 20)
+///             .days(Weekday::WEEKDAYS)
+///             .utc_offset(60),
+///     )
+///     .build();
+/// ```
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SendWindow {
+    days: Vec<Weekday>,
+    start_hour: u8,
+    end_hour: u8,
+    utc_offset_minutes: i32,
+    reject: bool,
+}
+
+impl SendWindow {
+    /// Creates a [`SendWindow`] open every day from `start_hour` up to, but
+    /// not including, `end_hour`, in UTC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start_hour` is not before `end_hour`, or `end_hour` is
+    /// after 24.
+    pub fn hours(start_hour: u8, end_hour: u8) -> Self {
+        assert!(
+            start_hour < end_hour && end_hour <= 24,
+            "send window hours must satisfy start_hour < end_hour <= 24"
+        );
+        Self {
+            days: vec![
+                Weekday::Monday,
+                Weekday::Tuesday,
+                Weekday::Wednesday,
+                Weekday::Thursday,
+                Weekday::Friday,
+                Weekday::Saturday,
+                Weekday::Sunday,
+            ],
+            start_hour,
+            end_hour,
+            utc_offset_minutes: 0,
+            reject: false,
+        }
+    }
+
+    /// Restricts the window to the given days.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `days` is empty.
+    #[inline]
+    pub fn days(mut self, days: impl IntoIterator<Item = Weekday>) -> Self {
+        self.days = days.into_iter().collect();
+        assert!(!self.days.is_empty(), "send window needs at least one day");
+        self
+    }
+
+    /// Sets the offset from UTC, in minutes, that the hours are in, e.g.
+    /// `-300` for US Eastern Standard Time.
+    ///
+    /// The offset is fixed: it does not switch to `-240` when daylight saving
+    /// time starts.
+    #[inline]
+    pub fn utc_offset(mut self, minutes: i32) -> Self {
+        self.utc_offset_minutes = minutes;
+        self
+    }
+
+    /// Fails sends outside the window with
+    /// [`Error::OutsideSendWindow`](crate::Error::OutsideSendWindow) instead
+    /// of waiting for it to open.
+    #[inline]
+    pub fn reject_outside(mut self) -> Self {
+        self.reject = true;
+        self
+    }
+
+    /// Returns how long after `unix_now` the window opens, or zero if it is
+    /// open.
+    fn opens_in(&self, unix_now: Duration) -> Duration {
+        let local = i64::try_from(unix_now.as_secs()).unwrap_or(i64::MAX)
+            + i64::from(self.utc_offset_minutes) * 60;
+        let day = local.div_euclid(SECS_PER_DAY as i64);
+        let second = local.rem_euclid(SECS_PER_DAY as i64).unsigned_abs();
+        let start = u64::from(self.start_hour) * SECS_PER_HOUR;
+        let end = u64::from(self.end_hour) * SECS_PER_HOUR;
+
+        let allowed = |offset: i64| {
+            self.days
+                .contains(&Weekday::from_days_since_epoch(day + offset))
+        };
+        if allowed(0) && (start..end).contains(&second) {
+            return Duration::ZERO;
+        }
+        let first = if second < start { 0 } else { 1 };
+        (first..=7)
+            .find(|&offset| allowed(offset))
+            .map(|offset| {
+                let seconds = offset.unsigned_abs() * SECS_PER_DAY + start - second;
+                Duration::from_secs(seconds) - Duration::from_nanos(unix_now.subsec_nanos().into())
+            })
+            .unwrap_or_default()
+    }
+}
+
+type DeferHookFn = dyn Fn(&CreateEmailOptions, Duration) + Send + Sync;
+
+/// Callback registered with
+/// [`LettrBuilder::on_send_deferred`](crate::LettrBuilder::on_send_deferred).
+#[derive(Clone)]
+pub(crate) struct DeferHook(Arc<DeferHookFn>);
+
+impl DeferHook {
+    pub fn new(hook: impl Fn(&CreateEmailOptions, Duration) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+
+    pub fn call(&self, email: &CreateEmailOptions, wait: Duration) {
+        (self.0)(email, wait);
+    }
+}
+
+impl fmt::Debug for DeferHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DeferHook")
+    }
+}
+
+/// Waits until the client's send window is open for `email`, or fails if
+/// the window rejects sends outside it.
+pub(crate) async fn enter(config: &Config, email: &CreateEmailOptions) -> crate::Result<()> {
+    let Some(window) = config.send_window() else {
+        return Ok(());
+    };
+    let transactional = email
+        .options()
+        .and_then(|options| options.transactional)
+        .unwrap_or(true);
+    if transactional {
+        return Ok(());
+    }
+    let Some(now) = crate::clock::unix_now(config.clock()) else {
+        return Ok(());
+    };
+    let opens_in = window.opens_in(now);
+    if opens_in.is_zero() {
+        return Ok(());
+    }
+    if window.reject {
+        return Err(crate::Error::OutsideSendWindow { opens_in });
+    }
+    for hook in config.defer_hooks() {
+        hook.call(email, opens_in);
+    }
+    config.clock().sleep(opens_in).await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, SystemTime};
+
+    use super::{SendWindow, Weekday};
+    use crate::clock::MockClock;
+    use crate::transport::{async_trait, HeaderMap, HttpTransport, Request, Response, StatusCode};
+    use crate::{CreateEmailOptions, Error, Lettr};
+
+    /// Monday, 2024-01-01 00:00 UTC.
+    const MONDAY: u64 = 1_704_067_200;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    /// Accepts every email.
+    struct Accepting;
+
+    #[async_trait]
+    impl HttpTransport for Accepting {
+        async fn execute(&self, _request: Request) -> crate::Result<Response> {
+            let body =
+                r#"{"message":"OK","data":{"request_id":"request-1","accepted":1,"rejected":0}}"#;
+            Ok(Response::new(StatusCode::OK, HeaderMap::new(), body))
+        }
+    }
+
+    /// Sends a non-transactional email `since_monday` after [`MONDAY`],
+    /// returning the result, the sleeps, and the delays reported to the
+    /// defer hook.
+    async fn send_at(
+        window: SendWindow,
+        since_monday: Duration,
+    ) -> (crate::Result<()>, Vec<Duration>, Vec<Duration>) {
+        let clock = MockClock::starting_at(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(MONDAY) + since_monday,
+        );
+        let deferred = Arc::new(Mutex::new(Vec::new()));
+        let client = Lettr::builder("test-key")
+            .transport(Accepting)
+            .clock(clock.clone())
+            .send_window(window)
+            .on_send_deferred({
+                let deferred = deferred.clone();
+                move |_, wait| deferred.lock().unwrap().push(wait)
+            })
+            .build();
+        let email = CreateEmailOptions::new("sender@example.com", ["user@example.com"], "News")
+            .with_text("Hello!")
+            .with_transactional(false);
+
+        let result = client.emails.send(email).await.map(drop);
+        let deferred = deferred.lock().unwrap().clone();
+        (result, clock.sleeps(), deferred)
+    }
+
+    #[tokio::test]
+    async fn sends_inside_the_window_right_away() {
+        let (result, sleeps, deferred) = send_at(SendWindow::hours(9, 17), 10 * HOUR).await;
+        assert!(result.is_ok());
+        assert!(sleeps.is_empty());
+        assert!(deferred.is_empty());
+    }
+
+    #[tokio::test]
+    async fn waits_past_midnight_for_the_next_day() {
+        // Monday 20:00 until Tuesday 09:00.
+        let (result, sleeps, deferred) = send_at(SendWindow::hours(9, 17), 20 * HOUR).await;
+        assert!(result.is_ok());
+        assert_eq!(sleeps, [13 * HOUR]);
+        assert_eq!(deferred, [13 * HOUR]);
+    }
+
+    #[tokio::test]
+    async fn applies_the_offset_across_midnight() {
+        // Friday 23:30 UTC is Saturday 00:30 at UTC+1, so the window opens
+        // on Monday 09:00 at UTC+1, which is 08:00 UTC.
+        let window = SendWindow::hours(9, 17)
+            .days(Weekday::WEEKDAYS)
+            .utc_offset(60);
+        let friday = 4 * 24 * HOUR + 23 * HOUR + HOUR / 2;
+        let (result, sleeps, _) = send_at(window, friday).await;
+        assert!(result.is_ok());
+        assert_eq!(sleeps, [2 * 24 * HOUR + 8 * HOUR + HOUR / 2]);
+    }
+
+    #[tokio::test]
+    async fn rejects_outside_the_window_when_asked() {
+        let window = SendWindow::hours(9, 17).reject_outside();
+        let (result, sleeps, deferred) = send_at(window, 20 * HOUR).await;
+        assert!(matches!(
+            result,
+            Err(Error::OutsideSendWindow { opens_in }) if opens_in == 13 * HOUR
+        ));
+        assert!(sleeps.is_empty());
+        assert!(deferred.is_empty());
+    }
+}
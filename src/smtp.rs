@@ -0,0 +1,360 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::meta::WithMeta;
+
+/// Service for the `/smtp/credentials` endpoints.
+///
+/// SMTP credentials let systems that can only speak SMTP send through the
+/// Lettr relay. Each credential has its own username and password, so one
+/// can be revoked without affecting the others or the API key.
+#[derive(Clone, Debug)]
+pub struct SmtpCredentialsSvc(pub(crate) Arc<Config>);
+
+impl SmtpCredentialsSvc {
+    /// List all SMTP credentials. Passwords are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let credentials = client.smtp_credentials.list().await?;
+    /// for credential in &credentials {
+    ///     println!("{credential}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<Vec<SmtpCredential>> {
+        self.list_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<SmtpCredential>>> {
+        let request = self.0.build(Method::GET, "/smtp/credentials");
+        let response: WithMeta<ListSmtpCredentialsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.credentials))
+    }
+
+    /// Create an SMTP credential.
+    ///
+    /// The password is only returned here; store it right away.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::smtp::CreateSmtpCredentialOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = CreateSmtpCredentialOptions::new("Legacy CRM").with_domain("example.com");
+    /// let created = client.smtp_credentials.create(options).await?;
+    ///
+    /// let smtp = &created.credential;
+    /// println!("{}:{} as {}", smtp.host, smtp.port, smtp.username);
+    /// // Hand `created.password` to the legacy system's configuration.
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        options: CreateSmtpCredentialOptions,
+    ) -> crate::Result<NewSmtpCredential> {
+        self.create_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`create`](Self::create), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn create_with_meta(
+        &self,
+        options: CreateSmtpCredentialOptions,
+    ) -> crate::Result<WithMeta<NewSmtpCredential>> {
+        let request = self
+            .0
+            .build(Method::POST, "/smtp/credentials")
+            .json(&options);
+        let response: WithMeta<NewSmtpCredentialResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve a single SMTP credential. The password is not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let credential = client.smtp_credentials.get("credential-id").await?;
+    /// println!("Last used: {:?}", credential.last_used_at);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, credential_id: &str) -> crate::Result<SmtpCredential> {
+        self.get_with_meta(credential_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(
+        &self,
+        credential_id: &str,
+    ) -> crate::Result<WithMeta<SmtpCredential>> {
+        let path = format!("/smtp/credentials/{credential_id}");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/smtp/credentials/{id}");
+        let response: WithMeta<SmtpCredentialResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Revoke an SMTP credential. The relay rejects it from then on.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.smtp_credentials.revoke("credential-id").await?;
+    /// println!("Credential revoked.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn revoke(&self, credential_id: &str) -> crate::Result<()> {
+        self.revoke_with_meta(credential_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`revoke`](Self::revoke), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn revoke_with_meta(&self, credential_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/smtp/credentials/{credential_id}");
+        let request = self
+            .0
+            .build(Method::DELETE, &path)
+            .route("/smtp/credentials/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Drops cached `/smtp/credentials` responses, so the next call fetches
+    /// fresh data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/smtp/credentials");
+    }
+}
+
+/// The `/smtp/credentials` operations of [`SmtpCredentialsSvc`], as a trait.
+///
+/// Application code can depend on `dyn SmtpCredentialsApi` instead of the
+/// concrete service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait SmtpCredentialsApi: Send + Sync {
+    /// See [`SmtpCredentialsSvc::list`].
+    async fn list(&self) -> crate::Result<Vec<SmtpCredential>>;
+
+    /// See [`SmtpCredentialsSvc::create`].
+    async fn create(
+        &self,
+        options: CreateSmtpCredentialOptions,
+    ) -> crate::Result<NewSmtpCredential>;
+
+    /// See [`SmtpCredentialsSvc::get`].
+    async fn get(&self, credential_id: &str) -> crate::Result<SmtpCredential>;
+
+    /// See [`SmtpCredentialsSvc::revoke`].
+    async fn revoke(&self, credential_id: &str) -> crate::Result<()>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl SmtpCredentialsApi for SmtpCredentialsSvc {
+    async fn list(&self) -> crate::Result<Vec<SmtpCredential>> {
+        SmtpCredentialsSvc::list(self).await
+    }
+
+    async fn create(
+        &self,
+        options: CreateSmtpCredentialOptions,
+    ) -> crate::Result<NewSmtpCredential> {
+        SmtpCredentialsSvc::create(self, options).await
+    }
+
+    async fn get(&self, credential_id: &str) -> crate::Result<SmtpCredential> {
+        SmtpCredentialsSvc::get(self, credential_id).await
+    }
+
+    async fn revoke(&self, credential_id: &str) -> crate::Result<()> {
+        SmtpCredentialsSvc::revoke(self, credential_id).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// Options for creating an SMTP credential.
+#[must_use]
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateSmtpCredentialOptions {
+    /// Name to recognize the credential by.
+    pub(crate) name: String,
+
+    /// Sending domain the credential is restricted to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) domain: Option<String>,
+}
+
+impl CreateSmtpCredentialOptions {
+    /// Creates new [`CreateSmtpCredentialOptions`] with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            domain: None,
+        }
+    }
+
+    /// Only allows sending from this domain with the credential.
+    #[inline]
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListSmtpCredentialsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListSmtpCredentialsData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListSmtpCredentialsData {
+    credentials: Vec<SmtpCredential>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SmtpCredentialResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: SmtpCredential,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewSmtpCredentialResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: NewSmtpCredential,
+}
+
+/// An SMTP relay credential.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct SmtpCredential {
+    /// Credential ID.
+    pub id: String,
+    /// Name to recognize the credential by.
+    pub name: String,
+    /// SMTP username.
+    pub username: String,
+    /// Sending domain the credential is restricted to, if any.
+    #[serde(default)]
+    pub domain: Option<String>,
+    /// SMTP relay host.
+    pub host: String,
+    /// SMTP relay port, for STARTTLS.
+    pub port: u16,
+    /// When the credential was last used to send.
+    #[serde(default)]
+    pub last_used_at: Option<String>,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl SmtpCredential {
+    /// Creates a credential with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`SmtpCredentialsApi`].
+    pub fn new(id: impl Into<String>, username: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            username: username.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g.
+/// `Legacy CRM (smtp_123, user@smtp.lettr.com:587)`.
+impl fmt::Display for SmtpCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {}@{}:{})",
+            self.name, self.id, self.username, self.host, self.port
+        )
+    }
+}
+
+/// A newly created SMTP credential, with its password.
+///
+/// The password never appears in `Debug` output, wire logs, or cassettes.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct NewSmtpCredential {
+    /// The credential. Fields the SDK does not know about yet end up in its
+    /// `extra` map.
+    #[serde(flatten)]
+    pub credential: SmtpCredential,
+    /// SMTP password. It cannot be retrieved again later.
+    pub password: String,
+}
+
+impl NewSmtpCredential {
+    /// Creates a new credential response, e.g. for a mock
+    /// [`SmtpCredentialsApi`].
+    pub fn new(credential: SmtpCredential, password: impl Into<String>) -> Self {
+        Self {
+            credential,
+            password: password.into(),
+        }
+    }
+}
+
+impl fmt::Debug for NewSmtpCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NewSmtpCredential")
+            .field("credential", &self.credential)
+            .field("password", &"[REDACTED]")
+            .finish()
+    }
+}
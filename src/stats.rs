@@ -0,0 +1,360 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::filter::{DateRange, Timestamp};
+use crate::meta::WithMeta;
+
+/// Service for the `/stats` endpoint.
+///
+/// Returns sending and engagement counts aggregated into time buckets, for
+/// dashboards and reports that would otherwise page through every event.
+#[derive(Clone, Debug)]
+pub struct StatsSvc(pub(crate) Arc<Config>);
+
+impl StatsSvc {
+    /// Retrieve aggregate statistics over a date range.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::stats::{StatsInterval, StatsOptions};
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = StatsOptions::new("2024-01-01", "2024-01-31")
+    ///     .interval(StatsInterval::Week)
+    ///     .domain("example.com");
+    /// let stats = client.stats.get(options).await?;
+    ///
+    /// for bucket in &stats.buckets {
+    ///     println!("{}: {} delivered", bucket.start, bucket.counts.delivered);
+    /// }
+    /// println!("open rate: {:.1}%", stats.totals.open_rate() * 100.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, options: StatsOptions) -> crate::Result<StatsResponse> {
+        self.get_with_meta(options).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(
+        &self,
+        options: StatsOptions,
+    ) -> crate::Result<WithMeta<StatsResponse>> {
+        let mut request = self.0.build(Method::GET, "/stats").query(&[
+            ("from", options.from),
+            ("to", options.to),
+            ("interval", options.interval.as_str().to_owned()),
+        ]);
+
+        if let Some(domain) = options.domain {
+            request = request.query(&[("domain", domain)]);
+        }
+        if let Some(template) = options.template {
+            request = request.query(&[("template", template)]);
+        }
+        if let Some(tag) = options.tag {
+            request = request.query(&[("tag", tag)]);
+        }
+
+        let response: WithMeta<StatsResponseWrapper> = self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Drops cached `/stats` responses, so the next call fetches fresh data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/stats");
+    }
+}
+
+/// The `/stats` operations of [`StatsSvc`], as a trait.
+///
+/// Application code can depend on `dyn StatsApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait StatsApi: Send + Sync {
+    /// See [`StatsSvc::get`].
+    async fn get(&self, options: StatsOptions) -> crate::Result<StatsResponse>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl StatsApi for StatsSvc {
+    async fn get(&self, options: StatsOptions) -> crate::Result<StatsResponse> {
+        StatsSvc::get(self, options).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// Length of the time buckets statistics are aggregated into.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatsInterval {
+    /// One bucket per hour.
+    Hour,
+    /// One bucket per day.
+    #[default]
+    Day,
+    /// One bucket per week, starting on Monday.
+    Week,
+    /// One bucket per calendar month.
+    Month,
+}
+
+impl StatsInterval {
+    /// Returns the name the API uses for this interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::stats::StatsInterval;
+    ///
+    /// assert_eq!(StatsInterval::Week.as_str(), "week");
+    /// ```
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+}
+
+impl fmt::Display for StatsInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Options for retrieving statistics.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct StatsOptions {
+    pub(crate) from: String,
+    pub(crate) to: String,
+    pub(crate) interval: StatsInterval,
+    pub(crate) domain: Option<String>,
+    pub(crate) template: Option<String>,
+    pub(crate) tag: Option<String>,
+}
+
+impl StatsOptions {
+    /// Creates new [`StatsOptions`] for the range from `from` to `to`
+    /// inclusive, in daily buckets.
+    pub fn new(from: impl Into<Timestamp>, to: impl Into<Timestamp>) -> Self {
+        Self {
+            from: from.into().to_string(),
+            to: to.into().to_string(),
+            interval: StatsInterval::default(),
+            domain: None,
+            template: None,
+            tag: None,
+        }
+    }
+
+    /// Creates new [`StatsOptions`] for `range`, in daily buckets.
+    pub fn in_range(range: DateRange) -> Self {
+        Self::new(range.from, range.to)
+    }
+
+    /// Sets the length of the time buckets.
+    #[inline]
+    pub fn interval(mut self, interval: StatsInterval) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Only counts emails sent from this domain.
+    #[inline]
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Only counts emails sent with the template with this slug.
+    #[inline]
+    pub fn template(mut self, slug: impl Into<String>) -> Self {
+        self.template = Some(slug.into());
+        self
+    }
+
+    /// Only counts emails sent with this tag; see
+    /// [`CreateEmailOptions::with_tag`](crate::CreateEmailOptions::with_tag).
+    #[inline]
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct StatsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: StatsResponse,
+}
+
+/// Response from retrieving statistics.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct StatsResponse {
+    /// Counts per time bucket, oldest first. Buckets without any emails are
+    /// included with zero counts.
+    pub buckets: Vec<StatsBucket>,
+    /// Counts over the whole range.
+    pub totals: StatsCounts,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl StatsResponse {
+    /// Creates a response from `buckets`, with totals summed from them, e.g.
+    /// for a mock [`StatsApi`] in tests.
+    pub fn new(buckets: Vec<StatsBucket>) -> Self {
+        let totals = buckets
+            .iter()
+            .fold(StatsCounts::default(), |totals, bucket| {
+                totals + &bucket.counts
+            });
+        Self {
+            buckets,
+            totals,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+/// Counts for one time bucket.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct StatsBucket {
+    /// Start of the bucket (ISO 8601 format).
+    pub start: String,
+    /// Counts within the bucket. Fields the SDK does not know about yet end
+    /// up in its `extra` map.
+    #[serde(flatten)]
+    pub counts: StatsCounts,
+}
+
+impl StatsBucket {
+    /// Creates a bucket starting at `start`.
+    pub fn new(start: impl Into<String>, counts: StatsCounts) -> Self {
+        Self {
+            start: start.into(),
+            counts,
+        }
+    }
+}
+
+/// Sending and engagement counts.
+///
+/// Opens and clicks count unique recipients.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct StatsCounts {
+    /// Emails handed to the delivery pipeline.
+    #[serde(default)]
+    pub sent: u64,
+    /// Emails accepted by the recipient's server.
+    #[serde(default)]
+    pub delivered: u64,
+    /// Recipients who opened the email.
+    #[serde(default)]
+    pub opened: u64,
+    /// Recipients who clicked a link.
+    #[serde(default)]
+    pub clicked: u64,
+    /// Emails that bounced.
+    #[serde(default)]
+    pub bounced: u64,
+    /// Recipients who marked the email as spam.
+    #[serde(default)]
+    pub complaints: u64,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl StatsCounts {
+    /// Returns the share of delivered emails that were opened, from 0 to 1.
+    #[must_use]
+    pub fn open_rate(&self) -> f64 {
+        ratio(self.opened, self.delivered)
+    }
+
+    /// Returns the share of delivered emails with a clicked link, from 0
+    /// to 1.
+    #[must_use]
+    pub fn click_rate(&self) -> f64 {
+        ratio(self.clicked, self.delivered)
+    }
+
+    /// Returns the share of sent emails that bounced, from 0 to 1.
+    #[must_use]
+    pub fn bounce_rate(&self) -> f64 {
+        ratio(self.bounced, self.sent)
+    }
+
+    /// Returns the share of delivered emails marked as spam, from 0 to 1.
+    #[must_use]
+    pub fn complaint_rate(&self) -> f64 {
+        ratio(self.complaints, self.delivered)
+    }
+}
+
+/// Adds the counts of two buckets, e.g. to total a subset of them.
+///
+/// # Example
+///
+/// ```
+/// use lettr::stats::StatsCounts;
+///
+/// let mut monday = StatsCounts::default();
+/// monday.sent = 10;
+/// let mut tuesday = StatsCounts::default();
+/// tuesday.sent = 5;
+/// assert_eq!((monday + &tuesday).sent, 15);
+/// ```
+impl std::ops::Add<&StatsCounts> for StatsCounts {
+    type Output = StatsCounts;
+
+    fn add(self, other: &StatsCounts) -> StatsCounts {
+        StatsCounts {
+            sent: self.sent + other.sent,
+            delivered: self.delivered + other.delivered,
+            opened: self.opened + other.opened,
+            clicked: self.clicked + other.clicked,
+            bounced: self.bounced + other.bounced,
+            complaints: self.complaints + other.complaints,
+            extra: self.extra,
+        }
+    }
+}
+
+/// Returns `part / whole`, or 0 if `whole` is 0.
+pub(crate) fn ratio(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
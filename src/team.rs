@@ -0,0 +1,457 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::meta::WithMeta;
+
+/// Service for the `/team` endpoints.
+///
+/// Lists the people with access to the account and manages invitations, so
+/// user provisioning can add and remove people without the dashboard.
+#[derive(Clone, Debug)]
+pub struct TeamSvc(pub(crate) Arc<Config>);
+
+impl TeamSvc {
+    /// List the members of the team.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let members = client.team.list_members().await?;
+    /// for member in &members {
+    ///     println!("{member}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_members(&self) -> crate::Result<Vec<TeamMember>> {
+        self.list_members_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list_members`](Self::list_members), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn list_members_with_meta(&self) -> crate::Result<WithMeta<Vec<TeamMember>>> {
+        let request = self.0.build(Method::GET, "/team/members");
+        let response: WithMeta<ListTeamMembersResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.members))
+    }
+
+    /// Remove a member from the team. Their access ends at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.team.remove_member("member-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_member(&self, member_id: &str) -> crate::Result<()> {
+        self.remove_member_with_meta(member_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`remove_member`](Self::remove_member), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn remove_member_with_meta(&self, member_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/team/members/{member_id}");
+        let request = self
+            .0
+            .build(Method::DELETE, &path)
+            .route("/team/members/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// List the roles members can have.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// for role in client.team.list_roles().await? {
+    ///     println!("{}: {}", role.name, role.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_roles(&self) -> crate::Result<Vec<TeamRole>> {
+        self.list_roles_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list_roles`](Self::list_roles), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn list_roles_with_meta(&self) -> crate::Result<WithMeta<Vec<TeamRole>>> {
+        let request = self.0.build(Method::GET, "/team/roles");
+        let response: WithMeta<ListTeamRolesResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.roles))
+    }
+
+    /// List invitations that have not been accepted yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// for invitation in client.team.list_invitations().await? {
+    ///     println!("{invitation}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_invitations(&self) -> crate::Result<Vec<TeamInvitation>> {
+        self.list_invitations_with_meta()
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`list_invitations`](Self::list_invitations), but also returns
+    /// the HTTP status, headers, and rate-limit information of the response.
+    pub async fn list_invitations_with_meta(&self) -> crate::Result<WithMeta<Vec<TeamInvitation>>> {
+        let request = self.0.build(Method::GET, "/team/invitations");
+        let response: WithMeta<ListTeamInvitationsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.invitations))
+    }
+
+    /// Invite someone to the team. Lettr emails them a link to accept.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::team::InviteTeamMemberOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = InviteTeamMemberOptions::new("dev@example.com", "developer");
+    /// let invitation = client.team.invite(options).await?;
+    /// println!("Invitation expires at {}", invitation.expires_at);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn invite(&self, options: InviteTeamMemberOptions) -> crate::Result<TeamInvitation> {
+        self.invite_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`invite`](Self::invite), but also returns the HTTP status,
+    /// headers, and rate-limit information of the response.
+    pub async fn invite_with_meta(
+        &self,
+        options: InviteTeamMemberOptions,
+    ) -> crate::Result<WithMeta<TeamInvitation>> {
+        let request = self
+            .0
+            .build(Method::POST, "/team/invitations")
+            .json(&options);
+        let response: WithMeta<TeamInvitationResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Revoke an invitation that has not been accepted yet. Its link stops
+    /// working.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.team.revoke_invitation("invitation-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn revoke_invitation(&self, invitation_id: &str) -> crate::Result<()> {
+        self.revoke_invitation_with_meta(invitation_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`revoke_invitation`](Self::revoke_invitation), but also returns
+    /// the HTTP status, headers, and rate-limit information of the response.
+    pub async fn revoke_invitation_with_meta(
+        &self,
+        invitation_id: &str,
+    ) -> crate::Result<WithMeta<()>> {
+        let path = format!("/team/invitations/{invitation_id}");
+        let request = self
+            .0
+            .build(Method::DELETE, &path)
+            .route("/team/invitations/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Drops cached `/team` responses, so the next call fetches fresh data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/team");
+    }
+}
+
+/// The `/team` operations of [`TeamSvc`], as a trait.
+///
+/// Application code can depend on `dyn TeamApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait TeamApi: Send + Sync {
+    /// See [`TeamSvc::list_members`].
+    async fn list_members(&self) -> crate::Result<Vec<TeamMember>>;
+
+    /// See [`TeamSvc::remove_member`].
+    async fn remove_member(&self, member_id: &str) -> crate::Result<()>;
+
+    /// See [`TeamSvc::list_roles`].
+    async fn list_roles(&self) -> crate::Result<Vec<TeamRole>>;
+
+    /// See [`TeamSvc::list_invitations`].
+    async fn list_invitations(&self) -> crate::Result<Vec<TeamInvitation>>;
+
+    /// See [`TeamSvc::invite`].
+    async fn invite(&self, options: InviteTeamMemberOptions) -> crate::Result<TeamInvitation>;
+
+    /// See [`TeamSvc::revoke_invitation`].
+    async fn revoke_invitation(&self, invitation_id: &str) -> crate::Result<()>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TeamApi for TeamSvc {
+    async fn list_members(&self) -> crate::Result<Vec<TeamMember>> {
+        TeamSvc::list_members(self).await
+    }
+
+    async fn remove_member(&self, member_id: &str) -> crate::Result<()> {
+        TeamSvc::remove_member(self, member_id).await
+    }
+
+    async fn list_roles(&self) -> crate::Result<Vec<TeamRole>> {
+        TeamSvc::list_roles(self).await
+    }
+
+    async fn list_invitations(&self) -> crate::Result<Vec<TeamInvitation>> {
+        TeamSvc::list_invitations(self).await
+    }
+
+    async fn invite(&self, options: InviteTeamMemberOptions) -> crate::Result<TeamInvitation> {
+        TeamSvc::invite(self, options).await
+    }
+
+    async fn revoke_invitation(&self, invitation_id: &str) -> crate::Result<()> {
+        TeamSvc::revoke_invitation(self, invitation_id).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// Options for inviting someone to the team.
+#[must_use]
+#[derive(Debug, Clone, Serialize)]
+pub struct InviteTeamMemberOptions {
+    /// Email address to send the invitation to.
+    pub(crate) email: String,
+
+    /// Role the member gets on accepting.
+    pub(crate) role: String,
+}
+
+impl InviteTeamMemberOptions {
+    /// Creates new [`InviteTeamMemberOptions`] for `email` with the role
+    /// named `role`; see [`TeamSvc::list_roles`].
+    pub fn new(email: impl Into<String>, role: impl Into<String>) -> Self {
+        Self {
+            email: email.into(),
+            role: role.into(),
+        }
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListTeamMembersResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListTeamMembersData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListTeamMembersData {
+    members: Vec<TeamMember>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListTeamRolesResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListTeamRolesData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListTeamRolesData {
+    roles: Vec<TeamRole>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListTeamInvitationsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListTeamInvitationsData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListTeamInvitationsData {
+    invitations: Vec<TeamInvitation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TeamInvitationResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: TeamInvitation,
+}
+
+/// A person with access to the account.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct TeamMember {
+    /// Member ID.
+    pub id: String,
+    /// Full name.
+    #[serde(default)]
+    pub name: String,
+    /// Email address.
+    pub email: String,
+    /// Name of the member's role, e.g. `"admin"`.
+    pub role: String,
+    /// When the member last signed in.
+    #[serde(default)]
+    pub last_login_at: Option<String>,
+    /// When the member joined.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TeamMember {
+    /// Creates a member with the other fields empty. Set them as needed, e.g.
+    /// for a mock [`TeamApi`].
+    pub fn new(id: impl Into<String>, email: impl Into<String>, role: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            email: email.into(),
+            role: role.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g. `Jane Doe <jane@example.com> (admin)`.
+impl fmt::Display for TeamMember {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "{} ({})", self.email, self.role)
+        } else {
+            write!(f, "{} <{}> ({})", self.name, self.email, self.role)
+        }
+    }
+}
+
+/// A role team members can have.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct TeamRole {
+    /// Role name, as used in [`TeamMember::role`].
+    pub name: String,
+    /// What the role may do.
+    #[serde(default)]
+    pub description: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TeamRole {
+    /// Creates a role, e.g. for a mock [`TeamApi`].
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// An invitation to join the team that has not been accepted yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct TeamInvitation {
+    /// Invitation ID.
+    pub id: String,
+    /// Email address the invitation was sent to.
+    pub email: String,
+    /// Role the member gets on accepting.
+    pub role: String,
+    /// When the invitation link stops working.
+    pub expires_at: String,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TeamInvitation {
+    /// Creates an invitation with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`TeamApi`].
+    pub fn new(id: impl Into<String>, email: impl Into<String>, role: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            email: email.into(),
+            role: role.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g. `dev@example.com (developer,
+/// expires 2024-01-22T10:30:00+00:00)`.
+impl fmt::Display for TeamInvitation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, expires {})",
+            self.email, self.role, self.expires_at
+        )
+    }
+}
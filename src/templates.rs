@@ -1,9 +1,19 @@
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::config::Config;
+use crate::emails::{LinkClicks, LinkClicksResponseWrapper};
+use crate::error::{InvalidOptions, Problem};
+use crate::meta::WithMeta;
+use crate::time::Instant;
+
+/// How long the template slugs listed for locale resolution are reused.
+const SLUGS_TTL: Duration = Duration::from_secs(5 * 60);
 
 /// Service for the `/templates` endpoints.
 #[derive(Clone, Debug)]
@@ -32,11 +42,19 @@ impl TemplatesSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn list(
         &self,
         options: ListTemplatesOptions,
     ) -> crate::Result<ListTemplatesResponse> {
+        self.list_with_meta(options).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(
+        &self,
+        options: ListTemplatesOptions,
+    ) -> crate::Result<WithMeta<ListTemplatesResponse>> {
         let mut request = self.0.build(Method::GET, "/templates");
 
         if let Some(project_id) = options.project_id {
@@ -49,9 +67,9 @@ impl TemplatesSvc {
             request = request.query(&[("page", page.to_string())]);
         }
 
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<ListTemplatesResponseWrapper>().await?;
-        Ok(wrapper.data)
+        let response: WithMeta<ListTemplatesResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
     }
 
     /// Create a new email template.
@@ -74,16 +92,372 @@ impl TemplatesSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn create(
         &self,
         options: CreateTemplateOptions,
     ) -> crate::Result<CreateTemplateResponse> {
+        self.create_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`create`](Self::create), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn create_with_meta(
+        &self,
+        options: CreateTemplateOptions,
+    ) -> crate::Result<WithMeta<CreateTemplateResponse>> {
         let request = self.0.build(Method::POST, "/templates").json(&options);
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<CreateTemplateResponseWrapper>().await?;
-        Ok(wrapper.data)
+        let response: WithMeta<CreateTemplateResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        self.0.template_slugs().clear();
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// List the [locale variants](CreateTemplateOptions::locale_variant) of
+    /// the template `slug`, following every page. The paging settings of
+    /// `options` are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::templates::ListTemplatesOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// for variant in client.templates.locales("welcome", ListTemplatesOptions::new()).await? {
+    ///     println!("{}: {}", variant.locale().unwrap_or_default(), variant.slug);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn locales(
+        &self,
+        slug: &str,
+        options: ListTemplatesOptions,
+    ) -> crate::Result<Vec<Template>> {
+        let templates = self.list_all(options).await?;
+        Ok(templates
+            .into_iter()
+            .filter(|template| template.base_slug() == Some(slug))
+            .collect())
+    }
+
+    /// Returns the slugs of every template in the project, reusing the ones
+    /// listed less than [`SLUGS_TTL`] ago.
+    pub(crate) async fn slugs(
+        &self,
+        project_id: Option<u64>,
+    ) -> crate::Result<Arc<HashSet<String>>> {
+        let memo = self.0.template_slugs();
+        if let Some(slugs) = memo.get(project_id, self.0.clock().now()) {
+            return Ok(slugs);
+        }
+
+        let options = ListTemplatesOptions {
+            project_id,
+            ..ListTemplatesOptions::default()
+        };
+        let templates = self.list_all(options).await?;
+        let slugs: Arc<HashSet<String>> = Arc::new(
+            templates
+                .into_iter()
+                .map(|template| template.slug)
+                .collect(),
+        );
+        memo.insert(project_id, self.0.clock().now(), Arc::clone(&slugs));
+        Ok(slugs)
+    }
+
+    async fn list_all(&self, mut options: ListTemplatesOptions) -> crate::Result<Vec<Template>> {
+        options.per_page = Some(100);
+        let mut templates = Vec::new();
+        for page in 1.. {
+            options.page = Some(page);
+            let response = self.list(options.clone()).await?;
+            templates.extend(response.templates);
+            if response.pagination.current_page >= response.pagination.last_page {
+                break;
+            }
+        }
+        Ok(templates)
+    }
+
+    /// Retrieve the clicks on each tracked link of a template, across every
+    /// email sent with it, most clicked first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let links = client.templates.clicks("order-receipt").await?;
+    /// if let Some(top) = links.first() {
+    ///     println!("Most clicked: {} ({} recipients)", top.url, top.unique_clicks);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clicks(&self, slug: &str) -> crate::Result<Vec<LinkClicks>> {
+        self.clicks_with_meta(slug).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`clicks`](Self::clicks), but also returns the HTTP status,
+    /// headers, and rate-limit information of the response.
+    pub async fn clicks_with_meta(&self, slug: &str) -> crate::Result<WithMeta<Vec<LinkClicks>>> {
+        let path = format!("/templates/{slug}/clicks");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/templates/{id}/clicks");
+        let response: WithMeta<LinkClicksResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.links))
+    }
+
+    /// Drops cached `/templates` responses, so the next call fetches fresh data.
+    ///
+    /// Also forgets the template slugs used to resolve
+    /// [locales](crate::CreateEmailOptions::with_locale). Cached responses
+    /// are only kept when caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/templates");
+        self.0.template_slugs().clear();
+    }
+}
+
+/// Template slugs per project, listed to resolve locale variants and shared
+/// by the clones of a client.
+#[derive(Debug, Default)]
+pub(crate) struct SlugMemo(Mutex<HashMap<Option<u64>, Listed>>);
+
+/// When slugs were listed, and the slugs.
+type Listed = (Instant, Arc<HashSet<String>>);
+
+impl SlugMemo {
+    /// Returns the slugs of `project_id` if they were listed less than
+    /// [`SLUGS_TTL`] before `now`.
+    fn get(&self, project_id: Option<u64>, now: Instant) -> Option<Arc<HashSet<String>>> {
+        let memo = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let (listed_at, slugs) = memo.get(&project_id)?;
+        (now.saturating_duration_since(*listed_at) < SLUGS_TTL).then(|| Arc::clone(slugs))
+    }
+
+    fn insert(&self, project_id: Option<u64>, now: Instant, slugs: Arc<HashSet<String>>) {
+        let mut memo = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        memo.insert(project_id, (now, slugs));
+    }
+
+    /// Forgets all slugs, e.g. after a template was created.
+    pub fn clear(&self) {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+/// The `/templates` operations of [`TemplatesSvc`], as a trait.
+///
+/// Application code can depend on `dyn TemplatesApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait TemplatesApi: Send + Sync {
+    /// See [`TemplatesSvc::list`].
+    async fn list(&self, options: ListTemplatesOptions) -> crate::Result<ListTemplatesResponse>;
+
+    /// See [`TemplatesSvc::create`].
+    async fn create(&self, options: CreateTemplateOptions)
+        -> crate::Result<CreateTemplateResponse>;
+
+    /// See [`TemplatesSvc::clicks`].
+    async fn clicks(&self, slug: &str) -> crate::Result<Vec<LinkClicks>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TemplatesApi for TemplatesSvc {
+    async fn list(&self, options: ListTemplatesOptions) -> crate::Result<ListTemplatesResponse> {
+        TemplatesSvc::list(self, options).await
+    }
+
+    async fn create(
+        &self,
+        options: CreateTemplateOptions,
+    ) -> crate::Result<CreateTemplateResponse> {
+        TemplatesSvc::create(self, options).await
+    }
+
+    async fn clicks(&self, slug: &str) -> crate::Result<Vec<LinkClicks>> {
+        TemplatesSvc::clicks(self, slug).await
+    }
+}
+
+// ── Locales ────────────────────────────────────────────────────────────────
+
+/// Returns the slug of the `locale` variant of the template `slug`, e.g.
+/// `welcome-de-at` for `welcome` and `de_AT`.
+///
+/// # Example
+///
+/// ```
+/// use lettr::templates::locale_slug;
+///
+/// assert_eq!(locale_slug("welcome", "de_AT"), "welcome-de-at");
+/// ```
+#[must_use]
+pub fn locale_slug(slug: &str, locale: &str) -> String {
+    format!("{slug}-{}", normalize_locale(locale))
+}
+
+/// Returns the locales to try for `locale`, most specific first: `de-AT`
+/// gives `de-at` and `de`, followed by the `fallback` locales.
+pub(crate) fn locale_chain(locale: &str, fallback: &[String]) -> Vec<String> {
+    let mut chain: Vec<String> = Vec::new();
+    let mut push = |locale: String| {
+        if !locale.is_empty() && !chain.contains(&locale) {
+            chain.push(locale);
+        }
+    };
+    let mut locale = normalize_locale(locale);
+    loop {
+        push(locale.clone());
+        match locale.rsplit_once('-') {
+            Some((parent, _)) => locale = parent.to_owned(),
+            None => break,
+        }
+    }
+    for locale in fallback {
+        push(normalize_locale(locale));
     }
+    chain
+}
+
+/// Lowercases `locale` and joins its parts with `-`, as template slugs do.
+fn normalize_locale(locale: &str) -> String {
+    locale
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// ── Sample Data ────────────────────────────────────────────────────────────
+
+/// Returns substitution data with a plausible sample value for every merge
+/// tag, e.g. `Jane` for `first_name` or `jane.doe@example.com` for
+/// `email`, so a template can be previewed or test-sent without writing the
+/// data by hand.
+///
+/// Dotted keys such as `user.first_name` become nested objects. The values
+/// are fixed, so previews do not change between runs; see
+/// [`MergeTag::sample_value`].
+///
+/// # Example
+///
+/// ```
+/// use lettr::CreateEmailOptions;
+/// use lettr::templates::{sample_substitutions, MergeTag};
+///
+/// let tags = [MergeTag::new("first_name"), MergeTag::new("order.total")];
+/// let data = sample_substitutions(&tags);
+/// assert_eq!(data["first_name"], "Jane");
+/// assert_eq!(data["order"]["total"], "$42.00");
+///
+/// let test_send = CreateEmailOptions::new("hello@example.com", ["designer@example.com"], "Preview")
+///     .with_template("order-shipped")
+///     .with_substitution_data(data);
+/// ```
+#[must_use]
+pub fn sample_substitutions(merge_tags: &[MergeTag]) -> HashMap<String, serde_json::Value> {
+    let mut data = serde_json::Map::new();
+    for tag in merge_tags {
+        let mut parts = tag.key.split('.').filter(|part| !part.is_empty());
+        let Some(mut part) = parts.next() else {
+            continue;
+        };
+        let mut object = &mut data;
+        for next in parts {
+            let entry = object
+                .entry(part)
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            if !entry.is_object() {
+                *entry = serde_json::Value::Object(serde_json::Map::new());
+            }
+            object = match entry {
+                serde_json::Value::Object(object) => object,
+                _ => unreachable!("replaced with an object above"),
+            };
+            part = next;
+        }
+        object.entry(part).or_insert_with(|| tag.sample_value());
+    }
+    data.into_iter().collect()
+}
+
+/// Returns a sample value for the merge tag `key`, guessed from its last
+/// dotted part.
+fn sample_value(key: &str) -> serde_json::Value {
+    let name = key.rsplit('.').next().unwrap_or(key).to_ascii_lowercase();
+    let words: Vec<&str> = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    let has = |candidates: &[&str]| {
+        candidates
+            .iter()
+            .any(|candidate| words.contains(candidate) || name == *candidate)
+    };
+
+    let value = if has(&["email", "mail"]) {
+        "jane.doe@example.com"
+    } else if has(&["first", "firstname", "given"]) {
+        "Jane"
+    } else if has(&["last", "lastname", "surname", "family"]) {
+        "Doe"
+    } else if has(&[
+        "company",
+        "organization",
+        "organisation",
+        "business",
+        "team",
+    ]) {
+        "Acme Inc"
+    } else if has(&["name", "fullname", "customer", "user", "recipient"]) {
+        "Jane Doe"
+    } else if has(&["phone", "mobile", "tel"]) {
+        "+1 555 0100"
+    } else if has(&["url", "link", "href", "website"]) {
+        "https://example.com"
+    } else if has(&[
+        "amount", "total", "subtotal", "price", "cost", "balance", "fee",
+    ]) {
+        "$42.00"
+    } else if has(&["date", "day", "deadline"]) {
+        "2025-01-15"
+    } else if has(&["time"]) {
+        "10:30"
+    } else if has(&["address", "street"]) {
+        "123 Main Street"
+    } else if has(&["city", "town"]) {
+        "Springfield"
+    } else if has(&["country"]) {
+        "United States"
+    } else if has(&["zip", "postal", "postcode"]) {
+        "12345"
+    } else if has(&["code", "otp", "pin", "token"]) {
+        "123456"
+    } else if has(&["count", "quantity", "qty"]) {
+        return serde_json::Value::from(3);
+    } else if has(&["id", "number", "no", "reference", "ref"]) {
+        "1042"
+    } else {
+        "Lorem ipsum"
+    };
+    serde_json::Value::from(value)
 }
 
 // ── Request Types ──────────────────────────────────────────────────────────
@@ -92,9 +466,9 @@ impl TemplatesSvc {
 #[must_use]
 #[derive(Debug, Default, Clone)]
 pub struct ListTemplatesOptions {
-    project_id: Option<u64>,
-    per_page: Option<u32>,
-    page: Option<u32>,
+    pub(crate) project_id: Option<u64>,
+    pub(crate) per_page: Option<u32>,
+    pub(crate) page: Option<u32>,
 }
 
 impl ListTemplatesOptions {
@@ -130,23 +504,23 @@ impl ListTemplatesOptions {
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateTemplateOptions {
     /// Template name.
-    name: String,
+    pub(crate) name: String,
 
     /// HTML content for the template.
     #[serde(skip_serializing_if = "Option::is_none")]
-    html: Option<String>,
+    pub(crate) html: Option<String>,
 
     /// Topol editor JSON content.
     #[serde(skip_serializing_if = "Option::is_none")]
-    json: Option<String>,
+    pub(crate) json: Option<String>,
 
     /// Project ID. If not set, uses the team's default project.
     #[serde(skip_serializing_if = "Option::is_none")]
-    project_id: Option<u64>,
+    pub(crate) project_id: Option<u64>,
 
     /// Folder ID within the project.
     #[serde(skip_serializing_if = "Option::is_none")]
-    folder_id: Option<u64>,
+    pub(crate) folder_id: Option<u64>,
 }
 
 impl CreateTemplateOptions {
@@ -161,6 +535,55 @@ impl CreateTemplateOptions {
         }
     }
 
+    /// Checks the options for problems the API would reject, and returns
+    /// them if there are none.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`Problem`] found: an empty name, or both HTML and JSON
+    /// content.
+    pub fn try_build(self) -> Result<Self, InvalidOptions> {
+        let mut problems = Vec::new();
+        if self.name.trim().is_empty() {
+            problems.push(Problem::NoName);
+        }
+        if self.html.is_some() && self.json.is_some() {
+            problems.push(Problem::ConflictingContent);
+        }
+
+        if problems.is_empty() {
+            Ok(self)
+        } else {
+            Err(InvalidOptions { problems })
+        }
+    }
+
+    /// Creates options for the `locale` variant of the template `slug`.
+    ///
+    /// The variant is named `{slug}.{locale}`, e.g. `welcome.de`, so its slug
+    /// is [`locale_slug(slug, locale)`](locale_slug) and
+    /// [`CreateEmailOptions::with_locale`](crate::CreateEmailOptions::with_locale)
+    /// can find it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::templates::CreateTemplateOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let variant = CreateTemplateOptions::locale_variant("welcome", "de")
+    ///     .with_html("<h1>Hallo {{FIRST_NAME}}!</h1>");
+    /// let result = client.templates.create(variant).await?;
+    /// assert_eq!(result.slug, "welcome-de");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn locale_variant(slug: &str, locale: &str) -> Self {
+        Self::new(format!("{slug}.{locale}"))
+    }
+
     /// Sets the HTML content for the template.
     #[inline]
     pub fn with_html(mut self, html: impl Into<String>) -> Self {
@@ -200,16 +623,41 @@ struct ListTemplatesResponseWrapper {
 }
 
 /// Response from listing templates.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct ListTemplatesResponse {
     /// List of templates.
     pub templates: Vec<Template>,
     /// Pagination information.
     pub pagination: TemplatePagination,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ListTemplatesResponse {
+    /// Creates a single page holding `templates`, e.g. for a mock
+    /// [`TemplatesApi`](crate::services::TemplatesApi) in tests.
+    pub fn new(templates: Vec<Template>) -> Self {
+        let count = u32::try_from(templates.len()).unwrap_or(u32::MAX);
+        Self {
+            pagination: TemplatePagination {
+                total: templates.len() as u64,
+                per_page: count,
+                current_page: 1,
+                last_page: 1,
+                extra: serde_json::Map::new(),
+            },
+            templates,
+            extra: serde_json::Map::new(),
+        }
+    }
 }
 
 /// An email template.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct Template {
     /// Template ID.
     pub id: u64,
@@ -225,10 +673,52 @@ pub struct Template {
     pub created_at: String,
     /// Last update timestamp.
     pub updated_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Template {
+    /// Creates a template with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`TemplatesApi`](crate::services::TemplatesApi).
+    pub fn new(id: u64, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the locale of a
+    /// [locale variant](CreateTemplateOptions::locale_variant), e.g. `de`
+    /// for the template named `welcome.de`.
+    #[must_use]
+    pub fn locale(&self) -> Option<&str> {
+        self.split_locale().map(|(_, locale)| locale)
+    }
+
+    /// Returns the slug of the template this is a locale variant of.
+    fn base_slug(&self) -> Option<&str> {
+        self.split_locale().map(|(base, _)| base)
+    }
+
+    fn split_locale(&self) -> Option<(&str, &str)> {
+        let (base, locale) = self.name.rsplit_once('.')?;
+        (!locale.is_empty() && self.slug == locale_slug(base, locale)).then_some((base, locale))
+    }
+}
+
+/// Formats as a one-line summary, e.g. `Welcome (#1, welcome)`.
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (#{}, {})", self.name, self.id, self.slug)
+    }
 }
 
 /// Pagination metadata for template list responses.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct TemplatePagination {
     /// Total number of templates.
     pub total: u64,
@@ -238,6 +728,10 @@ pub struct TemplatePagination {
     pub current_page: u32,
     /// Last page number.
     pub last_page: u32,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -248,7 +742,8 @@ struct CreateTemplateResponseWrapper {
 }
 
 /// Response from creating a template.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct CreateTemplateResponse {
     /// Template ID.
     pub id: u64,
@@ -267,13 +762,134 @@ pub struct CreateTemplateResponse {
     pub merge_tags: Vec<MergeTag>,
     /// Creation timestamp.
     pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CreateTemplateResponse {
+    /// Creates a response with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`TemplatesApi`](crate::services::TemplatesApi).
+    pub fn new(id: u64, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            ..Self::default()
+        }
+    }
 }
 
 /// A merge tag extracted from a template.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct MergeTag {
     /// The merge tag key.
     pub key: String,
     /// Whether this merge tag is required.
     pub required: bool,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl MergeTag {
+    /// Creates an optional merge tag, e.g. for a mock
+    /// [`TemplatesApi`](crate::services::TemplatesApi).
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns a plausible sample value for the tag, guessed from its key:
+    /// names, email addresses, amounts, dates, and the like, or
+    /// `Lorem ipsum` for keys it does not recognize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::templates::MergeTag;
+    ///
+    /// assert_eq!(MergeTag::new("customer_email").sample_value(), "jane.doe@example.com");
+    /// assert_eq!(MergeTag::new("invoice.amount").sample_value(), "$42.00");
+    /// assert_eq!(MergeTag::new("item_count").sample_value(), 3);
+    /// ```
+    #[must_use]
+    pub fn sample_value(&self) -> serde_json::Value {
+        sample_value(&self.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use crate::clock::MockClock;
+    use crate::transport::{
+        async_trait, HeaderMap, HttpTransport, Method, Request, Response, StatusCode,
+    };
+    use crate::{CreateEmailOptions, Lettr};
+
+    /// Lists the templates `welcome` and `welcome-de` and accepts every
+    /// email, recording the requests.
+    #[derive(Default)]
+    struct Templates {
+        requests: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for Arc<Templates> {
+        async fn execute(&self, request: Request) -> crate::Result<Response> {
+            let body = String::from_utf8_lossy(request.body.as_deref().unwrap_or_default());
+            self.requests.lock().unwrap().push(format!(
+                "{} {} {body}",
+                request.method,
+                request.url.path()
+            ));
+
+            let body = if request.method == Method::GET {
+                r#"{"message":"OK","data":{"templates":[
+                    {"id":1,"name":"Welcome","slug":"welcome","project_id":1,"folder_id":null,"created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"},
+                    {"id":2,"name":"Welcome","slug":"welcome-de","project_id":1,"folder_id":null,"created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"}
+                ],"pagination":{"total":2,"per_page":100,"current_page":1,"last_page":1}}}"#
+            } else {
+                r#"{"message":"OK","data":{"request_id":"request-1","accepted":1,"rejected":0}}"#
+            };
+            Ok(Response::new(StatusCode::OK, HeaderMap::new(), body))
+        }
+    }
+
+    #[tokio::test]
+    async fn reuses_listed_slugs_to_resolve_locales() {
+        let transport = Arc::new(Templates::default());
+        let clock = MockClock::new();
+        let client = Lettr::builder("test-key")
+            .transport(transport.clone())
+            .clock(clock.clone())
+            .build();
+        let email = CreateEmailOptions::new("sender@example.com", ["user@example.com"], "Hallo!")
+            .with_template("welcome")
+            .with_locale("de-AT");
+
+        client.emails.send(email.clone()).await.unwrap();
+        client.emails.send(email.clone()).await.unwrap();
+        clock.advance(Duration::from_secs(5 * 60));
+        client.emails.send(email).await.unwrap();
+
+        let requests = transport.requests.lock().unwrap();
+        let lists = requests.iter().filter(|r| r.starts_with("GET /")).count();
+        assert_eq!(lists, 2);
+        let sends: Vec<_> = requests
+            .iter()
+            .filter(|r| r.starts_with("POST /"))
+            .collect();
+        assert_eq!(sends.len(), 3);
+        assert!(sends
+            .iter()
+            .all(|send| send.contains(r#""template_slug":"welcome-de""#)));
+    }
 }
@@ -0,0 +1,23 @@
+//! Test doubles for the Lettr API.
+//!
+//! - [`FakeLettr`] is an in-process implementation of the
+//!   [service traits](crate::services) that keeps sent emails and created
+//!   resources in memory, for end-to-end application tests without any HTTP.
+//! - [`MockLettr`] starts a local [`wiremock`] server with canned responses,
+//!   for tests that exercise the real client.
+//!
+//! Only available with the `test-util` feature.
+
+mod fake;
+mod mock;
+
+pub use fake::{
+    FakeAssets, FakeAudiences, FakeBlocklists, FakeBroadcasts, FakeContacts, FakeDomains,
+    FakeEmails, FakeExports, FakeInbound, FakeIpPools, FakeLettr, FakeSmtpCredentials, FakeStats,
+    FakeTeam, FakeTemplates, FakeUnsubscribeGroups, FakeWebhooks,
+};
+pub use mock::MockLettr;
+pub use wiremock;
+
+/// Timestamp used in canned and fake responses.
+const TIMESTAMP: &str = "2024-01-15T10:30:00+00:00";
@@ -0,0 +1,2368 @@
+//! An in-process fake of the Lettr API.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::TIMESTAMP;
+use crate::assets::{Asset, UploadAssetOptions};
+use crate::audiences::{Audience, CreateAudienceOptions};
+use crate::blocklists::BlocklistStatus;
+use crate::broadcasts::{
+    Broadcast, BroadcastPagination, BroadcastStats, CreateBroadcastOptions, ListBroadcastsOptions,
+    ListBroadcastsResponse,
+};
+use crate::contacts::{
+    Contact, ContactPagination, CreateContactOptions, ListContactsOptions, ListContactsResponse,
+    UpdateContactOptions,
+};
+use crate::domains::{
+    CreateDomainResponse, DkimDnsRecord, DkimInfo, DnsRecords, Domain, DomainDetail,
+};
+use crate::emails::{
+    Bounce, EmailEvent, EmailEventDetail, GetEmailResponse, LinkClicks, ListBouncesOptions,
+    ListBouncesResponse, ListEmailsOptions, ListEmailsResponse, Pagination, RawJson,
+    RecipientErasure, SendEmailResponse,
+};
+use crate::error::{ApiError, ValidationError};
+use crate::event_stream::{EventBatch, StreamEventsOptions};
+use crate::exports::{CreateExportOptions, ExportFormat, ExportJob};
+use crate::inbound::{
+    CreateInboundRouteOptions, CreateInboundRuleOptions, InboundMessage, InboundRoute, InboundRule,
+    ListInboundMessagesOptions, ListInboundMessagesResponse,
+};
+use crate::ip_pools::IpPool;
+use crate::services::{
+    AssetsApi, AudiencesApi, BlocklistsApi, BroadcastsApi, ContactsApi, DomainsApi, EmailSender,
+    EmailsApi, ExportsApi, InboundApi, IpPoolsApi, SmtpCredentialsApi, StatsApi, TeamApi,
+    TemplatesApi, UnsubscribeGroupsApi, WebhooksApi,
+};
+use crate::smtp::{CreateSmtpCredentialOptions, NewSmtpCredential, SmtpCredential};
+use crate::stats::{StatsBucket, StatsCounts, StatsOptions, StatsResponse};
+use crate::team::{InviteTeamMemberOptions, TeamInvitation, TeamMember, TeamRole};
+use crate::templates::{
+    CreateTemplateOptions, CreateTemplateResponse, ListTemplatesOptions, ListTemplatesResponse,
+    Template, TemplatePagination,
+};
+use crate::transport::StatusCode;
+use crate::unsubscribe_groups::{
+    CreateUnsubscribeGroupOptions, GroupPreference, RecipientPreferences, UnsubscribeGroup,
+};
+use crate::webhooks::{Webhook, WebhookSecret, WebhookUrlValidation};
+use crate::CreateEmailOptions;
+
+/// Page size used when list options do not set one, as the API does.
+const DEFAULT_PER_PAGE: u32 = 25;
+
+/// Project of templates created without a project ID.
+const DEFAULT_PROJECT_ID: u64 = 1;
+
+/// Roles of fake team members, with their descriptions.
+const TEAM_ROLES: [(&str, &str); 4] = [
+    (
+        "owner",
+        "Full access, including billing and team management.",
+    ),
+    ("admin", "Full access except billing."),
+    ("developer", "Sending, API keys, domains, and webhooks."),
+    ("viewer", "Read-only access."),
+];
+
+/// Public key returned for every fake domain.
+const DKIM_PUBLIC_KEY: &str = "MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQC";
+
+/// An in-memory implementation of the Lettr service traits.
+///
+/// Each field implements the trait of the matching [`Lettr`](crate::Lettr)
+/// service, so application code written against
+/// [`EmailsApi`], [`DomainsApi`], [`TemplatesApi`], [`WebhooksApi`],
+/// [`ContactsApi`], [`AudiencesApi`], [`BroadcastsApi`], [`StatsApi`],
+/// [`SmtpCredentialsApi`], [`InboundApi`], [`IpPoolsApi`], [`TeamApi`],
+/// [`ExportsApi`], [`UnsubscribeGroupsApi`], [`BlocklistsApi`], and
+/// [`AssetsApi`] can run against a `FakeLettr` in tests. Sent emails and created resources are kept
+/// in memory and served back by the list and get methods; [`sent_emails`](Self::sent_emails)
+/// shows what was sent, and [`receive`](Self::receive) simulates incoming
+/// mail.
+///
+/// Clones share the same state. Unknown IDs fail with a 404
+/// [`Error::Api`](crate::Error::Api), and invalid input with an
+/// [`Error::Validation`](crate::Error::Validation), as the API would.
+///
+/// # Example
+///
+/// ```
+/// use lettr::services::EmailsApi;
+/// use lettr::testing::FakeLettr;
+/// use lettr::CreateEmailOptions;
+///
+/// async fn send_welcome(emails: &impl EmailsApi, to: &str) -> lettr::Result<()> {
+///     let email = CreateEmailOptions::new("hello@example.com", [to], "Welcome!")
+///         .with_text("Thanks for signing up.");
+///     emails.send(email).await?;
+///     Ok(())
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> lettr::Result<()> {
+/// let fake = FakeLettr::new();
+/// send_welcome(&fake.emails, "user@example.com").await?;
+///
+/// let sent = fake.sent_emails();
+/// assert_eq!(sent.len(), 1);
+/// assert_eq!(sent[0].to(), ["user@example.com"]);
+/// assert_eq!(sent[0].subject(), "Welcome!");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FakeLettr {
+    /// Fake of the `/emails` endpoints.
+    pub emails: FakeEmails,
+    /// Fake of the `/domains` endpoints.
+    pub domains: FakeDomains,
+    /// Fake of the `/templates` endpoints.
+    pub templates: FakeTemplates,
+    /// Fake of the `/webhooks` endpoints.
+    pub webhooks: FakeWebhooks,
+    /// Fake of the `/contacts` endpoints.
+    pub contacts: FakeContacts,
+    /// Fake of the `/audiences` endpoints.
+    pub audiences: FakeAudiences,
+    /// Fake of the `/broadcasts` endpoints.
+    pub broadcasts: FakeBroadcasts,
+    /// Fake of the `/stats` endpoint.
+    pub stats: FakeStats,
+    /// Fake of the `/smtp/credentials` endpoints.
+    pub smtp_credentials: FakeSmtpCredentials,
+    /// Fake of the `/inbound` endpoints.
+    pub inbound: FakeInbound,
+    /// Fake of the `/ip-pools` endpoints.
+    pub ip_pools: FakeIpPools,
+    /// Fake of the `/team` endpoints.
+    pub team: FakeTeam,
+    /// Fake of the `/exports` endpoints.
+    pub exports: FakeExports,
+    /// Fake of the `/unsubscribe-groups` endpoints.
+    pub unsubscribe_groups: FakeUnsubscribeGroups,
+    /// Fake of the `/blocklists` endpoints.
+    pub blocklists: FakeBlocklists,
+    /// Fake of the `/assets` endpoints.
+    pub assets: FakeAssets,
+    state: Shared,
+}
+
+/// Fake of the `/emails` endpoints; see [`FakeLettr`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeEmails(Shared);
+
+/// Fake of the `/domains` endpoints; see [`FakeLettr`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeDomains(Shared);
+
+/// Fake of the `/templates` endpoints; see [`FakeLettr`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeTemplates(Shared);
+
+/// Fake of the `/webhooks` endpoints; see [`FakeLettr`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeWebhooks(Shared);
+
+/// Fake of the `/contacts` endpoints; see [`FakeLettr`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeContacts(Shared);
+
+/// Fake of the `/audiences` endpoints; see [`FakeLettr`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeAudiences(Shared);
+
+/// Fake of the `/broadcasts` endpoints; see [`FakeLettr`].
+///
+/// Sending a broadcast finishes at once and counts every audience member as
+/// sent and delivered.
+#[derive(Debug, Clone, Default)]
+pub struct FakeBroadcasts(Shared);
+
+/// Fake of the `/stats` endpoint; see [`FakeLettr`].
+///
+/// Returns a single bucket starting at the beginning of the range, counting
+/// every recipient of matching sent emails as sent and delivered. The date
+/// range is not checked, since fake emails carry no send time.
+#[derive(Debug, Clone, Default)]
+pub struct FakeStats(Shared);
+
+/// Fake of the `/smtp/credentials` endpoints; see [`FakeLettr`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeSmtpCredentials(Shared);
+
+/// Fake of the `/inbound` endpoints; see [`FakeLettr`].
+///
+/// Messages only arrive through [`FakeLettr::receive`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeInbound(Shared);
+
+/// Fake of the `/ip-pools` endpoints; see [`FakeLettr`].
+///
+/// Pools only exist once added with [`FakeLettr::add_ip_pool`]; sending an
+/// email through a pool that does not exist fails.
+#[derive(Debug, Clone, Default)]
+pub struct FakeIpPools(Shared);
+
+/// Fake of the `/team` endpoints; see [`FakeLettr`].
+///
+/// Knows the roles `owner`, `admin`, `developer`, and `viewer`. Members only
+/// exist once added with [`FakeLettr::add_team_member`]; invitations are
+/// never accepted.
+#[derive(Debug, Clone, Default)]
+pub struct FakeTeam(Shared);
+
+/// Fake of the `/exports` endpoints; see [`FakeLettr`].
+///
+/// Exports complete at once and contain the `injection` events of all sent
+/// emails, like [`EmailsApi::list`]. The date range is not checked, since
+/// fake emails carry no send time.
+#[derive(Debug, Clone, Default)]
+pub struct FakeExports(Shared);
+
+/// Fake of the `/unsubscribe-groups` endpoints; see [`FakeLettr`].
+///
+/// Recipients only opt out through [`FakeLettr::unsubscribe`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeUnsubscribeGroups(Shared);
+
+/// Fake of the `/blocklists` endpoints; see [`FakeLettr`].
+///
+/// Dedicated IPs and sending domains are clean unless listed with
+/// [`FakeLettr::add_blocklist_status`].
+#[derive(Debug, Clone, Default)]
+pub struct FakeBlocklists(Shared);
+
+/// Fake of the `/assets` endpoints; see [`FakeLettr`].
+///
+/// Only image content types are accepted, and uploads get a URL on
+/// `assets.fake.lettr.com` that serves nothing.
+#[derive(Debug, Clone, Default)]
+pub struct FakeAssets(Shared);
+
+#[derive(Debug, Clone, Default)]
+struct Shared(Arc<Mutex<State>>);
+
+#[derive(Debug, Default)]
+struct State {
+    /// Sent emails with their request IDs, oldest first.
+    emails: Vec<(String, CreateEmailOptions)>,
+    /// Simulated bounces, oldest first.
+    bounces: Vec<Bounce>,
+    /// Simulated clicks, as request ID, recipient, and URL, oldest first.
+    clicks: Vec<(String, String, String)>,
+    domains: Vec<DomainDetail>,
+    templates: Vec<Template>,
+    webhooks: Vec<Webhook>,
+    /// Rotated webhook signing secrets; others are derived from the ID.
+    webhook_secrets: Vec<WebhookSecret>,
+    /// Number of signing secrets ever rotated, for unique secrets.
+    webhook_secrets_rotated: usize,
+    contacts: Vec<Contact>,
+    /// Number of contacts ever created, for unique IDs.
+    contacts_created: usize,
+    /// Audiences with the IDs of contacts added to them.
+    audiences: Vec<(Audience, Vec<String>)>,
+    /// Number of audiences ever created, for unique IDs.
+    audiences_created: usize,
+    /// Broadcasts with their statistics, oldest first.
+    broadcasts: Vec<(Broadcast, BroadcastStats)>,
+    /// Number of broadcasts ever created, for unique IDs.
+    broadcasts_created: usize,
+    smtp_credentials: Vec<SmtpCredential>,
+    /// Number of SMTP credentials ever created, for unique IDs.
+    smtp_credentials_created: usize,
+    inbound_routes: Vec<InboundRoute>,
+    /// Number of inbound routes ever created, for unique IDs.
+    inbound_routes_created: usize,
+    /// Inbound rules, by ascending priority.
+    inbound_rules: Vec<InboundRule>,
+    /// Number of inbound rules ever created, for unique IDs.
+    inbound_rules_created: usize,
+    /// Received messages, oldest first.
+    inbound_messages: Vec<InboundMessage>,
+    /// Number of messages ever received, for unique IDs.
+    inbound_messages_received: usize,
+    ip_pools: Vec<IpPool>,
+    team_members: Vec<TeamMember>,
+    team_invitations: Vec<TeamInvitation>,
+    /// Number of invitations ever created, for unique IDs.
+    team_invitations_created: usize,
+    /// Exports with their file contents.
+    exports: Vec<(ExportJob, Vec<u8>)>,
+    unsubscribe_groups: Vec<UnsubscribeGroup>,
+    /// Number of unsubscribe groups ever created, for unique IDs.
+    unsubscribe_groups_created: usize,
+    /// Group IDs with the addresses that opted out of them.
+    unsubscribes: Vec<(String, String)>,
+    blocklist_statuses: Vec<BlocklistStatus>,
+    recipient_erasures: Vec<RecipientErasure>,
+    assets: Vec<Asset>,
+    /// Number of assets ever uploaded, for unique IDs.
+    assets_uploaded: usize,
+}
+
+impl State {
+    /// Returns the audience with the given ID and its explicit members.
+    fn audience(&self, audience_id: &str) -> crate::Result<&(Audience, Vec<String>)> {
+        self.audiences
+            .iter()
+            .find(|(audience, _)| audience.id == audience_id)
+            .ok_or_else(|| not_found("Audience"))
+    }
+
+    /// Returns the contacts in an audience: those matching its filter for a
+    /// segment, and those added to it for a static list.
+    fn members<'a>(
+        &'a self,
+        audience: &'a Audience,
+        members: &'a [String],
+    ) -> impl Iterator<Item = &'a Contact> {
+        self.contacts.iter().filter(move |contact| {
+            if audience.is_segment() {
+                audience
+                    .filter
+                    .iter()
+                    .all(|(key, value)| contact.attributes.get(key) == Some(value))
+            } else {
+                members.contains(&contact.id)
+            }
+        })
+    }
+
+    /// Returns the broadcast with the given ID and its statistics.
+    fn broadcast(&self, broadcast_id: &str) -> crate::Result<&(Broadcast, BroadcastStats)> {
+        self.broadcasts
+            .iter()
+            .find(|(broadcast, _)| broadcast.id == broadcast_id)
+            .ok_or_else(|| not_found("Broadcast"))
+    }
+
+    /// Like [`broadcast`](Self::broadcast), but mutable.
+    fn broadcast_mut(
+        &mut self,
+        broadcast_id: &str,
+    ) -> crate::Result<&mut (Broadcast, BroadcastStats)> {
+        self.broadcasts
+            .iter_mut()
+            .find(|(broadcast, _)| broadcast.id == broadcast_id)
+            .ok_or_else(|| not_found("Broadcast"))
+    }
+
+    /// Returns whether `email` opted out of the unsubscribe group with ID
+    /// `group_id`.
+    fn unsubscribed(&self, group_id: &str, email: &str) -> bool {
+        self.unsubscribes
+            .iter()
+            .any(|(group, address)| group == group_id && address.eq_ignore_ascii_case(email))
+    }
+
+    /// Returns a copy of `group` with its current unsubscribe count.
+    fn counted_group(&self, group: &UnsubscribeGroup) -> UnsubscribeGroup {
+        let mut group = group.clone();
+        group.unsubscribe_count = self
+            .unsubscribes
+            .iter()
+            .filter(|(group_id, _)| *group_id == group.id)
+            .count() as u64;
+        group
+    }
+
+    /// Returns a copy of `audience` with its current contact count.
+    fn counted(&self, audience: &Audience, members: &[String]) -> Audience {
+        let mut audience = audience.clone();
+        audience.contact_count = self.members(&audience, members).count() as u64;
+        audience
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for FakeLettr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeLettr {
+    /// Creates a fake with no emails or other resources.
+    #[must_use]
+    pub fn new() -> Self {
+        let state = Shared::default();
+        Self {
+            emails: FakeEmails(state.clone()),
+            domains: FakeDomains(state.clone()),
+            templates: FakeTemplates(state.clone()),
+            webhooks: FakeWebhooks(state.clone()),
+            contacts: FakeContacts(state.clone()),
+            audiences: FakeAudiences(state.clone()),
+            broadcasts: FakeBroadcasts(state.clone()),
+            stats: FakeStats(state.clone()),
+            smtp_credentials: FakeSmtpCredentials(state.clone()),
+            inbound: FakeInbound(state.clone()),
+            ip_pools: FakeIpPools(state.clone()),
+            team: FakeTeam(state.clone()),
+            exports: FakeExports(state.clone()),
+            unsubscribe_groups: FakeUnsubscribeGroups(state.clone()),
+            blocklists: FakeBlocklists(state.clone()),
+            assets: FakeAssets(state.clone()),
+            state,
+        }
+    }
+
+    /// Returns every email sent so far, oldest first.
+    #[must_use]
+    pub fn sent_emails(&self) -> Vec<CreateEmailOptions> {
+        let state = self.state.lock();
+        state
+            .emails
+            .iter()
+            .map(|(_, email)| email.clone())
+            .collect()
+    }
+
+    /// Adds a domain, e.g. one that is already verified.
+    pub fn add_domain(&self, domain: DomainDetail) {
+        let mut state = self.state.lock();
+        state
+            .domains
+            .retain(|existing| existing.domain != domain.domain);
+        state.domains.push(domain);
+    }
+
+    /// Adds a webhook; the API has no endpoint to create one.
+    pub fn add_webhook(&self, webhook: Webhook) {
+        let mut state = self.state.lock();
+        state.webhooks.retain(|existing| existing.id != webhook.id);
+        state.webhooks.push(webhook);
+    }
+
+    /// Adds an IP pool; the API has no endpoint to create one.
+    pub fn add_ip_pool(&self, pool: IpPool) {
+        let mut state = self.state.lock();
+        state.ip_pools.retain(|existing| existing.id != pool.id);
+        state.ip_pools.push(pool);
+    }
+
+    /// Sets the blocklist status of an IP or domain, e.g. to simulate it
+    /// getting listed; the API has no endpoint to change it.
+    pub fn add_blocklist_status(&self, status: BlocklistStatus) {
+        let mut state = self.state.lock();
+        state
+            .blocklist_statuses
+            .retain(|existing| existing.target != status.target);
+        state.blocklist_statuses.push(status);
+    }
+
+    /// Adds a team member, e.g. one who accepted an invitation.
+    pub fn add_team_member(&self, member: TeamMember) {
+        let mut state = self.state.lock();
+        state
+            .team_members
+            .retain(|existing| existing.id != member.id);
+        state
+            .team_invitations
+            .retain(|invitation| !invitation.email.eq_ignore_ascii_case(&member.email));
+        state.team_members.push(member);
+    }
+
+    /// Simulates `email` opting out of the unsubscribe group with ID
+    /// `group_id`. Emails sent with that group skip the address from then
+    /// on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::services::{EmailsApi, UnsubscribeGroupsApi};
+    /// use lettr::testing::FakeLettr;
+    /// use lettr::unsubscribe_groups::CreateUnsubscribeGroupOptions;
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> lettr::Result<()> {
+    /// let fake = FakeLettr::new();
+    /// let options = CreateUnsubscribeGroupOptions::new("Product tips");
+    /// let group = fake.unsubscribe_groups.create(options).await?;
+    /// fake.unsubscribe(&group.id, "user@example.com");
+    ///
+    /// let email = CreateEmailOptions::new("hello@example.com", ["user@example.com"], "Tips")
+    ///     .with_text("Did you know…")
+    ///     .with_unsubscribe_group(&group.id);
+    /// let response = fake.emails.send(email).await?;
+    /// assert_eq!(response.accepted, 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unsubscribe(&self, group_id: &str, email: &str) {
+        let mut state = self.state.lock();
+        if !state.unsubscribed(group_id, email) {
+            state
+                .unsubscribes
+                .push((group_id.to_owned(), email.to_owned()));
+        }
+    }
+
+    /// Simulates `rcpt_to` clicking the link to `url` in the email
+    /// `request_id`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::services::EmailsApi;
+    /// use lettr::testing::FakeLettr;
+    /// use lettr::CreateEmailOptions;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> lettr::Result<()> {
+    /// let fake = FakeLettr::new();
+    /// let email = CreateEmailOptions::new("shop@example.com", ["ada@example.com"], "Receipt")
+    ///     .with_html(r#"<a href="https://example.com/orders/42">View order</a>"#);
+    /// let sent = fake.emails.send(email).await?;
+    ///
+    /// fake.click(&sent.request_id, "ada@example.com", "https://example.com/orders/42");
+    /// fake.click(&sent.request_id, "ada@example.com", "https://example.com/orders/42");
+    ///
+    /// let links = fake.emails.clicks(&sent.request_id).await?;
+    /// assert_eq!((links[0].unique_clicks, links[0].total_clicks), (1, 2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn click(&self, request_id: &str, rcpt_to: &str, url: &str) {
+        self.state
+            .lock()
+            .clicks
+            .push((request_id.to_owned(), rcpt_to.to_owned(), url.to_owned()));
+    }
+
+    /// Simulates a bounce and returns its event ID.
+    ///
+    /// Fills in an empty event ID and timestamp, and an empty recipient
+    /// domain from the recipient address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::emails::{Bounce, BounceCategory, ListBouncesOptions};
+    /// use lettr::services::EmailsApi;
+    /// use lettr::testing::FakeLettr;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> lettr::Result<()> {
+    /// let fake = FakeLettr::new();
+    /// let mut bounce = Bounce::new("fake-request-1", "gone@example.com", 10);
+    /// bounce.reason = Some("550 5.1.1 User unknown".to_owned());
+    /// fake.bounce(bounce);
+    ///
+    /// let options =
+    ///     ListBouncesOptions::new("2024-01-01", "2024-01-31").category(BounceCategory::Hard);
+    /// let page = fake.emails.list_bounces(options).await?;
+    /// assert_eq!(page.results[0].rcpt_to, "gone@example.com");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bounce(&self, mut bounce: Bounce) -> String {
+        let mut state = self.state.lock();
+        if bounce.event_id.is_empty() {
+            bounce.event_id = format!("fake-bounce-{}", state.bounces.len() + 1);
+        }
+        if bounce.timestamp.is_empty() {
+            bounce.timestamp = TIMESTAMP.to_owned();
+        }
+        if bounce.recipient_domain.is_empty() {
+            bounce.recipient_domain = domain_of(&bounce.rcpt_to).to_owned();
+        }
+        let event_id = bounce.event_id.clone();
+        state.bounces.push(bounce);
+        event_id
+    }
+
+    /// Simulates an incoming message and returns its ID.
+    ///
+    /// Fills in an empty ID and receive time, and an empty route ID with
+    /// the first route accepting one of the message's recipients. The
+    /// message is discarded if the first matching
+    /// [inbound rule](crate::inbound::InboundRule) drops it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::inbound::{CreateInboundRouteOptions, InboundMessage, ListInboundMessagesOptions};
+    /// use lettr::services::InboundApi;
+    /// use lettr::testing::FakeLettr;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> lettr::Result<()> {
+    /// let fake = FakeLettr::new();
+    /// let options = CreateInboundRouteOptions::new("reply.example.com").with_recipient("support");
+    /// let route = fake.inbound.create_route(options).await?;
+    ///
+    /// let mut message = InboundMessage::new("", "jane@example.org");
+    /// message.to = vec!["support@reply.example.com".to_owned()];
+    /// message.subject = "Re: Order #42".to_owned();
+    /// fake.receive(message);
+    ///
+    /// let page = fake
+    ///     .inbound
+    ///     .list_messages(ListInboundMessagesOptions::new().route(&route.id))
+    ///     .await?;
+    /// assert_eq!(page.messages[0].subject, "Re: Order #42");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive(&self, mut message: InboundMessage) -> String {
+        let mut state = self.state.lock();
+        state.inbound_messages_received += 1;
+        if message.id.is_empty() {
+            message.id = format!("fake-inbound-message-{}", state.inbound_messages_received);
+        }
+        if message.received_at.is_empty() {
+            message.received_at = TIMESTAMP.to_owned();
+        }
+        if message.route_id.is_empty() {
+            if let Some(route) = state
+                .inbound_routes
+                .iter()
+                .find(|route| message.to.iter().any(|to| accepts(route, to)))
+            {
+                message.route_id.clone_from(&route.id);
+            }
+        }
+        let id = message.id.clone();
+        let dropped = state
+            .inbound_rules
+            .iter()
+            .find(|rule| message.to.iter().any(|to| rule.matches(to)))
+            .is_some_and(|rule| rule.action == "drop");
+        if !dropped {
+            state.inbound_messages.retain(|existing| existing.id != id);
+            state.inbound_messages.push(message);
+        }
+        id
+    }
+
+    /// Forgets all emails and created resources.
+    pub fn reset(&self) {
+        *self.state.lock() = State::default();
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl EmailsApi for FakeEmails {
+    async fn send(&self, mut email: CreateEmailOptions) -> crate::Result<SendEmailResponse> {
+        if email.to().is_empty() && email.audience().is_none() {
+            return Err(invalid("to", "The to field is required."));
+        }
+        if email.html().is_none() && email.text().is_none() && email.template_slug().is_none() {
+            return Err(invalid(
+                "html",
+                "The html field is required when none of text / template slug are present.",
+            ));
+        }
+
+        let mut state = self.0.lock();
+        email.resolve_locale(&[], |slug| {
+            state.templates.iter().any(|template| template.slug == slug)
+        });
+        if let Some(group_id) = email.unsubscribe_group() {
+            if !state
+                .unsubscribe_groups
+                .iter()
+                .any(|group| group.id == group_id)
+            {
+                return Err(invalid(
+                    "unsubscribe_group_id",
+                    "The selected unsubscribe group id is invalid.",
+                ));
+            }
+        }
+        if let Some(pool) = email
+            .options()
+            .and_then(|options| options.ip_pool.as_deref())
+        {
+            if !state.ip_pools.iter().any(|existing| existing.name == pool) {
+                return Err(invalid(
+                    "options.ip_pool",
+                    "The selected options.ip pool is invalid.",
+                ));
+            }
+        }
+        let opted_out = |address: &str| {
+            email
+                .unsubscribe_group()
+                .is_some_and(|group_id| state.unsubscribed(group_id, address))
+        };
+        let mut recipients: Vec<&str> = email.to().iter().map(String::as_str).collect();
+        if let Some(audience_id) = email.audience() {
+            let (audience, members) = state.audience(audience_id)?;
+            recipients.extend(
+                state
+                    .members(audience, members)
+                    .map(|contact| contact.email.as_str()),
+            );
+        }
+        let rejected = recipients
+            .iter()
+            .filter(|address| opted_out(address))
+            .count();
+        let accepted = u32::try_from(recipients.len() - rejected).unwrap_or(u32::MAX);
+        let rejected = u32::try_from(rejected).unwrap_or(u32::MAX);
+
+        let request_id = format!("fake-request-{}", state.emails.len() + 1);
+        let message_id = email
+            .message_id()
+            .map_or_else(|| format!("<{request_id}@lettr.test>"), str::to_owned);
+        state.emails.push((request_id.clone(), email));
+        let mut response = SendEmailResponse::new(request_id, accepted, rejected);
+        response.message_id = Some(message_id);
+        Ok(response)
+    }
+
+    async fn list(&self, options: ListEmailsOptions) -> crate::Result<ListEmailsResponse> {
+        let state = self.0.lock();
+        let recipients: Vec<&str> = options
+            .recipients
+            .as_deref()
+            .map(|list| list.split(',').map(str::trim).collect())
+            .unwrap_or_default();
+        let events: Vec<EmailEvent> = state
+            .emails
+            .iter()
+            .rev()
+            .flat_map(|(request_id, email)| events(request_id, email))
+            .filter(|event| recipients.is_empty() || recipients.contains(&event.rcpt_to.as_str()))
+            .map(summary)
+            .collect();
+
+        let per_page = options.per_page.unwrap_or(DEFAULT_PER_PAGE);
+        let start = options
+            .cursor
+            .as_deref()
+            .map_or(Ok(0), str::parse::<usize>)
+            .map_err(|_| invalid("cursor", "The cursor is invalid."))?;
+        let end = start.saturating_add(per_page as usize).min(events.len());
+        let next_cursor = (end < events.len()).then(|| end.to_string());
+
+        let mut response =
+            ListEmailsResponse::new(events.get(start..end).unwrap_or_default().to_vec());
+        response.total_count = events.len() as u64;
+        response.pagination = Pagination {
+            next_cursor,
+            per_page,
+            extra: serde_json::Map::new(),
+        };
+        Ok(response)
+    }
+
+    async fn get(&self, request_id: &str) -> crate::Result<GetEmailResponse> {
+        let state = self.0.lock();
+        let (request_id, email) = state
+            .emails
+            .iter()
+            .find(|(id, _)| id == request_id)
+            .ok_or_else(|| not_found("Email"))?;
+        Ok(GetEmailResponse::new(events(request_id, email).collect()))
+    }
+
+    /// Returns the injection events of the emails sent since the cursor,
+    /// without waiting.
+    async fn events_since(
+        &self,
+        cursor: Option<&str>,
+        options: StreamEventsOptions,
+    ) -> crate::Result<EventBatch> {
+        let state = self.0.lock();
+        let events: Vec<EmailEventDetail> = state
+            .emails
+            .iter()
+            .flat_map(|(request_id, email)| events(request_id, email))
+            .collect();
+        let start = match cursor {
+            Some(cursor) => cursor
+                .parse::<usize>()
+                .map_err(|_| invalid("cursor", "The cursor is invalid."))?,
+            None => events.len(),
+        };
+        let recipients: Vec<&str> = options
+            .recipients
+            .as_deref()
+            .map(|list| list.split(',').map(str::trim).collect())
+            .unwrap_or_default();
+        let batch = events
+            .get(start..)
+            .unwrap_or_default()
+            .iter()
+            .filter(|event| recipients.is_empty() || recipients.contains(&event.rcpt_to.as_str()))
+            .cloned()
+            .collect();
+        Ok(EventBatch::new(batch, events.len().max(start).to_string()))
+    }
+
+    async fn clicks(&self, request_id: &str) -> crate::Result<Vec<LinkClicks>> {
+        let state = self.0.lock();
+        if !state.emails.iter().any(|(id, _)| id == request_id) {
+            return Err(not_found("Email"));
+        }
+        Ok(link_clicks(
+            state.clicks.iter().filter(|(id, _, _)| id == request_id),
+        ))
+    }
+
+    /// Ignores the date range, like [`list`](EmailsApi::list).
+    async fn list_bounces(
+        &self,
+        options: ListBouncesOptions,
+    ) -> crate::Result<ListBouncesResponse> {
+        let state = self.0.lock();
+        let bounces: Vec<&Bounce> = state
+            .bounces
+            .iter()
+            .rev()
+            .filter(|bounce| {
+                options.recipient.as_deref().map_or(true, |recipient| {
+                    bounce.rcpt_to.eq_ignore_ascii_case(recipient)
+                })
+            })
+            .filter(|bounce| {
+                options
+                    .domain
+                    .as_deref()
+                    .map_or(true, |domain| bounce.sending_domain == domain)
+            })
+            .filter(|bounce| {
+                options
+                    .category
+                    .map_or(true, |category| bounce.category() == category)
+            })
+            .collect();
+
+        let per_page = options.per_page.unwrap_or(DEFAULT_PER_PAGE);
+        let start = options
+            .cursor
+            .as_deref()
+            .map_or(Ok(0), str::parse::<usize>)
+            .map_err(|_| invalid("cursor", "The cursor is invalid."))?;
+        let end = start.saturating_add(per_page as usize).min(bounces.len());
+        let next_cursor = (end < bounces.len()).then(|| end.to_string());
+
+        let page = bounces.get(start..end).unwrap_or_default();
+        let mut response = ListBouncesResponse::new(page.iter().map(|b| (*b).clone()).collect());
+        response.pagination = Pagination {
+            next_cursor,
+            per_page,
+            extra: serde_json::Map::new(),
+        };
+        Ok(response)
+    }
+
+    /// Erases right away, forgetting sent emails addressed to `address`, its
+    /// bounces, clicks, and opt-outs.
+    async fn delete_recipient_data(&self, address: &str) -> crate::Result<RecipientErasure> {
+        if !address.contains('@') {
+            return Err(invalid("email", "The email must be a valid email address."));
+        }
+        let mut state = self.0.lock();
+        state
+            .emails
+            .retain(|(_, email)| !email.to().iter().any(|to| to.eq_ignore_ascii_case(address)));
+        state
+            .unsubscribes
+            .retain(|(_, email)| !email.eq_ignore_ascii_case(address));
+        state
+            .bounces
+            .retain(|bounce| !bounce.rcpt_to.eq_ignore_ascii_case(address));
+        state
+            .clicks
+            .retain(|(_, rcpt_to, _)| !rcpt_to.eq_ignore_ascii_case(address));
+
+        let mut erasure = RecipientErasure::new(
+            format!("fake-erasure-{}", state.recipient_erasures.len() + 1),
+            "completed",
+        );
+        erasure.email = address.to_owned();
+        erasure.created_at = TIMESTAMP.to_owned();
+        erasure.completed_at = Some(TIMESTAMP.to_owned());
+        state.recipient_erasures.push(erasure.clone());
+        Ok(erasure)
+    }
+
+    async fn get_recipient_erasure(&self, erasure_id: &str) -> crate::Result<RecipientErasure> {
+        self.0
+            .lock()
+            .recipient_erasures
+            .iter()
+            .find(|erasure| erasure.id == erasure_id)
+            .cloned()
+            .ok_or_else(|| not_found("Erasure"))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl EmailSender for FakeEmails {
+    async fn send(&self, email: CreateEmailOptions) -> crate::Result<SendEmailResponse> {
+        EmailsApi::send(self, email).await
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl DomainsApi for FakeDomains {
+    async fn list(&self) -> crate::Result<Vec<Domain>> {
+        let state = self.0.lock();
+        Ok(state
+            .domains
+            .iter()
+            .map(|detail| {
+                let mut domain = Domain::new(&detail.domain);
+                domain.status.clone_from(&detail.status);
+                domain.status_label.clone_from(&detail.status_label);
+                domain.can_send = detail.can_send;
+                domain.cname_status.clone_from(&detail.cname_status);
+                domain.dkim_status.clone_from(&detail.dkim_status);
+                domain.created_at.clone_from(&detail.created_at);
+                domain.updated_at.clone_from(&detail.updated_at);
+                domain
+            })
+            .collect())
+    }
+
+    async fn create(&self, domain: &str) -> crate::Result<CreateDomainResponse> {
+        if !domain.contains('.') {
+            return Err(invalid("domain", "The domain must be a valid domain name."));
+        }
+        let mut state = self.0.lock();
+        if state
+            .domains
+            .iter()
+            .any(|existing| existing.domain == domain)
+        {
+            return Err(invalid("domain", "The domain has already been taken."));
+        }
+
+        let mut detail = DomainDetail::new(domain);
+        detail.status = "pending".to_owned();
+        detail.status_label = "Pending".to_owned();
+        detail.cname_status = Some("pending".to_owned());
+        detail.dkim_status = Some("pending".to_owned());
+        detail.dns = Some(DnsRecords {
+            dkim: Some(DkimDnsRecord {
+                selector: "lettr".to_owned(),
+                public: DKIM_PUBLIC_KEY.to_owned(),
+                extra: serde_json::Map::new(),
+            }),
+            extra: serde_json::Map::new(),
+        });
+        detail.created_at = TIMESTAMP.to_owned();
+        detail.updated_at = TIMESTAMP.to_owned();
+
+        let mut response = CreateDomainResponse::new(domain);
+        response.status.clone_from(&detail.status);
+        response.status_label.clone_from(&detail.status_label);
+        response.dkim = Some(DkimInfo {
+            public: DKIM_PUBLIC_KEY.to_owned(),
+            selector: "lettr".to_owned(),
+            headers: "from:to:subject:date".to_owned(),
+            extra: serde_json::Map::new(),
+        });
+        state.domains.push(detail);
+        Ok(response)
+    }
+
+    async fn get(&self, domain: &str) -> crate::Result<DomainDetail> {
+        let state = self.0.lock();
+        state
+            .domains
+            .iter()
+            .find(|existing| existing.domain == domain)
+            .cloned()
+            .ok_or_else(|| not_found("Domain"))
+    }
+
+    async fn delete(&self, domain: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .domains
+            .iter()
+            .position(|existing| existing.domain == domain)
+            .ok_or_else(|| not_found("Domain"))?;
+        state.domains.remove(index);
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TemplatesApi for FakeTemplates {
+    async fn list(&self, options: ListTemplatesOptions) -> crate::Result<ListTemplatesResponse> {
+        let state = self.0.lock();
+        let project_id = options.project_id.unwrap_or(DEFAULT_PROJECT_ID);
+        let templates: Vec<&Template> = state
+            .templates
+            .iter()
+            .filter(|template| template.project_id == project_id)
+            .collect();
+
+        let per_page = options.per_page.unwrap_or(DEFAULT_PER_PAGE).max(1);
+        let page = options.page.unwrap_or(1).max(1);
+        let start = (page as usize - 1).saturating_mul(per_page as usize);
+        let page_templates = templates
+            .iter()
+            .skip(start)
+            .take(per_page as usize)
+            .map(|template| (*template).clone())
+            .collect();
+
+        let mut response = ListTemplatesResponse::new(page_templates);
+        response.pagination = TemplatePagination {
+            total: templates.len() as u64,
+            per_page,
+            current_page: page,
+            last_page: last_page(templates.len(), per_page),
+            extra: serde_json::Map::new(),
+        };
+        Ok(response)
+    }
+
+    async fn create(
+        &self,
+        options: CreateTemplateOptions,
+    ) -> crate::Result<CreateTemplateResponse> {
+        if options.name.trim().is_empty() {
+            return Err(invalid("name", "The name field is required."));
+        }
+        let mut state = self.0.lock();
+        let id = state.templates.iter().map(|t| t.id).max().unwrap_or(0) + 1;
+
+        let mut template = Template::new(id, options.name);
+        template.slug = slugify(&template.name);
+        template.project_id = options.project_id.unwrap_or(DEFAULT_PROJECT_ID);
+        template.folder_id = options.folder_id;
+        template.created_at = TIMESTAMP.to_owned();
+        template.updated_at = TIMESTAMP.to_owned();
+
+        let mut response = CreateTemplateResponse::new(id, &template.name);
+        response.slug.clone_from(&template.slug);
+        response.project_id = template.project_id;
+        response.folder_id = template.folder_id;
+        response.active_version = 1;
+        response.created_at = TIMESTAMP.to_owned();
+        state.templates.push(template);
+        Ok(response)
+    }
+
+    async fn clicks(&self, slug: &str) -> crate::Result<Vec<LinkClicks>> {
+        let state = self.0.lock();
+        if !state.templates.iter().any(|template| template.slug == slug) {
+            return Err(not_found("Template"));
+        }
+        let request_ids: Vec<&str> = state
+            .emails
+            .iter()
+            .filter(|(_, email)| email.template_slug() == Some(slug))
+            .map(|(id, _)| id.as_str())
+            .collect();
+        Ok(link_clicks(
+            state
+                .clicks
+                .iter()
+                .filter(|(id, _, _)| request_ids.contains(&id.as_str())),
+        ))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl WebhooksApi for FakeWebhooks {
+    async fn list(&self) -> crate::Result<Vec<Webhook>> {
+        Ok(self.0.lock().webhooks.clone())
+    }
+
+    async fn get(&self, webhook_id: &str) -> crate::Result<Webhook> {
+        let state = self.0.lock();
+        state
+            .webhooks
+            .iter()
+            .find(|webhook| webhook.id == webhook_id)
+            .cloned()
+            .ok_or_else(|| not_found("Webhook"))
+    }
+
+    async fn signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret> {
+        let state = self.0.lock();
+        if !state
+            .webhooks
+            .iter()
+            .any(|webhook| webhook.id == webhook_id)
+        {
+            return Err(not_found("Webhook"));
+        }
+        Ok(state
+            .webhook_secrets
+            .iter()
+            .find(|secret| secret.webhook_id == webhook_id)
+            .cloned()
+            .unwrap_or_else(|| {
+                let mut secret =
+                    WebhookSecret::new(webhook_id, format!("fake-webhook-secret-{webhook_id}"));
+                secret.created_at = TIMESTAMP.to_owned();
+                secret
+            }))
+    }
+
+    async fn rotate_signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret> {
+        let mut state = self.0.lock();
+        if !state
+            .webhooks
+            .iter()
+            .any(|webhook| webhook.id == webhook_id)
+        {
+            return Err(not_found("Webhook"));
+        }
+        state.webhook_secrets_rotated += 1;
+        let mut secret = WebhookSecret::new(
+            webhook_id,
+            format!(
+                "fake-webhook-secret-{webhook_id}-{}",
+                state.webhook_secrets_rotated
+            ),
+        );
+        secret.created_at = TIMESTAMP.to_owned();
+        state
+            .webhook_secrets
+            .retain(|existing| existing.webhook_id != webhook_id);
+        state.webhook_secrets.push(secret.clone());
+        Ok(secret)
+    }
+
+    /// Reports every `http://` and `https://` URL as reachable, and rejects
+    /// anything else.
+    async fn validate_url(&self, url: &str) -> crate::Result<WebhookUrlValidation> {
+        let tls_valid = if url.starts_with("https://") {
+            Some(true)
+        } else if url.starts_with("http://") {
+            None
+        } else {
+            return Err(invalid("url", "The url must be a valid HTTP or HTTPS URL."));
+        };
+        let mut validation = WebhookUrlValidation::new(url, true);
+        validation.tls_valid = tls_valid;
+        validation.status_code = Some(200);
+        validation.latency_ms = Some(0);
+        Ok(validation)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl ContactsApi for FakeContacts {
+    async fn list(&self, options: ListContactsOptions) -> crate::Result<ListContactsResponse> {
+        let state = self.0.lock();
+        Ok(contact_page(state.contacts.iter(), &options))
+    }
+
+    async fn create(&self, options: CreateContactOptions) -> crate::Result<Contact> {
+        if !options.email.contains('@') {
+            return Err(invalid("email", "The email must be a valid email address."));
+        }
+        let mut state = self.0.lock();
+        if state
+            .contacts
+            .iter()
+            .any(|contact| contact.email.eq_ignore_ascii_case(&options.email))
+        {
+            return Err(invalid("email", "The email has already been taken."));
+        }
+
+        state.contacts_created += 1;
+        let mut contact = Contact::new(
+            format!("fake-contact-{}", state.contacts_created),
+            options.email,
+        );
+        contact.first_name = options.first_name;
+        contact.last_name = options.last_name;
+        contact.attributes = options.attributes;
+        contact.created_at = TIMESTAMP.to_owned();
+        contact.updated_at = TIMESTAMP.to_owned();
+        state.contacts.push(contact.clone());
+        Ok(contact)
+    }
+
+    async fn get(&self, contact_id: &str) -> crate::Result<Contact> {
+        let state = self.0.lock();
+        state
+            .contacts
+            .iter()
+            .find(|contact| contact.id == contact_id)
+            .cloned()
+            .ok_or_else(|| not_found("Contact"))
+    }
+
+    async fn update(
+        &self,
+        contact_id: &str,
+        options: UpdateContactOptions,
+    ) -> crate::Result<Contact> {
+        let mut state = self.0.lock();
+        let contact = state
+            .contacts
+            .iter_mut()
+            .find(|contact| contact.id == contact_id)
+            .ok_or_else(|| not_found("Contact"))?;
+        if let Some(email) = options.email {
+            contact.email = email;
+        }
+        if let Some(first_name) = options.first_name {
+            contact.first_name = Some(first_name);
+        }
+        if let Some(last_name) = options.last_name {
+            contact.last_name = Some(last_name);
+        }
+        contact.attributes.extend(options.attributes);
+        if let Some(unsubscribed) = options.unsubscribed {
+            contact.unsubscribed = unsubscribed;
+        }
+        Ok(contact.clone())
+    }
+
+    async fn delete(&self, contact_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .contacts
+            .iter()
+            .position(|contact| contact.id == contact_id)
+            .ok_or_else(|| not_found("Contact"))?;
+        state.contacts.remove(index);
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AudiencesApi for FakeAudiences {
+    async fn list(&self) -> crate::Result<Vec<Audience>> {
+        let state = self.0.lock();
+        Ok(state
+            .audiences
+            .iter()
+            .map(|(audience, members)| state.counted(audience, members))
+            .collect())
+    }
+
+    async fn create(&self, options: CreateAudienceOptions) -> crate::Result<Audience> {
+        if options.name.trim().is_empty() {
+            return Err(invalid("name", "The name field is required."));
+        }
+        let mut state = self.0.lock();
+        state.audiences_created += 1;
+        let mut audience = Audience::new(
+            format!("fake-audience-{}", state.audiences_created),
+            options.name,
+        );
+        audience.description = options.description;
+        audience.filter = options.filter;
+        audience.created_at = TIMESTAMP.to_owned();
+        audience.updated_at = TIMESTAMP.to_owned();
+        state.audiences.push((audience, Vec::new()));
+        let (audience, members) = state.audiences.last().expect("just pushed");
+        Ok(state.counted(audience, members))
+    }
+
+    async fn get(&self, audience_id: &str) -> crate::Result<Audience> {
+        let state = self.0.lock();
+        let (audience, members) = state.audience(audience_id)?;
+        Ok(state.counted(audience, members))
+    }
+
+    async fn delete(&self, audience_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        state.audience(audience_id)?;
+        state
+            .audiences
+            .retain(|(audience, _)| audience.id != audience_id);
+        Ok(())
+    }
+
+    async fn add_contacts(&self, audience_id: &str, contact_ids: &[String]) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let (audience, _) = state.audience(audience_id)?;
+        if audience.is_segment() {
+            return Err(invalid(
+                "contact_ids",
+                "Contacts cannot be added to a segment.",
+            ));
+        }
+        if let Some(unknown) = contact_ids
+            .iter()
+            .find(|id| !state.contacts.iter().any(|contact| &contact.id == *id))
+        {
+            return Err(invalid(
+                "contact_ids",
+                &format!("The selected contact {unknown} is invalid."),
+            ));
+        }
+
+        let (_, members) = state
+            .audiences
+            .iter_mut()
+            .find(|(audience, _)| audience.id == audience_id)
+            .expect("checked above");
+        for id in contact_ids {
+            if !members.contains(id) {
+                members.push(id.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn remove_contacts(
+        &self,
+        audience_id: &str,
+        contact_ids: &[String],
+    ) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let (audience, _) = state.audience(audience_id)?;
+        if audience.is_segment() {
+            return Err(invalid(
+                "contact_ids",
+                "Contacts cannot be removed from a segment.",
+            ));
+        }
+        let (_, members) = state
+            .audiences
+            .iter_mut()
+            .find(|(audience, _)| audience.id == audience_id)
+            .expect("checked above");
+        members.retain(|id| !contact_ids.contains(id));
+        Ok(())
+    }
+
+    async fn contacts(
+        &self,
+        audience_id: &str,
+        options: ListContactsOptions,
+    ) -> crate::Result<ListContactsResponse> {
+        let state = self.0.lock();
+        let (audience, members) = state.audience(audience_id)?;
+        Ok(contact_page(state.members(audience, members), &options))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl BroadcastsApi for FakeBroadcasts {
+    async fn list(&self, options: ListBroadcastsOptions) -> crate::Result<ListBroadcastsResponse> {
+        let state = self.0.lock();
+        let broadcasts: Vec<&Broadcast> = state
+            .broadcasts
+            .iter()
+            .rev()
+            .map(|(broadcast, _)| broadcast)
+            .filter(|broadcast| {
+                options
+                    .status
+                    .as_deref()
+                    .map_or(true, |status| broadcast.status == status)
+            })
+            .collect();
+
+        let per_page = options.per_page.unwrap_or(DEFAULT_PER_PAGE).max(1);
+        let page = options.page.unwrap_or(1).max(1);
+        let start = (page as usize - 1).saturating_mul(per_page as usize);
+        let page_broadcasts = broadcasts
+            .iter()
+            .skip(start)
+            .take(per_page as usize)
+            .map(|broadcast| (*broadcast).clone())
+            .collect();
+
+        let mut response = ListBroadcastsResponse::new(page_broadcasts);
+        response.pagination = BroadcastPagination {
+            total: broadcasts.len() as u64,
+            per_page,
+            current_page: page,
+            last_page: last_page(broadcasts.len(), per_page),
+            extra: serde_json::Map::new(),
+        };
+        Ok(response)
+    }
+
+    async fn create(&self, options: CreateBroadcastOptions) -> crate::Result<Broadcast> {
+        if options.name.trim().is_empty() {
+            return Err(invalid("name", "The name field is required."));
+        }
+        let mut state = self.0.lock();
+        if state.audience(&options.audience_id).is_err() {
+            return Err(invalid(
+                "audience_id",
+                "The selected audience id is invalid.",
+            ));
+        }
+        if !state
+            .templates
+            .iter()
+            .any(|template| template.slug == options.template_slug)
+        {
+            return Err(invalid(
+                "template_slug",
+                "The selected template slug is invalid.",
+            ));
+        }
+
+        state.broadcasts_created += 1;
+        let mut broadcast = Broadcast::new(
+            format!("fake-broadcast-{}", state.broadcasts_created),
+            options.name,
+        );
+        broadcast.status = if options.scheduled_at.is_some() {
+            "scheduled"
+        } else {
+            "draft"
+        }
+        .to_owned();
+        broadcast.from = options.from;
+        broadcast.from_name = options.from_name;
+        broadcast.audience_id = options.audience_id;
+        broadcast.template_slug = options.template_slug;
+        broadcast.subject = options.subject;
+        broadcast.scheduled_at = options.scheduled_at;
+        broadcast.created_at = TIMESTAMP.to_owned();
+        broadcast.updated_at = TIMESTAMP.to_owned();
+        state
+            .broadcasts
+            .push((broadcast.clone(), BroadcastStats::default()));
+        Ok(broadcast)
+    }
+
+    async fn get(&self, broadcast_id: &str) -> crate::Result<Broadcast> {
+        let state = self.0.lock();
+        state
+            .broadcast(broadcast_id)
+            .map(|(broadcast, _)| broadcast.clone())
+    }
+
+    async fn schedule(&self, broadcast_id: &str, scheduled_at: &str) -> crate::Result<Broadcast> {
+        let mut state = self.0.lock();
+        let (broadcast, _) = state.broadcast_mut(broadcast_id)?;
+        if broadcast.status != "draft" && broadcast.status != "scheduled" {
+            return Err(invalid(
+                "status",
+                "Only draft or scheduled broadcasts can be scheduled.",
+            ));
+        }
+        broadcast.status = "scheduled".to_owned();
+        broadcast.scheduled_at = Some(scheduled_at.to_owned());
+        Ok(broadcast.clone())
+    }
+
+    async fn send(&self, broadcast_id: &str) -> crate::Result<Broadcast> {
+        let mut state = self.0.lock();
+        let (broadcast, _) = state.broadcast(broadcast_id)?;
+        if broadcast.status != "draft" && broadcast.status != "scheduled" {
+            return Err(invalid(
+                "status",
+                "Only draft or scheduled broadcasts can be sent.",
+            ));
+        }
+        let recipients = match state.audience(&broadcast.audience_id) {
+            Ok((audience, members)) => state.members(audience, members).count() as u64,
+            Err(_) => 0,
+        };
+
+        let (broadcast, stats) = state.broadcast_mut(broadcast_id)?;
+        broadcast.status = "sent".to_owned();
+        broadcast.sent_at = Some(TIMESTAMP.to_owned());
+        stats.recipients = recipients;
+        stats.sent = recipients;
+        stats.delivered = recipients;
+        Ok(broadcast.clone())
+    }
+
+    async fn cancel(&self, broadcast_id: &str) -> crate::Result<Broadcast> {
+        let mut state = self.0.lock();
+        let (broadcast, _) = state.broadcast_mut(broadcast_id)?;
+        if broadcast.status != "scheduled" {
+            return Err(invalid(
+                "status",
+                "Only scheduled broadcasts can be cancelled.",
+            ));
+        }
+        broadcast.status = "draft".to_owned();
+        broadcast.scheduled_at = None;
+        Ok(broadcast.clone())
+    }
+
+    async fn delete(&self, broadcast_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let (broadcast, _) = state.broadcast(broadcast_id)?;
+        if broadcast.status != "draft" {
+            return Err(invalid("status", "Only draft broadcasts can be deleted."));
+        }
+        state
+            .broadcasts
+            .retain(|(broadcast, _)| broadcast.id != broadcast_id);
+        Ok(())
+    }
+
+    async fn stats(&self, broadcast_id: &str) -> crate::Result<BroadcastStats> {
+        let state = self.0.lock();
+        state
+            .broadcast(broadcast_id)
+            .map(|(_, stats)| stats.clone())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl StatsApi for FakeStats {
+    async fn get(&self, options: StatsOptions) -> crate::Result<StatsResponse> {
+        let state = self.0.lock();
+        let recipients: usize = state
+            .emails
+            .iter()
+            .map(|(_, email)| email)
+            .filter(|email| {
+                options
+                    .domain
+                    .as_deref()
+                    .map_or(true, |domain| domain_of(email.from_address()) == domain)
+                    && options
+                        .template
+                        .as_deref()
+                        .map_or(true, |slug| email.template_slug() == Some(slug))
+                    && options
+                        .tag
+                        .as_deref()
+                        .map_or(true, |tag| email.tags().iter().any(|t| t == tag))
+            })
+            .map(|email| email.to().len())
+            .sum();
+
+        let counts = StatsCounts {
+            sent: recipients as u64,
+            delivered: recipients as u64,
+            ..StatsCounts::default()
+        };
+        Ok(StatsResponse::new(vec![StatsBucket::new(
+            options.from,
+            counts,
+        )]))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl SmtpCredentialsApi for FakeSmtpCredentials {
+    async fn list(&self) -> crate::Result<Vec<SmtpCredential>> {
+        Ok(self.0.lock().smtp_credentials.clone())
+    }
+
+    async fn create(
+        &self,
+        options: CreateSmtpCredentialOptions,
+    ) -> crate::Result<NewSmtpCredential> {
+        if options.name.trim().is_empty() {
+            return Err(invalid("name", "The name field is required."));
+        }
+        let mut state = self.0.lock();
+        if let Some(domain) = &options.domain {
+            if !state.domains.iter().any(|detail| &detail.domain == domain) {
+                return Err(invalid("domain", "The selected domain is invalid."));
+            }
+        }
+
+        state.smtp_credentials_created += 1;
+        let number = state.smtp_credentials_created;
+        let mut credential = SmtpCredential::new(
+            format!("fake-smtp-credential-{number}"),
+            format!("fake-smtp-user-{number}"),
+        );
+        credential.name = options.name;
+        credential.domain = options.domain;
+        credential.host = "smtp.lettr.com".to_owned();
+        credential.port = 587;
+        credential.created_at = TIMESTAMP.to_owned();
+        state.smtp_credentials.push(credential.clone());
+        Ok(NewSmtpCredential::new(
+            credential,
+            format!("fake-smtp-password-{number}"),
+        ))
+    }
+
+    async fn get(&self, credential_id: &str) -> crate::Result<SmtpCredential> {
+        let state = self.0.lock();
+        state
+            .smtp_credentials
+            .iter()
+            .find(|credential| credential.id == credential_id)
+            .cloned()
+            .ok_or_else(|| not_found("SMTP credential"))
+    }
+
+    async fn revoke(&self, credential_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .smtp_credentials
+            .iter()
+            .position(|credential| credential.id == credential_id)
+            .ok_or_else(|| not_found("SMTP credential"))?;
+        state.smtp_credentials.remove(index);
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl InboundApi for FakeInbound {
+    async fn list_routes(&self) -> crate::Result<Vec<InboundRoute>> {
+        Ok(self.0.lock().inbound_routes.clone())
+    }
+
+    async fn create_route(
+        &self,
+        options: CreateInboundRouteOptions,
+    ) -> crate::Result<InboundRoute> {
+        if !options.domain.contains('.') {
+            return Err(invalid("domain", "The domain must be a valid domain name."));
+        }
+        let mut state = self.0.lock();
+        if state
+            .inbound_routes
+            .iter()
+            .any(|route| route.domain == options.domain && route.recipient == options.recipient)
+        {
+            return Err(invalid("recipient", "The route has already been taken."));
+        }
+
+        state.inbound_routes_created += 1;
+        let mut route = InboundRoute::new(
+            format!("fake-inbound-route-{}", state.inbound_routes_created),
+            options.domain,
+        );
+        route.recipient = options.recipient;
+        route.forward_url = options.forward_url;
+        route.mx_host = "inbound.lettr.com".to_owned();
+        route.created_at = TIMESTAMP.to_owned();
+        state.inbound_routes.push(route.clone());
+        Ok(route)
+    }
+
+    async fn delete_route(&self, route_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .inbound_routes
+            .iter()
+            .position(|route| route.id == route_id)
+            .ok_or_else(|| not_found("Inbound route"))?;
+        state.inbound_routes.remove(index);
+        Ok(())
+    }
+
+    async fn list_rules(&self) -> crate::Result<Vec<InboundRule>> {
+        Ok(self.0.lock().inbound_rules.clone())
+    }
+
+    async fn create_rule(&self, options: CreateInboundRuleOptions) -> crate::Result<InboundRule> {
+        if !options.pattern.contains('@') {
+            return Err(invalid(
+                "pattern",
+                "The pattern must match an email address.",
+            ));
+        }
+        if options
+            .forward_url
+            .as_deref()
+            .is_some_and(|url| !url.starts_with("https://"))
+        {
+            return Err(invalid(
+                "forward_url",
+                "The forward url must be an HTTPS URL.",
+            ));
+        }
+        let mut state = self.0.lock();
+        let priority = options.priority.unwrap_or_else(|| {
+            state
+                .inbound_rules
+                .last()
+                .map_or(0, |rule| rule.priority.saturating_add(1))
+        });
+
+        state.inbound_rules_created += 1;
+        let mut rule = InboundRule::new(
+            format!("fake-inbound-rule-{}", state.inbound_rules_created),
+            options.pattern,
+            options.action,
+        );
+        rule.forward_url = options.forward_url;
+        rule.priority = priority;
+        rule.created_at = TIMESTAMP.to_owned();
+        // Rules with equal priority run in creation order.
+        let index = state
+            .inbound_rules
+            .partition_point(|existing| existing.priority <= priority);
+        state.inbound_rules.insert(index, rule.clone());
+        Ok(rule)
+    }
+
+    async fn delete_rule(&self, rule_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .inbound_rules
+            .iter()
+            .position(|rule| rule.id == rule_id)
+            .ok_or_else(|| not_found("Inbound rule"))?;
+        state.inbound_rules.remove(index);
+        Ok(())
+    }
+
+    async fn list_messages(
+        &self,
+        options: ListInboundMessagesOptions,
+    ) -> crate::Result<ListInboundMessagesResponse> {
+        let state = self.0.lock();
+        let messages: Vec<&InboundMessage> = state
+            .inbound_messages
+            .iter()
+            .rev()
+            .filter(|message| {
+                options
+                    .route_id
+                    .as_deref()
+                    .map_or(true, |route_id| message.route_id == route_id)
+            })
+            .filter(|message| {
+                options
+                    .since
+                    .as_deref()
+                    .map_or(true, |since| message.received_at.as_str() >= since)
+            })
+            .collect();
+
+        let per_page = options.per_page.unwrap_or(DEFAULT_PER_PAGE);
+        let start = options
+            .cursor
+            .as_deref()
+            .map_or(Ok(0), str::parse::<usize>)
+            .map_err(|_| invalid("cursor", "The cursor is invalid."))?;
+        let end = start.saturating_add(per_page as usize).min(messages.len());
+        let next_cursor = (end < messages.len()).then(|| end.to_string());
+
+        let page = messages
+            .get(start..end)
+            .unwrap_or_default()
+            .iter()
+            .map(|message| {
+                let mut message = (*message).clone();
+                for attachment in &mut message.attachments {
+                    attachment.data = None;
+                }
+                message
+            })
+            .collect();
+        let mut response = ListInboundMessagesResponse::new(page);
+        response.pagination = Pagination {
+            next_cursor,
+            per_page,
+            extra: serde_json::Map::new(),
+        };
+        Ok(response)
+    }
+
+    async fn get_message(&self, message_id: &str) -> crate::Result<InboundMessage> {
+        let state = self.0.lock();
+        state
+            .inbound_messages
+            .iter()
+            .find(|message| message.id == message_id)
+            .cloned()
+            .ok_or_else(|| not_found("Inbound message"))
+    }
+
+    async fn delete_message(&self, message_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .inbound_messages
+            .iter()
+            .position(|message| message.id == message_id)
+            .ok_or_else(|| not_found("Inbound message"))?;
+        state.inbound_messages.remove(index);
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl IpPoolsApi for FakeIpPools {
+    async fn list(&self) -> crate::Result<Vec<IpPool>> {
+        Ok(self.0.lock().ip_pools.clone())
+    }
+
+    async fn get(&self, pool_id: &str) -> crate::Result<IpPool> {
+        let state = self.0.lock();
+        state
+            .ip_pools
+            .iter()
+            .find(|pool| pool.id == pool_id)
+            .cloned()
+            .ok_or_else(|| not_found("IP pool"))
+    }
+
+    async fn assign_domain(&self, pool_id: &str, domain: &str) -> crate::Result<IpPool> {
+        let mut state = self.0.lock();
+        let index = state
+            .ip_pools
+            .iter()
+            .position(|pool| pool.id == pool_id)
+            .ok_or_else(|| not_found("IP pool"))?;
+        if !state.domains.iter().any(|detail| detail.domain == domain) {
+            return Err(invalid("domain", "The selected domain is invalid."));
+        }
+
+        for pool in &mut state.ip_pools {
+            pool.domains.retain(|existing| existing != domain);
+        }
+        let pool = &mut state.ip_pools[index];
+        pool.domains.push(domain.to_owned());
+        Ok(pool.clone())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TeamApi for FakeTeam {
+    async fn list_members(&self) -> crate::Result<Vec<TeamMember>> {
+        Ok(self.0.lock().team_members.clone())
+    }
+
+    async fn remove_member(&self, member_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .team_members
+            .iter()
+            .position(|member| member.id == member_id)
+            .ok_or_else(|| not_found("Team member"))?;
+        state.team_members.remove(index);
+        Ok(())
+    }
+
+    async fn list_roles(&self) -> crate::Result<Vec<TeamRole>> {
+        Ok(TEAM_ROLES
+            .iter()
+            .map(|(name, description)| TeamRole::new(*name, *description))
+            .collect())
+    }
+
+    async fn list_invitations(&self) -> crate::Result<Vec<TeamInvitation>> {
+        Ok(self.0.lock().team_invitations.clone())
+    }
+
+    async fn invite(&self, options: InviteTeamMemberOptions) -> crate::Result<TeamInvitation> {
+        if !options.email.contains('@') {
+            return Err(invalid("email", "The email must be a valid email address."));
+        }
+        if !TEAM_ROLES.iter().any(|(name, _)| *name == options.role) {
+            return Err(invalid("role", "The selected role is invalid."));
+        }
+        let mut state = self.0.lock();
+        if state
+            .team_members
+            .iter()
+            .any(|member| member.email.eq_ignore_ascii_case(&options.email))
+        {
+            return Err(invalid("email", "The email is already a team member."));
+        }
+        if state
+            .team_invitations
+            .iter()
+            .any(|invitation| invitation.email.eq_ignore_ascii_case(&options.email))
+        {
+            return Err(invalid("email", "The email has already been invited."));
+        }
+
+        state.team_invitations_created += 1;
+        let mut invitation = TeamInvitation::new(
+            format!("fake-invitation-{}", state.team_invitations_created),
+            options.email,
+            options.role,
+        );
+        invitation.expires_at = TIMESTAMP.to_owned();
+        invitation.created_at = TIMESTAMP.to_owned();
+        state.team_invitations.push(invitation.clone());
+        Ok(invitation)
+    }
+
+    async fn revoke_invitation(&self, invitation_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .team_invitations
+            .iter()
+            .position(|invitation| invitation.id == invitation_id)
+            .ok_or_else(|| not_found("Invitation"))?;
+        state.team_invitations.remove(index);
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl ExportsApi for FakeExports {
+    async fn create(&self, options: CreateExportOptions) -> crate::Result<ExportJob> {
+        if options.to < options.from {
+            return Err(invalid("to", "The to date must be after the from date."));
+        }
+        let mut state = self.0.lock();
+        let events: Vec<EmailEventDetail> = state
+            .emails
+            .iter()
+            .flat_map(|(request_id, email)| events(request_id, email))
+            .filter(|event| {
+                options.event_types.is_empty() || options.event_types.contains(&event.event_type)
+            })
+            .filter(|event| {
+                options
+                    .domain
+                    .as_deref()
+                    .map_or(true, |domain| event.sending_domain == domain)
+            })
+            .collect();
+        let file = export_file(&events, options.format);
+
+        let mut job = ExportJob::new(
+            format!("fake-export-{}", state.exports.len() + 1),
+            "completed",
+        );
+        job.format = options.format;
+        job.from = options.from;
+        job.to = options.to;
+        job.event_count = Some(events.len() as u64);
+        job.size = Some(file.len() as u64);
+        job.expires_at = Some(TIMESTAMP.to_owned());
+        job.created_at = TIMESTAMP.to_owned();
+        job.completed_at = Some(TIMESTAMP.to_owned());
+        state.exports.push((job.clone(), file));
+        Ok(job)
+    }
+
+    async fn get(&self, export_id: &str) -> crate::Result<ExportJob> {
+        let state = self.0.lock();
+        state
+            .exports
+            .iter()
+            .find(|(job, _)| job.id == export_id)
+            .map(|(job, _)| job.clone())
+            .ok_or_else(|| not_found("Export"))
+    }
+
+    async fn download(
+        &self,
+        export_id: &str,
+        writer: &mut (dyn std::io::Write + Send),
+    ) -> crate::Result<u64> {
+        let state = self.0.lock();
+        let (_, file) = state
+            .exports
+            .iter()
+            .find(|(job, _)| job.id == export_id)
+            .ok_or_else(|| not_found("Export"))?;
+        writer.write_all(file)?;
+        writer.flush()?;
+        Ok(file.len() as u64)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl UnsubscribeGroupsApi for FakeUnsubscribeGroups {
+    async fn list(&self) -> crate::Result<Vec<UnsubscribeGroup>> {
+        let state = self.0.lock();
+        Ok(state
+            .unsubscribe_groups
+            .iter()
+            .map(|group| state.counted_group(group))
+            .collect())
+    }
+
+    async fn create(
+        &self,
+        options: CreateUnsubscribeGroupOptions,
+    ) -> crate::Result<UnsubscribeGroup> {
+        if options.name.trim().is_empty() {
+            return Err(invalid("name", "The name field is required."));
+        }
+        let mut state = self.0.lock();
+        state.unsubscribe_groups_created += 1;
+        let mut group = UnsubscribeGroup::new(
+            format!(
+                "fake-unsubscribe-group-{}",
+                state.unsubscribe_groups_created
+            ),
+            options.name,
+        );
+        group.description = options.description;
+        group.created_at = TIMESTAMP.to_owned();
+        state.unsubscribe_groups.push(group.clone());
+        Ok(group)
+    }
+
+    async fn get(&self, group_id: &str) -> crate::Result<UnsubscribeGroup> {
+        let state = self.0.lock();
+        state
+            .unsubscribe_groups
+            .iter()
+            .find(|group| group.id == group_id)
+            .map(|group| state.counted_group(group))
+            .ok_or_else(|| not_found("Unsubscribe group"))
+    }
+
+    async fn delete(&self, group_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .unsubscribe_groups
+            .iter()
+            .position(|group| group.id == group_id)
+            .ok_or_else(|| not_found("Unsubscribe group"))?;
+        state.unsubscribe_groups.remove(index);
+        state.unsubscribes.retain(|(group, _)| group != group_id);
+        Ok(())
+    }
+
+    async fn preferences(&self, email: &str) -> crate::Result<RecipientPreferences> {
+        if !email.contains('@') {
+            return Err(invalid("email", "The email must be a valid email address."));
+        }
+        let state = self.0.lock();
+        let groups = state
+            .unsubscribe_groups
+            .iter()
+            .map(|group| {
+                let mut preference =
+                    GroupPreference::new(&group.id, !state.unsubscribed(&group.id, email));
+                preference.name.clone_from(&group.name);
+                preference
+            })
+            .collect();
+        Ok(RecipientPreferences::new(email, groups))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl BlocklistsApi for FakeBlocklists {
+    async fn list(&self) -> crate::Result<Vec<BlocklistStatus>> {
+        let state = self.0.lock();
+        let ips = state
+            .ip_pools
+            .iter()
+            .flat_map(|pool| &pool.ips)
+            .map(|ip| (ip.ip.as_str(), "ip"));
+        let domains = state
+            .domains
+            .iter()
+            .map(|detail| (detail.domain.as_str(), "domain"));
+        let mut statuses: Vec<BlocklistStatus> = ips
+            .chain(domains)
+            .filter(|(target, _)| {
+                !state
+                    .blocklist_statuses
+                    .iter()
+                    .any(|status| status.target == *target)
+            })
+            .map(|(target, target_type)| clean_status(target, target_type))
+            .collect();
+        statuses.extend(state.blocklist_statuses.iter().cloned());
+        Ok(statuses)
+    }
+
+    async fn get(&self, target: &str) -> crate::Result<BlocklistStatus> {
+        let state = self.0.lock();
+        if let Some(status) = state
+            .blocklist_statuses
+            .iter()
+            .find(|status| status.target == target)
+        {
+            return Ok(status.clone());
+        }
+        if state
+            .ip_pools
+            .iter()
+            .flat_map(|pool| &pool.ips)
+            .any(|ip| ip.ip == target)
+        {
+            return Ok(clean_status(target, "ip"));
+        }
+        if state.domains.iter().any(|detail| detail.domain == target) {
+            return Ok(clean_status(target, "domain"));
+        }
+        Err(not_found("Blocklist target"))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AssetsApi for FakeAssets {
+    async fn upload(&self, options: UploadAssetOptions) -> crate::Result<Asset> {
+        if options.filename.trim().is_empty() {
+            return Err(invalid("filename", "The filename field is required."));
+        }
+        if !options.content_type.starts_with("image/") {
+            return Err(invalid("file", "The file must be an image."));
+        }
+        let mut state = self.0.lock();
+        state.assets_uploaded += 1;
+        let id = format!("fake-asset-{}", state.assets_uploaded);
+        let url = format!("https://assets.fake.lettr.com/{id}/{}", options.filename);
+        let mut asset = Asset::new(id, url);
+        asset.filename = options.filename;
+        asset.content_type = options.content_type;
+        asset.size = options.data.len() as u64;
+        asset.created_at = TIMESTAMP.to_owned();
+        state.assets.push(asset.clone());
+        Ok(asset)
+    }
+
+    async fn list(&self) -> crate::Result<Vec<Asset>> {
+        Ok(self.0.lock().assets.clone())
+    }
+
+    async fn get(&self, asset_id: &str) -> crate::Result<Asset> {
+        let state = self.0.lock();
+        state
+            .assets
+            .iter()
+            .find(|asset| asset.id == asset_id)
+            .cloned()
+            .ok_or_else(|| not_found("Asset"))
+    }
+
+    async fn delete(&self, asset_id: &str) -> crate::Result<()> {
+        let mut state = self.0.lock();
+        let index = state
+            .assets
+            .iter()
+            .position(|asset| asset.id == asset_id)
+            .ok_or_else(|| not_found("Asset"))?;
+        state.assets.remove(index);
+        Ok(())
+    }
+}
+
+/// Returns the status of an IP or domain no blocklist lists.
+fn clean_status(target: &str, target_type: &str) -> BlocklistStatus {
+    let mut status = BlocklistStatus::new(target, target_type);
+    status.checked_at = TIMESTAMP.to_owned();
+    status
+}
+
+/// Returns the page of `contacts` selected by `options`, filtered by email.
+fn contact_page<'a>(
+    contacts: impl Iterator<Item = &'a Contact>,
+    options: &ListContactsOptions,
+) -> ListContactsResponse {
+    let contacts: Vec<&Contact> = contacts
+        .filter(|contact| {
+            options
+                .email
+                .as_deref()
+                .map_or(true, |email| contact.email.eq_ignore_ascii_case(email))
+        })
+        .collect();
+
+    let per_page = options.per_page.unwrap_or(DEFAULT_PER_PAGE).max(1);
+    let page = options.page.unwrap_or(1).max(1);
+    let start = (page as usize - 1).saturating_mul(per_page as usize);
+    let page_contacts = contacts
+        .iter()
+        .skip(start)
+        .take(per_page as usize)
+        .map(|contact| (*contact).clone())
+        .collect();
+
+    let mut response = ListContactsResponse::new(page_contacts);
+    response.pagination = ContactPagination {
+        total: contacts.len() as u64,
+        per_page,
+        current_page: page,
+        last_page: last_page(contacts.len(), per_page),
+        extra: serde_json::Map::new(),
+    };
+    response
+}
+
+/// Returns the number of the last page of `total` items, at least 1.
+fn last_page(total: usize, per_page: u32) -> u32 {
+    let pages = (total + per_page as usize - 1) / per_page as usize;
+    u32::try_from(pages).unwrap_or(u32::MAX).max(1)
+}
+
+/// Counts `clicks`, given as request ID, recipient, and URL, per URL, most
+/// clicked first.
+fn link_clicks<'a>(clicks: impl Iterator<Item = &'a (String, String, String)>) -> Vec<LinkClicks> {
+    let mut links: Vec<(LinkClicks, Vec<(&str, &str)>)> = Vec::new();
+    for (request_id, rcpt_to, url) in clicks {
+        let index = match links.iter().position(|(link, _)| link.url == *url) {
+            Some(index) => index,
+            None => {
+                links.push((LinkClicks::new(url, 0, 0), Vec::new()));
+                links.len() - 1
+            }
+        };
+        let (link, clickers) = &mut links[index];
+        link.total_clicks += 1;
+        let clicker = (request_id.as_str(), rcpt_to.as_str());
+        if !clickers.contains(&clicker) {
+            clickers.push(clicker);
+            link.unique_clicks += 1;
+        }
+    }
+    let mut links: Vec<LinkClicks> = links.into_iter().map(|(link, _)| link).collect();
+    links.sort_by_key(|link| Reverse(link.total_clicks));
+    links
+}
+
+/// Returns the injection events of a sent email, one per recipient.
+fn events<'a>(
+    request_id: &'a str,
+    email: &'a CreateEmailOptions,
+) -> impl Iterator<Item = EmailEventDetail> + 'a {
+    let options = email.options().cloned().unwrap_or_default();
+    email.to().iter().enumerate().map(move |(index, rcpt_to)| {
+        let mut event = EmailEventDetail::new("injection", request_id, rcpt_to);
+        event.event_id = format!("{request_id}-{index}");
+        event.timestamp = TIMESTAMP.to_owned();
+        event.message_id = format!("{request_id}-message");
+        event.subject = email.subject().to_owned();
+        event.friendly_from = email.from_address().to_owned();
+        event.sending_domain = domain_of(email.from_address()).to_owned();
+        event.recipient_domain = domain_of(rcpt_to).to_owned();
+        event.click_tracking = options.click_tracking.unwrap_or(true);
+        event.open_tracking = options.open_tracking.unwrap_or(true);
+        event.transactional = options.transactional.unwrap_or(true);
+        event.injection_time = Some(TIMESTAMP.to_owned());
+        event.rcpt_meta = email
+            .metadata()
+            .and_then(|metadata| RawJson::from_value(metadata).ok());
+        event
+    })
+}
+
+/// Returns the contents of an export file holding `events`.
+fn export_file(events: &[EmailEventDetail], format: ExportFormat) -> Vec<u8> {
+    let mut file = String::new();
+    match format {
+        ExportFormat::Jsonl => {
+            for event in events {
+                let line = serde_json::json!({
+                    "event_id": event.event_id,
+                    "type": event.event_type,
+                    "timestamp": event.timestamp,
+                    "request_id": event.request_id,
+                    "rcpt_to": event.rcpt_to,
+                    "subject": event.subject,
+                });
+                file.push_str(&line.to_string());
+                file.push('\n');
+            }
+        }
+        ExportFormat::Csv => {
+            file.push_str("event_id,type,timestamp,request_id,rcpt_to,subject\n");
+            for event in events {
+                let fields = [
+                    &event.event_id,
+                    &event.event_type,
+                    &event.timestamp,
+                    &event.request_id,
+                    &event.rcpt_to,
+                    &event.subject,
+                ];
+                let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+                file.push_str(&row.join(","));
+                file.push('\n');
+            }
+        }
+    }
+    file.into_bytes()
+}
+
+/// Quotes a CSV field if it contains a separator, quote, or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Returns the list-endpoint view of an event.
+fn summary(detail: EmailEventDetail) -> EmailEvent {
+    let mut event = EmailEvent::new(detail.request_id, detail.rcpt_to);
+    event.event_id = detail.event_id;
+    event.timestamp = detail.timestamp;
+    event.message_id = detail.message_id;
+    event.subject = detail.subject;
+    event.friendly_from = detail.friendly_from;
+    event.sending_domain = detail.sending_domain;
+    event.raw_rcpt_to = detail.raw_rcpt_to;
+    event.recipient_domain = detail.recipient_domain;
+    event.click_tracking = detail.click_tracking;
+    event.open_tracking = detail.open_tracking;
+    event.transactional = detail.transactional;
+    event.injection_time = detail.injection_time;
+    event.rcpt_meta = detail.rcpt_meta;
+    event
+}
+
+/// Returns whether `route` accepts mail for `address`.
+fn accepts(route: &InboundRoute, address: &str) -> bool {
+    address
+        .rsplit_once('@')
+        .is_some_and(|(local_part, domain)| {
+            domain.eq_ignore_ascii_case(&route.domain)
+                && route
+                    .recipient
+                    .as_deref()
+                    .map_or(true, |recipient| local_part.eq_ignore_ascii_case(recipient))
+        })
+}
+
+fn domain_of(address: &str) -> &str {
+    address.rsplit_once('@').map_or("", |(_, domain)| domain)
+}
+
+/// Derives a template slug from its name, as the API does.
+fn slugify(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn not_found(resource: &str) -> crate::Error {
+    ApiError {
+        message: format!("{resource} not found."),
+        error_code: Some("not_found".to_owned()),
+        status: StatusCode::NOT_FOUND,
+        request_id: None,
+        rate_limit: Default::default(),
+        raw_body: None,
+    }
+    .into()
+}
+
+fn invalid(field: &str, message: &str) -> crate::Error {
+    ValidationError {
+        message: "The given data was invalid.".to_owned(),
+        error_code: Some("validation_error".to_owned()),
+        errors: HashMap::from([(field.to_owned(), vec![message.to_owned()])]),
+        status: StatusCode::UNPROCESSABLE_ENTITY,
+        request_id: None,
+    }
+    .into()
+}
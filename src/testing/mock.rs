@@ -0,0 +1,1081 @@
+//! An HTTP mock of the Lettr API.
+
+use std::fmt;
+
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::TIMESTAMP;
+use crate::{Lettr, LettrBuilder, Region};
+
+/// Priority of the canned responses; lower than wiremock's default, so mocks
+/// mounted by tests win.
+const CANNED_PRIORITY: u8 = u8::MAX;
+
+/// Contents of the canned export file.
+const EXPORT_FILE: &str = "event_id,type,timestamp,request_id,rcpt_to,subject\n\
+    mock-event-id,delivery,2024-01-15T10:30:00+00:00,mock-request-id,recipient@example.com,Hello\n";
+
+/// A local mock server preloaded with canned Lettr API responses.
+///
+/// `MockLettr` starts a [`wiremock`] server answering every endpoint the
+/// client supports with a canned, schema-correct response, and hands out
+/// clients pointed at it. Tests can mount their own mocks on
+/// [`server`](MockLettr::server) to override a response; those take
+/// precedence over the canned ones.
+///
+/// Only available with the async client.
+///
+/// # Example
+///
+/// ```
+/// use lettr::testing::wiremock::matchers::{method, path};
+/// use lettr::testing::wiremock::{Mock, ResponseTemplate};
+/// use lettr::testing::MockLettr;
+/// use lettr::{CreateEmailOptions, Error};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mock = MockLettr::start().await;
+/// let client = mock.client();
+///
+/// let email = CreateEmailOptions::new("sender@example.com", ["user@example.com"], "Hello!")
+///     .with_text("Hello!");
+/// let response = client.emails.send(email.clone()).await.unwrap();
+/// assert_eq!(response.request_id, MockLettr::REQUEST_ID);
+///
+/// // Override a canned response.
+/// Mock::given(method("POST"))
+///     .and(path("/emails"))
+///     .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+///         "message": "The given data was invalid.",
+///         "errors": { "to": ["The to field is required."] },
+///     })))
+///     .mount(mock.server())
+///     .await;
+///
+/// let err = client.emails.send(email).await.unwrap_err();
+/// assert!(matches!(err, Error::Validation(_)));
+/// # }
+/// ```
+pub struct MockLettr {
+    server: MockServer,
+}
+
+impl MockLettr {
+    /// Request ID of the canned send response and email events.
+    pub const REQUEST_ID: &'static str = "mock-request-id";
+    /// Domain of the canned domain responses.
+    pub const DOMAIN: &'static str = "example.com";
+    /// ID of the canned webhook.
+    pub const WEBHOOK_ID: &'static str = "mock-webhook-id";
+    /// ID of the canned template.
+    pub const TEMPLATE_ID: u64 = 1;
+    /// ID of the canned contact.
+    pub const CONTACT_ID: &'static str = "mock-contact-id";
+    /// ID of the canned audience.
+    pub const AUDIENCE_ID: &'static str = "mock-audience-id";
+    /// ID of the canned broadcast.
+    pub const BROADCAST_ID: &'static str = "mock-broadcast-id";
+    /// ID of the canned SMTP credential.
+    pub const SMTP_CREDENTIAL_ID: &'static str = "mock-smtp-credential-id";
+    /// ID of the canned inbound route.
+    pub const INBOUND_ROUTE_ID: &'static str = "mock-inbound-route-id";
+    /// ID of the canned inbound message.
+    pub const INBOUND_MESSAGE_ID: &'static str = "mock-inbound-message-id";
+    /// ID of the canned inbound rule.
+    pub const INBOUND_RULE_ID: &'static str = "mock-inbound-rule-id";
+    /// ID of the canned IP pool.
+    pub const IP_POOL_ID: &'static str = "mock-ip-pool-id";
+    /// ID of the canned team member.
+    pub const TEAM_MEMBER_ID: &'static str = "mock-team-member-id";
+    /// ID of the canned team invitation.
+    pub const INVITATION_ID: &'static str = "mock-invitation-id";
+    /// ID of the canned recipient data erasure.
+    pub const ERASURE_ID: &'static str = "mock-erasure-id";
+    /// ID of the canned export.
+    pub const EXPORT_ID: &'static str = "mock-export-id";
+    /// ID of the canned unsubscribe group.
+    pub const UNSUBSCRIBE_GROUP_ID: &'static str = "mock-unsubscribe-group-id";
+    /// ID of the canned asset.
+    pub const ASSET_ID: &'static str = "mock-asset-id";
+
+    /// Starts a mock server answering every endpoint with a canned response.
+    pub async fn start() -> Self {
+        let server = MockServer::start().await;
+        for (verb, pattern, body) in canned_responses() {
+            Mock::given(method(verb))
+                .and(path_regex(pattern))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .with_priority(CANNED_PRIORITY)
+                .named(format!("canned {verb} {pattern}"))
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path_regex("^/exports/[^/]+/download$"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/csv")
+                    .set_body_string(EXPORT_FILE),
+            )
+            .with_priority(CANNED_PRIORITY)
+            .named("canned GET export download")
+            .mount(&server)
+            .await;
+        Self { server }
+    }
+
+    /// Returns a client pointed at the mock server.
+    #[must_use]
+    pub fn client(&self) -> Lettr {
+        self.builder().build()
+    }
+
+    /// Returns a builder pointed at the mock server, to configure the client
+    /// further (retries, middleware, ...).
+    pub fn builder(&self) -> LettrBuilder {
+        Lettr::builder("mock-api-key").region(Region::Custom(self.server.uri()))
+    }
+
+    /// Returns the underlying server, to mount overrides or inspect the
+    /// received requests.
+    #[must_use]
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// Returns the base URL of the mock server.
+    #[must_use]
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+}
+
+impl fmt::Debug for MockLettr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockLettr")
+            .field("uri", &self.server.uri())
+            .finish()
+    }
+}
+
+/// Method, path pattern, and body of every canned response.
+fn canned_responses() -> Vec<(&'static str, &'static str, Value)> {
+    let domain = json!({
+        "domain": MockLettr::DOMAIN,
+        "status": "approved",
+        "status_label": "Approved",
+        "can_send": true,
+        "cname_status": "valid",
+        "dkim_status": "valid",
+        "created_at": TIMESTAMP,
+        "updated_at": TIMESTAMP,
+    });
+    let webhook = json!({
+        "id": MockLettr::WEBHOOK_ID,
+        "name": "Mock webhook",
+        "url": "https://example.com/webhooks/lettr",
+        "enabled": true,
+        "event_types": ["message.delivery"],
+        "auth_type": "none",
+        "has_auth_credentials": false,
+        "last_successful_at": TIMESTAMP,
+        "last_failure_at": null,
+        "last_status": "200",
+    });
+    let contact = json!({
+        "id": MockLettr::CONTACT_ID,
+        "email": "user@example.com",
+        "first_name": "Jane",
+        "last_name": "Doe",
+        "attributes": { "plan": "pro" },
+        "unsubscribed": false,
+        "created_at": TIMESTAMP,
+        "updated_at": TIMESTAMP,
+    });
+    let audience = json!({
+        "id": MockLettr::AUDIENCE_ID,
+        "name": "Newsletter",
+        "description": null,
+        "filter": {},
+        "contact_count": 1,
+        "created_at": TIMESTAMP,
+        "updated_at": TIMESTAMP,
+    });
+    let broadcast = json!({
+        "id": MockLettr::BROADCAST_ID,
+        "name": "Newsletter",
+        "status": "scheduled",
+        "from": "sender@example.com",
+        "from_name": null,
+        "audience_id": MockLettr::AUDIENCE_ID,
+        "template_slug": "welcome",
+        "subject": null,
+        "scheduled_at": TIMESTAMP,
+        "sent_at": null,
+        "created_at": TIMESTAMP,
+        "updated_at": TIMESTAMP,
+    });
+    let smtp_credential = json!({
+        "id": MockLettr::SMTP_CREDENTIAL_ID,
+        "name": "Legacy CRM",
+        "username": "mock-smtp-user",
+        "domain": MockLettr::DOMAIN,
+        "host": "smtp.lettr.com",
+        "port": 587,
+        "last_used_at": null,
+        "created_at": TIMESTAMP,
+    });
+    let inbound_route = json!({
+        "id": MockLettr::INBOUND_ROUTE_ID,
+        "domain": "reply.example.com",
+        "recipient": "support",
+        "forward_url": null,
+        "mx_host": "inbound.lettr.com",
+        "created_at": TIMESTAMP,
+    });
+    let inbound_rule = json!({
+        "id": MockLettr::INBOUND_RULE_ID,
+        "pattern": "reply+*@reply.example.com",
+        "action": "forward",
+        "forward_url": "https://example.com/hooks/replies",
+        "priority": 0,
+        "created_at": TIMESTAMP,
+    });
+    let inbound_message = |data: Option<&str>| {
+        json!({
+            "id": MockLettr::INBOUND_MESSAGE_ID,
+            "route_id": MockLettr::INBOUND_ROUTE_ID,
+            "from": "jane@example.org",
+            "to": ["support@reply.example.com"],
+            "cc": [],
+            "subject": "Re: Welcome!",
+            "headers": [
+                { "name": "Message-ID", "value": "<mock-reply@example.org>" },
+                { "name": "In-Reply-To", "value": "<mock-request-id-message@example.com>" },
+            ],
+            "text": "Thanks!",
+            "html": "<p>Thanks!</p>",
+            "attachments": [{
+                "filename": "screenshot.png",
+                "content_type": "image/png",
+                "size": 4,
+                "content_id": null,
+                "data": data,
+            }],
+            "received_at": TIMESTAMP,
+        })
+    };
+    let ip_pool = json!({
+        "id": MockLettr::IP_POOL_ID,
+        "name": "Marketing",
+        "is_default": false,
+        "ips": [
+            {
+                "ip": "192.0.2.10",
+                "hostname": "mta1.lettr.com",
+                "warmup_status": "warm",
+                "warmup_progress": 100,
+                "daily_limit": null,
+            },
+            {
+                "ip": "192.0.2.11",
+                "hostname": "mta2.lettr.com",
+                "warmup_status": "warming",
+                "warmup_progress": 40,
+                "daily_limit": 20000,
+            },
+        ],
+        "domains": [MockLettr::DOMAIN],
+        "created_at": TIMESTAMP,
+    });
+    let invitation = json!({
+        "id": MockLettr::INVITATION_ID,
+        "email": "dev@example.com",
+        "role": "developer",
+        "expires_at": TIMESTAMP,
+        "created_at": TIMESTAMP,
+    });
+    let export = |status: &str| {
+        let completed = status == "completed";
+        json!({
+            "id": MockLettr::EXPORT_ID,
+            "status": status,
+            "format": "csv",
+            "from": "2024-01-01",
+            "to": "2024-01-31",
+            "event_count": completed.then_some(1),
+            "size": completed.then_some(EXPORT_FILE.len()),
+            "error": null,
+            "expires_at": completed.then_some(TIMESTAMP),
+            "created_at": TIMESTAMP,
+            "completed_at": completed.then_some(TIMESTAMP),
+        })
+    };
+    let bounce = json!({
+        "event_id": "mock-bounce-event-id",
+        "type": "bounce",
+        "timestamp": TIMESTAMP,
+        "request_id": MockLettr::REQUEST_ID,
+        "message_id": "mock-message-id",
+        "subject": "Hello!",
+        "sending_domain": MockLettr::DOMAIN,
+        "rcpt_to": "user@example.com",
+        "recipient_domain": "example.com",
+        "bounce_class": 10,
+        "reason": "550 5.1.1 User unknown",
+        "raw_reason": "550 5.1.1 <user@example.com>: Recipient address rejected: User unknown",
+        "error_code": "550",
+    });
+    let link_clicks = json!({
+        "url": "https://example.com/welcome",
+        "unique_clicks": 1,
+        "total_clicks": 2,
+    });
+    let webhook_secret = json!({
+        "webhook_id": MockLettr::WEBHOOK_ID,
+        "secret": "mock-webhook-secret",
+        "created_at": TIMESTAMP,
+    });
+    let erasure = |status: &str| {
+        json!({
+            "id": MockLettr::ERASURE_ID,
+            "status": status,
+            "email": "user@example.com",
+            "created_at": TIMESTAMP,
+            "completed_at": (status == "completed").then_some(TIMESTAMP),
+        })
+    };
+    let blocklist_status = json!({
+        "target": "192.0.2.10",
+        "target_type": "ip",
+        "listings": [{
+            "blocklist": "Spamhaus ZEN",
+            "listed_since": TIMESTAMP,
+            "delist_url": "https://check.spamhaus.org/",
+        }],
+        "checked_at": TIMESTAMP,
+    });
+    let asset = json!({
+        "id": MockLettr::ASSET_ID,
+        "filename": "logo.png",
+        "content_type": "image/png",
+        "size": 2048,
+        "url": "https://assets.lettr.com/mock-asset-id/logo.png",
+        "created_at": TIMESTAMP,
+    });
+    let unsubscribe_group = json!({
+        "id": MockLettr::UNSUBSCRIBE_GROUP_ID,
+        "name": "Product tips",
+        "description": "Occasional tips on getting more out of the app.",
+        "unsubscribe_count": 0,
+        "created_at": TIMESTAMP,
+    });
+    let event = |event_type: Option<&str>| {
+        let mut event = json!({
+            "event_id": "mock-event-id",
+            "timestamp": TIMESTAMP,
+            "request_id": MockLettr::REQUEST_ID,
+            "message_id": "mock-message-id",
+            "subject": "Hello!",
+            "friendly_from": "sender@example.com",
+            "sending_domain": MockLettr::DOMAIN,
+            "rcpt_to": "user@example.com",
+            "raw_rcpt_to": "user@example.com",
+            "recipient_domain": "example.com",
+            "click_tracking": true,
+            "open_tracking": true,
+            "transactional": true,
+            "msg_size": 1024,
+            "injection_time": TIMESTAMP,
+        });
+        if let Some(event_type) = event_type {
+            event["type"] = json!(event_type);
+        }
+        event
+    };
+
+    vec![
+        (
+            "POST",
+            "^/emails$",
+            json!({
+                "message": "Email queued for delivery.",
+                "data": {
+                    "request_id": MockLettr::REQUEST_ID,
+                    "accepted": 1,
+                    "rejected": 0,
+                    "message_id": "<mock-request-id-message@example.com>",
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/emails$",
+            json!({
+                "message": "Emails retrieved successfully.",
+                "data": {
+                    "results": [event(None)],
+                    "total_count": 1,
+                    "pagination": { "next_cursor": null, "per_page": 25 },
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/emails/[^/]+/clicks$",
+            json!({
+                "message": "Link clicks retrieved successfully.",
+                "data": { "links": [link_clicks.clone()] },
+            }),
+        ),
+        // Before the pattern of single emails, which would match it too.
+        (
+            "GET",
+            "^/emails/bounces$",
+            json!({
+                "message": "Bounces retrieved successfully.",
+                "data": {
+                    "results": [bounce],
+                    "pagination": { "next_cursor": null, "per_page": 25 },
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/emails/[^/]+$",
+            json!({
+                "message": "Email retrieved successfully.",
+                "data": { "results": [event(Some("delivery"))], "total_count": 1 },
+            }),
+        ),
+        (
+            "POST",
+            "^/emails/erasures$",
+            json!({
+                "message": "Recipient data erasure requested successfully.",
+                "data": erasure("pending"),
+            }),
+        ),
+        (
+            "GET",
+            "^/emails/erasures/[^/]+$",
+            json!({
+                "message": "Recipient data erasure retrieved successfully.",
+                "data": erasure("completed"),
+            }),
+        ),
+        (
+            "GET",
+            "^/events/stream$",
+            json!({
+                "message": "Events retrieved successfully.",
+                "data": {
+                    "events": [event(Some("delivery"))],
+                    "next_cursor": "mock-cursor",
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/domains$",
+            json!({
+                "message": "Domains retrieved successfully.",
+                "data": { "domains": [domain.clone()] },
+            }),
+        ),
+        (
+            "POST",
+            "^/domains$",
+            json!({
+                "message": "Domain created successfully.",
+                "data": {
+                    "domain": MockLettr::DOMAIN,
+                    "status": "pending",
+                    "status_label": "Pending",
+                    "dkim": {
+                        "public": "MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQC",
+                        "selector": "lettr",
+                        "headers": "from:to:subject:date",
+                    },
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/domains/[^/]+$",
+            json!({
+                "message": "Domain retrieved successfully.",
+                "data": {
+                    "domain": MockLettr::DOMAIN,
+                    "status": "approved",
+                    "status_label": "Approved",
+                    "can_send": true,
+                    "cname_status": "valid",
+                    "dkim_status": "valid",
+                    "tracking_domain": null,
+                    "dns": {
+                        "dkim": {
+                            "selector": "lettr",
+                            "public": "MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQC",
+                        },
+                    },
+                    "created_at": TIMESTAMP,
+                    "updated_at": TIMESTAMP,
+                },
+            }),
+        ),
+        (
+            "DELETE",
+            "^/domains/[^/]+$",
+            json!({ "message": "Domain deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/webhooks$",
+            json!({
+                "message": "Webhooks retrieved successfully.",
+                "data": { "webhooks": [webhook.clone()] },
+            }),
+        ),
+        (
+            "GET",
+            "^/webhooks/[^/]+$",
+            json!({ "message": "Webhook retrieved successfully.", "data": webhook }),
+        ),
+        (
+            "GET",
+            "^/webhooks/[^/]+/secret$",
+            json!({
+                "message": "Webhook secret retrieved successfully.",
+                "data": webhook_secret.clone(),
+            }),
+        ),
+        (
+            "POST",
+            "^/webhooks/[^/]+/secret/rotate$",
+            json!({
+                "message": "Webhook secret rotated successfully.",
+                "data": webhook_secret,
+            }),
+        ),
+        (
+            "POST",
+            "^/webhooks/validate$",
+            json!({
+                "message": "Webhook URL validated successfully.",
+                "data": {
+                    "url": "https://example.com/webhooks/lettr",
+                    "reachable": true,
+                    "tls_valid": true,
+                    "status_code": 200,
+                    "latency_ms": 42,
+                    "error": null,
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/templates$",
+            json!({
+                "message": "Templates retrieved successfully.",
+                "data": {
+                    "templates": [{
+                        "id": MockLettr::TEMPLATE_ID,
+                        "name": "Welcome",
+                        "slug": "welcome",
+                        "project_id": 1,
+                        "folder_id": null,
+                        "created_at": TIMESTAMP,
+                        "updated_at": TIMESTAMP,
+                    }],
+                    "pagination": { "total": 1, "per_page": 25, "current_page": 1, "last_page": 1 },
+                },
+            }),
+        ),
+        (
+            "POST",
+            "^/templates$",
+            json!({
+                "message": "Template created successfully.",
+                "data": {
+                    "id": MockLettr::TEMPLATE_ID,
+                    "name": "Welcome",
+                    "slug": "welcome",
+                    "project_id": 1,
+                    "folder_id": null,
+                    "active_version": 1,
+                    "merge_tags": [{ "key": "first_name", "required": true }],
+                    "created_at": TIMESTAMP,
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/templates/[^/]+/clicks$",
+            json!({
+                "message": "Link clicks retrieved successfully.",
+                "data": { "links": [link_clicks] },
+            }),
+        ),
+        (
+            "GET",
+            "^/contacts$",
+            json!({
+                "message": "Contacts retrieved successfully.",
+                "data": {
+                    "contacts": [contact.clone()],
+                    "pagination": { "total": 1, "per_page": 25, "current_page": 1, "last_page": 1 },
+                },
+            }),
+        ),
+        (
+            "POST",
+            "^/contacts$",
+            json!({ "message": "Contact created successfully.", "data": contact.clone() }),
+        ),
+        (
+            "GET",
+            "^/contacts/[^/]+$",
+            json!({ "message": "Contact retrieved successfully.", "data": contact.clone() }),
+        ),
+        (
+            "PATCH",
+            "^/contacts/[^/]+$",
+            json!({ "message": "Contact updated successfully.", "data": contact.clone() }),
+        ),
+        (
+            "DELETE",
+            "^/contacts/[^/]+$",
+            json!({ "message": "Contact deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/audiences$",
+            json!({
+                "message": "Audiences retrieved successfully.",
+                "data": { "audiences": [audience.clone()] },
+            }),
+        ),
+        (
+            "POST",
+            "^/audiences$",
+            json!({ "message": "Audience created successfully.", "data": audience.clone() }),
+        ),
+        (
+            "GET",
+            "^/audiences/[^/]+$",
+            json!({ "message": "Audience retrieved successfully.", "data": audience }),
+        ),
+        (
+            "DELETE",
+            "^/audiences/[^/]+$",
+            json!({ "message": "Audience deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/audiences/[^/]+/contacts$",
+            json!({
+                "message": "Audience contacts retrieved successfully.",
+                "data": {
+                    "contacts": [contact],
+                    "pagination": { "total": 1, "per_page": 25, "current_page": 1, "last_page": 1 },
+                },
+            }),
+        ),
+        (
+            "POST",
+            "^/audiences/[^/]+/contacts$",
+            json!({ "message": "Contacts added to audience." }),
+        ),
+        (
+            "DELETE",
+            "^/audiences/[^/]+/contacts$",
+            json!({ "message": "Contacts removed from audience." }),
+        ),
+        (
+            "GET",
+            "^/broadcasts$",
+            json!({
+                "message": "Broadcasts retrieved successfully.",
+                "data": {
+                    "broadcasts": [broadcast.clone()],
+                    "pagination": { "total": 1, "per_page": 25, "current_page": 1, "last_page": 1 },
+                },
+            }),
+        ),
+        (
+            "POST",
+            "^/broadcasts$",
+            json!({ "message": "Broadcast created successfully.", "data": broadcast.clone() }),
+        ),
+        (
+            "GET",
+            "^/broadcasts/[^/]+$",
+            json!({ "message": "Broadcast retrieved successfully.", "data": broadcast.clone() }),
+        ),
+        (
+            "POST",
+            "^/broadcasts/[^/]+/(schedule|send|cancel)$",
+            json!({ "message": "Broadcast updated successfully.", "data": broadcast }),
+        ),
+        (
+            "DELETE",
+            "^/broadcasts/[^/]+$",
+            json!({ "message": "Broadcast deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/broadcasts/[^/]+/stats$",
+            json!({
+                "message": "Broadcast statistics retrieved successfully.",
+                "data": {
+                    "recipients": 1,
+                    "sent": 1,
+                    "delivered": 1,
+                    "opened": 1,
+                    "clicked": 0,
+                    "bounced": 0,
+                    "complained": 0,
+                    "unsubscribed": 0,
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/stats$",
+            json!({
+                "message": "Statistics retrieved successfully.",
+                "data": {
+                    "buckets": [{
+                        "start": TIMESTAMP,
+                        "sent": 1,
+                        "delivered": 1,
+                        "opened": 1,
+                        "clicked": 0,
+                        "bounced": 0,
+                        "complaints": 0,
+                    }],
+                    "totals": {
+                        "sent": 1,
+                        "delivered": 1,
+                        "opened": 1,
+                        "clicked": 0,
+                        "bounced": 0,
+                        "complaints": 0,
+                    },
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/smtp/credentials$",
+            json!({
+                "message": "SMTP credentials retrieved successfully.",
+                "data": { "credentials": [smtp_credential.clone()] },
+            }),
+        ),
+        (
+            "POST",
+            "^/smtp/credentials$",
+            json!({
+                "message": "SMTP credential created successfully.",
+                "data": {
+                    "id": MockLettr::SMTP_CREDENTIAL_ID,
+                    "name": "Legacy CRM",
+                    "username": "mock-smtp-user",
+                    "domain": MockLettr::DOMAIN,
+                    "host": "smtp.lettr.com",
+                    "port": 587,
+                    "last_used_at": null,
+                    "created_at": TIMESTAMP,
+                    "password": "mock-smtp-password",
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/smtp/credentials/[^/]+$",
+            json!({
+                "message": "SMTP credential retrieved successfully.",
+                "data": smtp_credential,
+            }),
+        ),
+        (
+            "DELETE",
+            "^/smtp/credentials/[^/]+$",
+            json!({ "message": "SMTP credential revoked successfully." }),
+        ),
+        (
+            "GET",
+            "^/inbound/routes$",
+            json!({
+                "message": "Inbound routes retrieved successfully.",
+                "data": { "routes": [inbound_route.clone()] },
+            }),
+        ),
+        (
+            "POST",
+            "^/inbound/routes$",
+            json!({
+                "message": "Inbound route created successfully.",
+                "data": inbound_route,
+            }),
+        ),
+        (
+            "DELETE",
+            "^/inbound/routes/[^/]+$",
+            json!({ "message": "Inbound route deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/inbound/rules$",
+            json!({
+                "message": "Inbound rules retrieved successfully.",
+                "data": { "rules": [inbound_rule.clone()] },
+            }),
+        ),
+        (
+            "POST",
+            "^/inbound/rules$",
+            json!({
+                "message": "Inbound rule created successfully.",
+                "data": inbound_rule,
+            }),
+        ),
+        (
+            "DELETE",
+            "^/inbound/rules/[^/]+$",
+            json!({ "message": "Inbound rule deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/inbound/messages$",
+            json!({
+                "message": "Inbound messages retrieved successfully.",
+                "data": {
+                    "messages": [inbound_message(None)],
+                    "pagination": { "next_cursor": null, "per_page": 25 },
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/inbound/messages/[^/]+$",
+            json!({
+                "message": "Inbound message retrieved successfully.",
+                "data": inbound_message(Some("iVBORw==")),
+            }),
+        ),
+        (
+            "DELETE",
+            "^/inbound/messages/[^/]+$",
+            json!({ "message": "Inbound message deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/ip-pools$",
+            json!({
+                "message": "IP pools retrieved successfully.",
+                "data": { "pools": [ip_pool.clone()] },
+            }),
+        ),
+        (
+            "GET",
+            "^/ip-pools/[^/]+$",
+            json!({
+                "message": "IP pool retrieved successfully.",
+                "data": ip_pool.clone(),
+            }),
+        ),
+        (
+            "POST",
+            "^/ip-pools/[^/]+/domains$",
+            json!({
+                "message": "Domain assigned successfully.",
+                "data": ip_pool,
+            }),
+        ),
+        (
+            "GET",
+            "^/team/members$",
+            json!({
+                "message": "Team members retrieved successfully.",
+                "data": {
+                    "members": [{
+                        "id": MockLettr::TEAM_MEMBER_ID,
+                        "name": "Jane Doe",
+                        "email": "jane@example.com",
+                        "role": "owner",
+                        "last_login_at": TIMESTAMP,
+                        "created_at": TIMESTAMP,
+                    }],
+                },
+            }),
+        ),
+        (
+            "DELETE",
+            "^/team/members/[^/]+$",
+            json!({ "message": "Team member removed successfully." }),
+        ),
+        (
+            "GET",
+            "^/team/roles$",
+            json!({
+                "message": "Roles retrieved successfully.",
+                "data": {
+                    "roles": [
+                        { "name": "owner", "description": "Full access." },
+                        { "name": "developer", "description": "Sending and API access." },
+                    ],
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/team/invitations$",
+            json!({
+                "message": "Invitations retrieved successfully.",
+                "data": { "invitations": [invitation.clone()] },
+            }),
+        ),
+        (
+            "POST",
+            "^/team/invitations$",
+            json!({
+                "message": "Invitation sent successfully.",
+                "data": invitation,
+            }),
+        ),
+        (
+            "DELETE",
+            "^/team/invitations/[^/]+$",
+            json!({ "message": "Invitation revoked successfully." }),
+        ),
+        (
+            "POST",
+            "^/exports$",
+            json!({
+                "message": "Export requested successfully.",
+                "data": export("pending"),
+            }),
+        ),
+        (
+            "GET",
+            "^/exports/[^/]+$",
+            json!({
+                "message": "Export retrieved successfully.",
+                "data": export("completed"),
+            }),
+        ),
+        (
+            "GET",
+            "^/unsubscribe-groups$",
+            json!({
+                "message": "Unsubscribe groups retrieved successfully.",
+                "data": { "groups": [unsubscribe_group.clone()] },
+            }),
+        ),
+        (
+            "POST",
+            "^/unsubscribe-groups$",
+            json!({
+                "message": "Unsubscribe group created successfully.",
+                "data": unsubscribe_group.clone(),
+            }),
+        ),
+        // Mounted before the group lookup, which would also match it.
+        (
+            "GET",
+            "^/unsubscribe-groups/preferences$",
+            json!({
+                "message": "Preferences retrieved successfully.",
+                "data": {
+                    "email": "recipient@example.com",
+                    "unsubscribed_all": false,
+                    "groups": [{
+                        "group_id": MockLettr::UNSUBSCRIBE_GROUP_ID,
+                        "name": "Product tips",
+                        "subscribed": true,
+                    }],
+                },
+            }),
+        ),
+        (
+            "GET",
+            "^/unsubscribe-groups/[^/]+$",
+            json!({
+                "message": "Unsubscribe group retrieved successfully.",
+                "data": unsubscribe_group,
+            }),
+        ),
+        (
+            "DELETE",
+            "^/unsubscribe-groups/[^/]+$",
+            json!({ "message": "Unsubscribe group deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/blocklists$",
+            json!({
+                "message": "Blocklist statuses retrieved successfully.",
+                "data": { "statuses": [blocklist_status.clone()] },
+            }),
+        ),
+        (
+            "GET",
+            "^/blocklists/[^/]+$",
+            json!({
+                "message": "Blocklist status retrieved successfully.",
+                "data": blocklist_status,
+            }),
+        ),
+        (
+            "POST",
+            "^/assets$",
+            json!({
+                "message": "Asset uploaded successfully.",
+                "data": asset.clone(),
+            }),
+        ),
+        (
+            "GET",
+            "^/assets$",
+            json!({
+                "message": "Assets retrieved successfully.",
+                "data": { "assets": [asset.clone()] },
+            }),
+        ),
+        (
+            "GET",
+            "^/assets/[^/]+$",
+            json!({
+                "message": "Asset retrieved successfully.",
+                "data": asset,
+            }),
+        ),
+        (
+            "DELETE",
+            "^/assets/[^/]+$",
+            json!({ "message": "Asset deleted successfully." }),
+        ),
+        (
+            "GET",
+            "^/health$",
+            json!({
+                "message": "OK",
+                "data": { "status": "ok", "timestamp": TIMESTAMP },
+            }),
+        ),
+        (
+            "GET",
+            "^/auth/check$",
+            json!({
+                "message": "API key is valid.",
+                "data": { "team_id": 1, "timestamp": TIMESTAMP },
+            }),
+        ),
+    ]
+}
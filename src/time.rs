@@ -0,0 +1,46 @@
+//! Time primitives that work on every supported target.
+//!
+//! `std::time::Instant` and `SystemTime::now` panic on
+//! `wasm32-unknown-unknown`, and there is no tokio timer in the browser, so
+//! the WASM build uses `web-time` and `gloo-timers` instead.
+
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::{Instant, SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Returns the current time as a duration since the Unix epoch.
+pub(crate) fn unix_now() -> Option<Duration> {
+    SystemTime::now().duration_since(UNIX_EPOCH).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Runs `future` to completion, or returns `None` if `duration` elapses first.
+pub(crate) async fn timeout<F: std::future::Future>(
+    duration: Duration,
+    future: F,
+) -> Option<F::Output> {
+    use std::future::Future;
+    use std::task::Poll;
+
+    let mut future = std::pin::pin!(future);
+    let mut delay = std::pin::pin!(sleep(duration));
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        delay.as_mut().poll(cx).map(|()| None)
+    })
+    .await
+}
@@ -0,0 +1,111 @@
+//! Custom TLS trust roots and client certificates.
+//!
+//! Needed when a TLS-intercepting proxy or a private gateway sits between the
+//! application and the Lettr API. Add the proxy's CA with
+//! [`LettrBuilder::add_root_certificate`](crate::LettrBuilder::add_root_certificate)
+//! and, if the gateway requires mutual TLS, a client [`Identity`] with
+//! [`LettrBuilder::identity`](crate::LettrBuilder::identity).
+//!
+//! Only available with the `rustls` or `native-tls` feature.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use lettr::Lettr;
+//! use lettr::tls::{Certificate, Identity};
+//!
+//! # fn run() -> lettr::Result<()> {
+//! let ca = Certificate::from_pem(&std::fs::read("proxy-ca.pem").unwrap())?;
+//! let identity = Identity::from_pem(
+//!     &std::fs::read("client.crt").unwrap(),
+//!     &std::fs::read("client.key").unwrap(),
+//! )?;
+//!
+//! let client = Lettr::builder("your-api-key")
+//!     .add_root_certificate(ca)
+//!     .identity(identity)
+//!     .try_build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::transport::HttpClientBuilder;
+
+/// A trusted root certificate.
+#[derive(Debug, Clone)]
+pub struct Certificate(reqwest::Certificate);
+
+impl Certificate {
+    /// Parses a PEM-encoded certificate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the certificate is
+    /// invalid.
+    pub fn from_pem(pem: &[u8]) -> crate::Result<Self> {
+        reqwest::Certificate::from_pem(pem)
+            .map(Self)
+            .map_err(|e| crate::Error::Config(format!("invalid certificate: {e}")))
+    }
+
+    /// Parses a DER-encoded certificate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the certificate is
+    /// invalid.
+    pub fn from_der(der: &[u8]) -> crate::Result<Self> {
+        reqwest::Certificate::from_der(der)
+            .map(Self)
+            .map_err(|e| crate::Error::Config(format!("invalid certificate: {e}")))
+    }
+}
+
+/// A client certificate and private key for mutual TLS.
+#[derive(Debug, Clone)]
+pub struct Identity(reqwest::Identity);
+
+impl Identity {
+    /// Creates an identity from a PEM-encoded certificate chain and a
+    /// PEM-encoded PKCS #8 private key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`](crate::Error::Config) if the certificate or
+    /// key is invalid.
+    pub fn from_pem(cert: &[u8], key: &[u8]) -> crate::Result<Self> {
+        #[cfg(feature = "native-tls")]
+        let identity = reqwest::Identity::from_pkcs8_pem(cert, key);
+        #[cfg(not(feature = "native-tls"))]
+        let identity = reqwest::Identity::from_pem(&[cert, b"\n", key].concat());
+
+        identity
+            .map(Self)
+            .map_err(|e| crate::Error::Config(format!("invalid client identity: {e}")))
+    }
+}
+
+/// TLS settings collected by the [`LettrBuilder`](crate::LettrBuilder).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TlsConfig {
+    pub root_certificates: Vec<Certificate>,
+    pub identity: Option<Identity>,
+}
+
+impl TlsConfig {
+    /// Returns `true` if no custom TLS settings were made.
+    pub fn is_empty(&self) -> bool {
+        self.root_certificates.is_empty() && self.identity.is_none()
+    }
+
+    /// Applies the settings to an HTTP client builder.
+    pub fn apply(self, mut builder: HttpClientBuilder) -> HttpClientBuilder {
+        for certificate in self.root_certificates {
+            builder = builder.add_root_certificate(certificate.0);
+        }
+        if let Some(identity) = self.identity {
+            builder = builder.identity(identity.0);
+        }
+        builder
+    }
+}
@@ -0,0 +1,121 @@
+//! [`tower::Service`](Service) implementation of the client.
+//!
+//! [`Lettr`] implements `Service<ApiRequest>`, so standard tower layers
+//! (timeouts, retries, concurrency limits, load shedding) can be composed
+//! around Lettr calls. Requests pass through the client's own pipeline
+//! (middleware, caching, retries, rate limiting) before reaching the layers'
+//! view of the response.
+//!
+//! Only available with the `tower` feature and the async client.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use lettr::Lettr;
+//! use lettr::tower::{ApiRequest, Service};
+//!
+//! # async fn run() -> lettr::Result<()> {
+//! let mut service = Lettr::new("your-api-key");
+//!
+//! std::future::poll_fn(|cx| service.poll_ready(cx)).await?;
+//! let response = service.call(ApiRequest::get("/domains")).await?;
+//! println!("{}: {} bytes", response.status, response.body.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use reqwest::Method;
+
+pub use tower_service::Service;
+
+use crate::transport::Response;
+use crate::Lettr;
+
+/// A request to an arbitrary Lettr API endpoint.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct ApiRequest {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Option<serde_json::Value>,
+}
+
+impl ApiRequest {
+    /// Creates a request for `path`, relative to the API base URL
+    /// (e.g. `"/emails"`).
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            query: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Creates a `GET` request for `path`.
+    pub fn get(path: impl Into<String>) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    /// Creates a `POST` request for `path` with a JSON body.
+    pub fn post(path: impl Into<String>, body: serde_json::Value) -> Self {
+        Self::new(Method::POST, path).json(body)
+    }
+
+    /// Creates a `DELETE` request for `path`.
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, path)
+    }
+
+    /// Appends a query parameter.
+    pub fn query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the JSON request body.
+    pub fn json(mut self, body: serde_json::Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+}
+
+/// Future returned by [`Lettr`]'s [`Service::call`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type ResponseFuture = Pin<Box<dyn Future<Output = crate::Result<Response>> + Send>>;
+/// Future returned by [`Lettr`]'s [`Service::call`].
+#[cfg(target_arch = "wasm32")]
+pub type ResponseFuture = Pin<Box<dyn Future<Output = crate::Result<Response>>>>;
+
+impl Service<ApiRequest> for Lettr {
+    type Response = Response;
+    type Error = crate::Error;
+    type Future = ResponseFuture;
+
+    /// The client is always ready; backpressure comes from its own rate
+    /// limiter, or from tower layers.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<crate::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: ApiRequest) -> Self::Future {
+        let config = Arc::clone(&self.config);
+
+        Box::pin(async move {
+            let mut builder = config.build(request.method, &request.path);
+            if !request.query.is_empty() {
+                builder = builder.query(&request.query);
+            }
+            if let Some(ref body) = request.body {
+                builder = builder.json(body);
+            }
+            config.send(builder).await
+        })
+    }
+}
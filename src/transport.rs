@@ -0,0 +1,341 @@
+//! The HTTP layer underneath the client.
+//!
+//! Every request is handed to an [`HttpTransport`] as a fully built
+//! [`Request`] and comes back as a fully buffered [`Response`]. The default
+//! transport is [`ReqwestTransport`]; plug in another one with
+//! [`LettrBuilder::transport`](crate::LettrBuilder::transport) to use a
+//! different HTTP stack or to serve canned responses in tests.
+
+use std::fmt;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use serde::Serialize;
+
+pub use bytes::Bytes;
+pub use reqwest::header::HeaderMap;
+pub use reqwest::{Method, StatusCode, Url};
+
+/// Attribute for implementing [`HttpTransport`].
+pub use async_trait::async_trait;
+
+pub(crate) use reqwest::{Client as HttpClient, ClientBuilder as HttpClientBuilder};
+
+/// An HTTP request ready to be sent.
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// Request method.
+    pub method: Method,
+    /// Absolute request URL, including the query string.
+    pub url: Url,
+    /// Request headers, including authentication.
+    pub headers: HeaderMap,
+    /// Request body, if any.
+    pub body: Option<Bytes>,
+}
+
+/// A buffered HTTP response.
+#[derive(Debug, Clone)]
+pub struct Response {
+    /// Response status code.
+    pub status: StatusCode,
+    /// Response headers.
+    pub headers: HeaderMap,
+    /// Response body.
+    pub body: Bytes,
+}
+
+impl Response {
+    /// Creates a new [`Response`].
+    pub fn new(status: StatusCode, headers: HeaderMap, body: impl Into<Bytes>) -> Self {
+        Self {
+            status,
+            headers,
+            body: body.into(),
+        }
+    }
+}
+
+/// Sends HTTP requests on behalf of a client.
+///
+/// Implementations only move bytes: authentication, retries, rate limiting,
+/// and error decoding all happen before and after the transport is called.
+/// Failures to reach the server are reported as [`Error::Transport`]
+/// (or [`Error::Http`] by [`ReqwestTransport`]).
+///
+/// # Example
+///
+/// ```
+/// use lettr::transport::{async_trait, HttpTransport, Request, Response, StatusCode};
+/// use lettr::Lettr;
+///
+/// /// Answers every request with the same canned response.
+/// struct Canned(&'static str);
+///
+/// #[async_trait]
+/// impl HttpTransport for Canned {
+///     async fn execute(&self, _request: Request) -> lettr::Result<Response> {
+///         Ok(Response::new(StatusCode::OK, Default::default(), self.0))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> lettr::Result<()> {
+/// let client = Lettr::builder("your-api-key")
+///     .transport(Canned(r#"{"message":"OK","data":{"status":"ok","timestamp":"now"}}"#))
+///     .build();
+///
+/// let health = client.health().await?;
+/// assert_eq!(health.data.status, "ok");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Error::Transport`]: crate::Error::Transport
+/// [`Error::Http`]: crate::Error::Http
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait HttpTransport: Send + Sync {
+    /// Send a request and buffer its response.
+    async fn execute(&self, request: Request) -> crate::Result<Response>;
+}
+
+impl fmt::Debug for dyn HttpTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HttpTransport")
+    }
+}
+
+/// The default [`HttpTransport`], backed by a `reqwest` client.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: HttpClient,
+}
+
+impl ReqwestTransport {
+    /// Creates a new [`ReqwestTransport`] sending requests through `client`.
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl HttpTransport for ReqwestTransport {
+    async fn execute(&self, request: Request) -> crate::Result<Response> {
+        let mut builder = self
+            .client
+            .request(request.method, request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        Ok(Response {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Settings for the reqwest client built when no transport is given.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct HttpOptions {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub connect_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub tcp_keepalive: Option<Duration>,
+    #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+    pub http2_prior_knowledge: bool,
+    #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+    pub http2_adaptive_window: Option<bool>,
+    #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+    pub http2_keep_alive_interval: Option<Duration>,
+    #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+    pub http2_keep_alive_timeout: Option<Duration>,
+}
+
+impl HttpOptions {
+    /// Returns `true` if any setting differs from reqwest's defaults.
+    pub fn is_customized(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// Applies the settings to an HTTP client builder.
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+    pub fn apply(&self, mut builder: HttpClientBuilder) -> HttpClientBuilder {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(timeout) = self.timeout {
+                builder = builder.timeout(timeout);
+            }
+            if let Some(timeout) = self.connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+            if let Some(interval) = self.tcp_keepalive {
+                builder = builder.tcp_keepalive(interval);
+            }
+        }
+        #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+        {
+            if self.http2_prior_knowledge {
+                builder = builder.http2_prior_knowledge();
+            }
+            if let Some(enabled) = self.http2_adaptive_window {
+                builder = builder.http2_adaptive_window(enabled);
+            }
+        }
+        #[cfg(all(feature = "http2", not(target_arch = "wasm32")))]
+        {
+            if let Some(interval) = self.http2_keep_alive_interval {
+                builder = builder
+                    .http2_keep_alive_interval(interval)
+                    .http2_keep_alive_while_idle(true);
+            }
+            if let Some(timeout) = self.http2_keep_alive_timeout {
+                builder = builder.http2_keep_alive_timeout(timeout);
+            }
+        }
+        builder
+    }
+}
+
+/// Returns `true` if the error means the request never reached the server.
+pub(crate) fn is_connect(err: &crate::Error) -> bool {
+    match err {
+        #[cfg(not(target_arch = "wasm32"))]
+        crate::Error::Http(err) => err.is_connect(),
+        // The browser's fetch API does not tell connection failures apart.
+        _ => false,
+    }
+}
+
+/// Builder for a [`Request`], used by the service methods.
+///
+/// Errors are deferred until the request is sent, so call sites can chain
+/// methods without handling a `Result` at every step.
+#[derive(Debug)]
+pub(crate) struct RequestBuilder {
+    request: crate::Result<Request>,
+    method: Method,
+    url: String,
+    cacheable: bool,
+    route: Option<&'static str>,
+}
+
+impl RequestBuilder {
+    /// Creates a request for `url` with the given default headers.
+    pub fn new(method: Method, url: &str, headers: HeaderMap) -> Self {
+        let request = Url::parse(url)
+            .map(|parsed| Request {
+                method: method.clone(),
+                url: parsed,
+                headers,
+                body: None,
+            })
+            .map_err(|e| crate::Error::Config(format!("invalid URL `{url}`: {e}")));
+        Self {
+            request,
+            method,
+            url: url.to_owned(),
+            cacheable: true,
+            route: None,
+        }
+    }
+
+    /// Returns the method and the URL the request was created with, before
+    /// query parameters were added. Available even if building failed.
+    pub fn target(&self) -> (&Method, &str) {
+        (&self.method, &self.url)
+    }
+
+    /// Sets the route template of a path with IDs in it, e.g.
+    /// `/emails/{id}`, which labels the request in metrics instead of the
+    /// concrete path.
+    pub fn route(mut self, template: &'static str) -> Self {
+        self.route = Some(template);
+        self
+    }
+
+    /// Returns the route template, if the path has IDs in it.
+    pub fn route_template(&self) -> Option<&'static str> {
+        self.route
+    }
+
+    /// Bypasses the response cache for this request.
+    pub fn no_cache(mut self) -> Self {
+        self.cacheable = false;
+        self
+    }
+
+    /// Returns `false` if the request must not be served from or stored in
+    /// the response cache.
+    pub fn is_cacheable(&self) -> bool {
+        self.cacheable
+    }
+
+    /// Appends query parameters to the URL.
+    pub fn query<K, V>(mut self, pairs: &[(K, V)]) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        if let Ok(ref mut request) = self.request {
+            let mut query = request.url.query_pairs_mut();
+            for (key, value) in pairs {
+                query.append_pair(key.as_ref(), value.as_ref());
+            }
+        }
+        self
+    }
+
+    /// Sets the body to `body` serialized as JSON.
+    ///
+    /// The body is serialized here, once; retries share the resulting
+    /// buffer.
+    pub fn json<T: Serialize + ?Sized>(mut self, body: &T) -> Self {
+        if let Ok(ref mut request) = self.request {
+            match serde_json::to_vec(body) {
+                Ok(bytes) => request.body = Some(bytes.into()),
+                Err(e) => self.request = Err(crate::Error::Serialize(e)),
+            }
+        }
+        self
+    }
+
+    /// Sets the body to raw `bytes` of the given content type.
+    pub fn bytes(mut self, content_type: &str, bytes: Bytes) -> Self {
+        if let Ok(ref mut request) = self.request {
+            match HeaderValue::from_str(content_type) {
+                Ok(value) => {
+                    request.headers.insert(CONTENT_TYPE, value);
+                    request.body = Some(bytes);
+                }
+                Err(e) => {
+                    self.request = Err(crate::Error::Config(format!(
+                        "invalid content type `{content_type}`: {e}"
+                    )));
+                }
+            }
+        }
+        self
+    }
+
+    /// Finishes building the request.
+    pub fn build(self) -> crate::Result<Request> {
+        self.request
+    }
+}
+
+/// Shared handle to a transport.
+pub(crate) type SharedTransport = Arc<dyn HttpTransport>;
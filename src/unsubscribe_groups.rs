@@ -0,0 +1,443 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::meta::WithMeta;
+
+/// Service for the `/unsubscribe-groups` endpoints.
+///
+/// Unsubscribe groups let recipients opt out of one kind of mail, such as
+/// product tips, while still receiving the rest. Assign an email to a group
+/// with [`CreateEmailOptions::with_unsubscribe_group`](crate::CreateEmailOptions::with_unsubscribe_group).
+#[derive(Clone, Debug)]
+pub struct UnsubscribeGroupsSvc(pub(crate) Arc<Config>);
+
+impl UnsubscribeGroupsSvc {
+    /// List all unsubscribe groups.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let groups = client.unsubscribe_groups.list().await?;
+    /// for group in &groups {
+    ///     println!("{group}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<Vec<UnsubscribeGroup>> {
+        self.list_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<UnsubscribeGroup>>> {
+        let request = self.0.build(Method::GET, "/unsubscribe-groups");
+        let response: WithMeta<ListUnsubscribeGroupsResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.groups))
+    }
+
+    /// Create an unsubscribe group.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # use lettr::unsubscribe_groups::CreateUnsubscribeGroupOptions;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let options = CreateUnsubscribeGroupOptions::new("Product tips")
+    ///     .with_description("Occasional tips on getting more out of the app.");
+    /// let group = client.unsubscribe_groups.create(options).await?;
+    /// println!("Created group {}", group.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        options: CreateUnsubscribeGroupOptions,
+    ) -> crate::Result<UnsubscribeGroup> {
+        self.create_with_meta(options)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`create`](Self::create), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn create_with_meta(
+        &self,
+        options: CreateUnsubscribeGroupOptions,
+    ) -> crate::Result<WithMeta<UnsubscribeGroup>> {
+        let request = self
+            .0
+            .build(Method::POST, "/unsubscribe-groups")
+            .json(&options);
+        let response: WithMeta<UnsubscribeGroupResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve a single unsubscribe group.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let group = client.unsubscribe_groups.get("group-id").await?;
+    /// println!("{} recipients opted out", group.unsubscribe_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, group_id: &str) -> crate::Result<UnsubscribeGroup> {
+        self.get_with_meta(group_id).await.map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, group_id: &str) -> crate::Result<WithMeta<UnsubscribeGroup>> {
+        let path = format!("/unsubscribe-groups/{group_id}");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/unsubscribe-groups/{id}");
+        let response: WithMeta<UnsubscribeGroupResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Delete an unsubscribe group. Emails still assigned to it are sent
+    /// as if they had no group.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// client.unsubscribe_groups.delete("group-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, group_id: &str) -> crate::Result<()> {
+        self.delete_with_meta(group_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`delete`](Self::delete), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn delete_with_meta(&self, group_id: &str) -> crate::Result<WithMeta<()>> {
+        let path = format!("/unsubscribe-groups/{group_id}");
+        let request = self
+            .0
+            .build(Method::DELETE, &path)
+            .route("/unsubscribe-groups/{id}");
+        let response = self.0.send(request).await?;
+        Ok(WithMeta::new((), response))
+    }
+
+    /// Retrieve which groups a recipient is subscribed to.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let preferences = client
+    ///     .unsubscribe_groups
+    ///     .preferences("user@example.com")
+    ///     .await?;
+    /// if preferences.is_subscribed("group-id") {
+    ///     println!("Still wants product tips");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preferences(&self, email: &str) -> crate::Result<RecipientPreferences> {
+        self.preferences_with_meta(email)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`preferences`](Self::preferences), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn preferences_with_meta(
+        &self,
+        email: &str,
+    ) -> crate::Result<WithMeta<RecipientPreferences>> {
+        let request = self
+            .0
+            .build(Method::GET, "/unsubscribe-groups/preferences")
+            .query(&[("email", email)]);
+        let response: WithMeta<RecipientPreferencesResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Drops cached `/unsubscribe-groups` responses, so the next call
+    /// fetches fresh data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/unsubscribe-groups");
+    }
+}
+
+/// The `/unsubscribe-groups` operations of [`UnsubscribeGroupsSvc`], as a
+/// trait.
+///
+/// Application code can depend on `dyn UnsubscribeGroupsApi` instead of the
+/// concrete service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait UnsubscribeGroupsApi: Send + Sync {
+    /// See [`UnsubscribeGroupsSvc::list`].
+    async fn list(&self) -> crate::Result<Vec<UnsubscribeGroup>>;
+
+    /// See [`UnsubscribeGroupsSvc::create`].
+    async fn create(
+        &self,
+        options: CreateUnsubscribeGroupOptions,
+    ) -> crate::Result<UnsubscribeGroup>;
+
+    /// See [`UnsubscribeGroupsSvc::get`].
+    async fn get(&self, group_id: &str) -> crate::Result<UnsubscribeGroup>;
+
+    /// See [`UnsubscribeGroupsSvc::delete`].
+    async fn delete(&self, group_id: &str) -> crate::Result<()>;
+
+    /// See [`UnsubscribeGroupsSvc::preferences`].
+    async fn preferences(&self, email: &str) -> crate::Result<RecipientPreferences>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl UnsubscribeGroupsApi for UnsubscribeGroupsSvc {
+    async fn list(&self) -> crate::Result<Vec<UnsubscribeGroup>> {
+        UnsubscribeGroupsSvc::list(self).await
+    }
+
+    async fn create(
+        &self,
+        options: CreateUnsubscribeGroupOptions,
+    ) -> crate::Result<UnsubscribeGroup> {
+        UnsubscribeGroupsSvc::create(self, options).await
+    }
+
+    async fn get(&self, group_id: &str) -> crate::Result<UnsubscribeGroup> {
+        UnsubscribeGroupsSvc::get(self, group_id).await
+    }
+
+    async fn delete(&self, group_id: &str) -> crate::Result<()> {
+        UnsubscribeGroupsSvc::delete(self, group_id).await
+    }
+
+    async fn preferences(&self, email: &str) -> crate::Result<RecipientPreferences> {
+        UnsubscribeGroupsSvc::preferences(self, email).await
+    }
+}
+
+// ── Request Types ──────────────────────────────────────────────────────────
+
+/// Options for creating an unsubscribe group.
+#[must_use]
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateUnsubscribeGroupOptions {
+    /// Group name, shown to recipients on the preferences page.
+    pub(crate) name: String,
+
+    /// Group description, shown to recipients on the preferences page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+}
+
+impl CreateUnsubscribeGroupOptions {
+    /// Creates new [`CreateUnsubscribeGroupOptions`] with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+        }
+    }
+
+    /// Sets the description shown to recipients on the preferences page.
+    #[inline]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+// ── Response Types ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ListUnsubscribeGroupsResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: ListUnsubscribeGroupsData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListUnsubscribeGroupsData {
+    groups: Vec<UnsubscribeGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnsubscribeGroupResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: UnsubscribeGroup,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecipientPreferencesResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: RecipientPreferences,
+}
+
+/// A kind of mail recipients can opt out of separately.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct UnsubscribeGroup {
+    /// Group ID.
+    pub id: String,
+    /// Group name.
+    pub name: String,
+    /// Group description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Number of recipients who unsubscribed from the group.
+    #[serde(default)]
+    pub unsubscribe_count: u64,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UnsubscribeGroup {
+    /// Creates a group with the other fields empty. Set them as needed, e.g.
+    /// for a mock [`UnsubscribeGroupsApi`].
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g. `Product tips (grp_123, 42
+/// unsubscribed)`.
+impl fmt::Display for UnsubscribeGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {} unsubscribed)",
+            self.name, self.id, self.unsubscribe_count
+        )
+    }
+}
+
+/// The unsubscribe preferences of one recipient.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct RecipientPreferences {
+    /// Recipient email address.
+    pub email: String,
+    /// Whether the recipient unsubscribed from all mail sent with a group.
+    #[serde(default)]
+    pub unsubscribed_all: bool,
+    /// The recipient's choice for each group.
+    #[serde(default)]
+    pub groups: Vec<GroupPreference>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl RecipientPreferences {
+    /// Creates preferences with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`UnsubscribeGroupsApi`].
+    pub fn new(email: impl Into<String>, groups: Vec<GroupPreference>) -> Self {
+        Self {
+            email: email.into(),
+            groups,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether emails in the group with ID `group_id` reach the
+    /// recipient. Groups the API did not report count as subscribed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::unsubscribe_groups::{GroupPreference, RecipientPreferences};
+    ///
+    /// let preferences = RecipientPreferences::new(
+    ///     "user@example.com",
+    ///     vec![GroupPreference::new("tips", false)],
+    /// );
+    /// assert!(!preferences.is_subscribed("tips"));
+    /// assert!(preferences.is_subscribed("billing"));
+    /// ```
+    #[must_use]
+    pub fn is_subscribed(&self, group_id: &str) -> bool {
+        !self.unsubscribed_all
+            && self
+                .groups
+                .iter()
+                .find(|group| group.group_id == group_id)
+                .map_or(true, |group| group.subscribed)
+    }
+}
+
+/// A recipient's choice for one unsubscribe group.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct GroupPreference {
+    /// Group ID.
+    pub group_id: String,
+    /// Group name.
+    #[serde(default)]
+    pub name: String,
+    /// Whether the recipient still receives emails in the group.
+    pub subscribed: bool,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl GroupPreference {
+    /// Creates a preference with the other fields empty, e.g. for a mock
+    /// [`UnsubscribeGroupsApi`].
+    pub fn new(group_id: impl Into<String>, subscribed: bool) -> Self {
+        Self {
+            group_id: group_id.into(),
+            subscribed,
+            ..Self::default()
+        }
+    }
+}
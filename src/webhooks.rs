@@ -1,9 +1,11 @@
+use std::fmt;
 use std::sync::Arc;
 
 use reqwest::Method;
 use serde::Deserialize;
 
 use crate::config::Config;
+use crate::meta::WithMeta;
 
 /// Service for the `/webhooks` endpoints.
 #[derive(Clone, Debug)]
@@ -26,12 +28,17 @@ impl WebhooksSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn list(&self) -> crate::Result<Vec<Webhook>> {
+        self.list_with_meta().await.map(WithMeta::into_data)
+    }
+
+    /// Like [`list`](Self::list), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn list_with_meta(&self) -> crate::Result<WithMeta<Vec<Webhook>>> {
         let request = self.0.build(Method::GET, "/webhooks");
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<ListWebhooksResponseWrapper>().await?;
-        Ok(wrapper.data.webhooks)
+        let response: WithMeta<ListWebhooksResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data.webhooks))
     }
 
     /// Retrieve details of a single webhook.
@@ -48,13 +55,202 @@ impl WebhooksSvc {
     /// # Ok(())
     /// # }
     /// ```
-    #[maybe_async::maybe_async]
     pub async fn get(&self, webhook_id: &str) -> crate::Result<Webhook> {
+        self.get_with_meta(webhook_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`get`](Self::get), but also returns the HTTP status, headers,
+    /// and rate-limit information of the response.
+    pub async fn get_with_meta(&self, webhook_id: &str) -> crate::Result<WithMeta<Webhook>> {
         let path = format!("/webhooks/{webhook_id}");
-        let request = self.0.build(Method::GET, &path);
-        let response = self.0.send(request).await?;
-        let wrapper = response.json::<ShowWebhookResponseWrapper>().await?;
-        Ok(wrapper.data)
+        let request = self.0.build(Method::GET, &path).route("/webhooks/{id}");
+        let response: WithMeta<ShowWebhookResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Retrieve the secret Lettr signs the deliveries of a webhook with, so a
+    /// receiver can load it at startup instead of having it copied into its
+    /// configuration by hand.
+    ///
+    /// Never served from the response cache. API keys without permission to
+    /// manage webhooks get an [`ApiError`](crate::types::ApiError) with
+    /// status 403.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let secret = client.webhooks.signing_secret("webhook-abc123").await?;
+    /// // Hand `secret.secret` to the code verifying deliveries.
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret> {
+        self.signing_secret_with_meta(webhook_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`signing_secret`](Self::signing_secret), but also returns the
+    /// HTTP status, headers, and rate-limit information of the response.
+    pub async fn signing_secret_with_meta(
+        &self,
+        webhook_id: &str,
+    ) -> crate::Result<WithMeta<WebhookSecret>> {
+        let path = format!("/webhooks/{webhook_id}/secret");
+        let request = self
+            .0
+            .build(Method::GET, &path)
+            .route("/webhooks/{id}/secret")
+            .no_cache();
+        let response: WithMeta<WebhookSecretResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Replace the signing secret of a webhook and return the new one.
+    ///
+    /// Deliveries are signed with the new secret from then on, so update
+    /// every receiver right away.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let secret = client.webhooks.rotate_signing_secret("webhook-abc123").await?;
+    /// println!("Rotated at {}", secret.created_at);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rotate_signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret> {
+        self.rotate_signing_secret_with_meta(webhook_id)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`rotate_signing_secret`](Self::rotate_signing_secret), but also
+    /// returns the HTTP status, headers, and rate-limit information of the
+    /// response.
+    pub async fn rotate_signing_secret_with_meta(
+        &self,
+        webhook_id: &str,
+    ) -> crate::Result<WithMeta<WebhookSecret>> {
+        let path = format!("/webhooks/{webhook_id}/secret/rotate");
+        let request = self
+            .0
+            .build(Method::POST, &path)
+            .route("/webhooks/{id}/secret/rotate");
+        let response: WithMeta<WebhookSecretResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Ask the API to probe a webhook destination before the webhook is
+    /// created: whether it answers, with which status, how fast, and whether
+    /// its TLS certificate is valid.
+    ///
+    /// Catches misconfigured endpoints at provisioning time instead of when
+    /// deliveries start failing. A destination that fails the probe is not an
+    /// error; check [`WebhookUrlValidation::is_valid`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use lettr::Lettr;
+    /// # async fn run() -> lettr::Result<()> {
+    /// let client = Lettr::new("your-api-key");
+    ///
+    /// let probe = client
+    ///     .webhooks
+    ///     .validate_url("https://example.com/webhooks/lettr")
+    ///     .await?;
+    /// if !probe.is_valid() {
+    ///     eprintln!("Webhook destination is broken: {probe}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_url(&self, url: &str) -> crate::Result<WebhookUrlValidation> {
+        self.validate_url_with_meta(url)
+            .await
+            .map(WithMeta::into_data)
+    }
+
+    /// Like [`validate_url`](Self::validate_url), but also returns the HTTP
+    /// status, headers, and rate-limit information of the response.
+    pub async fn validate_url_with_meta(
+        &self,
+        url: &str,
+    ) -> crate::Result<WithMeta<WebhookUrlValidation>> {
+        let body = serde_json::json!({ "url": url });
+        let request = self.0.build(Method::POST, "/webhooks/validate").json(&body);
+        let response: WithMeta<WebhookUrlValidationResponseWrapper> =
+            self.0.send_json_with_meta(request).await?;
+        Ok(response.map(|wrapper| wrapper.data))
+    }
+
+    /// Drops cached `/webhooks` responses, so the next call fetches fresh data.
+    ///
+    /// Does nothing unless caching was enabled with
+    /// [`LettrBuilder::cache`](crate::LettrBuilder::cache).
+    pub fn invalidate_cache(&self) {
+        self.0.invalidate_cache("/webhooks");
+    }
+}
+
+/// The `/webhooks` operations of [`WebhooksSvc`], as a trait.
+///
+/// Application code can depend on `dyn WebhooksApi` instead of the concrete
+/// service, so unit tests can pass in a mock.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait WebhooksApi: Send + Sync {
+    /// See [`WebhooksSvc::list`].
+    async fn list(&self) -> crate::Result<Vec<Webhook>>;
+
+    /// See [`WebhooksSvc::get`].
+    async fn get(&self, webhook_id: &str) -> crate::Result<Webhook>;
+
+    /// See [`WebhooksSvc::signing_secret`].
+    async fn signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret>;
+
+    /// See [`WebhooksSvc::rotate_signing_secret`].
+    async fn rotate_signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret>;
+
+    /// See [`WebhooksSvc::validate_url`].
+    async fn validate_url(&self, url: &str) -> crate::Result<WebhookUrlValidation>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl WebhooksApi for WebhooksSvc {
+    async fn list(&self) -> crate::Result<Vec<Webhook>> {
+        WebhooksSvc::list(self).await
+    }
+
+    async fn get(&self, webhook_id: &str) -> crate::Result<Webhook> {
+        WebhooksSvc::get(self, webhook_id).await
+    }
+
+    async fn signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret> {
+        WebhooksSvc::signing_secret(self, webhook_id).await
+    }
+
+    async fn rotate_signing_secret(&self, webhook_id: &str) -> crate::Result<WebhookSecret> {
+        WebhooksSvc::rotate_signing_secret(self, webhook_id).await
+    }
+
+    async fn validate_url(&self, url: &str) -> crate::Result<WebhookUrlValidation> {
+        WebhooksSvc::validate_url(self, url).await
     }
 }
 
@@ -80,7 +276,8 @@ struct ShowWebhookResponseWrapper {
 }
 
 /// A configured webhook.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
 pub struct Webhook {
     /// Unique webhook ID.
     pub id: String,
@@ -102,4 +299,170 @@ pub struct Webhook {
     pub last_failure_at: Option<String>,
     /// Last delivery status (e.g. "success", "failure").
     pub last_status: Option<String>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Webhook {
+    /// Creates a webhook with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`WebhooksApi`](crate::services::WebhooksApi).
+    pub fn new(id: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Formats as a one-line summary, e.g.
+/// `Orders (wh_123) -> https://example.com/hook [enabled]`.
+impl fmt::Display for Webhook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.enabled { "enabled" } else { "disabled" };
+        write!(f, "{} ({}) -> {} [{state}]", self.name, self.id, self.url)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WebhookSecretResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: WebhookSecret,
+}
+
+/// The secret a webhook's deliveries are signed with.
+///
+/// The secret never appears in `Debug` output, wire logs, or cassettes.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct WebhookSecret {
+    /// ID of the webhook.
+    pub webhook_id: String,
+    /// The signing secret.
+    pub secret: String,
+    /// When the secret was created or last rotated.
+    pub created_at: String,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl WebhookSecret {
+    /// Creates a secret with the other fields empty. Set them as needed,
+    /// e.g. for a mock [`WebhooksApi`].
+    pub fn new(webhook_id: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            webhook_id: webhook_id.into(),
+            secret: secret.into(),
+            ..Self::default()
+        }
+    }
+}
+
+impl fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookSecret")
+            .field("webhook_id", &self.webhook_id)
+            .field("secret", &"[REDACTED]")
+            .field("created_at", &self.created_at)
+            .field("extra", &self.extra)
+            .finish()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WebhookUrlValidationResponseWrapper {
+    #[allow(dead_code)]
+    message: String,
+    data: WebhookUrlValidation,
+}
+
+/// Result of probing a webhook destination with
+/// [`WebhooksSvc::validate_url`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub struct WebhookUrlValidation {
+    /// The probed URL.
+    pub url: String,
+    /// Whether the destination answered with a success status.
+    pub reachable: bool,
+    /// Whether the destination's TLS certificate is valid; `None` for plain
+    /// HTTP URLs.
+    pub tls_valid: Option<bool>,
+    /// HTTP status the destination answered with, if it answered.
+    pub status_code: Option<u16>,
+    /// Time the destination took to answer, in milliseconds.
+    pub latency_ms: Option<u64>,
+    /// Why the probe failed, e.g. a DNS, connection, or certificate error.
+    pub error: Option<String>,
+    /// Fields sent by the API that this version of the SDK does not know
+    /// about yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl WebhookUrlValidation {
+    /// Creates a validation result with the other fields empty. Set them as
+    /// needed, e.g. for a mock [`WebhooksApi`].
+    pub fn new(url: impl Into<String>, reachable: bool) -> Self {
+        Self {
+            url: url.into(),
+            reachable,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether deliveries to the destination should succeed: it is
+    /// reachable and its TLS certificate, if any, is valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lettr::webhooks::WebhookUrlValidation;
+    ///
+    /// let mut probe = WebhookUrlValidation::new("https://example.com/hook", true);
+    /// assert!(probe.is_valid());
+    ///
+    /// probe.tls_valid = Some(false);
+    /// assert!(!probe.is_valid());
+    /// ```
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.reachable && self.tls_valid != Some(false)
+    }
+}
+
+/// Formats as a one-line summary, e.g.
+/// `https://example.com/hook: reachable (200, 85 ms)` or
+/// `https://example.com/hook: unreachable (connection refused)`.
+impl fmt::Display for WebhookUrlValidation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.reachable {
+            "reachable"
+        } else {
+            "unreachable"
+        };
+        write!(f, "{}: {state}", self.url)?;
+        let mut details = Vec::new();
+        if let Some(status) = self.status_code {
+            details.push(status.to_string());
+        }
+        if let Some(latency) = self.latency_ms {
+            details.push(format!("{latency} ms"));
+        }
+        if self.tls_valid == Some(false) {
+            details.push("invalid TLS certificate".to_owned());
+        }
+        if let Some(error) = &self.error {
+            details.push(error.clone());
+        }
+        if !details.is_empty() {
+            write!(f, " ({})", details.join(", "))?;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,132 @@
+//! Debug logging of HTTP traffic with sensitive data redacted.
+//!
+//! Enabled with [`LettrBuilder::wire_logging`](crate::LettrBuilder::wire_logging).
+//! Records are emitted through the [`log`] facade at debug level under the
+//! `lettr::wire` target.
+
+use reqwest::header::{HeaderMap, AUTHORIZATION};
+use reqwest::{Method, StatusCode, Url};
+use serde_json::Value;
+
+const TARGET: &str = "lettr::wire";
+
+/// Bodies longer than this are truncated in the log.
+const MAX_BODY_LEN: usize = 4096;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Returns `true` if wire records would be emitted by the installed logger.
+pub(crate) fn enabled() -> bool {
+    log::log_enabled!(target: TARGET, log::Level::Debug)
+}
+
+/// Log an outgoing request.
+pub(crate) fn log_request(method: &Method, url: &Url, headers: &HeaderMap, body: Option<&[u8]>) {
+    log::debug!(
+        target: TARGET,
+        "--> {method} {url}\n{}{}",
+        format_headers(headers),
+        body.map(format_body).unwrap_or_default(),
+    );
+}
+
+/// Log the status line and headers of a response.
+pub(crate) fn log_response(status: StatusCode, url: &Url, headers: &HeaderMap) {
+    log::debug!(
+        target: TARGET,
+        "<-- {status} {url}\n{}",
+        format_headers(headers),
+    );
+}
+
+/// Log a response body.
+pub(crate) fn log_response_body(body: &[u8]) {
+    log::debug!(target: TARGET, "<-- body\n{}", format_body(body));
+}
+
+fn format_headers(headers: &HeaderMap) -> String {
+    let mut out = String::new();
+    for (name, value) in headers {
+        let value = if name == AUTHORIZATION {
+            REDACTED
+        } else {
+            value.to_str().unwrap_or("<binary>")
+        };
+        out.push_str(&format!("{name}: {value}\n"));
+    }
+    out
+}
+
+fn format_body(body: &[u8]) -> String {
+    let text = match serde_json::from_slice::<Value>(body) {
+        Ok(mut json) => {
+            redact(&mut json);
+            json.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    };
+    truncate(text)
+}
+
+/// Replace attachment contents and substitution values, which may carry
+/// personal data, and [secrets](redact_secrets) with placeholders.
+pub(crate) fn redact(json: &mut Value) {
+    redact_secrets(json);
+    let Value::Object(map) = json else {
+        return;
+    };
+
+    if let Some(Value::Array(attachments)) = map.get_mut("attachments") {
+        for attachment in attachments {
+            if let Some(data) = attachment.get_mut("data") {
+                let len = data.as_str().map_or(0, str::len);
+                *data = Value::String(format!("[REDACTED {len} bytes]"));
+            }
+        }
+    }
+    if let Some(Value::Object(substitutions)) = map.get_mut("substitution_data") {
+        for value in substitutions.values_mut() {
+            *value = Value::String(REDACTED.to_owned());
+        }
+    }
+}
+
+/// Replace passwords and secrets returned by the API, such as new SMTP
+/// credentials and webhook signing secrets, with a placeholder. Returns
+/// `true` if anything was replaced.
+pub(crate) fn redact_secrets(json: &mut Value) -> bool {
+    let Value::Object(map) = json else {
+        return false;
+    };
+
+    let mut redacted = false;
+    if let Some(Value::Object(data)) = map.get_mut("data") {
+        redacted |= redact_credentials(data);
+    }
+    redacted |= redact_credentials(map);
+    redacted
+}
+
+fn redact_credentials(object: &mut serde_json::Map<String, Value>) -> bool {
+    let mut redacted = false;
+    for key in ["password", "secret"] {
+        if let Some(value) = object.get_mut(key) {
+            *value = Value::String(REDACTED.to_owned());
+            redacted = true;
+        }
+    }
+    redacted
+}
+
+fn truncate(mut text: String) -> String {
+    if text.len() > MAX_BODY_LEN {
+        let mut end = MAX_BODY_LEN;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let omitted = text.len() - end;
+        text.truncate(end);
+        text.push_str(&format!("... ({omitted} more bytes)"));
+    }
+    text
+}